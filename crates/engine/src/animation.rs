@@ -1,10 +1,34 @@
 use crate::sprite::SpriteData;
 
+/// Gameplay cue fired when an animation enters a frame.
+/// String tags are interpreted by the game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimEvent {
+    HitboxOn,
+    HitboxOff,
+    SpawnParticle(&'static str),
+    PlaySound(&'static str),
+    Footstep,
+}
+
+/// An event bound to a frame index of an animation.
+pub struct FrameEvent {
+    pub frame: usize,
+    pub event: AnimEvent,
+}
+
+impl FrameEvent {
+    pub const fn new(frame: usize, event: AnimEvent) -> Self {
+        Self { frame, event }
+    }
+}
+
 /// A sequence of sprite frames with timing.
 pub struct AnimationData {
     pub frames: &'static [&'static SpriteData],
     pub frame_duration: f64,
     pub looping: bool,
+    pub events: &'static [FrameEvent],
 }
 
 /// Runtime animation state.
@@ -14,6 +38,12 @@ pub struct AnimationPlayer {
     elapsed: f64,
     finished: bool,
     flipped: bool,
+    /// Frame 0 has not been announced yet (set on new/play)
+    enter_pending: bool,
+    /// Events fired during the last `update`
+    fired: Vec<AnimEvent>,
+    /// Latched by HitboxOn/HitboxOff, cleared when the animation changes
+    hitbox_on: bool,
 }
 
 impl AnimationPlayer {
@@ -24,6 +54,9 @@ impl AnimationPlayer {
             elapsed: 0.0,
             finished: false,
             flipped: false,
+            enter_pending: true,
+            fired: Vec::new(),
+            hitbox_on: false,
         }
     }
 
@@ -37,10 +70,19 @@ impl AnimationPlayer {
         self.current_frame = 0;
         self.elapsed = 0.0;
         self.finished = false;
+        self.enter_pending = true;
+        self.hitbox_on = false;
     }
 
     /// Advance animation by dt seconds.
+    /// Events for every frame entered during this step (including frame 0
+    /// after `play`) are available from `events` until the next update.
     pub fn update(&mut self, dt: f64) {
+        self.fired.clear();
+        if self.enter_pending {
+            self.enter_pending = false;
+            self.fire_frame(0);
+        }
         if self.finished {
             return;
         }
@@ -57,9 +99,34 @@ impl AnimationPlayer {
                     return;
                 }
             }
+            self.fire_frame(self.current_frame);
+        }
+    }
+
+    fn fire_frame(&mut self, frame: usize) {
+        for fe in self.current_animation.events {
+            if fe.frame != frame {
+                continue;
+            }
+            match fe.event {
+                AnimEvent::HitboxOn => self.hitbox_on = true,
+                AnimEvent::HitboxOff => self.hitbox_on = false,
+                _ => {}
+            }
+            self.fired.push(fe.event);
         }
     }
 
+    /// Events fired by the last `update`, in frame order.
+    pub fn events(&self) -> &[AnimEvent] {
+        &self.fired
+    }
+
+    /// True between a HitboxOn and HitboxOff event of the current animation.
+    pub fn hitbox_active(&self) -> bool {
+        self.hitbox_on
+    }
+
     /// Get the current frame's sprite data.
     /// Returns the last frame if index is somehow out of bounds (safety guard).
     pub fn current_sprite(&self) -> &'static SpriteData {
@@ -122,12 +189,26 @@ mod tests {
         frames: &FRAMES_3,
         frame_duration: 0.1,
         looping: true,
+        events: &[],
     };
 
     static ANIM_ONESHOT: AnimationData = AnimationData {
         frames: &FRAMES_3,
         frame_duration: 0.1,
         looping: false,
+        events: &[],
+    };
+
+    static ANIM_EVENTS: AnimationData = AnimationData {
+        frames: &FRAMES_3,
+        frame_duration: 0.1,
+        looping: true,
+        events: &[
+            FrameEvent::new(0, AnimEvent::PlaySound("swing")),
+            FrameEvent::new(1, AnimEvent::HitboxOn),
+            FrameEvent::new(2, AnimEvent::HitboxOff),
+            FrameEvent::new(2, AnimEvent::Footstep),
+        ],
     };
 
     static FRAMES_ALT: [&SpriteData; 2] = [&SPRITE_B, &SPRITE_A];
@@ -136,6 +217,7 @@ mod tests {
         frames: &FRAMES_ALT,
         frame_duration: 0.2,
         looping: true,
+        events: &[],
     };
 
     #[test]
@@ -416,4 +498,99 @@ mod tests {
             "Accumulated small dt values should advance the frame"
         );
     }
+
+    #[test]
+    fn test_frame_zero_events_fire_on_first_update() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+
+        // Act
+        player.update(0.0);
+
+        // Assert
+        assert_eq!(
+            player.events(),
+            &[AnimEvent::PlaySound("swing")],
+            "Entering frame 0 should fire its events"
+        );
+    }
+
+    #[test]
+    fn test_events_fire_when_frame_is_entered() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+        player.update(0.0);
+
+        // Act
+        player.update(0.1);
+
+        // Assert
+        assert_eq!(player.events(), &[AnimEvent::HitboxOn]);
+        assert!(player.hitbox_active(), "HitboxOn should latch the hitbox");
+    }
+
+    #[test]
+    fn test_events_are_cleared_on_next_update() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+        player.update(0.1);
+
+        // Act: no frame change
+        player.update(0.01);
+
+        // Assert
+        assert!(player.events().is_empty(), "Events should only last one update");
+        assert!(player.hitbox_active(), "Hitbox latch should persist between updates");
+    }
+
+    #[test]
+    fn test_skipped_frames_fire_all_events_in_order() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+
+        // Act: jump from frame 0 straight to frame 2
+        player.update(0.2);
+
+        // Assert
+        assert_eq!(
+            player.events(),
+            &[
+                AnimEvent::PlaySound("swing"),
+                AnimEvent::HitboxOn,
+                AnimEvent::HitboxOff,
+                AnimEvent::Footstep,
+            ]
+        );
+        assert!(!player.hitbox_active(), "HitboxOff should clear the latch");
+    }
+
+    #[test]
+    fn test_looping_refires_frame_zero_events() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+        player.update(0.2);
+
+        // Act: wrap from frame 2 back to frame 0
+        player.update(0.1);
+
+        // Assert
+        assert_eq!(player.events(), &[AnimEvent::PlaySound("swing")]);
+    }
+
+    #[test]
+    fn test_play_new_animation_clears_hitbox() {
+        // Arrange
+        let mut player = AnimationPlayer::new(&ANIM_EVENTS);
+        player.update(0.1);
+        assert!(player.hitbox_active());
+
+        // Act
+        player.play(&ANIM_ALT);
+
+        // Assert
+        assert!(
+            !player.hitbox_active(),
+            "Switching animation should drop an open hitbox window"
+        );
+    }
 }
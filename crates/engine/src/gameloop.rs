@@ -4,6 +4,7 @@ use crossterm::event;
 
 use crate::framebuffer::FrameBuffer;
 use crate::input::InputState;
+use crate::profiler;
 use crate::renderer::RenderStats;
 use crate::Terminal;
 
@@ -61,8 +62,10 @@ pub fn run(term: &mut Terminal, game: &mut dyn Game) {
         let frame_time = frame_time.min(MAX_FRAME_TIME);
         current_time = new_time;
         accumulator += frame_time;
+        profiler::begin_frame();
 
         // --- Input phase ---
+        let input_scope = profiler::scope("input");
         let input_start = Instant::now();
         term.input.begin_frame();
         events.clear();
@@ -76,8 +79,10 @@ pub fn run(term: &mut Terminal, game: &mut dyn Game) {
         }
         term.input.process_events(&events);
         last_input_us = input_start.elapsed().as_micros() as u64;
+        drop(input_scope);
 
         // --- Update phase (fixed-timestep) ---
+        let update_scope = profiler::scope("update");
         let update_start = Instant::now();
         while accumulator >= TICK_RATE {
             if !game.update(&term.input, TICK_RATE) {
@@ -86,11 +91,13 @@ pub fn run(term: &mut Terminal, game: &mut dyn Game) {
             accumulator -= TICK_RATE;
         }
         last_update_us = update_start.elapsed().as_micros() as u64;
+        drop(update_scope);
 
         // Interpolation factor for smooth rendering
         let alpha = (accumulator / TICK_RATE) as f32;
 
         // --- Render phase ---
+        let render_scope = profiler::scope("render");
         let render_start = Instant::now();
         term.fb.clear();
 
@@ -109,6 +116,8 @@ pub fn run(term: &mut Terminal, game: &mut dyn Game) {
             last_stats = stats;
         }
         last_render_us = render_start.elapsed().as_micros() as u64;
+        drop(render_scope);
+        profiler::end_frame();
 
         // FPS calculation
         fps_frame_count += 1;
@@ -17,6 +17,12 @@ pub enum GameKey {
     Pause,
     Map,
     Quit,
+    /// Toggle the profiler overlay (F3).
+    Profiler,
+    /// Write the profiler trace to disk (F4).
+    TraceDump,
+    /// Delete the last typed character (Backspace).
+    Erase,
 }
 
 pub struct InputState {
@@ -26,6 +32,8 @@ pub struct InputState {
     held: HashMap<GameKey, Instant>,
     /// Keys that timed out (inferred release) this frame.
     released: HashSet<GameKey>,
+    /// Characters typed this frame, in order, for text entry.
+    typed: Vec<char>,
 }
 
 impl InputState {
@@ -34,15 +42,17 @@ impl InputState {
             pressed: HashSet::new(),
             held: HashMap::new(),
             released: HashSet::new(),
+            typed: Vec::new(),
         }
     }
 
     /// Call at the start of each frame before processing events.
     /// Clears per-frame state and checks for timed-out held keys.
-    /// Zero-allocation: uses a fixed-size stack buffer (max 8 game keys).
+    /// Zero-allocation: uses a fixed-size stack buffer (max 16 game keys).
     pub fn begin_frame(&mut self) {
         self.pressed.clear();
         self.released.clear();
+        self.typed.clear();
 
         let now = Instant::now();
 
@@ -58,7 +68,7 @@ impl InputState {
             .filter_map(|k| self.held.get(k).copied())
             .max();
 
-        let mut timed_out = [None; 8];
+        let mut timed_out = [None; 16];
         let mut count = 0;
         for (&key, &last_seen) in &self.held {
             let effective_time = if dir_keys.contains(&key) {
@@ -84,6 +94,9 @@ impl InputState {
     pub fn process_events(&mut self, events: &[Event]) {
         for evt in events {
             if let Event::Key(KeyEvent { code, .. }) = evt {
+                if let KeyCode::Char(c) = code {
+                    self.typed.push(*c);
+                }
                 if let Some(game_key) = map_key(*code) {
                     if self.held.contains_key(&game_key) {
                         // Already held — update timestamp
@@ -110,6 +123,12 @@ impl InputState {
         self.held.contains_key(&key)
     }
 
+    /// Characters typed this frame. Letters bound to game keys show up here
+    /// as well as pressing their key.
+    pub fn typed(&self) -> &[char] {
+        &self.typed
+    }
+
     /// True on the frame the key times out (inferred release).
     pub fn is_released(&self, key: GameKey) -> bool {
         self.released.contains(&key)
@@ -163,6 +182,9 @@ fn map_key(code: KeyCode) -> Option<GameKey> {
         KeyCode::Esc => Some(GameKey::Pause),
         KeyCode::Tab => Some(GameKey::Map),
         KeyCode::Char('q') => Some(GameKey::Quit),
+        KeyCode::F(3) => Some(GameKey::Profiler),
+        KeyCode::F(4) => Some(GameKey::TraceDump),
+        KeyCode::Backspace => Some(GameKey::Erase),
         _ => None,
     }
 }
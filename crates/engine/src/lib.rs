@@ -6,6 +6,7 @@ pub mod framebuffer;
 pub mod gameloop;
 pub mod input;
 pub mod particle;
+pub mod profiler;
 pub mod renderer;
 pub mod sprite;
 pub mod tilemap;
@@ -15,14 +16,15 @@ use std::io;
 
 use crossterm::{cursor, event, execute, terminal};
 
-pub use animation::{AnimationData, AnimationPlayer};
+pub use animation::{AnimEvent, AnimationData, AnimationPlayer, FrameEvent};
 pub use camera::Camera;
 pub use collision::AABB;
 pub use color::Color;
 pub use framebuffer::FrameBuffer;
-pub use particle::{BurstConfig, Particle, ParticleSystem};
 pub use gameloop::{FrameInfo, Game};
 pub use input::{GameKey, InputState};
+pub use particle::{BurstConfig, EmitterId, Particle, ParticleSystem};
+pub use profiler::ProfileSnapshot;
 pub use renderer::{RenderStats, Renderer};
 pub use sprite::SpriteData;
 pub use tilemap::{render_tilemap, TileMap, TileType, TILE_SIZE};
@@ -1,5 +1,8 @@
+use std::collections::VecDeque;
+
 use crate::color::Color;
 use crate::framebuffer::FrameBuffer;
+use crate::tilemap::TileMap;
 
 /// A single particle with physics and lifetime.
 pub struct Particle {
@@ -12,6 +15,13 @@ pub struct Particle {
     pub max_lifetime: f32,
     pub gravity: f32,
     pub friction: f32,
+    /// Color stops sampled over lifetime. Empty = `color` with age fade.
+    pub gradient: &'static [Color],
+    /// Square size in pixels at birth and at death, interpolated linearly.
+    pub size_start: f32,
+    pub size_end: f32,
+    /// Velocity kept when hitting a solid tile. `None` = passes through walls.
+    pub bounce: Option<f32>,
 }
 
 impl Particle {
@@ -23,6 +33,42 @@ impl Particle {
     pub fn age_ratio(&self) -> f32 {
         self.lifetime / self.max_lifetime
     }
+
+    /// Color at the current age: gradient sample, or base color faded by age.
+    pub fn current_color(&self) -> Color {
+        let t = self.age_ratio().clamp(0.0, 1.0);
+        if !self.gradient.is_empty() {
+            return sample_gradient(self.gradient, t);
+        }
+        let fade = 1.0 - t;
+        [
+            (self.color[0] as f32 * fade) as u8,
+            (self.color[1] as f32 * fade) as u8,
+            (self.color[2] as f32 * fade) as u8,
+        ]
+    }
+
+    /// Size in pixels at the current age.
+    pub fn current_size(&self) -> f32 {
+        let t = self.age_ratio().clamp(0.0, 1.0);
+        self.size_start + (self.size_end - self.size_start) * t
+    }
+}
+
+/// Linearly interpolate between evenly spaced color stops. `t` in [0, 1].
+fn sample_gradient(stops: &[Color], t: f32) -> Color {
+    if stops.len() == 1 {
+        return stops[0];
+    }
+    let pos = t * (stops.len() - 1) as f32;
+    let i = (pos.floor() as usize).min(stops.len() - 2);
+    let f = pos - i as f32;
+    let (a, b) = (stops[i], stops[i + 1]);
+    [
+        (a[0] as f32 + (b[0] as f32 - a[0] as f32) * f) as u8,
+        (a[1] as f32 + (b[1] as f32 - a[1] as f32) * f) as u8,
+        (a[2] as f32 + (b[2] as f32 - a[2] as f32) * f) as u8,
+    ]
 }
 
 /// Configuration for spawning a burst of particles.
@@ -40,11 +86,37 @@ pub struct BurstConfig {
     pub angle_spread: f32,
     /// Base angle in radians. 0 = right, PI/2 = down, PI = left, etc.
     pub base_angle: f32,
+    /// Color over lifetime. When non-empty, replaces `colors` and the age fade.
+    pub gradient: &'static [Color],
+    /// Pixel size at birth and death. 1.0 = single pixel.
+    pub size_start: f32,
+    pub size_end: f32,
+    /// Fraction of velocity kept on a wall bounce. Only applies when the
+    /// system is updated with a tilemap; `None` ignores walls.
+    pub bounce: Option<f32>,
+}
+
+/// Handle to a continuous emitter owned by a `ParticleSystem`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmitterId(usize);
+
+/// Spawns particles continuously at `rate` per second while active.
+/// Each emission is a single particle drawn from `config`; the burst
+/// count range is ignored.
+struct Emitter {
+    x: f32,
+    y: f32,
+    config: &'static BurstConfig,
+    rate: f32,
+    accumulator: f32,
+    active: bool,
 }
 
 /// Manages a pool of particles with update and render.
 pub struct ParticleSystem {
-    particles: Vec<Particle>,
+    particles: VecDeque<Particle>,
+    emitters: Vec<Option<Emitter>>,
+    budget: usize,
     rng_state: u32,
 }
 
@@ -53,78 +125,209 @@ const MAX_PARTICLES: usize = 500;
 impl ParticleSystem {
     pub fn new() -> Self {
         Self {
-            particles: Vec::with_capacity(256),
+            particles: VecDeque::with_capacity(256),
+            emitters: Vec::new(),
+            budget: MAX_PARTICLES,
             rng_state: 98765,
         }
     }
 
     /// Spawn a burst of particles at the given world position.
+    /// When the budget is full, the oldest particles are culled to make room.
     pub fn burst(&mut self, x: f32, y: f32, config: &BurstConfig) {
         let count = self.rand_range(config.count_min, config.count_max);
         for _ in 0..count {
-            if self.particles.len() >= MAX_PARTICLES {
-                break;
+            self.spawn_one(x, y, config);
+        }
+    }
+
+    fn spawn_one(&mut self, x: f32, y: f32, config: &BurstConfig) {
+        // Random angle within spread
+        let angle_offset = self.rand_float() * config.angle_spread - config.angle_spread * 0.5;
+        let angle = config.base_angle + angle_offset;
+
+        // Random speed
+        let speed = config.speed_min + self.rand_float() * (config.speed_max - config.speed_min);
+
+        // Random lifetime
+        let lifetime_max =
+            config.lifetime_min + self.rand_float() * (config.lifetime_max - config.lifetime_min);
+
+        // Random color from palette
+        let palette_len = config.colors.len().max(1);
+        let color = config.colors[self.rand_u32() as usize % palette_len];
+
+        self.push(Particle {
+            x,
+            y,
+            vx: angle.cos() * speed,
+            vy: angle.sin() * speed,
+            color,
+            lifetime: 0.0,
+            max_lifetime: lifetime_max,
+            gravity: config.gravity,
+            friction: config.friction,
+            gradient: config.gradient,
+            size_start: config.size_start,
+            size_end: config.size_end,
+            bounce: config.bounce,
+        });
+    }
+
+    /// Add a particle, culling the oldest ones if the budget is exhausted.
+    fn push(&mut self, particle: Particle) {
+        if self.budget == 0 {
+            return;
+        }
+        while self.particles.len() >= self.budget {
+            self.particles.pop_front();
+        }
+        self.particles.push_back(particle);
+    }
+
+    /// Set the hard particle cap. Excess particles are culled oldest-first.
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        while self.particles.len() > budget {
+            self.particles.pop_front();
+        }
+    }
+
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    // --- Continuous emitters ---
+
+    /// Register an emitter that spawns `rate` particles per second at (x, y).
+    /// Emitters start active and live until `remove_emitter`.
+    pub fn add_emitter(
+        &mut self,
+        x: f32,
+        y: f32,
+        config: &'static BurstConfig,
+        rate: f32,
+    ) -> EmitterId {
+        let emitter = Emitter {
+            x,
+            y,
+            config,
+            rate,
+            accumulator: 0.0,
+            active: true,
+        };
+        if let Some(slot) = self.emitters.iter().position(|e| e.is_none()) {
+            self.emitters[slot] = Some(emitter);
+            EmitterId(slot)
+        } else {
+            self.emitters.push(Some(emitter));
+            EmitterId(self.emitters.len() - 1)
+        }
+    }
+
+    /// Move an emitter, e.g. to follow an entity each tick.
+    pub fn set_emitter_position(&mut self, id: EmitterId, x: f32, y: f32) {
+        if let Some(Some(e)) = self.emitters.get_mut(id.0) {
+            e.x = x;
+            e.y = y;
+        }
+    }
+
+    /// Pause or resume an emitter without removing it.
+    pub fn set_emitter_active(&mut self, id: EmitterId, active: bool) {
+        if let Some(Some(e)) = self.emitters.get_mut(id.0) {
+            if !active {
+                e.accumulator = 0.0;
             }
+            e.active = active;
+        }
+    }
 
-            // Random angle within spread
-            let angle_offset = self.rand_float() * config.angle_spread - config.angle_spread * 0.5;
-            let angle = config.base_angle + angle_offset;
-
-            // Random speed
-            let speed =
-                config.speed_min + self.rand_float() * (config.speed_max - config.speed_min);
-
-            // Random lifetime
-            let lifetime_max = config.lifetime_min
-                + self.rand_float() * (config.lifetime_max - config.lifetime_min);
-
-            // Random color from palette
-            let palette_len = config.colors.len().max(1);
-            let color = config.colors[self.rand_u32() as usize % palette_len];
-
-            self.particles.push(Particle {
-                x,
-                y,
-                vx: angle.cos() * speed,
-                vy: angle.sin() * speed,
-                color,
-                lifetime: 0.0,
-                max_lifetime: lifetime_max,
-                gravity: config.gravity,
-                friction: config.friction,
-            });
+    /// Remove an emitter. Particles it already spawned live out their lifetime.
+    pub fn remove_emitter(&mut self, id: EmitterId) {
+        if let Some(slot) = self.emitters.get_mut(id.0) {
+            *slot = None;
         }
     }
 
+    /// Number of registered emitters.
+    pub fn emitter_count(&self) -> usize {
+        self.emitters.iter().filter(|e| e.is_some()).count()
+    }
+
     /// Update all particles: apply physics, age, and remove dead ones.
+    /// Active emitters spawn their particles afterwards.
     pub fn update(&mut self, dt: f32) {
+        self.step(dt, None);
+    }
+
+    /// Like `update`, but particles with `bounce` set rebound off solid tiles.
+    pub fn update_with_tilemap(&mut self, dt: f32, tilemap: &TileMap) {
+        self.step(dt, Some(tilemap));
+    }
+
+    fn step(&mut self, dt: f32, tilemap: Option<&TileMap>) {
         for p in &mut self.particles {
             p.vx *= p.friction.powf(dt * 30.0);
             p.vy *= p.friction.powf(dt * 30.0);
             p.vy += p.gravity * dt;
-            p.x += p.vx * dt;
-            p.y += p.vy * dt;
+            match (p.bounce, tilemap) {
+                (Some(restitution), Some(map)) => {
+                    // Resolve each axis separately so glancing hits slide
+                    let nx = p.x + p.vx * dt;
+                    if map.collides(nx, p.y, 1.0, 1.0) {
+                        p.vx = -p.vx * restitution;
+                    } else {
+                        p.x = nx;
+                    }
+                    let ny = p.y + p.vy * dt;
+                    if map.collides(p.x, ny, 1.0, 1.0) {
+                        p.vy = -p.vy * restitution;
+                    } else {
+                        p.y = ny;
+                    }
+                }
+                _ => {
+                    p.x += p.vx * dt;
+                    p.y += p.vy * dt;
+                }
+            }
             p.lifetime += dt;
         }
         self.particles.retain(|p| p.alive());
+
+        for i in 0..self.emitters.len() {
+            let (x, y, config, count) = match &mut self.emitters[i] {
+                Some(e) if e.active => {
+                    e.accumulator += e.rate * dt;
+                    let count = e.accumulator.floor();
+                    e.accumulator -= count;
+                    (e.x, e.y, e.config, count as u32)
+                }
+                _ => continue,
+            };
+            for _ in 0..count {
+                self.spawn_one(x, y, config);
+            }
+        }
     }
 
     /// Render all particles to the framebuffer.
-    /// Uses lifetime-based fade: particles get dimmer as they age.
+    /// Color and size follow each particle's over-lifetime curves.
     pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
         for p in &self.particles {
-            let sx = p.x as i32 - cam_x;
-            let sy = p.y as i32 - cam_y;
-
-            // Fade based on age
-            let fade = 1.0 - p.age_ratio();
-            let c = [
-                (p.color[0] as f32 * fade) as u8,
-                (p.color[1] as f32 * fade) as u8,
-                (p.color[2] as f32 * fade) as u8,
-            ];
-
-            fb.set_pixel_safe(sx, sy, c);
+            let size = p.current_size().round() as i32;
+            if size <= 0 {
+                continue;
+            }
+            let c = p.current_color();
+            let sx = p.x as i32 - cam_x - (size - 1) / 2;
+            let sy = p.y as i32 - cam_y - (size - 1) / 2;
+            for dy in 0..size {
+                for dx in 0..size {
+                    fb.set_pixel_safe(sx + dx, sy + dy, c);
+                }
+            }
         }
     }
 
@@ -133,7 +336,12 @@ impl ParticleSystem {
         self.particles.len()
     }
 
-    /// Clear all particles.
+    /// Iterate live particles, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Particle> {
+        self.particles.iter()
+    }
+
+    /// Clear all particles. Emitters are kept.
     pub fn clear(&mut self) {
         self.particles.clear();
     }
@@ -186,6 +394,10 @@ mod tests {
             friction: 1.0, // no friction
             angle_spread: std::f32::consts::TAU,
             base_angle: 0.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         }
     }
 
@@ -203,6 +415,10 @@ mod tests {
             friction: 1.0,
             angle_spread: std::f32::consts::TAU,
             base_angle: 0.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         }
     }
 
@@ -228,6 +444,10 @@ mod tests {
             max_lifetime: 1.0,
             gravity: 0.0,
             friction: 1.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         // Act & Assert
@@ -247,6 +467,10 @@ mod tests {
             max_lifetime: 1.0,
             gravity: 0.0,
             friction: 1.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         // Act & Assert
@@ -269,6 +493,10 @@ mod tests {
             max_lifetime: 1.0,
             gravity: 0.0,
             friction: 1.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         // Act & Assert
@@ -291,6 +519,10 @@ mod tests {
             max_lifetime: 1.0,
             gravity: 0.0,
             friction: 1.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         // Act
@@ -440,6 +672,10 @@ mod tests {
             friction: 1.0, // no friction
             angle_spread: 0.0, // no spread - exactly base_angle direction
             base_angle: 0.0,   // rightward
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
         ps.burst(0.0, 0.0, &config);
 
@@ -472,6 +708,10 @@ mod tests {
             friction: 1.0,
             angle_spread: 0.0,
             base_angle: 0.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         let config_grav = BurstConfig {
@@ -486,6 +726,10 @@ mod tests {
             friction: 1.0,
             angle_spread: 0.0,
             base_angle: 0.0,
+            gradient: &[],
+            size_start: 1.0,
+            size_end: 1.0,
+            bounce: None,
         };
 
         ps_no_gravity.burst(50.0, 50.0, &config_no_grav);
@@ -550,4 +794,304 @@ mod tests {
         // Assert
         assert_eq!(ps.count(), 0, "Default particle system should be empty");
     }
+
+    /// Helper: a stationary particle with the given curves.
+    fn curve_particle(gradient: &'static [Color], size_start: f32, size_end: f32) -> Particle {
+        Particle {
+            x: 0.0,
+            y: 0.0,
+            vx: 0.0,
+            vy: 0.0,
+            color: [255, 255, 255],
+            lifetime: 0.0,
+            max_lifetime: 1.0,
+            gravity: 0.0,
+            friction: 1.0,
+            gradient,
+            size_start,
+            size_end,
+            bounce: None,
+        }
+    }
+
+    static EMIT_CONFIG: BurstConfig = BurstConfig {
+        count_min: 99,
+        count_max: 99,
+        speed_min: 0.0,
+        speed_max: 0.0,
+        lifetime_min: 10.0,
+        lifetime_max: 10.0,
+        colors: &[[255, 255, 255]],
+        gravity: 0.0,
+        friction: 1.0,
+        angle_spread: 0.0,
+        base_angle: 0.0,
+        gradient: &[],
+        size_start: 1.0,
+        size_end: 1.0,
+        bounce: None,
+    };
+
+    #[test]
+    fn test_gradient_interpolates_between_stops() {
+        // Arrange
+        let mut p = curve_particle(&[[0, 0, 0], [200, 100, 0], [0, 0, 200]], 1.0, 1.0);
+
+        // Act & Assert: start, first stop midpoint, middle stop, end
+        assert_eq!(p.current_color(), [0, 0, 0], "Birth should use first stop");
+        p.lifetime = 0.25;
+        assert_eq!(
+            p.current_color(),
+            [100, 50, 0],
+            "Quarter age is halfway to stop 2"
+        );
+        p.lifetime = 0.5;
+        assert_eq!(
+            p.current_color(),
+            [200, 100, 0],
+            "Half age should hit middle stop"
+        );
+        p.lifetime = 1.0;
+        assert_eq!(p.current_color(), [0, 0, 200], "Death should use last stop");
+    }
+
+    #[test]
+    fn test_empty_gradient_fades_base_color() {
+        // Arrange
+        let mut p = curve_particle(&[], 1.0, 1.0);
+        p.color = [200, 100, 50];
+        p.lifetime = 0.5;
+
+        // Act
+        let c = p.current_color();
+
+        // Assert
+        assert_eq!(
+            c,
+            [100, 50, 25],
+            "Without gradient, color should fade with age"
+        );
+    }
+
+    #[test]
+    fn test_size_over_lifetime() {
+        // Arrange
+        let mut p = curve_particle(&[], 3.0, 1.0);
+
+        // Act
+        p.lifetime = 0.5;
+
+        // Assert
+        assert!(
+            (p.current_size() - 2.0).abs() < f32::EPSILON,
+            "Size should interpolate from 3 to 1, got {}",
+            p.current_size()
+        );
+    }
+
+    #[test]
+    fn test_render_draws_square_for_large_particles() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        let mut p = curve_particle(&[[255, 0, 0]], 3.0, 3.0);
+        p.x = 5.0;
+        p.y = 5.0;
+        ps.push(p);
+        let mut fb = FrameBuffer::new(10, 5);
+
+        // Act
+        ps.render(&mut fb, 0, 0);
+
+        // Assert: 3x3 centered on (5, 5)
+        for y in 4..=6 {
+            for x in 4..=6 {
+                assert_eq!(
+                    fb.get_pixel(x, y),
+                    Some([255, 0, 0]),
+                    "Pixel ({x}, {y}) should be drawn"
+                );
+            }
+        }
+        assert_ne!(
+            fb.get_pixel(7, 5),
+            Some([255, 0, 0]),
+            "Pixel outside square should be empty"
+        );
+    }
+
+    #[test]
+    fn test_budget_culls_oldest_first() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        ps.burst(0.0, 0.0, &test_burst_config(MAX_PARTICLES as u32));
+        let mut newer = test_burst_config(10);
+        newer.colors = &[[0, 255, 0]];
+
+        // Act
+        ps.burst(0.0, 0.0, &newer);
+
+        // Assert
+        let green = ps.iter().filter(|p| p.color == [0, 255, 0]).count();
+        assert_eq!(ps.count(), MAX_PARTICLES, "Count should stay at the budget");
+        assert_eq!(green, 10, "New particles should replace the oldest ones");
+    }
+
+    #[test]
+    fn test_set_budget_shrinks_pool() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        ps.burst(0.0, 0.0, &test_burst_config(50));
+
+        // Act
+        ps.set_budget(20);
+
+        // Assert
+        assert_eq!(
+            ps.count(),
+            20,
+            "Lowering the budget should cull excess particles"
+        );
+        assert_eq!(ps.budget(), 20);
+    }
+
+    #[test]
+    fn test_bounce_reflects_off_wall() {
+        // Arrange: wall column at tile x=2 (pixels 16..24)
+        let mut map = TileMap::new(4, 4);
+        for ty in 0..4 {
+            map.set(2, ty, crate::tilemap::TileType::Wall);
+        }
+        let mut ps = ParticleSystem::new();
+        let mut p = curve_particle(&[], 1.0, 1.0);
+        p.x = 14.0;
+        p.y = 10.0;
+        p.vx = 100.0;
+        p.max_lifetime = 10.0;
+        p.bounce = Some(0.5);
+        ps.push(p);
+
+        // Act
+        ps.update_with_tilemap(0.05, &map);
+
+        // Assert
+        let p = ps.iter().next().unwrap();
+        assert!(
+            p.x < 16.0,
+            "Particle should not enter the wall, x = {}",
+            p.x
+        );
+        assert!(
+            (p.vx + 50.0).abs() < 0.01,
+            "Velocity should reverse and halve, got {}",
+            p.vx
+        );
+    }
+
+    #[test]
+    fn test_update_without_tilemap_ignores_bounce() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        let mut p = curve_particle(&[], 1.0, 1.0);
+        p.vx = 100.0;
+        p.bounce = Some(0.5);
+        ps.push(p);
+
+        // Act
+        ps.update(0.5);
+
+        // Assert
+        let p = ps.iter().next().unwrap();
+        assert!((p.x - 50.0).abs() < 0.01, "Plain update should move freely");
+    }
+
+    #[test]
+    fn test_emitter_spawns_at_rate() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 30.0);
+
+        // Act: one second in 30 ticks
+        for _ in 0..30 {
+            ps.update(1.0 / 30.0);
+        }
+
+        // Assert: burst count range is ignored, one particle per emission
+        assert!(
+            (29..=30).contains(&ps.count()),
+            "30/s emitter should spawn ~30 particles in 1s, got {}",
+            ps.count()
+        );
+    }
+
+    #[test]
+    fn test_emitter_follows_position() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        let id = ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 10.0);
+
+        // Act
+        ps.set_emitter_position(id, 40.0, 20.0);
+        ps.update(0.1);
+
+        // Assert
+        let p = ps.iter().next().expect("Emitter should have spawned");
+        assert_eq!(
+            (p.x, p.y),
+            (40.0, 20.0),
+            "Particle should spawn at new position"
+        );
+    }
+
+    #[test]
+    fn test_inactive_emitter_spawns_nothing() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        let id = ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 100.0);
+        ps.set_emitter_active(id, false);
+
+        // Act
+        ps.update(1.0);
+
+        // Assert
+        assert_eq!(ps.count(), 0, "Paused emitter should not spawn particles");
+    }
+
+    #[test]
+    fn test_remove_emitter_frees_slot() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        let a = ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 10.0);
+        ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 10.0);
+
+        // Act
+        ps.remove_emitter(a);
+        let c = ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 10.0);
+
+        // Assert
+        assert_eq!(
+            ps.emitter_count(),
+            2,
+            "Removed emitter should not be counted"
+        );
+        assert_eq!(c, a, "Freed slot should be reused");
+    }
+
+    #[test]
+    fn test_clear_keeps_emitters() {
+        // Arrange
+        let mut ps = ParticleSystem::new();
+        ps.add_emitter(0.0, 0.0, &EMIT_CONFIG, 10.0);
+        ps.update(1.0);
+
+        // Act
+        ps.clear();
+
+        // Assert
+        assert_eq!(ps.count(), 0);
+        assert_eq!(
+            ps.emitter_count(),
+            1,
+            "clear() should only remove particles"
+        );
+    }
 }
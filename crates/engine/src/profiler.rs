@@ -0,0 +1,384 @@
+//! Lightweight frame profiler: named scoped timers, a rolling frame-time
+//! history for on-screen graphs, and a chrome://tracing JSON dump.
+//!
+//! A thread-local instance is driven by the game loop; instrument code with
+//! `let _t = profiler::scope("ai");`. Scopes cost one flag check while the
+//! profiler is disabled.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Write as FmtWrite;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+/// Frame times kept for the rolling graph.
+pub const HISTORY_LEN: usize = 120;
+/// Cap on buffered trace events (~a minute of heavily instrumented frames).
+const MAX_TRACE_EVENTS: usize = 50_000;
+
+/// One completed scope, in microseconds since the profiler epoch.
+#[derive(Clone, Copy, Debug)]
+pub struct TraceEvent {
+    pub name: &'static str,
+    pub start_us: u64,
+    pub dur_us: u64,
+    /// Nesting depth when the scope was opened.
+    pub depth: usize,
+}
+
+/// Copy of the profiler state for drawing an overlay.
+#[derive(Clone, Debug, Default)]
+pub struct ProfileSnapshot {
+    /// Per-scope totals for the last completed frame, in first-seen order.
+    pub scopes: Vec<(&'static str, u64)>,
+    /// Recent frame times in microseconds, oldest first.
+    pub frame_times: Vec<u64>,
+}
+
+pub struct Profiler {
+    enabled: bool,
+    epoch: Instant,
+    frame_start: Option<Instant>,
+    open: Vec<(&'static str, Instant)>,
+    current: Vec<(&'static str, u64)>,
+    last: Vec<(&'static str, u64)>,
+    history: VecDeque<u64>,
+    trace: VecDeque<TraceEvent>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            epoch: Instant::now(),
+            frame_start: None,
+            open: Vec::new(),
+            current: Vec::new(),
+            last: Vec::new(),
+            history: VecDeque::with_capacity(HISTORY_LEN),
+            trace: VecDeque::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enabling starts a fresh capture; disabling drops any open scopes.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled && !self.enabled {
+            self.history.clear();
+            self.trace.clear();
+            self.last.clear();
+        }
+        self.enabled = enabled;
+        self.open.clear();
+        self.current.clear();
+        self.frame_start = None;
+    }
+
+    pub fn begin_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        self.current.clear();
+        self.frame_start = Some(Instant::now());
+    }
+
+    /// Close the frame: publish scope totals and push the frame time.
+    pub fn end_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(start) = self.frame_start.take() {
+            if self.history.len() >= HISTORY_LEN {
+                self.history.pop_front();
+            }
+            self.history.push_back(start.elapsed().as_micros() as u64);
+        }
+        std::mem::swap(&mut self.last, &mut self.current);
+    }
+
+    pub fn begin(&mut self, name: &'static str) {
+        if self.enabled {
+            self.open.push((name, Instant::now()));
+        }
+    }
+
+    /// Close the innermost open scope.
+    pub fn end(&mut self) {
+        let Some((name, start)) = self.open.pop() else {
+            return;
+        };
+        let dur_us = start.elapsed().as_micros() as u64;
+        self.record(name, start, dur_us);
+    }
+
+    fn record(&mut self, name: &'static str, start: Instant, dur_us: u64) {
+        match self.current.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 += dur_us,
+            None => self.current.push((name, dur_us)),
+        }
+        if self.trace.len() >= MAX_TRACE_EVENTS {
+            self.trace.pop_front();
+        }
+        self.trace.push_back(TraceEvent {
+            name,
+            start_us: start.duration_since(self.epoch).as_micros() as u64,
+            dur_us,
+            depth: self.open.len(),
+        });
+    }
+
+    pub fn snapshot(&self) -> ProfileSnapshot {
+        ProfileSnapshot {
+            scopes: self.last.clone(),
+            frame_times: self.history.iter().copied().collect(),
+        }
+    }
+
+    /// Buffered scopes in the Chrome trace event format ("X" complete events).
+    pub fn chrome_trace_json(&self) -> String {
+        let mut out = String::with_capacity(64 + self.trace.len() * 80);
+        out.push_str("{\"traceEvents\":[");
+        for (i, ev) in self.trace.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"name\":\"{}\",\"cat\":\"frame\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1}}",
+                ev.name.escape_default(),
+                ev.start_us,
+                ev.dur_us
+            );
+        }
+        out.push_str("],\"displayTimeUnit\":\"ms\"}");
+        out
+    }
+
+    pub fn trace_len(&self) -> usize {
+        self.trace.len()
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::new());
+}
+
+/// Run `f` against the thread-local profiler.
+pub fn with<R>(f: impl FnOnce(&mut Profiler) -> R) -> R {
+    PROFILER.with(|p| f(&mut p.borrow_mut()))
+}
+
+pub fn is_enabled() -> bool {
+    with(|p| p.is_enabled())
+}
+
+pub fn set_enabled(enabled: bool) {
+    with(|p| p.set_enabled(enabled));
+}
+
+pub fn begin_frame() {
+    with(|p| p.begin_frame());
+}
+
+pub fn end_frame() {
+    with(|p| p.end_frame());
+}
+
+pub fn snapshot() -> ProfileSnapshot {
+    with(|p| p.snapshot())
+}
+
+/// Write the buffered trace to `path`; load it in chrome://tracing or Perfetto.
+pub fn write_chrome_trace(path: &Path) -> io::Result<()> {
+    let json = with(|p| p.chrome_trace_json());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, json)
+}
+
+/// RAII guard returned by `scope`; records the elapsed time on drop.
+pub struct Scope {
+    active: bool,
+}
+
+/// Time the enclosing block under `name`.
+#[must_use = "the scope ends when this guard is dropped"]
+pub fn scope(name: &'static str) -> Scope {
+    let active = with(|p| {
+        p.begin(name);
+        p.is_enabled()
+    });
+    Scope { active }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        if self.active {
+            with(|p| p.end());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin(us: u64) {
+        let start = Instant::now();
+        while start.elapsed().as_micros() < us as u128 {}
+    }
+
+    #[test]
+    fn test_disabled_profiler_records_nothing() {
+        // Arrange
+        let mut p = Profiler::new();
+
+        // Act
+        p.begin_frame();
+        p.begin("ai");
+        p.end();
+        p.end_frame();
+
+        // Assert
+        let snap = p.snapshot();
+        assert!(
+            snap.scopes.is_empty(),
+            "Disabled profiler should not record scopes"
+        );
+        assert!(
+            snap.frame_times.is_empty(),
+            "Disabled profiler should not record frames"
+        );
+        assert_eq!(p.trace_len(), 0);
+    }
+
+    #[test]
+    fn test_scopes_with_same_name_accumulate() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+
+        // Act
+        p.begin_frame();
+        p.begin("particles");
+        spin(200);
+        p.end();
+        p.begin("particles");
+        spin(200);
+        p.end();
+        p.end_frame();
+
+        // Assert
+        let snap = p.snapshot();
+        assert_eq!(snap.scopes.len(), 1, "Repeated scope should be merged");
+        assert!(
+            snap.scopes[0].1 >= 400,
+            "Merged total should include both scopes, got {}us",
+            snap.scopes[0].1
+        );
+        assert_eq!(p.trace_len(), 2, "Each scope is a separate trace event");
+    }
+
+    #[test]
+    fn test_snapshot_reports_last_completed_frame() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+        p.begin_frame();
+        p.begin("combat");
+        p.end();
+        p.end_frame();
+
+        // Act: next frame is in progress with a different scope
+        p.begin_frame();
+        p.begin("hud");
+        p.end();
+
+        // Assert
+        let names: Vec<_> = p.snapshot().scopes.iter().map(|s| s.0).collect();
+        assert_eq!(
+            names,
+            vec!["combat"],
+            "In-progress frame should not be visible"
+        );
+    }
+
+    #[test]
+    fn test_frame_history_is_capped() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+
+        // Act
+        for _ in 0..HISTORY_LEN + 10 {
+            p.begin_frame();
+            p.end_frame();
+        }
+
+        // Assert
+        assert_eq!(p.snapshot().frame_times.len(), HISTORY_LEN);
+    }
+
+    #[test]
+    fn test_nested_scopes_record_depth() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+
+        // Act
+        p.begin_frame();
+        p.begin("render");
+        p.begin("tilemap");
+        p.end();
+        p.end();
+        p.end_frame();
+
+        // Assert: inner scope closes first
+        let depths: Vec<_> = p.trace.iter().map(|e| (e.name, e.depth)).collect();
+        assert_eq!(depths, vec![("tilemap", 1), ("render", 0)]);
+    }
+
+    #[test]
+    fn test_chrome_trace_json_shape() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+        p.begin("ai");
+        p.end();
+
+        // Act
+        let json = p.chrome_trace_json();
+
+        // Assert
+        assert!(json.starts_with("{\"traceEvents\":[{\"name\":\"ai\""));
+        assert!(
+            json.contains("\"ph\":\"X\""),
+            "Scopes should be complete events"
+        );
+        assert!(json.ends_with("}"));
+    }
+
+    #[test]
+    fn test_end_without_begin_is_ignored() {
+        // Arrange
+        let mut p = Profiler::new();
+        p.set_enabled(true);
+
+        // Act
+        p.end();
+
+        // Assert
+        assert_eq!(p.trace_len(), 0);
+    }
+}
@@ -3,6 +3,7 @@ use std::io::{self, Write};
 
 use crate::color::Color;
 use crate::framebuffer::FrameBuffer;
+use crate::profiler;
 
 const HALF_BLOCK: char = '\u{2584}';
 const BEGIN_SYNC: &str = "\x1b[?2026h";
@@ -79,6 +80,7 @@ impl Renderer {
         }
         let bg = fb.background();
         let total = width * height;
+        let encode_scope = profiler::scope("ansi_encode");
 
         // Convert framebuffer pixels to back buffer cells
         for row in 0..height {
@@ -153,7 +155,9 @@ impl Renderer {
 
         self.buf.push_str("\x1b[0m");
         self.buf.push_str(END_SYNC);
+        drop(encode_scope);
 
+        let _present = profiler::scope("present");
         let mut stdout = io::stdout().lock();
         stdout.write_all(self.buf.as_bytes())?;
         stdout.flush()?;
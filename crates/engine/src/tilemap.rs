@@ -54,6 +54,31 @@ impl TileMap {
 
     /// Check if a pixel-space rectangle collides with any solid tile.
     pub fn collides(&self, x: f32, y: f32, w: f32, h: f32) -> bool {
+        self.any_tile_in(x, y, w, h, |tx, ty| self.is_solid(tx, ty))
+    }
+
+    /// Check if a pixel-space rectangle overlaps any tile of the given type.
+    pub fn overlaps_tile(&self, x: f32, y: f32, w: f32, h: f32, tile: TileType) -> bool {
+        self.any_tile_in(x, y, w, h, |tx, ty| self.get(tx, ty) == tile)
+    }
+
+    /// Tile under a pixel position. Out of bounds reads as Wall.
+    pub fn tile_at(&self, px: f32, py: f32) -> TileType {
+        if px < 0.0 || py < 0.0 {
+            return TileType::Wall;
+        }
+        let ts = TILE_SIZE as f32;
+        self.get((px / ts) as usize, (py / ts) as usize)
+    }
+
+    fn any_tile_in(
+        &self,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        pred: impl Fn(usize, usize) -> bool,
+    ) -> bool {
         let ts = TILE_SIZE as f32;
         let tx0 = (x / ts).floor() as i32;
         let ty0 = (y / ts).floor() as i32;
@@ -62,7 +87,7 @@ impl TileMap {
 
         for tiy in ty0.max(0)..=ty1.min(self.height as i32 - 1) {
             for tix in tx0.max(0)..=tx1.min(self.width as i32 - 1) {
-                if self.is_solid(tix as usize, tiy as usize) {
+                if pred(tix as usize, tiy as usize) {
                     return true;
                 }
             }
@@ -108,3 +133,33 @@ pub fn render_tilemap(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlaps_tile_finds_pits() {
+        // Arrange
+        let mut map = TileMap::new(4, 4);
+        map.set(2, 1, TileType::Pit);
+
+        // Act / Assert
+        assert!(map.overlaps_tile(14.0, 6.0, 4.0, 4.0, TileType::Pit));
+        assert!(!map.overlaps_tile(0.0, 0.0, 8.0, 8.0, TileType::Pit));
+        assert!(!map.collides(16.0, 8.0, 8.0, 8.0));
+    }
+
+    #[test]
+    fn tile_at_maps_pixels_to_tiles() {
+        // Arrange
+        let mut map = TileMap::new(4, 4);
+        map.set(1, 2, TileType::Pit);
+
+        // Act / Assert
+        assert_eq!(map.tile_at(12.0, 20.0), TileType::Pit);
+        assert_eq!(map.tile_at(4.0, 4.0), TileType::Floor);
+        assert_eq!(map.tile_at(-1.0, 4.0), TileType::Wall);
+        assert_eq!(map.tile_at(40.0, 4.0), TileType::Wall);
+    }
+}
@@ -0,0 +1,281 @@
+//! Achievements: fixed goals fed by gameplay events. Progress lives in the
+//! save file, keyed by each achievement's `key` so the table can grow or
+//! lose entries without breaking older saves.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::enemies::EnemyType;
+use crate::weapons::{all_weapons, WeaponId};
+
+/// Something that happened during a run that achievements count.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Event {
+    Kill(EnemyType),
+    /// Reached a floor's exit without taking a hit on it.
+    FlawlessFloor,
+    FloorReached(u32),
+    Victory(WeaponId),
+}
+
+/// What an achievement counts, and how far.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Goal {
+    Kills(u32),
+    GhostKills(u32),
+    BossKills(u32),
+    FlawlessFloors(u32),
+    /// Deepest floor reached in any run.
+    Floor(u32),
+    Wins(u32),
+    /// Win a run with every weapon at least once.
+    WinWithEveryWeapon,
+}
+
+impl Goal {
+    pub fn target(self) -> u32 {
+        match self {
+            Goal::Kills(n)
+            | Goal::GhostKills(n)
+            | Goal::BossKills(n)
+            | Goal::FlawlessFloors(n)
+            | Goal::Floor(n)
+            | Goal::Wins(n) => n,
+            Goal::WinWithEveryWeapon => all_weapons().len() as u32,
+        }
+    }
+}
+
+pub struct AchievementDef {
+    /// Name in the save file. Never change one once it has shipped.
+    pub key: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub goal: Goal,
+}
+
+pub const ACHIEVEMENTS: &[AchievementDef] = &[
+    AchievementDef {
+        key: "first_blood",
+        name: "First Blood",
+        description: "Kill an enemy",
+        goal: Goal::Kills(1),
+    },
+    AchievementDef {
+        key: "exterminator",
+        name: "Exterminator",
+        description: "Kill 1000 enemies",
+        goal: Goal::Kills(1000),
+    },
+    AchievementDef {
+        key: "ghostbuster",
+        name: "Ghostbuster",
+        description: "Kill 100 ghosts",
+        goal: Goal::GhostKills(100),
+    },
+    AchievementDef {
+        key: "kingslayer",
+        name: "Kingslayer",
+        description: "Slay 10 bosses",
+        goal: Goal::BossKills(10),
+    },
+    AchievementDef {
+        key: "untouchable",
+        name: "Untouchable",
+        description: "Clear a floor without getting hit",
+        goal: Goal::FlawlessFloors(1),
+    },
+    AchievementDef {
+        key: "deep_delver",
+        name: "Deep Delver",
+        description: "Reach floor 5",
+        goal: Goal::Floor(5),
+    },
+    AchievementDef {
+        key: "champion",
+        name: "Champion",
+        description: "Win a run",
+        goal: Goal::Wins(1),
+    },
+    AchievementDef {
+        key: "weapon_master",
+        name: "Weapon Master",
+        description: "Win with every weapon",
+        goal: Goal::WinWithEveryWeapon,
+    },
+];
+
+/// Achievement progress, stored in the save file.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct Achievements {
+    /// Count toward each achievement, by key. Plain strings so a retired
+    /// key is carried along instead of failing the whole save.
+    progress: BTreeMap<String, u32>,
+    /// Keys of every unlocked achievement.
+    unlocked: BTreeSet<String>,
+    /// Weapons a run has been won with.
+    weapons_won: BTreeSet<WeaponId>,
+}
+
+impl Achievements {
+    pub fn progress(&self, def: &AchievementDef) -> u32 {
+        let count = self.progress.get(def.key).copied().unwrap_or(0);
+        count.min(def.goal.target())
+    }
+
+    pub fn is_unlocked(&self, def: &AchievementDef) -> bool {
+        self.unlocked.contains(def.key)
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        ACHIEVEMENTS.iter().filter(|d| self.is_unlocked(d)).count()
+    }
+
+    /// Count an event. Returns the achievements it unlocked.
+    pub fn record(&mut self, event: Event) -> Vec<&'static AchievementDef> {
+        if let Event::Victory(weapon) = event {
+            self.weapons_won.insert(weapon);
+        }
+        let mut unlocked = Vec::new();
+        for def in ACHIEVEMENTS {
+            if self.is_unlocked(def) {
+                continue;
+            }
+            let count = match (def.goal, event) {
+                (Goal::Kills(_), Event::Kill(_))
+                | (Goal::GhostKills(_), Event::Kill(EnemyType::Ghost))
+                | (Goal::FlawlessFloors(_), Event::FlawlessFloor)
+                | (Goal::Wins(_), Event::Victory(_)) => self.add(def.key, 1),
+                (Goal::BossKills(_), Event::Kill(kind)) if kind.is_boss() => self.add(def.key, 1),
+                (Goal::Floor(_), Event::FloorReached(floor)) => self.raise(def.key, floor),
+                (Goal::WinWithEveryWeapon, Event::Victory(_)) => {
+                    self.raise(def.key, self.weapons_won.len() as u32)
+                }
+                _ => continue,
+            };
+            if count >= def.goal.target() {
+                self.unlocked.insert(def.key.to_string());
+                unlocked.push(def);
+            }
+        }
+        unlocked
+    }
+
+    /// Credit lifetime totals kept from before achievements existed, so an
+    /// old save doesn't start from nothing. Unlocks quietly.
+    pub fn backfill(&mut self, total_kills: u32, best_floor: u32, wins: u32) {
+        for def in ACHIEVEMENTS {
+            let count = match def.goal {
+                Goal::Kills(_) => self.raise(def.key, total_kills),
+                Goal::Floor(_) => self.raise(def.key, best_floor),
+                Goal::Wins(_) => self.raise(def.key, wins),
+                _ => continue,
+            };
+            if count >= def.goal.target() {
+                self.unlocked.insert(def.key.to_string());
+            }
+        }
+    }
+
+    fn add(&mut self, key: &str, amount: u32) -> u32 {
+        let count = self.progress.entry(key.to_string()).or_insert(0);
+        *count = count.saturating_add(amount);
+        *count
+    }
+
+    fn raise(&mut self, key: &str, to: u32) -> u32 {
+        let count = self.progress.entry(key.to_string()).or_insert(0);
+        *count = (*count).max(to);
+        *count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(key: &str) -> &'static AchievementDef {
+        ACHIEVEMENTS.iter().find(|d| d.key == key).unwrap()
+    }
+
+    fn names(unlocked: Vec<&'static AchievementDef>) -> Vec<&'static str> {
+        unlocked.iter().map(|d| d.key).collect()
+    }
+
+    #[test]
+    fn keys_are_unique() {
+        let keys: BTreeSet<&str> = ACHIEVEMENTS.iter().map(|d| d.key).collect();
+        assert_eq!(keys.len(), ACHIEVEMENTS.len());
+    }
+
+    #[test]
+    fn kills_count_toward_the_matching_goals() {
+        let mut a = Achievements::default();
+        let first = a.record(Event::Kill(EnemyType::Ghost));
+        assert_eq!(names(first), ["first_blood"]);
+        a.record(Event::Kill(EnemyType::Skeleton));
+        assert_eq!(a.progress(def("exterminator")), 2);
+        assert_eq!(a.progress(def("ghostbuster")), 1);
+        assert_eq!(a.progress(def("kingslayer")), 0);
+        a.record(Event::Kill(EnemyType::Lich));
+        assert_eq!(a.progress(def("kingslayer")), 1);
+    }
+
+    #[test]
+    fn unlocks_once_at_the_target() {
+        let mut a = Achievements::default();
+        for _ in 0..99 {
+            assert!(!names(a.record(Event::Kill(EnemyType::Ghost))).contains(&"ghostbuster"));
+        }
+        assert!(names(a.record(Event::Kill(EnemyType::Ghost))).contains(&"ghostbuster"));
+        assert!(a.is_unlocked(def("ghostbuster")));
+        assert!(a.record(Event::Kill(EnemyType::Ghost)).is_empty());
+        assert_eq!(a.progress(def("ghostbuster")), 100);
+    }
+
+    #[test]
+    fn floors_keep_the_deepest() {
+        let mut a = Achievements::default();
+        a.record(Event::FloorReached(4));
+        a.record(Event::FloorReached(2));
+        assert_eq!(a.progress(def("deep_delver")), 4);
+        assert_eq!(names(a.record(Event::FloorReached(5))), ["deep_delver"]);
+    }
+
+    #[test]
+    fn weapon_master_needs_a_win_with_each_weapon() {
+        let mut a = Achievements::default();
+        let weapons = all_weapons();
+        let (last, rest) = weapons.split_last().unwrap();
+        for w in rest {
+            a.record(Event::Victory(w.id));
+            a.record(Event::Victory(w.id));
+        }
+        assert!(!a.is_unlocked(def("weapon_master")));
+        assert_eq!(a.progress(def("weapon_master")), rest.len() as u32);
+        assert_eq!(names(a.record(Event::Victory(last.id))), ["weapon_master"]);
+    }
+
+    #[test]
+    fn backfill_credits_lifetime_totals() {
+        let mut a = Achievements::default();
+        a.backfill(250, 5, 0);
+        assert!(a.is_unlocked(def("first_blood")));
+        assert!(a.is_unlocked(def("deep_delver")));
+        assert!(!a.is_unlocked(def("champion")));
+        assert_eq!(a.progress(def("exterminator")), 250);
+        // Never lowers progress already made
+        a.backfill(3, 1, 0);
+        assert_eq!(a.progress(def("exterminator")), 250);
+    }
+
+    #[test]
+    fn unknown_keys_survive_a_roundtrip() {
+        let json = r#"{"progress":{"retired":7,"first_blood":1},"unlocked":["retired"]}"#;
+        let a: Achievements = serde_json::from_str(json).unwrap();
+        assert_eq!(a.progress(def("first_blood")), 1);
+        let back = serde_json::to_string(&a).unwrap();
+        assert!(back.contains("retired"));
+    }
+}
@@ -1,11 +1,13 @@
 use engine::{Color, FrameBuffer, GameKey, InputState};
 
+use crate::boons::effects::PlayerBoons;
+use crate::boons::selection::synergy;
 use crate::boons::{boon_def, BoonId, Rarity};
 use crate::sprites::boon_icons;
 use crate::sprites::font::{render_text, text_width};
 
 /// Rarity border colors.
-fn rarity_color(rarity: Rarity) -> Color {
+pub fn rarity_color(rarity: Rarity) -> Color {
     match rarity {
         Rarity::Common => [200, 200, 200],
         Rarity::Rare => [80, 120, 255],
@@ -22,8 +24,13 @@ fn rarity_label(rarity: Rarity) -> &'static str {
     }
 }
 
+/// Badge and label color for duo boons.
+const SYNERGY_COLOR: Color = [90, 230, 210];
+/// Label color for cards that level up an owned boon.
+const UPGRADE_COLOR: Color = [120, 230, 120];
+
 /// Get the boon icon sprite for a given BoonId.
-fn boon_icon(id: BoonId) -> &'static engine::SpriteData {
+pub fn boon_icon(id: BoonId) -> &'static engine::SpriteData {
     match id {
         BoonId::SharpenedBlade => &boon_icons::ICON_SHARPENED_BLADE,
         BoonId::BerserkersRage => &boon_icons::ICON_BERSERKERS_RAGE,
@@ -33,6 +40,9 @@ fn boon_icon(id: BoonId) -> &'static engine::SpriteData {
         BoonId::ProjectileSlash => &boon_icons::ICON_PROJECTILE_SLASH,
         BoonId::CriticalEdge => &boon_icons::ICON_CRITICAL_EDGE,
         BoonId::Fury => &boon_icons::ICON_FURY,
+        BoonId::SearingStrikes => &boon_icons::ICON_SEARING_STRIKES,
+        BoonId::VenomBlade => &boon_icons::ICON_VENOM_BLADE,
+        BoonId::Frostbite => &boon_icons::ICON_FROSTBITE,
         BoonId::ToughSkin => &boon_icons::ICON_TOUGH_SKIN,
         BoonId::IronShield => &boon_icons::ICON_IRON_SHIELD,
         BoonId::LifeSteal => &boon_icons::ICON_LIFE_STEAL,
@@ -47,6 +57,8 @@ fn boon_icon(id: BoonId) -> &'static engine::SpriteData {
         BoonId::Lucky => &boon_icons::ICON_LUCKY,
         BoonId::TreasureSense => &boon_icons::ICON_TREASURE_SENSE,
         BoonId::DeathsBargain => &boon_icons::ICON_DEATHS_BARGAIN,
+        BoonId::StormEdge => &boon_icons::ICON_STORM_EDGE,
+        BoonId::PhaseStrike => &boon_icons::ICON_PHASE_STRIKE,
     }
 }
 
@@ -59,24 +71,45 @@ const FLASH_DURATION: f32 = 0.4;
 
 pub struct BoonSelectScreen {
     pub options: Vec<BoonId>,
+    /// Level the player owns each option at (0 = new) and its current rarity.
+    owned: Vec<(u32, Rarity)>,
     pub selected: usize,
     pub active: bool,
+    /// Rerolls the player has left this run.
+    pub rerolls: u32,
     /// Set to Some(index) when a boon is confirmed, triggers flash before closing.
     confirmed: Option<usize>,
     flash_timer: f32,
+    reroll_requested: bool,
 }
 
 impl BoonSelectScreen {
-    pub fn new(options: Vec<BoonId>) -> Self {
+    pub fn new(options: Vec<BoonId>, boons: &PlayerBoons, rerolls: u32) -> Self {
         Self {
+            owned: owned_levels(&options, boons),
             options,
             selected: 0,
             active: true,
+            rerolls,
             confirmed: None,
             flash_timer: 0.0,
+            reroll_requested: false,
         }
     }
 
+    /// Returns true once after the player asks for a reroll.
+    pub fn take_reroll_request(&mut self) -> bool {
+        std::mem::take(&mut self.reroll_requested)
+    }
+
+    /// Replace the offered boons, consuming one reroll.
+    pub fn reroll(&mut self, options: Vec<BoonId>, boons: &PlayerBoons) {
+        self.owned = owned_levels(&options, boons);
+        self.options = options;
+        self.selected = 0;
+        self.rerolls = self.rerolls.saturating_sub(1);
+    }
+
     /// Handle input. Returns Some(BoonId) when the selection animation completes.
     pub fn update(&mut self, input: &InputState, dt: f32) -> Option<BoonId> {
         if !self.active || self.options.is_empty() {
@@ -105,6 +138,8 @@ impl BoonSelectScreen {
         if input.is_pressed(GameKey::Attack) {
             self.confirmed = Some(self.selected);
             self.flash_timer = FLASH_DURATION;
+        } else if input.is_pressed(GameKey::Dash) && self.rerolls > 0 {
+            self.reroll_requested = true;
         }
 
         None
@@ -140,14 +175,14 @@ impl BoonSelectScreen {
         let start_x = (fw - total_w) / 2;
         let start_y = (fh - CARD_H) / 2;
 
-        for (i, &boon_id) in self.options.iter().enumerate() {
+        for i in 0..self.options.len() {
             let is_selected = i == self.selected;
             let card_x = start_x + i as i32 * (CARD_W + CARD_GAP);
             let card_y = start_y;
 
             // During flash, highlight the confirmed card
             let is_confirmed = self.confirmed == Some(i);
-            self.render_card(fb, boon_id, card_x, card_y, is_selected, is_confirmed);
+            self.render_card(fb, i, card_x, card_y, is_selected, is_confirmed);
         }
 
         // Navigation hint (hide during confirmation flash)
@@ -157,20 +192,36 @@ impl BoonSelectScreen {
             let hx = (fw - hw) / 2;
             let hy = start_y + CARD_H + 4;
             render_text(fb, hint, hx, hy, [120, 120, 120]);
+
+            if self.rerolls > 0 {
+                let reroll_hint = format!("DASH - REROLL {}", self.rerolls);
+                let rw = text_width(&reroll_hint);
+                render_text(fb, &reroll_hint, (fw - rw) / 2, hy + 7, [255, 200, 50]);
+            }
+
+            // Spell out which pair unlocked the highlighted duo boon
+            if let Some(rule) = synergy(self.options[self.selected]) {
+                let [a, b] = rule.requires;
+                let pair = format!("{} + {}", boon_def(a).name, boon_def(b).name);
+                let pw = text_width(&pair);
+                render_text(fb, &pair, (fw - pw) / 2, hy + 14, SYNERGY_COLOR);
+            }
         }
     }
 
     fn render_card(
         &self,
         fb: &mut FrameBuffer,
-        boon_id: BoonId,
+        index: usize,
         x: i32,
         y: i32,
         selected: bool,
         confirmed: bool,
     ) {
+        let boon_id = self.options[index];
+        let (level, rarity) = self.owned[index];
         let def = boon_def(boon_id);
-        let border_color = rarity_color(def.rarity);
+        let border_color = rarity_color(rarity);
 
         let bg: Color = if confirmed {
             [50, 50, 80]
@@ -221,6 +272,11 @@ impl BoonSelectScreen {
         let icon_y = y + 2;
         fb.blit_sprite(icon, icon_x, icon_y);
 
+        let duo = synergy(boon_id).is_some();
+        if duo {
+            fb.blit_sprite(&boon_icons::SYNERGY_BADGE, x + CARD_W - 7, y + 2);
+        }
+
         // Name below icon
         let name_color: Color = if selected || confirmed {
             [255, 255, 255]
@@ -232,17 +288,31 @@ impl BoonSelectScreen {
         let ny = y + 12;
         render_text(fb, def.name, nx, ny, name_color);
 
-        // Rarity label
-        let rarity_label_text = rarity_label(def.rarity);
-        let rlw = text_width(rarity_label_text);
+        // Rarity label; upgrades show the level they reach and duo boons are
+        // labelled as such instead
+        let (rarity_label_text, rc) = if level > 0 {
+            (format!("LV {}", level + 1), UPGRADE_COLOR)
+        } else if duo {
+            ("DUO".to_string(), SYNERGY_COLOR)
+        } else {
+            (rarity_label(rarity).to_string(), rarity_color(rarity))
+        };
+        let rlw = text_width(&rarity_label_text);
         let rlx = x + (CARD_W - rlw) / 2;
         let rly = y + 18;
         let rl_color = if selected || confirmed {
-            rarity_color(def.rarity)
+            rc
         } else {
-            let rc = rarity_color(def.rarity);
             [rc[0] / 2, rc[1] / 2, rc[2] / 2]
         };
-        render_text(fb, rarity_label_text, rlx, rly, rl_color);
+        render_text(fb, &rarity_label_text, rlx, rly, rl_color);
     }
 }
+
+/// Snapshot how far the player has levelled each offered boon.
+fn owned_levels(options: &[BoonId], boons: &PlayerBoons) -> Vec<(u32, Rarity)> {
+    options
+        .iter()
+        .map(|&id| (boons.level(id), boons.rarity(id)))
+        .collect()
+}
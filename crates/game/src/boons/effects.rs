@@ -1,7 +1,19 @@
-use super::BoonId;
+use std::collections::HashMap;
+
+use super::{boon_def, BoonId, Rarity};
+use crate::status::StatusKind;
+
+/// Highest level an upgrade offer can take a boon to. Stackable boons taken
+/// again from the regular pool keep levelling past it.
+pub const MAX_UPGRADE_LEVEL: u32 = 3;
 
 pub struct PlayerBoons {
+    /// Owned boons, each once, in the order they were taken.
     pub active: Vec<BoonId>,
+    /// Level of each owned boon; taking it again raises it by one.
+    levels: HashMap<BoonId, u32>,
+    /// Rarity of owned boons a blessing has raised above their base.
+    raised: HashMap<BoonId, Rarity>,
     // Cached stat modifiers
     pub damage_flat_bonus: i32,
     pub damage_mult: f32,
@@ -25,15 +37,30 @@ pub struct PlayerBoons {
     pub lucky: bool,
     pub treasure_sense: bool,
     pub crit_chance: f32,
+    /// Extra Chain Lightning arcs on a crit (Storm Edge).
+    pub crit_arc_targets: i32,
+    /// Effect Dash Strike leaves on enemies it hits (Phase Strike).
+    pub dash_strike_status: Option<StatusKind>,
     pub fury_kills_this_room: i32,
-    pub has_retaliation: bool,
+    /// Fury damage bonus per kill this room.
+    pub fury_per_kill: f32,
+    /// Damage dealt back to melee attackers (Retaliation, 0 = none).
+    pub retaliation_damage: i32,
     pub has_deaths_bargain: bool,
+    /// Status effects every hit leaves on the enemy.
+    pub on_hit_statuses: Vec<StatusKind>,
+    // Trigger state carried between hook calls
+    /// Fractional HP accumulated by Life Steal until it adds up to a heart.
+    pub life_steal_pool: f32,
+    rng_state: u64,
 }
 
 impl PlayerBoons {
     pub fn new() -> Self {
         Self {
             active: Vec::new(),
+            levels: HashMap::new(),
+            raised: HashMap::new(),
             damage_flat_bonus: 0,
             damage_mult: 1.0,
             attack_speed_mult: 1.0,
@@ -55,17 +82,89 @@ impl PlayerBoons {
             lucky: false,
             treasure_sense: false,
             crit_chance: 0.0,
+            crit_arc_targets: 0,
+            dash_strike_status: None,
             fury_kills_this_room: 0,
-            has_retaliation: false,
+            fury_per_kill: 0.0,
+            retaliation_damage: 0,
             has_deaths_bargain: false,
+            on_hit_statuses: Vec::new(),
+            life_steal_pool: 0.0,
+            rng_state: 1,
         }
     }
 
+    /// Seed the RNG used for chance-based boons (crits, Vampiric Touch).
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng_state = seed;
+    }
+
+    /// Next roll in [0, 1).
+    pub(super) fn roll(&mut self) -> f32 {
+        self.rng_state = self.rng_state.wrapping_mul(1103515245).wrapping_add(12345);
+        ((self.rng_state >> 16) % 10_000) as f32 / 10_000.0
+    }
+
+    /// Take a boon, or level it up if it's already owned.
     pub fn add(&mut self, boon_id: BoonId) {
-        self.active.push(boon_id);
+        if !self.active.contains(&boon_id) {
+            self.active.push(boon_id);
+        }
+        *self.levels.entry(boon_id).or_insert(0) += 1;
         self.recalculate();
     }
 
+    /// Level of an owned boon (0 if not owned).
+    pub fn level(&self, id: BoonId) -> u32 {
+        if self.has_boon(id) {
+            self.levels.get(&id).copied().unwrap_or(1)
+        } else {
+            0
+        }
+    }
+
+    /// Current rarity of a boon, counting blessings.
+    pub fn rarity(&self, id: BoonId) -> Rarity {
+        self.raised.get(&id).copied().unwrap_or(boon_def(id).rarity)
+    }
+
+    /// How strongly a boon applies: its level, plus one per rarity step a
+    /// blessing raised it.
+    pub fn rank(&self, id: BoonId) -> i32 {
+        let steps = self.rarity(id) as i32 - boon_def(id).rarity as i32;
+        self.level(id) as i32 + steps
+    }
+
+    /// Owned boons an upgrade offer can level up.
+    pub fn upgradable(&self) -> Vec<BoonId> {
+        self.active
+            .iter()
+            .copied()
+            .filter(|&id| id.has_levels() && self.level(id) < MAX_UPGRADE_LEVEL)
+            .collect()
+    }
+
+    /// Owned boons a blessing can raise to a higher rarity.
+    pub fn raisable(&self) -> Vec<BoonId> {
+        self.active
+            .iter()
+            .copied()
+            .filter(|&id| id.has_levels() && self.rarity(id).raised().is_some())
+            .collect()
+    }
+
+    /// Raise an owned boon one rarity step. Returns the new rarity, or `None`
+    /// if the boon isn't owned, doesn't scale or is already Legendary.
+    pub fn raise_rarity(&mut self, id: BoonId) -> Option<Rarity> {
+        if !self.has_boon(id) || !id.has_levels() {
+            return None;
+        }
+        let rarity = self.rarity(id).raised()?;
+        self.raised.insert(id, rarity);
+        self.recalculate();
+        Some(rarity)
+    }
+
     pub fn recalculate(&mut self) {
         // Reset all cached values to defaults
         self.damage_flat_bonus = 0;
@@ -87,68 +186,91 @@ impl PlayerBoons {
         self.lucky = false;
         self.treasure_sense = false;
         self.crit_chance = 0.0;
-        self.has_retaliation = false;
+        self.crit_arc_targets = 0;
+        self.dash_strike_status = None;
+        self.fury_per_kill = 0.0;
+        self.retaliation_damage = 0;
         self.has_deaths_bargain = false;
+        self.on_hit_statuses.clear();
 
-        for &boon in &self.active {
+        let ranked: Vec<(BoonId, i32)> =
+            self.active.iter().map(|&b| (b, self.rank(b))).collect();
+        for (boon, rank) in ranked {
+            // Counted effects grow by one per rank; scaled ones by half their
+            // base value per rank above the first.
+            let scale = 1.0 + 0.5 * (rank - 1) as f32;
             match boon {
                 BoonId::SharpenedBlade => {
-                    self.damage_flat_bonus += 1;
+                    self.damage_flat_bonus += rank;
                 }
                 BoonId::BerserkersRage => {
-                    self.damage_mult += 0.25;
+                    self.damage_mult += 0.25 * scale;
                 }
                 BoonId::SwiftStrikes => {
-                    self.attack_speed_mult *= 0.7;
+                    self.attack_speed_mult *= 0.7f32.powf(scale);
                 }
                 BoonId::KillingBlow => {
-                    self.on_kill_explode_damage = 2;
+                    self.on_kill_explode_damage = 1 + rank;
                 }
                 BoonId::ChainLightning => {
-                    self.chain_lightning_targets = 2;
+                    self.chain_lightning_targets = 1 + rank;
                 }
                 BoonId::ProjectileSlash => {
                     self.has_projectile_attack = true;
                 }
                 BoonId::CriticalEdge => {
-                    self.crit_chance = 0.2;
+                    self.crit_chance = 0.2 * scale;
                 }
                 BoonId::Fury => {
                     // Damage bonus applied dynamically via fury_damage_mult()
+                    self.fury_per_kill = 0.05 * scale;
+                }
+                BoonId::SearingStrikes => {
+                    self.on_hit_statuses.push(StatusKind::Burn);
+                }
+                BoonId::VenomBlade => {
+                    for _ in 0..rank {
+                        self.on_hit_statuses.push(StatusKind::Poison);
+                    }
+                }
+                BoonId::Frostbite => {
+                    for _ in 0..rank {
+                        self.on_hit_statuses.push(StatusKind::Slow);
+                    }
                 }
                 BoonId::ToughSkin => {
-                    self.max_hp_bonus += 1;
+                    self.max_hp_bonus += rank;
                 }
                 BoonId::IronShield => {
-                    self.shield_max = 2;
+                    self.shield_max = 1 + rank;
                     // shield_charges are set by reset_floor_state, not recalculate
                 }
                 BoonId::LifeSteal => {
-                    self.life_steal_percent = 0.15;
+                    self.life_steal_percent = 0.15 * scale;
                 }
                 BoonId::VampiricTouch => {
-                    self.on_hit_heal_chance = 0.1;
+                    self.on_hit_heal_chance = 0.1 * scale;
                 }
                 BoonId::Retaliation => {
-                    self.has_retaliation = true;
+                    self.retaliation_damage = rank;
                 }
                 BoonId::SecondWind => {
                     self.has_second_wind = true;
                 }
                 BoonId::SwiftFeet => {
-                    self.move_speed_mult += 0.2;
+                    self.move_speed_mult += 0.2 * rank as f32;
                 }
                 BoonId::PhantomDash => {
-                    self.dash_distance_mult += 0.4;
+                    self.dash_distance_mult += 0.4 * scale;
                 }
                 BoonId::ShadowStep => {
-                    self.dash_cooldown_reduction += 0.5;
+                    self.dash_cooldown_reduction += 0.5 * scale;
                 }
                 BoonId::DashStrike => {
-                    self.on_dash_damage = 2;
+                    self.on_dash_damage = 1 + rank;
                 }
                 BoonId::GoldMagnet => {
-                    self.gold_mult += 0.5;
+                    self.gold_mult += 0.5 * scale;
                 }
                 BoonId::Lucky => {
                     self.lucky = true;
@@ -157,24 +279,26 @@ impl PlayerBoons {
                     self.treasure_sense = true;
                 }
                 BoonId::DeathsBargain => {
-                    self.damage_flat_bonus += 3;
+                    self.damage_flat_bonus += 2 + rank;
                     self.has_deaths_bargain = true;
                     // max_hp_bonus is set to reduce max HP to 1
                     // The game integration layer will handle this based on player's base max HP
                 }
+                BoonId::StormEdge => {
+                    self.crit_arc_targets = 1 + rank;
+                }
+                BoonId::PhaseStrike => {
+                    self.on_dash_damage += 1 + rank;
+                    self.dash_strike_status = Some(StatusKind::Stun);
+                }
             }
         }
     }
 
     /// Returns the Fury damage multiplier based on kills this room.
-    /// Each kill grants +5% damage.
-    #[allow(dead_code)] // Used by tests; will be called from combat damage calc in Phase 5
+    /// Each kill grants +5% damage at level 1.
     pub fn fury_damage_mult(&self) -> f32 {
-        if self.has_boon(BoonId::Fury) {
-            1.0 + self.fury_kills_this_room as f32 * 0.05
-        } else {
-            1.0
-        }
+        1.0 + self.fury_kills_this_room as f32 * self.fury_per_kill
     }
 
     /// Calculate effective max HP bonus, accounting for Death's Bargain.
@@ -188,7 +312,6 @@ impl PlayerBoons {
         }
     }
 
-    #[allow(dead_code)] // Used by fury_damage_mult and tests; general-purpose query method
     pub fn has_boon(&self, id: BoonId) -> bool {
         self.active.contains(&id)
     }
@@ -351,6 +474,16 @@ mod tests {
         assert_eq!(boons.on_dash_damage, 2);
     }
 
+    #[test]
+    fn test_duo_boons() {
+        let mut boons = PlayerBoons::new();
+        boons.add(BoonId::DashStrike);
+        boons.add(BoonId::PhantomDash);
+        boons.add(BoonId::PhaseStrike);
+        assert_eq!(boons.on_dash_damage, 4);
+        assert_eq!(boons.dash_strike_status, Some(StatusKind::Stun));
+    }
+
     #[test]
     fn test_special_boons() {
         let mut boons = PlayerBoons::new();
@@ -371,6 +504,51 @@ mod tests {
         assert!(!boons.has_boon(BoonId::Fury));
     }
 
+    #[test]
+    fn test_adding_owned_boon_levels_it_up() {
+        let mut boons = PlayerBoons::new();
+        assert_eq!(boons.level(BoonId::BerserkersRage), 0);
+        boons.add(BoonId::BerserkersRage);
+        boons.add(BoonId::BerserkersRage);
+        assert_eq!(boons.active, vec![BoonId::BerserkersRage]);
+        assert_eq!(boons.level(BoonId::BerserkersRage), 2);
+        assert!((boons.damage_mult - 1.375).abs() < 0.001);
+        boons.add(BoonId::ChainLightning);
+        boons.add(BoonId::ChainLightning);
+        assert_eq!(boons.chain_lightning_targets, 3);
+    }
+
+    #[test]
+    fn test_upgradable_stops_at_max_level() {
+        let mut boons = PlayerBoons::new();
+        boons.add(BoonId::CriticalEdge);
+        boons.add(BoonId::Lucky);
+        assert_eq!(boons.upgradable(), vec![BoonId::CriticalEdge]);
+        for _ in 1..MAX_UPGRADE_LEVEL {
+            boons.add(BoonId::CriticalEdge);
+        }
+        assert!(boons.upgradable().is_empty());
+        assert!((boons.crit_chance - 0.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_raise_rarity_counts_as_a_level() {
+        let mut boons = PlayerBoons::new();
+        assert_eq!(boons.raise_rarity(BoonId::ToughSkin), None);
+        boons.add(BoonId::ToughSkin);
+        assert_eq!(boons.rarity(BoonId::ToughSkin), Rarity::Common);
+        assert_eq!(boons.raise_rarity(BoonId::ToughSkin), Some(Rarity::Rare));
+        assert_eq!(boons.max_hp_bonus, 2);
+        assert_eq!(boons.raise_rarity(BoonId::ToughSkin), Some(Rarity::Legendary));
+        assert_eq!(boons.raise_rarity(BoonId::ToughSkin), None);
+        assert_eq!(boons.max_hp_bonus, 3);
+        assert_eq!(boons.level(BoonId::ToughSkin), 1);
+        assert!(boons.raisable().is_empty());
+
+        boons.add(BoonId::SecondWind);
+        assert_eq!(boons.raise_rarity(BoonId::SecondWind), None);
+    }
+
     #[test]
     fn test_recalculate_resets_properly() {
         let mut boons = PlayerBoons::new();
@@ -0,0 +1,397 @@
+//! Boon triggers: gameplay code reports an event and gets back what the
+//! active boons want to happen. Hooks only touch boon state; the caller
+//! applies the outcome to enemies, the player and the run.
+
+use super::effects::PlayerBoons;
+use crate::pickup::{Pickup, PickupType};
+use crate::status::StatusKind;
+
+/// Radius of the Killing Blow explosion around a slain enemy.
+pub const KILL_EXPLOSION_RADIUS: f32 = 18.0;
+/// How far Chain Lightning can jump from the struck enemy.
+pub const CHAIN_LIGHTNING_RADIUS: f32 = 40.0;
+
+/// Result of the player landing a hit.
+#[derive(Debug, Default, PartialEq)]
+pub struct HitOutcome {
+    /// Damage to deal to the struck enemy.
+    pub damage: i32,
+    pub crit: bool,
+    /// HP the player regains (Life Steal, Vampiric Touch).
+    pub heal: i32,
+    /// Extra enemies Chain Lightning jumps to, and the damage each takes.
+    pub chain_targets: usize,
+    pub chain_damage: i32,
+    /// Status effects to leave on the struck enemy.
+    pub inflicts: Vec<StatusKind>,
+}
+
+/// Result of an enemy dying to the player.
+#[derive(Debug, Default, PartialEq)]
+pub struct KillOutcome {
+    pub gold: u32,
+    /// Killing Blow damage to enemies around the corpse (0 = no explosion).
+    pub explode_damage: i32,
+}
+
+/// Result of an attack about to land on the player.
+#[derive(Debug, Default, PartialEq)]
+pub struct DamagedOutcome {
+    /// Damage the player actually takes.
+    pub damage: i32,
+    /// Iron Shield ate the hit.
+    pub blocked: bool,
+    /// Second Wind kept the player alive at 1 HP.
+    pub second_wind: bool,
+    /// Damage to deal back to the attacker (melee only).
+    pub retaliate: i32,
+}
+
+/// Result of collecting a pickup.
+#[derive(Debug, Default, PartialEq)]
+pub struct PickupOutcome {
+    pub heal: i32,
+    pub gold: u32,
+    pub souls: u32,
+}
+
+impl PlayerBoons {
+    fn scale_gold(&self, gold: u32) -> u32 {
+        (gold as f32 * self.gold_mult).round() as u32
+    }
+
+    /// The player's attack connected for `base_damage` before boons.
+    pub fn on_hit(&mut self, base_damage: i32) -> HitOutcome {
+        let raw = (base_damage + self.damage_flat_bonus) as f32
+            * self.damage_mult
+            * self.fury_damage_mult();
+        let mut damage = (raw.round() as i32).max(1);
+
+        let crit = self.crit_chance > 0.0 && self.roll() < self.crit_chance;
+        if crit {
+            damage *= 2;
+        }
+
+        let mut heal = 0;
+        if self.life_steal_percent > 0.0 {
+            self.life_steal_pool += damage as f32 * self.life_steal_percent;
+            let whole = self.life_steal_pool.floor();
+            self.life_steal_pool -= whole;
+            heal += whole as i32;
+        }
+        if self.on_hit_heal_chance > 0.0 && self.roll() < self.on_hit_heal_chance {
+            heal += 1;
+        }
+
+        // Storm Edge: crits arc further, and at full damage
+        let arcs = if crit { self.crit_arc_targets } else { 0 };
+        let chain_damage = if arcs > 0 {
+            damage
+        } else {
+            (damage / 2).max(1)
+        };
+        HitOutcome {
+            damage,
+            crit,
+            heal,
+            chain_targets: (self.chain_lightning_targets + arcs).max(0) as usize,
+            chain_damage,
+            inflicts: self.on_hit_statuses.clone(),
+        }
+    }
+
+    /// An enemy worth `enemy_gold` died to the player.
+    pub fn on_kill(&mut self, enemy_gold: u32) -> KillOutcome {
+        self.record_kill();
+        KillOutcome {
+            gold: self.scale_gold(enemy_gold),
+            explode_damage: self.on_kill_explode_damage,
+        }
+    }
+
+    /// An attack for `incoming` damage is about to land while the player has
+    /// `current_hp`. `melee` is false for projectiles, which can't be retaliated.
+    pub fn on_damaged(&mut self, incoming: i32, current_hp: i32, melee: bool) -> DamagedOutcome {
+        if self.shield_charges > 0 {
+            self.shield_charges -= 1;
+            return DamagedOutcome {
+                blocked: true,
+                ..DamagedOutcome::default()
+            };
+        }
+
+        let mut damage = incoming;
+        let mut second_wind = false;
+        if damage >= current_hp && self.has_second_wind && !self.second_wind_used {
+            self.second_wind_used = true;
+            second_wind = true;
+            damage = (current_hp - 1).max(0);
+        }
+
+        DamagedOutcome {
+            damage,
+            blocked: false,
+            second_wind,
+            retaliate: if melee { self.retaliation_damage } else { 0 },
+        }
+    }
+
+    /// Knockback slammed an enemy into a wall for `base_damage` before boons.
+    /// Flat weapon bonuses don't apply; multipliers do.
+    pub fn on_wall_slam(&self, base_damage: i32) -> i32 {
+        ((base_damage as f32 * self.damage_mult).round() as i32).max(1)
+    }
+
+    /// The player started a dash. Returns Dash Strike contact damage (0 = none).
+    pub fn on_dash_start(&self) -> i32 {
+        self.on_dash_damage
+    }
+
+    /// The player's dash ended. Returns the cooldown before the next dash.
+    pub fn on_dash_end(&self, base_cooldown: f32) -> f32 {
+        base_cooldown * (1.0 - self.dash_cooldown_reduction).max(0.0)
+    }
+
+    /// The current room's encounter was cleared. Returns the clear bonus gold.
+    pub fn on_room_clear(&mut self, bonus_gold: u32) -> u32 {
+        self.reset_room_state();
+        self.scale_gold(bonus_gold)
+    }
+
+    /// The player walked over a pickup.
+    pub fn on_pickup(&self, pickup: &Pickup) -> PickupOutcome {
+        let gold = match pickup.pickup_type {
+            PickupType::Gold(amount) => self.scale_gold(amount),
+            _ => 0,
+        };
+        let souls = match pickup.pickup_type {
+            PickupType::Soul(amount) => amount,
+            _ => 0,
+        };
+        PickupOutcome {
+            heal: pickup.heal_amount(),
+            gold,
+            souls,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boons::BoonId;
+
+    fn with(ids: &[BoonId]) -> PlayerBoons {
+        let mut boons = PlayerBoons::new();
+        for &id in ids {
+            boons.add(id);
+        }
+        boons
+    }
+
+    #[test]
+    fn test_on_hit_without_boons_is_base_damage() {
+        let mut boons = PlayerBoons::new();
+        let hit = boons.on_hit(2);
+        assert_eq!(hit.damage, 2);
+        assert!(!hit.crit);
+        assert_eq!(hit.heal, 0);
+        assert_eq!(hit.chain_targets, 0);
+    }
+
+    #[test]
+    fn test_on_hit_applies_damage_boons() {
+        let mut boons = with(&[BoonId::SharpenedBlade, BoonId::BerserkersRage]);
+        // (2 + 1) * 1.25 = 3.75
+        assert_eq!(boons.on_hit(2).damage, 4);
+    }
+
+    #[test]
+    fn test_on_hit_fury_grows_with_kills() {
+        let mut boons = with(&[BoonId::Fury]);
+        let before = boons.on_hit(10).damage;
+        for _ in 0..4 {
+            boons.on_kill(0);
+        }
+        assert_eq!(before, 10);
+        assert_eq!(boons.on_hit(10).damage, 12);
+    }
+
+    #[test]
+    fn test_on_hit_crit_doubles_damage() {
+        let mut boons = with(&[BoonId::CriticalEdge]);
+        boons.crit_chance = 1.0;
+        let hit = boons.on_hit(3);
+        assert!(hit.crit);
+        assert_eq!(hit.damage, 6);
+    }
+
+    #[test]
+    fn test_on_hit_crit_rate_is_roughly_twenty_percent() {
+        let mut boons = with(&[BoonId::CriticalEdge]);
+        boons.seed_rng(12345);
+        let crits = (0..1000).filter(|_| boons.on_hit(1).crit).count();
+        assert!((120..280).contains(&crits), "{crits} crits");
+    }
+
+    #[test]
+    fn test_on_hit_life_steal_accumulates() {
+        let mut boons = with(&[BoonId::LifeSteal]);
+        // 15% of 3 damage per hit: a heart every third hit
+        let heals: Vec<i32> = (0..3).map(|_| boons.on_hit(3).heal).collect();
+        assert_eq!(heals, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_on_hit_vampiric_touch_heals_sometimes() {
+        let mut boons = with(&[BoonId::VampiricTouch]);
+        boons.seed_rng(99);
+        let heals: i32 = (0..500).map(|_| boons.on_hit(1).heal).sum();
+        assert!(heals > 0 && heals < 150, "{heals} heals");
+    }
+
+    #[test]
+    fn test_on_hit_chain_lightning() {
+        let mut boons = with(&[BoonId::ChainLightning]);
+        let hit = boons.on_hit(4);
+        assert_eq!(hit.chain_targets, 2);
+        assert_eq!(hit.chain_damage, 2);
+    }
+
+    #[test]
+    fn test_on_hit_storm_edge_crits_arc_at_full_damage() {
+        let mut boons = with(&[
+            BoonId::ChainLightning,
+            BoonId::CriticalEdge,
+            BoonId::StormEdge,
+        ]);
+        boons.crit_chance = 1.0;
+        let hit = boons.on_hit(2);
+        assert_eq!(hit.chain_targets, 4);
+        assert_eq!(hit.chain_damage, hit.damage);
+    }
+
+    #[test]
+    fn test_on_hit_status_boons_inflict() {
+        let mut boons = with(&[BoonId::SearingStrikes, BoonId::Frostbite]);
+        let inflicts = boons.on_hit(2).inflicts;
+        assert_eq!(inflicts, vec![StatusKind::Burn, StatusKind::Slow]);
+        assert!(PlayerBoons::new().on_hit(2).inflicts.is_empty());
+    }
+
+    #[test]
+    fn test_on_kill_gold_magnet_and_explosion() {
+        let mut boons = PlayerBoons::new();
+        assert_eq!(
+            boons.on_kill(2),
+            KillOutcome {
+                gold: 2,
+                explode_damage: 0
+            }
+        );
+
+        let mut boons = with(&[BoonId::GoldMagnet, BoonId::KillingBlow]);
+        let kill = boons.on_kill(2);
+        assert_eq!(kill.gold, 3);
+        assert_eq!(kill.explode_damage, 2);
+        assert_eq!(boons.fury_kills_this_room, 1);
+    }
+
+    #[test]
+    fn test_on_damaged_passes_through_without_boons() {
+        let mut boons = PlayerBoons::new();
+        let hit = boons.on_damaged(2, 5, true);
+        assert_eq!(hit.damage, 2);
+        assert!(!hit.blocked);
+        assert_eq!(hit.retaliate, 0);
+    }
+
+    #[test]
+    fn test_on_damaged_iron_shield_blocks_until_empty() {
+        let mut boons = with(&[BoonId::IronShield]);
+        boons.reset_floor_state();
+        assert!(boons.on_damaged(1, 5, true).blocked);
+        assert!(boons.on_damaged(1, 5, true).blocked);
+        let third = boons.on_damaged(1, 5, true);
+        assert!(!third.blocked);
+        assert_eq!(third.damage, 1);
+    }
+
+    #[test]
+    fn test_on_damaged_second_wind_once_per_floor() {
+        let mut boons = with(&[BoonId::SecondWind]);
+        let saved = boons.on_damaged(3, 2, true);
+        assert!(saved.second_wind);
+        assert_eq!(saved.damage, 1);
+
+        let lethal = boons.on_damaged(3, 1, true);
+        assert!(!lethal.second_wind);
+        assert_eq!(lethal.damage, 3);
+
+        boons.reset_floor_state();
+        assert!(boons.on_damaged(3, 1, true).second_wind);
+    }
+
+    #[test]
+    fn test_on_damaged_retaliation_melee_only() {
+        let mut boons = with(&[BoonId::Retaliation]);
+        assert_eq!(boons.on_damaged(1, 5, true).retaliate, 1);
+        assert_eq!(boons.on_damaged(1, 5, false).retaliate, 0);
+    }
+
+    #[test]
+    fn test_on_wall_slam_scales_with_damage_mult() {
+        assert_eq!(PlayerBoons::new().on_wall_slam(1), 1);
+        let mut boons = with(&[BoonId::SharpenedBlade]);
+        boons.damage_mult = 2.0;
+        assert_eq!(boons.on_wall_slam(1), 2);
+    }
+
+    #[test]
+    fn test_on_dash_hooks() {
+        let boons = PlayerBoons::new();
+        assert_eq!(boons.on_dash_start(), 0);
+        assert!((boons.on_dash_end(0.4) - 0.4).abs() < 0.001);
+
+        let boons = with(&[BoonId::DashStrike, BoonId::ShadowStep]);
+        assert_eq!(boons.on_dash_start(), 2);
+        assert!((boons.on_dash_end(0.4) - 0.2).abs() < 0.001);
+
+        let boons = with(&[BoonId::ShadowStep, BoonId::ShadowStep, BoonId::ShadowStep]);
+        assert_eq!(boons.on_dash_end(0.4), 0.0);
+    }
+
+    #[test]
+    fn test_on_room_clear_resets_fury_and_scales_gold() {
+        let mut boons = with(&[BoonId::Fury, BoonId::GoldMagnet]);
+        boons.on_kill(0);
+        assert_eq!(boons.on_room_clear(6), 9);
+        assert_eq!(boons.fury_kills_this_room, 0);
+    }
+
+    #[test]
+    fn test_on_pickup() {
+        let boons = with(&[BoonId::GoldMagnet]);
+        let coin = Pickup::new(0.0, 0.0, PickupType::Gold(4));
+        assert_eq!(
+            boons.on_pickup(&coin),
+            PickupOutcome {
+                heal: 0,
+                gold: 6,
+                souls: 0
+            }
+        );
+        let heart = Pickup::new(0.0, 0.0, PickupType::BigHeal);
+        assert_eq!(
+            boons.on_pickup(&heart),
+            PickupOutcome {
+                heal: 3,
+                gold: 0,
+                souls: 0
+            }
+        );
+        // Gold Magnet leaves souls alone
+        let soul = Pickup::new(0.0, 0.0, PickupType::Soul(2));
+        assert_eq!(boons.on_pickup(&soul).souls, 2);
+    }
+}
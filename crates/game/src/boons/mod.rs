@@ -1,7 +1,10 @@
 pub mod effects;
+pub mod hooks;
 pub mod selection;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+use serde::Deserialize;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
 pub enum BoonId {
     // Offense
     SharpenedBlade,
@@ -12,6 +15,9 @@ pub enum BoonId {
     ProjectileSlash,
     CriticalEdge,
     Fury,
+    SearingStrikes,
+    VenomBlade,
+    Frostbite,
     // Defense
     ToughSkin,
     IronShield,
@@ -29,16 +35,45 @@ pub enum BoonId {
     Lucky,
     TreasureSense,
     DeathsBargain,
+    // Duo: only offered once the player owns both halves
+    StormEdge,
+    PhaseStrike,
+}
+
+impl BoonId {
+    /// Whether levels and raised rarity strengthen this boon. All-or-nothing
+    /// effects (an extra projectile, a one-off revive) don't scale.
+    pub fn has_levels(self) -> bool {
+        !matches!(
+            self,
+            BoonId::ProjectileSlash
+                | BoonId::SearingStrikes
+                | BoonId::SecondWind
+                | BoonId::Lucky
+                | BoonId::TreasureSense
+        )
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize)]
 pub enum Rarity {
     Common,
     Rare,
     Legendary,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+impl Rarity {
+    /// The next rarity up, or `None` for Legendary.
+    pub fn raised(self) -> Option<Rarity> {
+        match self {
+            Rarity::Common => Some(Rarity::Rare),
+            Rarity::Rare => Some(Rarity::Legendary),
+            Rarity::Legendary => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
 pub enum BoonCategory {
     Offense,
     Defense,
@@ -46,6 +81,7 @@ pub enum BoonCategory {
     Special,
 }
 
+#[derive(Clone)]
 pub struct BoonDef {
     pub id: BoonId,
     pub name: &'static str,
@@ -122,6 +158,30 @@ pub const BOON_DEFS: &[BoonDef] = &[
         category: BoonCategory::Offense,
         stackable: false,
     },
+    BoonDef {
+        id: BoonId::SearingStrikes,
+        name: "Searing Strikes",
+        description: "Attacks set enemies burning",
+        rarity: Rarity::Rare,
+        category: BoonCategory::Offense,
+        stackable: false,
+    },
+    BoonDef {
+        id: BoonId::VenomBlade,
+        name: "Venom Blade",
+        description: "Attacks poison; poison stacks up to 3",
+        rarity: Rarity::Common,
+        category: BoonCategory::Offense,
+        stackable: false,
+    },
+    BoonDef {
+        id: BoonId::Frostbite,
+        name: "Frostbite",
+        description: "Attacks chill; a full chill freezes",
+        rarity: Rarity::Rare,
+        category: BoonCategory::Offense,
+        stackable: false,
+    },
     // --- Defense ---
     BoonDef {
         id: BoonId::ToughSkin,
@@ -237,8 +297,33 @@ pub const BOON_DEFS: &[BoonDef] = &[
         category: BoonCategory::Special,
         stackable: false,
     },
+    // --- Duo (see selection::SYNERGIES for what unlocks each) ---
+    BoonDef {
+        id: BoonId::StormEdge,
+        name: "Storm Edge",
+        description: "Crits arc to 2 more enemies at full damage",
+        rarity: Rarity::Legendary,
+        category: BoonCategory::Offense,
+        stackable: false,
+    },
+    BoonDef {
+        id: BoonId::PhaseStrike,
+        name: "Phase Strike",
+        description: "Dash strikes hit twice as hard and stun",
+        rarity: Rarity::Legendary,
+        category: BoonCategory::Mobility,
+        stackable: false,
+    },
 ];
 
+/// The boon table in play: `BOON_DEFS` as patched by `defs/boons.json`.
+pub fn boon_defs() -> &'static [BoonDef] {
+    crate::defs::boons().unwrap_or(BOON_DEFS)
+}
+
 pub fn boon_def(id: BoonId) -> &'static BoonDef {
-    BOON_DEFS.iter().find(|b| b.id == id).expect("missing boon definition")
+    boon_defs()
+        .iter()
+        .find(|b| b.id == id)
+        .expect("missing boon definition")
 }
@@ -21,6 +21,68 @@ impl Rng {
 
 }
 
+/// Weight of a duo boon whose pair the player owns. Higher than its rarity
+/// alone would give, since the player built towards it.
+const SYNERGY_WEIGHT: u32 = 30;
+
+/// A duo boon and the two boons the player must own before it's offered.
+pub struct Synergy {
+    pub boon: BoonId,
+    pub requires: [BoonId; 2],
+}
+
+pub const SYNERGIES: &[Synergy] = &[
+    Synergy {
+        boon: BoonId::StormEdge,
+        requires: [BoonId::ChainLightning, BoonId::CriticalEdge],
+    },
+    Synergy {
+        boon: BoonId::PhaseStrike,
+        requires: [BoonId::PhantomDash, BoonId::DashStrike],
+    },
+];
+
+/// The prerequisite rule for `id`, if it's a duo boon.
+pub fn synergy(id: BoonId) -> Option<&'static Synergy> {
+    SYNERGIES.iter().find(|s| s.boon == id)
+}
+
+/// Whether `def` can be offered to a player holding `active`: not an owned
+/// one-off, and with both halves of its pair owned if it's a duo boon.
+pub fn is_eligible(def: &BoonDef, active: &[BoonId]) -> bool {
+    if !def.stackable && active.contains(&def.id) {
+        return false;
+    }
+    synergy(def.id).is_none_or(|s| s.requires.iter().all(|r| active.contains(r)))
+}
+
+/// Percent chance a boon choice swaps one option for an upgrade of an
+/// owned boon, when there is one to upgrade.
+const UPGRADE_OFFER_CHANCE: u64 = 35;
+
+/// Sometimes replace the last of `options` with an owned boon from
+/// `upgradable` (see `PlayerBoons::upgradable`), so picking it levels it up.
+/// Never duplicates an option already on offer.
+pub fn offer_upgrade(options: &mut Vec<BoonId>, upgradable: &[BoonId], seed: u64) {
+    let mut rng = Rng::new(seed);
+    if rng.next() % 100 >= UPGRADE_OFFER_CHANCE {
+        return;
+    }
+    let fresh: Vec<BoonId> = upgradable
+        .iter()
+        .copied()
+        .filter(|id| !options.contains(id))
+        .collect();
+    if fresh.is_empty() {
+        return;
+    }
+    let upgrade = fresh[(rng.next() % fresh.len() as u64) as usize];
+    if options.len() >= 3 {
+        options.pop();
+    }
+    options.push(upgrade);
+}
+
 /// Rarity weight tables.
 fn rarity_weight(rarity: Rarity, lucky: bool) -> u32 {
     match (rarity, lucky) {
@@ -35,8 +97,10 @@ fn rarity_weight(rarity: Rarity, lucky: bool) -> u32 {
 
 /// Select 3 boon options for the player to choose from.
 ///
-/// - Weights by rarity (Common 60/30/10, Lucky 40/40/20)
-/// - Excludes non-stackable boons the player already has
+/// - Weights by rarity (Common 60/30/10, Lucky 40/40/20); unlocked duo boons
+///   weigh `SYNERGY_WEIGHT`
+/// - Excludes boons that aren't `is_eligible`, i.e. owned one-offs and duo
+///   boons whose pair the player lacks
 /// - Tries to include at least 2 different categories
 /// - Uses seeded RNG for determinism
 pub fn select_boon_options<'a>(
@@ -45,14 +109,34 @@ pub fn select_boon_options<'a>(
     lucky: bool,
     seed: u64,
 ) -> Vec<&'a BoonDef> {
-    let mut rng = Rng::new(seed);
+    let candidates: Vec<&BoonDef> = available
+        .iter()
+        .filter(|b| is_eligible(b, active))
+        .collect();
+
+    pick_options(candidates, lucky, seed)
+}
 
-    // Filter out non-stackable boons the player already has
+/// Like `select_boon_options`, but only offers Rare and Legendary boons.
+/// Used for treasure chest rewards.
+pub fn select_rare_boon_options<'a>(
+    available: &'a [BoonDef],
+    active: &[BoonId],
+    lucky: bool,
+    seed: u64,
+) -> Vec<&'a BoonDef> {
     let candidates: Vec<&BoonDef> = available
         .iter()
-        .filter(|b| b.stackable || !active.contains(&b.id))
+        .filter(|b| b.rarity != Rarity::Common)
+        .filter(|b| is_eligible(b, active))
         .collect();
 
+    pick_options(candidates, lucky, seed)
+}
+
+fn pick_options(candidates: Vec<&BoonDef>, lucky: bool, seed: u64) -> Vec<&BoonDef> {
+    let mut rng = Rng::new(seed);
+
     if candidates.is_empty() {
         return Vec::new();
     }
@@ -64,7 +148,13 @@ pub fn select_boon_options<'a>(
     // Build weighted list
     let weights: Vec<u32> = candidates
         .iter()
-        .map(|b| rarity_weight(b.rarity, lucky))
+        .map(|b| {
+            if synergy(b.id).is_some() {
+                SYNERGY_WEIGHT
+            } else {
+                rarity_weight(b.rarity, lucky)
+            }
+        })
         .collect();
     let total_weight: u32 = weights.iter().sum();
 
@@ -195,7 +285,7 @@ fn weighted_pick_excluding_category(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::boons::BOON_DEFS;
+    use crate::boons::{boon_def, BOON_DEFS};
 
     #[test]
     fn test_returns_3_options() {
@@ -323,4 +413,86 @@ mod tests {
         let result = select_boon_options(&small, &[], false, 42);
         assert_eq!(result.len(), 2);
     }
+
+    #[test]
+    fn test_duo_boons_need_both_halves() {
+        let storm = boon_def(BoonId::StormEdge);
+        assert!(!is_eligible(storm, &[]));
+        assert!(!is_eligible(storm, &[BoonId::ChainLightning]));
+        let pair = [BoonId::CriticalEdge, BoonId::ChainLightning];
+        assert!(is_eligible(storm, &pair));
+        let owned = [
+            BoonId::CriticalEdge,
+            BoonId::ChainLightning,
+            BoonId::StormEdge,
+        ];
+        assert!(!is_eligible(storm, &owned));
+        assert!(!is_eligible(boon_def(BoonId::PhaseStrike), &pair));
+    }
+
+    #[test]
+    fn test_duo_boons_offered_only_once_unlocked() {
+        let duo = |b: &&BoonDef| synergy(b.id).is_some();
+        for seed in 1..200 {
+            assert!(!select_boon_options(BOON_DEFS, &[], false, seed).iter().any(duo));
+        }
+        let active = [BoonId::PhantomDash, BoonId::DashStrike];
+        let offered = (1..200).any(|seed| {
+            select_boon_options(BOON_DEFS, &active, false, seed)
+                .iter()
+                .any(|b| b.id == BoonId::PhaseStrike)
+        });
+        assert!(offered, "unlocked duo boon should be offered");
+    }
+
+    #[test]
+    fn test_synergy_rules_reference_real_boons() {
+        for s in SYNERGIES {
+            assert!(!boon_def(s.boon).stackable);
+            assert_ne!(s.requires[0], s.requires[1]);
+            assert!(s.requires.iter().all(|&r| synergy(r).is_none()));
+        }
+    }
+
+    #[test]
+    fn test_offer_upgrade_sometimes_swaps_in_an_owned_boon() {
+        let upgradable = [BoonId::BerserkersRage];
+        let mut offered = 0;
+        for seed in 1..200 {
+            let mut options: Vec<BoonId> = select_boon_options(BOON_DEFS, &upgradable, false, seed)
+                .iter()
+                .map(|b| b.id)
+                .collect();
+            offer_upgrade(&mut options, &upgradable, seed);
+            assert_eq!(options.len(), 3);
+            if options.contains(&BoonId::BerserkersRage) {
+                assert_eq!(options[2], BoonId::BerserkersRage);
+                offered += 1;
+            }
+        }
+        assert!(
+            offered > 20 && offered < 130,
+            "offered upgrade {offered}/199 times"
+        );
+    }
+
+    #[test]
+    fn test_offer_upgrade_never_duplicates() {
+        for seed in 1..100 {
+            let mut options = vec![BoonId::SharpenedBlade];
+            offer_upgrade(&mut options, &[BoonId::SharpenedBlade], seed);
+            assert_eq!(options, vec![BoonId::SharpenedBlade]);
+            offer_upgrade(&mut options, &[], seed);
+            assert_eq!(options.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rare_options_exclude_common() {
+        for seed in 0..20 {
+            let result = select_rare_boon_options(BOON_DEFS, &[], false, seed * 31);
+            assert_eq!(result.len(), 3);
+            assert!(result.iter().all(|b| b.rarity != Rarity::Common));
+        }
+    }
 }
@@ -0,0 +1,252 @@
+//! Treasure chests: interactable loot containers found in treasure rooms.
+
+use engine::animation::{AnimEvent, AnimationPlayer};
+use engine::tilemap::TILE_SIZE;
+use engine::FrameBuffer;
+
+use crate::dungeon::room_template::RoomTemplate;
+use crate::pickup::{Pickup, PickupType};
+use crate::sprites::chest;
+
+const CHEST_W: f32 = 10.0;
+const CHEST_H: f32 = 8.0;
+/// Horizontal distance between chests when a room holds more than one.
+const CHEST_SPACING: f32 = 16.0;
+/// Max distance between player center and chest center to open it.
+const OPEN_RADIUS: f32 = 10.0;
+
+/// Loot roll weights out of 100: gold, heal, blessing, then rare boon for
+/// the rest.
+const GOLD_WEIGHT: u64 = 45;
+const HEAL_WEIGHT: u64 = 30;
+const BLESSING_WEIGHT: u64 = 6;
+
+const GOLD_MIN: u32 = 10;
+const GOLD_RANGE: u32 = 11;
+const GOLD_COINS: u32 = 4;
+
+/// What a chest holds.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ChestLoot {
+    Gold(u32),
+    Heal,
+    /// A boon choice drawn only from Rare and Legendary boons.
+    RareBoon,
+    /// Raises one owned boon a rarity step.
+    Blessing,
+}
+
+/// Roll a chest's contents. Gold scales with floor like shop prices do.
+pub fn roll_loot(floor: u32, seed: u64) -> ChestLoot {
+    let roll = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    let pick = (roll >> 16) % 100;
+    if pick < GOLD_WEIGHT {
+        let base = GOLD_MIN + ((roll >> 32) % GOLD_RANGE as u64) as u32;
+        ChestLoot::Gold(base * (3 + floor) / 4)
+    } else if pick < GOLD_WEIGHT + HEAL_WEIGHT {
+        ChestLoot::Heal
+    } else if pick < GOLD_WEIGHT + HEAL_WEIGHT + BLESSING_WEIGHT {
+        ChestLoot::Blessing
+    } else {
+        ChestLoot::RareBoon
+    }
+}
+
+pub struct Chest {
+    pub room_index: usize,
+    /// Top-left in world pixels.
+    pub x: f32,
+    pub y: f32,
+    pub loot: ChestLoot,
+    pub opened: bool,
+    pub animation: AnimationPlayer,
+}
+
+impl Chest {
+    pub fn new(room_index: usize, x: f32, y: f32, loot: ChestLoot) -> Self {
+        Self {
+            room_index,
+            x,
+            y,
+            loot,
+            opened: false,
+            animation: AnimationPlayer::new(&chest::CHEST_CLOSED_ANIM),
+        }
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + CHEST_W / 2.0, self.y + CHEST_H / 2.0)
+    }
+
+    /// Whether a player centered at (px, py) is close enough to open this chest.
+    pub fn can_open_from(&self, px: f32, py: f32) -> bool {
+        let (cx, cy) = self.center();
+        let dx = cx - px;
+        let dy = cy - py;
+        !self.opened && dx * dx + dy * dy < OPEN_RADIUS * OPEN_RADIUS
+    }
+
+    /// Start the opening animation. Loot comes out when the lid flips back.
+    pub fn open(&mut self) {
+        if self.opened {
+            return;
+        }
+        self.opened = true;
+        self.animation.play(&chest::CHEST_OPEN_ANIM);
+    }
+
+    pub fn update(&mut self, dt: f64) {
+        self.animation.update(dt);
+    }
+
+    /// True on the tick the opening animation releases the loot.
+    pub fn loot_released(&self) -> bool {
+        self.animation
+            .events()
+            .contains(&AnimEvent::SpawnParticle("chest_loot"))
+    }
+
+    /// World pickups for gold and heal loot, scattered in front of the chest.
+    /// Rare boon loot has no pickup; the caller opens a boon choice instead.
+    pub fn loot_pickups(&self) -> Vec<Pickup> {
+        let (cx, _) = self.center();
+        let front_y = self.y + CHEST_H + 3.0;
+        match self.loot {
+            ChestLoot::Gold(amount) => {
+                let per_coin = amount / GOLD_COINS;
+                let remainder = amount % GOLD_COINS;
+                (0..GOLD_COINS)
+                    .map(|i| {
+                        let value = per_coin + if i == 0 { remainder } else { 0 };
+                        let x = cx - 12.0 + i as f32 * 6.0;
+                        let y = front_y + (i % 2) as f32 * 3.0;
+                        Pickup::new(x, y, PickupType::Gold(value))
+                    })
+                    .collect()
+            }
+            ChestLoot::Heal => vec![Pickup::new(cx - 3.5, front_y, PickupType::BigHeal)],
+            ChestLoot::RareBoon | ChestLoot::Blessing => Vec::new(),
+        }
+    }
+
+    pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
+        let sprite = self.animation.current_sprite();
+        fb.blit_sprite(sprite, self.x as i32 - cam_x, self.y as i32 - cam_y);
+    }
+}
+
+/// Place `count` chests side by side, centered on the template's spawn points.
+pub fn spawn_chests(
+    room_index: usize,
+    template: &RoomTemplate,
+    floor: u32,
+    seed: u64,
+    count: usize,
+) -> Vec<Chest> {
+    let ts = TILE_SIZE as f32;
+    let (cx, cy) = if template.spawn_points.is_empty() {
+        (
+            template.width as f32 * ts / 2.0,
+            template.height as f32 * ts / 2.0,
+        )
+    } else {
+        let n = template.spawn_points.len() as f32;
+        let sx: f32 = template.spawn_points.iter().map(|sp| sp.x as f32).sum();
+        let sy: f32 = template.spawn_points.iter().map(|sp| sp.y as f32).sum();
+        ((sx / n + 0.5) * ts, (sy / n + 0.5) * ts)
+    };
+
+    (0..count)
+        .map(|i| {
+            let offset = (i as f32 - (count - 1) as f32 / 2.0) * CHEST_SPACING;
+            let loot = roll_loot(floor, seed.wrapping_add(i as u64 * 997));
+            Chest::new(
+                room_index,
+                cx + offset - CHEST_W / 2.0,
+                cy - CHEST_H / 2.0,
+                loot,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::templates;
+
+    #[test]
+    fn test_roll_loot_is_deterministic() {
+        for seed in 0..50 {
+            assert_eq!(roll_loot(2, seed), roll_loot(2, seed));
+        }
+    }
+
+    #[test]
+    fn test_roll_loot_covers_all_kinds() {
+        let rolls: Vec<_> = (0..200).map(|s| roll_loot(1, s * 7919)).collect();
+        assert!(rolls.iter().any(|l| matches!(l, ChestLoot::Gold(_))));
+        assert!(rolls.contains(&ChestLoot::Heal));
+        assert!(rolls.contains(&ChestLoot::RareBoon));
+        assert!(rolls.contains(&ChestLoot::Blessing));
+    }
+
+    #[test]
+    fn test_gold_loot_scales_with_floor() {
+        let seed = (0..200)
+            .map(|s| s * 7919)
+            .find(|&s| matches!(roll_loot(1, s), ChestLoot::Gold(_)))
+            .unwrap();
+        let (ChestLoot::Gold(low), ChestLoot::Gold(high)) =
+            (roll_loot(1, seed), roll_loot(5, seed))
+        else {
+            panic!("same seed should roll gold on every floor");
+        };
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_spawn_chests_centered_in_vault() {
+        let template = templates::treasure_vault();
+        let one = spawn_chests(0, &template, 1, 42, 1);
+        let two = spawn_chests(0, &template, 1, 42, 2);
+        assert_eq!(one.len(), 1);
+        assert_eq!(two.len(), 2);
+        let mid = (two[0].x + two[1].x) / 2.0;
+        assert!((mid - one[0].x).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_open_releases_loot_once() {
+        let mut chest = Chest::new(0, 0.0, 0.0, ChestLoot::Heal);
+        let (cx, cy) = chest.center();
+        assert!(chest.can_open_from(cx, cy));
+
+        chest.open();
+        assert!(!chest.can_open_from(cx, cy));
+
+        let mut releases = 0;
+        for _ in 0..30 {
+            chest.update(1.0 / 30.0);
+            if chest.loot_released() {
+                releases += 1;
+            }
+        }
+        assert_eq!(releases, 1);
+        assert!(chest.animation.is_finished());
+    }
+
+    #[test]
+    fn test_gold_loot_splits_into_coins() {
+        let chest = Chest::new(0, 0.0, 0.0, ChestLoot::Gold(14));
+        let total: u32 = chest
+            .loot_pickups()
+            .iter()
+            .map(|p| match p.pickup_type {
+                PickupType::Gold(v) => v,
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(total, 14);
+    }
+}
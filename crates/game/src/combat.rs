@@ -2,10 +2,18 @@
 
 use engine::{BurstConfig, Color, ParticleSystem};
 
-use crate::enemies::{Enemy, EnemyType};
+use crate::boons::effects::PlayerBoons;
+use crate::boons::hooks::{
+    DamagedOutcome, HitOutcome, CHAIN_LIGHTNING_RADIUS, KILL_EXPLOSION_RADIUS,
+};
+use crate::enemies::{Enemy, EnemyType, KnockbackEvent};
 use crate::hud;
-use crate::player::Player;
+use crate::player::{Player, Shot};
 use crate::projectile::ProjectileSystem;
+use crate::run_state::{self, DeathCause};
+use crate::status::StatusKind;
+use crate::traps::{self, Trap};
+use crate::weapons;
 
 // --- Particle burst configurations for combat feedback ---
 
@@ -16,6 +24,14 @@ const HIT_SPARK_COLORS: &[Color] = &[
     [255, 180, 50],
 ];
 
+/// Sparks cool from white-hot to a dull ember as they die.
+const HIT_SPARK_GRADIENT: &[Color] = &[
+    [255, 255, 255],
+    [255, 220, 100],
+    [230, 120, 30],
+    [80, 30, 10],
+];
+
 const HIT_SPARK_CONFIG: BurstConfig = BurstConfig {
     count_min: 8,
     count_max: 12,
@@ -28,6 +44,10 @@ const HIT_SPARK_CONFIG: BurstConfig = BurstConfig {
     friction: 0.9,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: HIT_SPARK_GRADIENT,
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: Some(0.6),
 };
 
 const DEATH_COLORS: &[Color] = &[
@@ -50,6 +70,10 @@ const DEATH_BURST_CONFIG: BurstConfig = BurstConfig {
     friction: 0.92,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const BLOOD_COLORS: &[Color] = &[[200, 30, 30], [150, 20, 20], [255, 50, 50], [180, 10, 10]];
@@ -66,6 +90,10 @@ const BLOOD_BURST_CONFIG: BurstConfig = BurstConfig {
     friction: 0.9,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const PLAYER_DEATH_BURST_CONFIG: BurstConfig = BurstConfig {
@@ -80,6 +108,10 @@ const PLAYER_DEATH_BURST_CONFIG: BurstConfig = BurstConfig {
     friction: 0.92,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 // --- Boss-specific particle configs ---
@@ -105,6 +137,10 @@ pub const BOSS_DEATH_BURST_CONFIG: BurstConfig = BurstConfig {
     friction: 0.92,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const BOSS_SLAM_COLORS: &[Color] = &[
@@ -126,6 +162,73 @@ pub const BOSS_SLAM_BURST_CONFIG: BurstConfig = BurstConfig {
     friction: 0.88,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const RAISE_COLORS: &[Color] = &[[80, 255, 120], [40, 140, 70], [200, 255, 210]];
+
+/// Soul fire rising from a corpse a necromancer raises.
+pub const RAISE_BURST_CONFIG: BurstConfig = BurstConfig {
+    count_min: 10,
+    count_max: 14,
+    speed_min: 10.0,
+    speed_max: 35.0,
+    lifetime_min: 0.3,
+    lifetime_max: 0.6,
+    colors: RAISE_COLORS,
+    gravity: -50.0,
+    friction: 0.9,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const BLINK_COLORS: &[Color] = &[[200, 130, 255], [120, 60, 200], [240, 220, 255]];
+
+/// Puff left where a teleporting elite vanishes and reappears.
+pub const ELITE_BLINK_CONFIG: BurstConfig = BurstConfig {
+    count_min: 8,
+    count_max: 12,
+    speed_min: 15.0,
+    speed_max: 45.0,
+    lifetime_min: 0.2,
+    lifetime_max: 0.4,
+    colors: BLINK_COLORS,
+    gravity: 0.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const SLIME_SPLAT_COLORS: &[Color] = &[[60, 160, 60], [120, 210, 120], [30, 100, 30]];
+
+/// Goo thrown out when a slime bursts.
+pub const SLIME_SPLAT_CONFIG: BurstConfig = BurstConfig {
+    count_min: 8,
+    count_max: 12,
+    speed_min: 20.0,
+    speed_max: 50.0,
+    lifetime_min: 0.2,
+    lifetime_max: 0.4,
+    colors: SLIME_SPLAT_COLORS,
+    gravity: 120.0,
+    friction: 0.88,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 pub const PROJ_TRAIL_COLORS: &[Color] = &[[60, 200, 255], [30, 100, 180], [100, 220, 255]];
@@ -142,6 +245,10 @@ pub const PROJ_TRAIL_CONFIG: BurstConfig = BurstConfig {
     friction: 0.8,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const PROJ_IMPACT_COLORS: &[Color] = &[
@@ -163,179 +270,844 @@ const PROJ_IMPACT_CONFIG: BurstConfig = BurstConfig {
     friction: 0.85,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+// --- Boon trigger feedback ---
+
+const CHAIN_SPARK_COLORS: &[Color] = &[[200, 240, 255], [120, 200, 255], [255, 255, 255]];
+
+const CHAIN_SPARK_CONFIG: BurstConfig = BurstConfig {
+    count_min: 2,
+    count_max: 4,
+    speed_min: 10.0,
+    speed_max: 30.0,
+    lifetime_min: 0.08,
+    lifetime_max: 0.2,
+    colors: CHAIN_SPARK_COLORS,
+    gravity: 0.0,
+    friction: 0.8,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const EXPLOSION_COLORS: &[Color] = &[
+    [255, 255, 200],
+    [255, 200, 80],
+    [255, 120, 30],
+    [200, 60, 20],
+];
+
+const EXPLOSION_GRADIENT: &[Color] = &[[255, 240, 180], [255, 140, 40], [120, 40, 20]];
+
+const KILL_EXPLOSION_CONFIG: BurstConfig = BurstConfig {
+    count_min: 16,
+    count_max: 24,
+    speed_min: 40.0,
+    speed_max: 110.0,
+    lifetime_min: 0.15,
+    lifetime_max: 0.35,
+    colors: EXPLOSION_COLORS,
+    gravity: 0.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: EXPLOSION_GRADIENT,
+    size_start: 2.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const SHIELD_BLOCK_COLORS: &[Color] = &[[220, 230, 255], [160, 180, 220], [255, 255, 255]];
+
+const SHIELD_BLOCK_CONFIG: BurstConfig = BurstConfig {
+    count_min: 10,
+    count_max: 14,
+    speed_min: 30.0,
+    speed_max: 60.0,
+    lifetime_min: 0.2,
+    lifetime_max: 0.35,
+    colors: SHIELD_BLOCK_COLORS,
+    gravity: 0.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
+const SECOND_WIND_COLORS: &[Color] = &[[255, 255, 180], [255, 220, 80], [255, 255, 255]];
+
+const SECOND_WIND_CONFIG: BurstConfig = BurstConfig {
+    count_min: 20,
+    count_max: 28,
+    speed_min: 20.0,
+    speed_max: 50.0,
+    lifetime_min: 0.4,
+    lifetime_max: 0.8,
+    colors: SECOND_WIND_COLORS,
+    gravity: -40.0,
+    friction: 0.9,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+// --- Knockback into terrain ---
+
+const WALL_SLAM_COLORS: &[Color] = &[[200, 180, 150], [160, 140, 110], [255, 255, 220]];
+
+const WALL_SLAM_CONFIG: BurstConfig = BurstConfig {
+    count_min: 6,
+    count_max: 10,
+    speed_min: 20.0,
+    speed_max: 60.0,
+    lifetime_min: 0.15,
+    lifetime_max: 0.35,
+    colors: WALL_SLAM_COLORS,
+    gravity: 40.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+/// Damage before boons when knockback slams an enemy into a wall.
+const WALL_SLAM_DAMAGE: i32 = 1;
+/// Seconds a wall slam leaves the enemy stunned.
+const WALL_SLAM_STUN: f32 = 0.8;
+
+/// Reach and damage of an explosive elite's death blast.
+const ELITE_BLAST_RADIUS: f32 = 22.0;
+const ELITE_BLAST_DAMAGE: i32 = 1;
+
+const HIT_NUMBER_COLOR: Color = [255, 255, 100];
+const KILL_NUMBER_COLOR: Color = [255, 80, 80];
+const CRIT_NUMBER_COLOR: Color = [255, 160, 40];
+const CHAIN_NUMBER_COLOR: Color = [120, 200, 255];
+const EXPLOSION_NUMBER_COLOR: Color = [255, 140, 60];
+const SLAM_NUMBER_COLOR: Color = [230, 210, 170];
+const HAZARD_NUMBER_COLOR: Color = [255, 120, 40];
+const LIFESTEAL_NUMBER_COLOR: Color = [120, 255, 120];
+const STATUS_NUMBER_COLOR: Color = [190, 230, 120];
+const PLAYER_DAMAGE_COLOR: Color = [255, 80, 80];
+
+/// Knockback applied by boon-triggered damage (chains, explosions, retaliation).
+const CHAIN_KNOCKBACK: f32 = 40.0;
+const EXPLOSION_KNOCKBACK: f32 = 80.0;
+const RETALIATION_KNOCKBACK: f32 = 60.0;
+const DASH_STRIKE_KNOCKBACK: f32 = 70.0;
+const SLASH_PROJECTILE_KNOCKBACK: f32 = 50.0;
+
 /// Effects produced by combat checks, applied by the game loop.
 #[derive(Default)]
 pub struct CombatEffects {
     pub hit_pause_frames: u32,
     pub camera_shake: f32,
     pub player_died: bool,
+    /// A hit got through to the player; blocked ones don't count.
+    pub player_hit: bool,
+    /// Gold from each enemy killed this check, after boon bonuses.
+    pub kill_gold: Vec<u32>,
+    /// What each enemy killed this check was.
+    pub killed: Vec<EnemyType>,
+    /// HP the player regains from on-hit boons.
+    pub heal: i32,
+    /// Enemies knocked into walls hard enough to be slammed.
+    pub wall_slams: u32,
+    /// Enemies killed by pits, traps or wall slams.
+    pub environment_kills: u32,
+    /// Where elites died this check; each drops a heal.
+    pub elite_drops: Vec<(f32, f32)>,
+    /// HP the player's hits took off enemies.
+    pub damage_dealt: u32,
+    /// HP the player lost.
+    pub damage_taken: u32,
+    /// What landed the killing blow, if the player died.
+    pub killed_by: Option<DeathCause>,
+}
+
+/// Applies damage dealt by the player, routing kills through the boon hooks
+/// and chaining any Killing Blow explosions they set off.
+struct HitResolver<'a> {
+    boons: &'a mut PlayerBoons,
+    particles: &'a mut ParticleSystem,
+    damage_numbers: &'a mut Vec<hud::DamageNumber>,
+    effects: CombatEffects,
+    /// Pending explosions: center and damage.
+    explosions: Vec<(f32, f32, i32)>,
+}
+
+impl<'a> HitResolver<'a> {
+    fn new(
+        boons: &'a mut PlayerBoons,
+        particles: &'a mut ParticleSystem,
+        damage_numbers: &'a mut Vec<hud::DamageNumber>,
+    ) -> Self {
+        Self {
+            boons,
+            particles,
+            damage_numbers,
+            effects: CombatEffects::default(),
+            explosions: Vec::new(),
+        }
+    }
+
+    /// Damage one enemy, knocking it away from `from`. Returns true if this blow killed it.
+    fn strike(
+        &mut self,
+        enemy: &mut Enemy,
+        dmg: i32,
+        from: (f32, f32),
+        kb_force: f32,
+        color: Color,
+    ) -> bool {
+        let was_alive = enemy.alive && enemy.hp > 0;
+        let (ecx, ecy) = enemy.world_hurtbox().center();
+        if enemy.absorb_hit() {
+            self.particles.burst(ecx, ecy, &SHIELD_BLOCK_CONFIG);
+            self.effects.hit_pause_frames = self.effects.hit_pause_frames.max(2);
+            return false;
+        }
+        let dx = ecx - from.0;
+        let dy = ecy - from.1;
+        let len = (dx * dx + dy * dy).sqrt().max(0.01);
+
+        let hp_before = enemy.hp.max(0);
+        enemy.take_damage_with_knockback(dmg, dx / len, dy / len, kb_force);
+        self.effects.damage_dealt += (hp_before - enemy.hp.max(0)) as u32;
+        self.particles.burst(ecx, ecy, &HIT_SPARK_CONFIG);
+
+        let killed = was_alive && enemy.hp <= 0;
+        let color = if killed { KILL_NUMBER_COLOR } else { color };
+        self.damage_numbers
+            .push(hud::DamageNumber::new(dmg, ecx - 2.0, ecy - 8.0, color));
+
+        if killed {
+            self.credit_kill(enemy);
+        } else {
+            // Hit: smaller feedback
+            self.effects.hit_pause_frames = self.effects.hit_pause_frames.max(3);
+            self.effects.camera_shake = self.effects.camera_shake.max(2.5);
+        }
+        killed
+    }
+
+    /// Status damage ticking on one enemy. Returns true if it killed it.
+    fn afflict(&mut self, enemy: &mut Enemy, dmg: i32) -> bool {
+        if !enemy.alive || enemy.hp <= 0 {
+            return false;
+        }
+        let hp_before = enemy.hp;
+        enemy.take_status_damage(dmg);
+        self.effects.damage_dealt += (hp_before - enemy.hp.max(0)) as u32;
+        let (ecx, ecy) = enemy.world_hurtbox().center();
+        let killed = enemy.hp <= 0;
+        let color = if killed {
+            KILL_NUMBER_COLOR
+        } else {
+            STATUS_NUMBER_COLOR
+        };
+        self.damage_numbers
+            .push(hud::DamageNumber::new(dmg, ecx - 2.0, ecy - 8.0, color));
+        if killed {
+            self.credit_kill(enemy);
+        }
+        killed
+    }
+
+    /// A shield bearer facing `from` deflects the blow. Returns true if it did.
+    fn shield_block(&mut self, enemy: &mut Enemy, from: (f32, f32)) -> bool {
+        if !enemy.shield_blocks(from.0) {
+            return false;
+        }
+        let (ecx, ecy) = enemy.world_hurtbox().center();
+        let dx = ecx - from.0;
+        let dy = ecy - from.1;
+        let len = (dx * dx + dy * dy).sqrt().max(0.01);
+        enemy.block_hit(dx / len, dy / len);
+        let face_x = if from.0 > ecx { ecx + 4.0 } else { ecx - 4.0 };
+        self.particles.burst(face_x, ecy, &SHIELD_BLOCK_CONFIG);
+        self.effects.hit_pause_frames = self.effects.hit_pause_frames.max(2);
+        self.effects.camera_shake = self.effects.camera_shake.max(1.5);
+        true
+    }
+
+    /// Kill feedback and rewards for an enemy the player just killed.
+    fn credit_kill(&mut self, enemy: &Enemy) {
+        let (ecx, ecy) = enemy.world_hurtbox().center();
+        self.effects.hit_pause_frames = self.effects.hit_pause_frames.max(5);
+        self.effects.camera_shake = self.effects.camera_shake.max(5.0);
+        self.particles.burst(ecx, ecy, &DEATH_BURST_CONFIG);
+
+        let mut gold = run_state::enemy_gold(enemy.enemy_type);
+        if enemy.is_elite() {
+            gold *= run_state::ELITE_GOLD_MULT;
+            self.effects.elite_drops.push((ecx, ecy));
+        }
+        // A raised skeleton was already paid for once; it can't be farmed
+        if enemy.is_raised() {
+            gold = 0;
+        }
+        let kill = self.boons.on_kill(gold);
+        if !enemy.is_raised() {
+            self.effects.kill_gold.push(kill.gold);
+            self.effects.killed.push(enemy.enemy_type);
+        }
+        if kill.explode_damage > 0 {
+            self.explosions.push((ecx, ecy, kill.explode_damage));
+        }
+    }
+
+    /// Resolve an on-hit boon outcome against `enemies[target]`: damage, heal,
+    /// status effects, chain.
+    fn land_hit(
+        &mut self,
+        enemies: &mut [Enemy],
+        target: usize,
+        hit: &HitOutcome,
+        from: (f32, f32),
+        kb_force: f32,
+    ) {
+        if self.shield_block(&mut enemies[target], from) {
+            return;
+        }
+        self.effects.heal += hit.heal;
+        let color = if hit.crit {
+            CRIT_NUMBER_COLOR
+        } else {
+            HIT_NUMBER_COLOR
+        };
+        self.strike(&mut enemies[target], hit.damage, from, kb_force, color);
+        for &kind in &hit.inflicts {
+            enemies[target].apply_status(kind);
+        }
+        if hit.chain_targets > 0 {
+            self.chain_lightning(enemies, target, hit.chain_targets, hit.chain_damage);
+        }
+    }
+
+    /// Arc from `source` to the nearest living enemies in range.
+    fn chain_lightning(&mut self, enemies: &mut [Enemy], source: usize, count: usize, dmg: i32) {
+        let (sx, sy) = enemies[source].world_hurtbox().center();
+        let mut nearby: Vec<(usize, f32)> = enemies
+            .iter()
+            .enumerate()
+            .filter(|&(i, e)| i != source && e.alive && e.hp > 0)
+            .map(|(i, e)| {
+                let (ex, ey) = e.world_hurtbox().center();
+                (i, (ex - sx) * (ex - sx) + (ey - sy) * (ey - sy))
+            })
+            .filter(|&(_, d2)| d2 <= CHAIN_LIGHTNING_RADIUS * CHAIN_LIGHTNING_RADIUS)
+            .collect();
+        nearby.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        for &(i, _) in nearby.iter().take(count) {
+            let (tx, ty) = enemies[i].world_hurtbox().center();
+            for step in 1..=3 {
+                let t = step as f32 / 4.0;
+                self.particles
+                    .burst(sx + (tx - sx) * t, sy + (ty - sy) * t, &CHAIN_SPARK_CONFIG);
+            }
+            self.strike(
+                &mut enemies[i],
+                dmg,
+                (sx, sy),
+                CHAIN_KNOCKBACK,
+                CHAIN_NUMBER_COLOR,
+            );
+        }
+    }
+
+    /// Set off queued explosions. Kills inside a blast can queue more.
+    fn finish(mut self, enemies: &mut [Enemy]) -> CombatEffects {
+        while let Some((x, y, dmg)) = self.explosions.pop() {
+            self.particles.burst(x, y, &KILL_EXPLOSION_CONFIG);
+            self.effects.camera_shake = self.effects.camera_shake.max(6.0);
+            for enemy in enemies.iter_mut() {
+                if !enemy.alive || enemy.hp <= 0 {
+                    continue;
+                }
+                let (ex, ey) = enemy.world_hurtbox().center();
+                let d2 = (ex - x) * (ex - x) + (ey - y) * (ey - y);
+                if d2 <= KILL_EXPLOSION_RADIUS * KILL_EXPLOSION_RADIUS {
+                    self.strike(
+                        enemy,
+                        dmg,
+                        (x, y),
+                        EXPLOSION_KNOCKBACK,
+                        EXPLOSION_NUMBER_COLOR,
+                    );
+                }
+            }
+        }
+        self.effects
+    }
 }
 
 /// Check player's attack hitbox against all enemy hurtboxes.
-/// Applies damage to enemies, spawns hit/death particles and damage numbers.
+/// Each connecting hit goes through the boons' on_hit hook before damage is applied.
 pub fn check_player_attacks(
     player: &Player,
+    boons: &mut PlayerBoons,
     enemies: &mut [Enemy],
     particles: &mut ParticleSystem,
     damage_numbers: &mut Vec<hud::DamageNumber>,
 ) -> CombatEffects {
-    let mut effects = CombatEffects::default();
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
 
     let attack_hb = match player.attack_hitbox() {
         Some(hb) => hb,
-        None => return effects,
+        None => return res.effects,
     };
 
-    let weapon = player.weapon();
-    let dmg = weapon.base_damage;
-    let kb_force = weapon.knockback_force;
+    let knockback = player.attack_knockback();
+    let from = player.center();
+    for i in 0..enemies.len() {
+        let enemy = &mut enemies[i];
+        if !enemy.alive || enemy.hit_this_attack {
+            continue;
+        }
+        if attack_hb.overlaps(&enemy.world_hurtbox()) {
+            enemy.hit_this_attack = true;
+            let mut hit = res.boons.on_hit(player.attack_damage());
+            hit.damage = player.status.weaken(hit.damage);
+            res.land_hit(enemies, i, &hit, from, knockback);
+        }
+    }
 
-    let (pcx, pcy) = player.center();
+    res.finish(enemies)
+}
+
+/// Check player-fired projectiles (Projectile Slash, arrows, spell bolts) against enemies.
+pub fn check_player_projectiles(
+    boons: &mut PlayerBoons,
+    enemies: &mut [Enemy],
+    projectiles: &mut ProjectileSystem,
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    for (idx, hx, hy, dmg) in projectiles.check_enemy_hits(enemies) {
+        res.particles.burst(hx, hy, &PROJ_IMPACT_CONFIG);
+        let hit = res.boons.on_hit(dmg);
+        res.land_hit(enemies, idx, &hit, (hx, hy), SLASH_PROJECTILE_KNOCKBACK);
+    }
+    res.finish(enemies)
+}
+
+/// Dash Strike: damage enemies the dashing player passes through, once each per dash.
+/// Uses `hit_this_attack` as the per-dash tracker; the caller resets it when a dash starts.
+pub fn check_dash_strike(
+    player: &Player,
+    damage: i32,
+    boons: &mut PlayerBoons,
+    enemies: &mut [Enemy],
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    if damage <= 0 || !player.is_dashing() {
+        return res.effects;
+    }
+    let hurtbox = player.world_hurtbox();
+    let from = player.center();
     for enemy in enemies.iter_mut() {
-        if !enemy.alive || enemy.hit_this_attack {
+        if !enemy.alive || enemy.hit_this_attack || !hurtbox.overlaps(&enemy.world_hurtbox()) {
             continue;
         }
-        let hurtbox = enemy.world_hurtbox();
-        if attack_hb.overlaps(&hurtbox) {
-            let (ecx, ecy) = hurtbox.center();
-            let dx = ecx - pcx;
-            let dy = ecy - pcy;
-            let len = (dx * dx + dy * dy).sqrt().max(0.01);
+        enemy.hit_this_attack = true;
+        if !res.shield_block(enemy, from) {
+            res.strike(enemy, damage, from, DASH_STRIKE_KNOCKBACK, HIT_NUMBER_COLOR);
+            if let Some(kind) = res.boons.dash_strike_status {
+                enemy.apply_status(kind);
+            }
+        }
+    }
+    res.finish(enemies)
+}
 
-            let was_alive = enemy.alive;
-            enemy.take_damage_with_knockback(dmg, dx / len, dy / len, kb_force);
-            enemy.hit_this_attack = true;
+/// Projectile Slash: launch a bolt in the facing direction as an attack starts.
+pub fn spawn_slash_projectile(player: &Player, projectiles: &mut ProjectileSystem) {
+    let (cx, cy) = player.center();
+    let dir = if player.facing_right { 1.0 } else { -1.0 };
+    projectiles.spawn_friendly(
+        cx - 1.5 + dir * 4.0,
+        cy - 1.5,
+        dir,
+        0.0,
+        player.status.weaken(player.weapon().base_damage),
+    );
+}
 
-            particles.burst(ecx, ecy, &HIT_SPARK_CONFIG);
-
-            if !enemy.alive && was_alive {
-                // Kill: bigger feedback
-                effects.hit_pause_frames = 5;
-                effects.camera_shake = 5.0;
-                particles.burst(ecx, ecy, &DEATH_BURST_CONFIG);
-                damage_numbers.push(hud::DamageNumber::new(
-                    dmg,
-                    ecx - 2.0,
-                    ecy - 8.0,
-                    [255, 80, 80],
-                ));
-            } else {
-                // Hit: smaller feedback
-                effects.hit_pause_frames = 3;
-                effects.camera_shake = 2.5;
-                damage_numbers.push(hud::DamageNumber::new(
-                    dmg,
-                    ecx - 2.0,
-                    ecy - 8.0,
-                    [255, 255, 100],
-                ));
+/// Fire the bow arrow or staff burst the player loosed this update, if any.
+pub fn spawn_player_shots(player: &Player, projectiles: &mut ProjectileSystem) {
+    let Some(shot) = player.shot else {
+        return;
+    };
+    let (cx, cy) = player.center();
+    let (ax, ay) = player.aim();
+    let (sx, sy) = (cx - 1.5 + ax * 4.0, cy - 1.5 + ay * 4.0);
+    let base = player.weapon().base_damage;
+    match shot {
+        Shot::Arrow { draw } => {
+            let (damage, pierce) = weapons::arrow_power(base, draw);
+            let damage = player.status.weaken(damage);
+            projectiles.spawn_arrow(sx, sy, ax, ay, damage, pierce);
+        }
+        Shot::Burst => {
+            let damage = player.status.weaken(base);
+            let aim = ay.atan2(ax);
+            let mid = (weapons::STAFF_BOLTS - 1) as f32 / 2.0;
+            for i in 0..weapons::STAFF_BOLTS {
+                let angle = aim + (i as f32 - mid) * weapons::STAFF_SPREAD;
+                projectiles.spawn_bolt(sx, sy, angle.cos(), angle.sin(), damage);
             }
         }
     }
+}
+
+/// Let the boons react to an incoming hit, then apply what gets through,
+/// along with any status effect the hit carries. `cause` is what dealt it.
+/// Returns true if the player died.
+fn land_player_hit(
+    player: &mut Player,
+    outcome: &DamagedOutcome,
+    kb_dir: (f32, f32),
+    at: (f32, f32),
+    status: Option<StatusKind>,
+    cause: DeathCause,
+    res: &mut HitResolver,
+) -> bool {
+    let (pcx, pcy) = player.center();
+    if outcome.blocked {
+        player.block_hit(kb_dir.0, kb_dir.1);
+        res.effects.hit_pause_frames = 2;
+        res.effects.camera_shake = 2.0;
+        res.particles.burst(pcx, pcy, &SHIELD_BLOCK_CONFIG);
+        return false;
+    }
+
+    res.effects.player_hit |= !player.is_invincible() && !player.is_dead();
+    let hp_before = player.hp;
+    let died = player.take_damage(outcome.damage, kb_dir.0, kb_dir.1);
+    res.effects.damage_taken += (hp_before - player.hp).max(0) as u32;
+    if let Some(kind) = status.filter(|_| !died) {
+        player.status.apply(kind);
+    }
+    if outcome.second_wind {
+        res.particles.burst(pcx, pcy, &SECOND_WIND_CONFIG);
+    }
+    if died {
+        res.effects.hit_pause_frames = 8;
+        res.effects.camera_shake = 8.0;
+        res.effects.player_died = true;
+        res.effects.killed_by = Some(cause);
+        res.particles.burst(pcx, pcy, &PLAYER_DEATH_BURST_CONFIG);
+    } else if player.invincible_timer > 0.0 {
+        res.effects.hit_pause_frames = 4;
+        res.effects.camera_shake = 4.0;
+        res.particles.burst(at.0, at.1, &BLOOD_BURST_CONFIG);
+        if outcome.damage > 0 {
+            res.damage_numbers.push(hud::DamageNumber::new(
+                outcome.damage,
+                at.0 - 2.0,
+                at.1 - 8.0,
+                PLAYER_DAMAGE_COLOR,
+            ));
+        }
+    }
+    died
+}
 
-    effects
+/// A vampiric elite heals off a hit that got through to the player.
+fn lifesteal(enemy: &mut Enemy, outcome: &DamagedOutcome, res: &mut HitResolver) {
+    if outcome.blocked {
+        return;
+    }
+    let healed = enemy.feed(outcome.damage);
+    if healed > 0 {
+        let (ecx, ecy) = enemy.world_hurtbox().center();
+        res.damage_numbers.push(hud::DamageNumber::new(
+            healed,
+            ecx - 2.0,
+            ecy - 8.0,
+            LIFESTEAL_NUMBER_COLOR,
+        ));
+    }
 }
 
 /// Check enemy melee attacks and projectile hits against the player.
-/// Applies damage to player, spawns blood/death particles and damage numbers.
+/// Hits go through the boons' on_damaged hook (shields, Second Wind, Retaliation).
 pub fn check_enemy_attacks(
     player: &mut Player,
-    enemies: &[Enemy],
+    boons: &mut PlayerBoons,
+    enemies: &mut [Enemy],
     projectiles: &mut ProjectileSystem,
     particles: &mut ParticleSystem,
     damage_numbers: &mut Vec<hud::DamageNumber>,
 ) -> CombatEffects {
-    let mut effects = CombatEffects::default();
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
 
     let player_hurtbox = player.world_hurtbox();
     let (pcx, pcy) = player.center();
 
-    // Melee attacks (skeletons and boss)
-    for enemy in enemies {
+    // Melee attacks (skeletons, shield bearers and bosses)
+    for enemy in enemies.iter_mut() {
         if !enemy.alive {
             continue;
         }
         // Only check enemies with melee attacks
         if !matches!(
             enemy.enemy_type,
-            EnemyType::Skeleton | EnemyType::BoneKing
+            EnemyType::Skeleton
+                | EnemyType::ShieldBearer
+                | EnemyType::BoneKing
+                | EnemyType::SlimeMother
         ) {
             continue;
         }
         if let Some(atk_hb) = enemy.attack_hitbox() {
             if atk_hb.overlaps(&player_hurtbox) {
+                if player.is_invincible() || player.is_dead() {
+                    break;
+                }
                 let (ecx, ecy) = enemy.center();
                 let dx = pcx - ecx;
                 let dy = pcy - ecy;
                 let len = (dx * dx + dy * dy).sqrt().max(0.01);
-                // Boss deals 2 damage per hit
-                let dmg = if enemy.enemy_type == EnemyType::BoneKing {
-                    2
-                } else {
-                    1
-                };
-                let died = player.take_damage(dmg, dx / len, dy / len);
-                if died {
-                    effects.hit_pause_frames = 8;
-                    effects.camera_shake = 8.0;
-                    effects.player_died = true;
-                    particles.burst(pcx, pcy, &PLAYER_DEATH_BURST_CONFIG);
-                } else if player.invincible_timer > 0.0 {
-                    effects.hit_pause_frames = 4;
-                    effects.camera_shake = 4.0;
-                    particles.burst(pcx, pcy, &BLOOD_BURST_CONFIG);
-                    damage_numbers.push(hud::DamageNumber::new(
-                        dmg,
-                        pcx - 2.0,
-                        pcy - 8.0,
-                        [255, 80, 80],
-                    ));
+                // Bosses deal 2 damage per hit
+                let dmg = enemy.weaken(if enemy.enemy_type.is_boss() { 2 } else { 1 });
+                let outcome = res.boons.on_damaged(dmg, player.hp, true);
+                let kb_dir = (dx / len, dy / len);
+                let status = enemy.enemy_type.inflicts();
+                let cause = DeathCause::Enemy(enemy.enemy_type);
+                let at = (pcx, pcy);
+                let died = land_player_hit(player, &outcome, kb_dir, at, status, cause, &mut res);
+                lifesteal(enemy, &outcome, &mut res);
+                if outcome.retaliate > 0 && !died {
+                    res.strike(
+                        enemy,
+                        outcome.retaliate,
+                        (pcx, pcy),
+                        RETALIATION_KNOCKBACK,
+                        HIT_NUMBER_COLOR,
+                    );
                 }
                 break; // only take one melee hit per frame
             }
         }
     }
 
+    // Contact damage (slimes bumping into the player)
+    for enemy in enemies.iter_mut() {
+        if !enemy.can_deal_contact_damage() || !enemy.world_hurtbox().overlaps(&player_hurtbox) {
+            continue;
+        }
+        if player.is_invincible() || player.is_dead() {
+            break;
+        }
+        enemy.apply_contact_damage_cooldown();
+        let (ecx, ecy) = enemy.center();
+        let dx = pcx - ecx;
+        let dy = pcy - ecy;
+        let len = (dx * dx + dy * dy).sqrt().max(0.01);
+        let outcome = res.boons.on_damaged(enemy.weaken(1), player.hp, true);
+        let kb_dir = (dx / len, dy / len);
+        let status = enemy.enemy_type.inflicts();
+        let cause = DeathCause::Enemy(enemy.enemy_type);
+        let at = (pcx, pcy);
+        let died = land_player_hit(player, &outcome, kb_dir, at, status, cause, &mut res);
+        lifesteal(enemy, &outcome, &mut res);
+        if outcome.retaliate > 0 && !died {
+            res.strike(
+                enemy,
+                outcome.retaliate,
+                (pcx, pcy),
+                RETALIATION_KNOCKBACK,
+                HIT_NUMBER_COLOR,
+            );
+        }
+        break;
+    }
+
     // Projectile hits
     if !player.is_dead() {
         let proj_hits = projectiles.check_player_hits(&player.world_hurtbox());
-        for (hx, hy, dmg) in proj_hits {
+        for (hx, hy, dmg, status, shooter) in proj_hits {
+            if player.is_invincible() {
+                continue;
+            }
             let dx = pcx - hx;
             let dy = pcy - hy;
             let len = (dx * dx + dy * dy).sqrt().max(0.01);
-            let died = player.take_damage(dmg, dx / len, dy / len);
-            if died {
-                effects.hit_pause_frames = 8;
-                effects.camera_shake = 8.0;
-                effects.player_died = true;
-                particles.burst(pcx, pcy, &PLAYER_DEATH_BURST_CONFIG);
-                break;
-            } else if player.invincible_timer > 0.0 {
-                effects.hit_pause_frames = 4;
-                effects.camera_shake = 4.0;
-                particles.burst(hx, hy, &BLOOD_BURST_CONFIG);
-                damage_numbers.push(hud::DamageNumber::new(
-                    dmg,
-                    hx - 2.0,
-                    hy - 8.0,
-                    [255, 80, 80],
-                ));
-                break; // one hit per frame due to i-frames
-            }
+            let outcome = res.boons.on_damaged(dmg, player.hp, false);
+            let kb_dir = (dx / len, dy / len);
+            let cause = shooter.map_or(DeathCause::Trap, DeathCause::Enemy);
+            land_player_hit(player, &outcome, kb_dir, (hx, hy), status, cause, &mut res);
+            break; // one hit per frame due to i-frames
         }
     }
 
-    effects
+    res.finish(enemies)
 }
 
-/// Spawn projectiles from ghost enemies that fired this frame.
+/// Damage from the room itself (traps, pits). Goes through on_damaged like a
+/// projectile would: shields and Second Wind apply, Retaliation has no target.
+pub fn apply_hazard_damage(
+    player: &mut Player,
+    boons: &mut PlayerBoons,
+    dmg: i32,
+    cause: DeathCause,
+    at: (f32, f32),
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    if player.is_invincible() || player.is_dead() {
+        return res.effects;
+    }
+    let (pcx, pcy) = player.center();
+    let dx = pcx - at.0;
+    let dy = pcy - at.1;
+    let len = (dx * dx + dy * dy).sqrt().max(0.01);
+    let outcome = res.boons.on_damaged(dmg, player.hp, false);
+    let kb_dir = (dx / len, dy / len);
+    land_player_hit(player, &outcome, kb_dir, (pcx, pcy), None, cause, &mut res);
+    res.effects
+}
+
+/// Damage from status effects ticking on the player and on enemies. It
+/// bypasses the boons' on_damaged hook, and kills count as the player's.
+pub fn apply_status_damage(
+    player: &mut Player,
+    boons: &mut PlayerBoons,
+    enemies: &mut [Enemy],
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    let lost = player.take_status_damage(player.status_damage);
+    res.effects.damage_taken += lost as u32;
+    if lost > 0 {
+        let (pcx, pcy) = player.center();
+        res.damage_numbers.push(hud::DamageNumber::new(
+            lost,
+            pcx - 2.0,
+            pcy - 8.0,
+            PLAYER_DAMAGE_COLOR,
+        ));
+    }
+    for enemy in enemies.iter_mut() {
+        if enemy.status_damage > 0 {
+            res.afflict(enemy, enemy.status_damage);
+        }
+    }
+    res.finish(enemies)
+}
+
+/// Explosive elites whose fuse ran out this tick blow up, hurting the player
+/// if they're caught in the blast.
+pub fn detonate_elites(
+    player: &mut Player,
+    boons: &mut PlayerBoons,
+    enemies: &[Enemy],
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    let (pcx, pcy) = player.center();
+    for enemy in enemies.iter().filter(|e| e.elite_detonated) {
+        let (x, y) = enemy.world_hurtbox().center();
+        res.particles.burst(x, y, &KILL_EXPLOSION_CONFIG);
+        res.effects.camera_shake = res.effects.camera_shake.max(6.0);
+        let (dx, dy) = (pcx - x, pcy - y);
+        let dist = (dx * dx + dy * dy).sqrt();
+        if dist > ELITE_BLAST_RADIUS || player.is_invincible() || player.is_dead() {
+            continue;
+        }
+        let len = dist.max(0.01);
+        let outcome = res.boons.on_damaged(ELITE_BLAST_DAMAGE, player.hp, false);
+        let kb_dir = (dx / len, dy / len);
+        let cause = DeathCause::Enemy(enemy.enemy_type);
+        land_player_hit(player, &outcome, kb_dir, (pcx, pcy), None, cause, &mut res);
+    }
+    res.effects
+}
+
+/// What enemies were knocked into this tick. Wall slams deal bonus damage and
+/// stun, pits swallow the enemy, and active traps hurt enemies sliding across
+/// them. Deaths all count as the player's kills.
+pub fn resolve_knockback(
+    boons: &mut PlayerBoons,
+    enemies: &mut [Enemy],
+    traps: &[Trap],
+    particles: &mut ParticleSystem,
+    damage_numbers: &mut Vec<hud::DamageNumber>,
+) -> CombatEffects {
+    let mut res = HitResolver::new(boons, particles, damage_numbers);
+    for enemy in enemies.iter_mut() {
+        let center = enemy.world_hurtbox().center();
+        match enemy.knockback_event {
+            Some(KnockbackEvent::WallSlam) => {
+                res.effects.wall_slams += 1;
+                res.effects.camera_shake = res.effects.camera_shake.max(4.0);
+                res.particles.burst(center.0, center.1, &WALL_SLAM_CONFIG);
+                let dmg = res.boons.on_wall_slam(WALL_SLAM_DAMAGE);
+                if res.strike(enemy, dmg, center, 0.0, SLAM_NUMBER_COLOR) {
+                    res.effects.environment_kills += 1;
+                } else {
+                    enemy.stun(WALL_SLAM_STUN);
+                }
+            }
+            Some(KnockbackEvent::FellInPit) => {
+                res.effects.environment_kills += 1;
+                res.credit_kill(enemy);
+            }
+            None => {
+                if !enemy.alive || enemy.hp <= 0 || !enemy.is_knocked_back() {
+                    continue;
+                }
+                // Zero knockback stops the slide, so each shove hurts once
+                if let Some(at) = traps::hazard_at(traps, &enemy.world_hurtbox()) {
+                    if res.strike(enemy, traps::TRAP_DAMAGE, at, 0.0, HAZARD_NUMBER_COLOR) {
+                        res.effects.environment_kills += 1;
+                    }
+                }
+            }
+        }
+    }
+    res.finish(enemies)
+}
+
+/// Spawn projectiles from ghosts and archers that fired this frame, and from
+/// the Lich's volleys.
 pub fn spawn_enemy_projectiles(enemies: &[Enemy], projectiles: &mut ProjectileSystem) {
     for enemy in enemies {
+        let (ex, ey) = enemy.center();
+        let mut fire = |dx: f32, dy: f32| {
+            projectiles.spawn_enemy(ex - 1.5, ey - 1.5, dx, dy, enemy.enemy_type)
+        };
         if enemy.fired_projectile {
-            let (ex, ey) = enemy.center();
-            projectiles.spawn(ex - 1.5, ey - 1.5, enemy.aim_dir_x, enemy.aim_dir_y);
+            fire(enemy.aim_dir_x, enemy.aim_dir_y);
+        }
+        for &angle in &enemy.volley {
+            fire(angle.cos(), angle.sin());
         }
     }
 }
@@ -355,3 +1127,56 @@ pub fn update_projectiles(
         particles.burst(ix, iy, &PROJ_IMPACT_CONFIG);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::room_template::TrapKind;
+    use crate::traps::TrapPhase;
+
+    fn knock(enemies: &mut [Enemy], traps: &[Trap]) -> CombatEffects {
+        let mut boons = PlayerBoons::new();
+        let mut particles = ParticleSystem::new();
+        let mut numbers = Vec::new();
+        resolve_knockback(&mut boons, enemies, traps, &mut particles, &mut numbers)
+    }
+
+    #[test]
+    fn test_wall_slam_hurts_without_killing_a_healthy_enemy() {
+        let mut enemies = vec![Enemy::new_skeleton(40.0, 40.0, 1)];
+        enemies[0].knockback_event = Some(KnockbackEvent::WallSlam);
+        let effects = knock(&mut enemies, &[]);
+        assert_eq!(enemies[0].hp, 3 - WALL_SLAM_DAMAGE);
+        assert_eq!((effects.wall_slams, effects.environment_kills), (1, 0));
+        assert!(enemies[0].alive && effects.kill_gold.is_empty());
+    }
+
+    #[test]
+    fn test_pit_fall_is_credited_as_an_environment_kill() {
+        let mut enemies = vec![Enemy::new_skeleton(40.0, 40.0, 1)];
+        enemies[0].hp = 0;
+        enemies[0].alive = false;
+        enemies[0].knockback_event = Some(KnockbackEvent::FellInPit);
+        let effects = knock(&mut enemies, &[]);
+        assert_eq!(effects.environment_kills, 1);
+        assert_eq!(effects.kill_gold.len(), 1);
+        assert!(effects.killed == [EnemyType::Skeleton]);
+    }
+
+    #[test]
+    fn test_sliding_onto_a_live_trap_can_kill() {
+        let mut spikes = Trap::new(TrapKind::Spikes, 5, 5, 0.0);
+        while spikes.phase() != TrapPhase::Active {
+            spikes.update(0.05);
+        }
+        let (tx, ty) = spikes.center();
+        let (ox, oy) = Enemy::new_skeleton(0.0, 0.0, 1).world_hurtbox().center();
+        let mut enemies = vec![Enemy::new_skeleton(tx - ox, ty - oy, 1)];
+        enemies[0].hp = 1;
+        enemies[0].take_damage_with_knockback(0, 1.0, 0.0, 60.0);
+
+        let effects = knock(&mut enemies, &[spikes]);
+        assert!(!enemies[0].alive);
+        assert_eq!((effects.wall_slams, effects.environment_kills), (0, 1));
+    }
+}
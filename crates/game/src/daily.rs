@@ -0,0 +1,272 @@
+//! Daily challenge: one dungeon per calendar day (UTC), the same for every
+//! player, with the weapon and pact picked by the date. The first run each
+//! day is the official attempt and goes on the local leaderboard.
+
+use serde::{Deserialize, Serialize};
+
+use crate::pact::{Pact, PACTS};
+use crate::save::data_path;
+use crate::weapons::{all_weapons, WeaponId};
+
+/// Pacts a daily run is dealt.
+const DAILY_PACT_COUNT: usize = 2;
+
+/// Score per floor reached.
+const SCORE_PER_FLOOR: u32 = 1000;
+const SCORE_PER_KILL: u32 = 10;
+const SCORE_VICTORY: u32 = 5000;
+/// A won run scores this much for every second it came in under par.
+const SCORE_PER_SEC_UNDER_PAR: u32 = 5;
+const PAR_SECS: u32 = 900;
+/// Entries shown on the title-screen leaderboard.
+pub const LEADERBOARD_SHOWN: usize = 5;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Today as a day number: days since 1970-01-01, UTC.
+pub fn today() -> u32 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| (d.as_secs() / SECS_PER_DAY) as u32)
+        .unwrap_or(0)
+}
+
+/// Calendar date of a day number as (year, month, day).
+fn civil_date(day: u32) -> (i32, u32, u32) {
+    // Howard Hinnant's days-to-civil, shifted so years start in March
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = (yoe + era * 400) as i32 + i32::from(m <= 2);
+    (y, m, d)
+}
+
+/// Date label, e.g. "2026-10-18".
+pub fn date_label(day: u32) -> String {
+    let (y, m, d) = civil_date(day);
+    format!("{y}-{m:02}-{d:02}")
+}
+
+/// Run seed for a day.
+pub fn seed(day: u32) -> u64 {
+    // Scramble so neighbouring days don't start from neighbouring seeds
+    let mut x = (day as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// The weapon everyone plays with on this day.
+pub fn weapon(day: u32) -> WeaponId {
+    let weapons = all_weapons();
+    weapons[(seed(day) % weapons.len() as u64) as usize].id
+}
+
+/// The pact everyone plays under on this day: a rank in a couple of
+/// different pacts.
+pub fn pact(day: u32) -> Pact {
+    let mut pact = Pact::default();
+    let mut bits = seed(day) >> 8;
+    let mut taken = 0;
+    while taken < DAILY_PACT_COUNT {
+        let def = &PACTS[(bits % PACTS.len() as u64) as usize];
+        bits = bits.rotate_right(7) ^ 0x5bd1_e995;
+        if pact.rank(def.id) == 0 {
+            pact.raise(def.id);
+            taken += 1;
+        }
+    }
+    pact
+}
+
+/// Leaderboard score for a finished run.
+pub fn score(floor: u32, kills: u32, secs: u32, victory: bool) -> u32 {
+    let mut score = floor * SCORE_PER_FLOOR + kills * SCORE_PER_KILL;
+    if victory {
+        score += SCORE_VICTORY + PAR_SECS.saturating_sub(secs) * SCORE_PER_SEC_UNDER_PAR;
+    }
+    score
+}
+
+/// One official daily attempt.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DailyEntry {
+    pub day: u32,
+    pub score: u32,
+    pub time_secs: u32,
+    pub floor: u32,
+    pub kills: u32,
+    pub victory: bool,
+}
+
+impl DailyEntry {
+    /// Stand-in written as the official attempt starts, so quitting the game
+    /// mid-run still uses up the day. `end_run` fills in the real result.
+    pub fn started(day: u32) -> Self {
+        Self {
+            day,
+            score: 0,
+            time_secs: 0,
+            floor: 0,
+            kills: 0,
+            victory: false,
+        }
+    }
+}
+
+/// Every official daily attempt, stored in `~/.cryptfall/daily.json`.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Leaderboard {
+    pub entries: Vec<DailyEntry>,
+}
+
+impl Leaderboard {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(data_path("daily.json")) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                eprintln!("Warning: corrupted leaderboard file, starting fresh: {e}");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        let path = data_path("daily.json");
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                eprintln!("Warning: could not create save directory: {e}");
+                return;
+            }
+        }
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(&path, json) {
+                    eprintln!("Warning: could not write leaderboard file: {e}");
+                }
+            }
+            Err(e) => eprintln!("Warning: could not serialize leaderboard: {e}"),
+        }
+    }
+
+    /// The day's official attempt has already been played.
+    pub fn attempted(&self, day: u32) -> bool {
+        self.entries.iter().any(|e| e.day == day)
+    }
+
+    /// Record the day's official attempt. Returns false if there already is one.
+    pub fn record(&mut self, entry: DailyEntry) -> bool {
+        if self.attempted(entry.day) {
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+
+    /// Replace the day's attempt with its final result.
+    pub fn finish(&mut self, entry: DailyEntry) {
+        match self.entries.iter_mut().find(|e| e.day == entry.day) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Best attempts first; ties go to the faster run.
+    pub fn ranked(&self) -> Vec<&DailyEntry> {
+        let mut ranked: Vec<&DailyEntry> = self.entries.iter().collect();
+        ranked.sort_by_key(|e| (std::cmp::Reverse(e.score), e.time_secs));
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(day: u32, score: u32, time_secs: u32) -> DailyEntry {
+        DailyEntry {
+            day,
+            score,
+            time_secs,
+            floor: 3,
+            kills: 20,
+            victory: false,
+        }
+    }
+
+    #[test]
+    fn date_labels() {
+        assert_eq!(date_label(0), "1970-01-01");
+        assert_eq!(date_label(59), "1970-03-01");
+        assert_eq!(date_label(11_016), "2000-02-29");
+        assert_eq!(date_label(20_744), "2026-10-18");
+    }
+
+    #[test]
+    fn each_day_has_its_own_fixed_seed() {
+        assert_eq!(seed(20_744), seed(20_744));
+        assert_ne!(seed(20_744), seed(20_745));
+        assert_eq!(weapon(20_744), weapon(20_744));
+        assert_eq!(pact(20_744), pact(20_744));
+    }
+
+    #[test]
+    fn daily_pact_takes_distinct_pacts() {
+        for day in 20_000..20_050 {
+            let pact = pact(day);
+            let taken = PACTS.iter().filter(|d| pact.rank(d.id) > 0).count();
+            assert_eq!(taken, DAILY_PACT_COUNT);
+            assert!(PACTS.iter().all(|d| pact.rank(d.id) <= 1));
+        }
+    }
+
+    #[test]
+    fn winning_outscores_dying_deeper_in() {
+        assert!(score(5, 40, 2000, true) > score(5, 80, 600, false));
+        assert!(score(5, 40, 600, true) > score(5, 40, 800, true));
+        assert_eq!(score(5, 40, 2000, true), score(5, 40, PAR_SECS, true));
+    }
+
+    #[test]
+    fn one_official_attempt_per_day() {
+        let mut board = Leaderboard::default();
+        assert!(board.record(entry(10, 3000, 400)));
+        assert!(board.attempted(10));
+        assert!(!board.record(entry(10, 9000, 300)));
+        assert_eq!(board.entries.len(), 1);
+        assert_eq!(board.entries[0].score, 3000);
+    }
+
+    #[test]
+    fn started_attempt_blocks_a_retry_until_finished() {
+        let mut board = Leaderboard::default();
+        assert!(board.record(DailyEntry::started(10)));
+        assert!(!board.record(DailyEntry::started(10)));
+        board.finish(entry(10, 3000, 400));
+        assert_eq!(board.entries, [entry(10, 3000, 400)]);
+    }
+
+    #[test]
+    fn ranked_by_score_then_time() {
+        let mut board = Leaderboard::default();
+        board.record(entry(1, 2000, 500));
+        board.record(entry(2, 4000, 700));
+        board.record(entry(3, 4000, 600));
+        let days: Vec<u32> = board.ranked().iter().map(|e| e.day).collect();
+        assert_eq!(days, [3, 2, 1]);
+    }
+
+    #[test]
+    fn leaderboard_roundtrips_through_json() {
+        let mut board = Leaderboard::default();
+        board.record(entry(7, 1234, 321));
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: Leaderboard = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.entries, board.entries);
+    }
+}
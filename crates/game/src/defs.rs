@@ -0,0 +1,508 @@
+//! Designer overrides for boon, weapon and upgrade definitions.
+//!
+//! The compiled-in tables (`BOON_DEFS`, the weapons in weapons.rs and
+//! `save::UPGRADES`) are the defaults. A JSON file in `~/.cryptfall/defs/`
+//! can patch any of them without a rebuild:
+//!
+//! - `boons.json`, keyed by `BoonId` (`{"Fury": {"rarity": "Rare"}}`)
+//! - `weapons.json`, keyed by `WeaponId` (`{"Sword": {"base_damage": 3}}`)
+//! - `upgrades.json`, keyed by upgrade name (`{"Vitality I": {"cost": 20}}`)
+//!
+//! Each entry lists only the fields it changes. Unknown keys, unknown fields,
+//! duplicate entries and out-of-range values are rejected with the file,
+//! line and column; a rejected file leaves its whole table at the defaults.
+//! See docs/data-definitions.md for every field.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::de::{self, Deserializer, Error as _, MapAccess, Unexpected, Visitor};
+use serde::Deserialize;
+
+use crate::boons::{BoonCategory, BoonDef, BoonId, Rarity, BOON_DEFS};
+use crate::save::{self, UpgradeDef, UPGRADES};
+use crate::weapons::{self, WeaponDef, WeaponId};
+
+static BOONS: OnceLock<Vec<BoonDef>> = OnceLock::new();
+static WEAPONS: OnceLock<Vec<WeaponDef>> = OnceLock::new();
+static UPGRADE_DEFS: OnceLock<Vec<UpgradeDef>> = OnceLock::new();
+
+/// Boon table patched by `boons.json`, if one was loaded.
+pub fn boons() -> Option<&'static [BoonDef]> {
+    BOONS.get().map(Vec::as_slice)
+}
+
+/// Weapon table patched by `weapons.json`, in `all_weapons` order.
+pub fn weapons() -> Option<&'static [WeaponDef]> {
+    WEAPONS.get().map(Vec::as_slice)
+}
+
+/// Upgrade table patched by `upgrades.json`, if one was loaded.
+pub fn upgrades() -> Option<&'static [UpgradeDef]> {
+    UPGRADE_DEFS.get().map(Vec::as_slice)
+}
+
+/// A definitions file that exists but couldn't be used.
+#[derive(Debug)]
+pub struct DefsError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for DefsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Load every definitions file present and install the patched tables.
+/// Call once at startup, before anything reads a definition. Returns the
+/// files that were rejected; their tables keep the compiled-in values.
+pub fn load() -> Vec<DefsError> {
+    let dir = save::data_path("defs");
+    let mut errors = Vec::new();
+    if let Some(defs) = load_file(&dir.join("boons.json"), parse_boons, &mut errors) {
+        let _ = BOONS.set(defs);
+    }
+    if let Some(defs) = load_file(&dir.join("weapons.json"), parse_weapons, &mut errors) {
+        let _ = WEAPONS.set(defs);
+    }
+    if let Some(defs) = load_file(&dir.join("upgrades.json"), parse_upgrades, &mut errors) {
+        let _ = UPGRADE_DEFS.set(defs);
+    }
+    errors
+}
+
+/// Read and parse one file. A missing file is not an error.
+fn load_file<T>(
+    path: &Path,
+    parse: fn(&str) -> Result<T, serde_json::Error>,
+    errors: &mut Vec<DefsError>,
+) -> Option<T> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            errors.push(DefsError {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            });
+            return None;
+        }
+    };
+    parse(&text)
+        .map_err(|e| {
+            errors.push(DefsError {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })
+        })
+        .ok()
+}
+
+/// Definitions live for the whole run, so overridden text is leaked once
+/// to fit the `&'static str` fields of the compiled tables.
+fn leak(text: String) -> &'static str {
+    text.leak()
+}
+
+// --- Boons ---
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BoonPatch {
+    #[serde(default, deserialize_with = "non_empty")]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    description: Option<String>,
+    rarity: Option<Rarity>,
+    category: Option<BoonCategory>,
+    stackable: Option<bool>,
+}
+
+/// Apply a `boons.json` document to `BOON_DEFS`.
+pub fn parse_boons(text: &str) -> Result<Vec<BoonDef>, serde_json::Error> {
+    let Patches(patches) = serde_json::from_str::<Patches<BoonId, BoonPatch>>(text)?;
+    let mut defs = BOON_DEFS.to_vec();
+    for (id, patch) in patches {
+        let def = defs
+            .iter_mut()
+            .find(|d| d.id == id)
+            .expect("every boon has a definition");
+        if let Some(name) = patch.name {
+            def.name = leak(name);
+        }
+        if let Some(description) = patch.description {
+            def.description = leak(description);
+        }
+        def.rarity = patch.rarity.unwrap_or(def.rarity);
+        def.category = patch.category.unwrap_or(def.category);
+        def.stackable = patch.stackable.unwrap_or(def.stackable);
+    }
+    Ok(defs)
+}
+
+// --- Weapons ---
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WeaponPatch {
+    #[serde(default, deserialize_with = "non_empty")]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "positive_i32")]
+    base_damage: Option<i32>,
+    #[serde(default, deserialize_with = "positive_f32")]
+    attack_cooldown: Option<f32>,
+    #[serde(default, deserialize_with = "non_negative_f32")]
+    hitbox_w: Option<f32>,
+    #[serde(default, deserialize_with = "non_negative_f32")]
+    hitbox_h: Option<f32>,
+    hitbox_offset_x: Option<f32>,
+    hitbox_offset_y: Option<f32>,
+    #[serde(default, deserialize_with = "non_negative_f32")]
+    knockback_force: Option<f32>,
+    #[serde(default, deserialize_with = "non_empty")]
+    description: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    speed_label: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    range_label: Option<String>,
+}
+
+/// Apply a `weapons.json` document to the compiled-in weapons.
+pub fn parse_weapons(text: &str) -> Result<Vec<WeaponDef>, serde_json::Error> {
+    let Patches(patches) = serde_json::from_str::<Patches<WeaponId, WeaponPatch>>(text)?;
+    let mut defs: Vec<WeaponDef> = weapons::default_weapons().into_iter().cloned().collect();
+    for (id, patch) in patches {
+        let def = defs
+            .iter_mut()
+            .find(|d| d.id == id)
+            .expect("every weapon has a definition");
+        if let Some(name) = patch.name {
+            def.name = leak(name);
+        }
+        def.base_damage = patch.base_damage.unwrap_or(def.base_damage);
+        def.attack_cooldown = patch.attack_cooldown.unwrap_or(def.attack_cooldown);
+        def.hitbox_w = patch.hitbox_w.unwrap_or(def.hitbox_w);
+        def.hitbox_h = patch.hitbox_h.unwrap_or(def.hitbox_h);
+        def.hitbox_offset_x = patch.hitbox_offset_x.unwrap_or(def.hitbox_offset_x);
+        def.hitbox_offset_y = patch.hitbox_offset_y.unwrap_or(def.hitbox_offset_y);
+        def.knockback_force = patch.knockback_force.unwrap_or(def.knockback_force);
+        if let Some(description) = patch.description {
+            def.description = leak(description);
+        }
+        if let Some(label) = patch.speed_label {
+            def.speed_label = leak(label);
+        }
+        if let Some(label) = patch.range_label {
+            def.range_label = leak(label);
+        }
+    }
+    Ok(defs)
+}
+
+// --- Permanent upgrades ---
+
+/// Upgrades have no id enum, so their file is keyed by display name.
+#[derive(PartialEq)]
+struct UpgradeName(String);
+
+impl fmt::Debug for UpgradeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for UpgradeName {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(d)?;
+        if UPGRADES.iter().any(|u| u.name == name) {
+            Ok(UpgradeName(name))
+        } else {
+            let known: Vec<&str> = UPGRADES.iter().map(|u| u.name).collect();
+            Err(D::Error::custom(format!(
+                "unknown upgrade `{name}`, expected one of: {}",
+                known.join(", ")
+            )))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UpgradePatch {
+    #[serde(default, deserialize_with = "non_empty")]
+    description: Option<String>,
+    #[serde(default, deserialize_with = "positive_u32")]
+    cost: Option<u32>,
+}
+
+/// Apply an `upgrades.json` document to `save::UPGRADES`.
+pub fn parse_upgrades(text: &str) -> Result<Vec<UpgradeDef>, serde_json::Error> {
+    let Patches(patches) = serde_json::from_str::<Patches<UpgradeName, UpgradePatch>>(text)?;
+    let mut defs = UPGRADES.to_vec();
+    for (UpgradeName(name), patch) in patches {
+        let def = defs
+            .iter_mut()
+            .find(|d| d.name == name)
+            .expect("name was validated");
+        if let Some(description) = patch.description {
+            def.description = leak(description);
+        }
+        def.cost = patch.cost.unwrap_or(def.cost);
+    }
+    Ok(defs)
+}
+
+// --- Schema helpers ---
+
+/// A JSON object of patches that rejects a key appearing twice, which a
+/// plain map would silently resolve to the last entry.
+struct Patches<K, V>(Vec<(K, V)>);
+
+impl<'de, K, V> Deserialize<'de> for Patches<K, V>
+where
+    K: Deserialize<'de> + PartialEq + fmt::Debug,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct PatchVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for PatchVisitor<K, V>
+        where
+            K: Deserialize<'de> + PartialEq + fmt::Debug,
+            V: Deserialize<'de>,
+        {
+            type Value = Patches<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of definition overrides")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries: Vec<(K, V)> = Vec::new();
+                while let Some(key) = map.next_key::<K>()? {
+                    if entries.iter().any(|(k, _)| *k == key) {
+                        return Err(A::Error::custom(format!("duplicate entry {key:?}")));
+                    }
+                    let value = map.next_value()?;
+                    entries.push((key, value));
+                }
+                Ok(Patches(entries))
+            }
+        }
+
+        d.deserialize_map(PatchVisitor(PhantomData))
+    }
+}
+
+// Checks run inside the visitors, so serde_json reports a bad value at its
+// own line rather than at the end of the entry holding it.
+
+struct NonEmpty;
+
+impl Visitor<'_> for NonEmpty {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("non-empty text")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<String, E> {
+        if v.trim().is_empty() {
+            return Err(E::invalid_value(Unexpected::Str(v), &self));
+        }
+        Ok(v.to_string())
+    }
+}
+
+/// A number no lower than `min` (or strictly above it when not `inclusive`).
+struct Bounded {
+    min: f64,
+    inclusive: bool,
+    integer: bool,
+}
+
+impl Bounded {
+    fn check<E: de::Error>(self, v: f64, unexpected: Unexpected) -> Result<f64, E> {
+        let in_range = if self.inclusive {
+            v >= self.min
+        } else {
+            v > self.min
+        };
+        if in_range {
+            Ok(v)
+        } else {
+            Err(E::invalid_value(unexpected, &self))
+        }
+    }
+}
+
+impl Visitor<'_> for Bounded {
+    type Value = f64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = if self.integer {
+            "a whole number"
+        } else {
+            "a number"
+        };
+        let bound = if self.inclusive { "at least" } else { "above" };
+        write!(f, "{kind} {bound} {}", self.min)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<f64, E> {
+        self.check(v as f64, Unexpected::Signed(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<f64, E> {
+        self.check(v as f64, Unexpected::Unsigned(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<f64, E> {
+        if self.integer {
+            return Err(E::invalid_type(Unexpected::Float(v), &self));
+        }
+        self.check(v, Unexpected::Float(v))
+    }
+}
+
+fn bounded<'de, D: Deserializer<'de>>(
+    d: D,
+    min: f64,
+    inclusive: bool,
+    integer: bool,
+) -> Result<f64, D::Error> {
+    let visitor = Bounded {
+        min,
+        inclusive,
+        integer,
+    };
+    if integer {
+        d.deserialize_i64(visitor)
+    } else {
+        d.deserialize_f64(visitor)
+    }
+}
+
+fn non_empty<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    d.deserialize_str(NonEmpty).map(Some)
+}
+
+fn positive_i32<'de, D: Deserializer<'de>>(d: D) -> Result<Option<i32>, D::Error> {
+    bounded(d, 1.0, true, true).map(|v| Some(v.min(i32::MAX as f64) as i32))
+}
+
+fn positive_u32<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u32>, D::Error> {
+    bounded(d, 1.0, true, true).map(|v| Some(v.min(u32::MAX as f64) as u32))
+}
+
+fn positive_f32<'de, D: Deserializer<'de>>(d: D) -> Result<Option<f32>, D::Error> {
+    bounded(d, 0.0, false, false).map(|v| Some(v as f32))
+}
+
+fn non_negative_f32<'de, D: Deserializer<'de>>(d: D) -> Result<Option<f32>, D::Error> {
+    bounded(d, 0.0, true, false).map(|v| Some(v as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boons::boon_def;
+
+    #[test]
+    fn test_empty_files_keep_defaults() {
+        let boons = parse_boons("{}").unwrap();
+        assert_eq!(boons.len(), BOON_DEFS.len());
+        assert_eq!(parse_weapons("{}").unwrap().len(), 5);
+        let upgrades = parse_upgrades("{}").unwrap();
+        assert_eq!(upgrades[0].cost, UPGRADES[0].cost);
+    }
+
+    #[test]
+    fn test_boon_patch_changes_only_listed_fields() {
+        let text = r#"{ "Fury": { "rarity": "Rare", "name": "Bloodlust" } }"#;
+        let boons = parse_boons(text).unwrap();
+        let fury = boons.iter().find(|b| b.id == BoonId::Fury).unwrap();
+        assert_eq!(fury.rarity, Rarity::Rare);
+        assert_eq!(fury.name, "Bloodlust");
+        assert_eq!(fury.category, boon_def(BoonId::Fury).category);
+        assert_eq!(fury.description, boon_def(BoonId::Fury).description);
+    }
+
+    #[test]
+    fn test_weapon_and_upgrade_patches() {
+        let weapons =
+            parse_weapons(r#"{ "Spear": { "base_damage": 5, "attack_cooldown": 0.4 } }"#).unwrap();
+        let spear = weapons.iter().find(|w| w.id == WeaponId::Spear).unwrap();
+        assert_eq!(spear.base_damage, 5);
+        assert!((spear.attack_cooldown - 0.4).abs() < f32::EPSILON);
+        assert_eq!(
+            spear.combo.len(),
+            weapons::get_weapon(WeaponId::Spear).combo.len()
+        );
+
+        let upgrades = parse_upgrades(r#"{ "Twin Dash": { "cost": 65 } }"#).unwrap();
+        assert_eq!(
+            upgrades
+                .iter()
+                .find(|u| u.name == "Twin Dash")
+                .unwrap()
+                .cost,
+            65
+        );
+    }
+
+    fn assert_rejected<T>(result: Result<T, serde_json::Error>, needle: &str, line: usize) {
+        let err = result
+            .err()
+            .expect("invalid definitions should be rejected");
+        assert!(err.to_string().contains(needle), "{err}");
+        assert_eq!(err.line(), line, "{err}");
+    }
+
+    #[test]
+    fn test_errors_name_the_line() {
+        assert_rejected(
+            parse_boons("{\n  \"Furry\": {}\n}"),
+            "unknown variant `Furry`",
+            2,
+        );
+        assert_rejected(
+            parse_boons("{\n\n  \"Fury\": { \"rarty\": 1 }\n}"),
+            "rarty",
+            3,
+        );
+        assert_rejected(
+            parse_boons("{\n \"Fury\": {},\n \"Fury\": {}\n}"),
+            "duplicate entry",
+            3,
+        );
+        assert_rejected(
+            parse_upgrades("{\n  \"Vitality V\": {}\n}"),
+            "unknown upgrade",
+            2,
+        );
+
+        let damage = "{\n  \"Sword\": {\n    \"base_damage\": 0\n  }\n}";
+        assert_rejected(parse_weapons(damage), "at least 1", 3);
+        let label = "{\n  \"Bow\": {\n    \"speed_label\": \"\"\n  }\n}";
+        assert_rejected(parse_weapons(label), "non-empty", 3);
+        let cost = "{\n  \"Twin Dash\": {\n    \"cost\": 1.5\n  }\n}";
+        assert_rejected(parse_upgrades(cost), "whole number", 3);
+    }
+
+    #[test]
+    fn test_defs_error_names_the_file() {
+        let err = DefsError {
+            path: PathBuf::from("defs/boons.json"),
+            message: "duplicate entry Fury at line 3 column 8".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "defs/boons.json: duplicate entry Fury at line 3 column 8"
+        );
+    }
+}
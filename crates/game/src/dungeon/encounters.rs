@@ -3,6 +3,8 @@
 //! Each combat room selects an `EncounterDef` based on difficulty and floor number.
 //! An encounter consists of one or more waves of enemies that spawn in sequence.
 
+use crate::enemies::elite::{Affix, Affixes};
+use crate::enemies::slime::SlimeSize;
 use crate::enemies::{Enemy, EnemyType};
 use engine::tilemap::TILE_SIZE;
 
@@ -24,6 +26,8 @@ pub struct EnemySpawn {
     pub enemy_type: EnemyType,
     /// Index into the room's spawn_points array (wraps around if out of bounds).
     pub spawn_index: usize,
+    /// Champion affixes; empty for an ordinary enemy.
+    pub affixes: Affixes,
 }
 
 /// When a wave should activate.
@@ -36,6 +40,12 @@ pub enum WaveTrigger {
     OnEnemyCountBelow(usize),
 }
 
+/// Chance per enemy of rolling as an elite, for each floor past the first.
+const ELITE_CHANCE_PER_FLOOR: f32 = 0.08;
+const ELITE_CHANCE_MAX: f32 = 0.35;
+/// From this floor on, an elite may roll two affixes.
+const DOUBLE_AFFIX_FLOOR: u32 = 4;
+
 /// Difficulty tier for encounter selection.
 #[derive(Clone, Copy)]
 pub enum EncounterDifficulty {
@@ -46,10 +56,12 @@ pub enum EncounterDifficulty {
 }
 
 /// Select an encounter based on difficulty, floor, and available spawn points.
+/// `extra_elite_chance` is added to the floor's elite chance (pacts).
 pub fn select_encounter(
     difficulty: EncounterDifficulty,
     floor_number: u32,
     num_spawn_points: usize,
+    extra_elite_chance: f32,
     seed: u64,
 ) -> EncounterDef {
     if num_spawn_points == 0 {
@@ -62,88 +74,187 @@ pub fn select_encounter(
 
     // Use seed to add variety within the same difficulty tier
     let variant = (seed % 3) as usize;
+    let n = num_spawn_points;
 
-    match difficulty {
+    let mut encounter = match difficulty {
         EncounterDifficulty::Easy => {
-            // Single wave, skeletons only
-            let sk_count = (2 + floor_bonus_sk + variant % 2).min(num_spawn_points);
+            // Single wave, skeletons; from floor 2 one may be an archer.
+            // Early floors sometimes swap two skeletons for slimes.
+            let sk_count = 2 + floor_bonus_sk + variant % 2;
+            let archers = usize::from(variant == 2 && floor_number >= 2);
+            let slimes = usize::from(variant == 1 && floor_number <= 2);
             EncounterDef {
                 waves: vec![WaveDef {
-                    enemies: make_spawns(sk_count, 0, 0),
+                    enemies: make_spawns(
+                        &[
+                            (EnemyType::SkeletonArcher, archers),
+                            (EnemyType::Slime(SlimeSize::Large), slimes),
+                            (EnemyType::Slime(SlimeSize::Medium), slimes),
+                            (EnemyType::Skeleton, sk_count - archers - 2 * slimes),
+                        ],
+                        0,
+                        n,
+                    ),
                     trigger: WaveTrigger::Immediate,
                 }],
             }
         }
         EncounterDifficulty::Medium => {
-            // Two waves: skeletons, then skeletons + ghosts
-            let w1_sk = (2 + floor_bonus_sk).min(num_spawn_points);
-            let w2_sk = (1 + floor_bonus_sk / 2).min(num_spawn_points);
-            let w2_gh = (1 + floor_bonus_gh).min(num_spawn_points.saturating_sub(w2_sk));
+            // Two waves: skeletons behind a shield (or a big slime on early
+            // floors), then skeletons + ranged support
+            let shields = usize::from(variant == 1 || floor_number >= 3);
+            let slimes = usize::from(variant == 2 && floor_number <= 2);
+            let w1 = make_spawns(
+                &[
+                    (EnemyType::ShieldBearer, shields),
+                    (EnemyType::Slime(SlimeSize::Large), slimes),
+                    (EnemyType::Skeleton, 2 + floor_bonus_sk - shields - slimes),
+                ],
+                0,
+                n,
+            );
+            let archers = usize::from(variant == 0 || floor_number >= 2);
+            let w2 = make_spawns(
+                &[
+                    (EnemyType::SkeletonArcher, archers),
+                    (EnemyType::Skeleton, 1 + floor_bonus_sk / 2),
+                    (EnemyType::Ghost, 1 + floor_bonus_gh),
+                ],
+                w1.len(),
+                n,
+            );
             EncounterDef {
                 waves: vec![
                     WaveDef {
-                        enemies: make_spawns(w1_sk, 0, 0),
+                        enemies: w1,
                         trigger: WaveTrigger::Immediate,
                     },
                     WaveDef {
-                        enemies: make_spawns(w2_sk, w2_gh, w1_sk),
+                        enemies: w2,
                         trigger: WaveTrigger::OnPreviousWaveCleared,
                     },
                 ],
             }
         }
         EncounterDifficulty::Hard => {
-            // Two waves, both with ghosts
-            let w1_sk = (3 + floor_bonus_sk).min(num_spawn_points);
-            let w1_gh = (1 + floor_bonus_gh).min(num_spawn_points.saturating_sub(w1_sk));
-            let w2_sk = (2 + floor_bonus_sk).min(num_spawn_points);
-            let w2_gh = (2 + floor_bonus_gh).min(num_spawn_points.saturating_sub(w2_sk));
+            // Two waves, both with ranged enemies. The second brings a
+            // necromancer to raise the first wave's bones.
+            let w1 = make_spawns(
+                &[
+                    (EnemyType::ShieldBearer, 1),
+                    (EnemyType::Skeleton, 2 + floor_bonus_sk),
+                    (EnemyType::Ghost, 1 + floor_bonus_gh),
+                ],
+                0,
+                n,
+            );
+            let archers = usize::from(floor_number >= 2);
+            let w2 = make_spawns(
+                &[
+                    (EnemyType::Necromancer, 1),
+                    (EnemyType::SkeletonArcher, archers),
+                    (EnemyType::Skeleton, 1 + floor_bonus_sk),
+                    (EnemyType::Ghost, 2 + floor_bonus_gh - archers),
+                ],
+                w1.len(),
+                n,
+            );
             EncounterDef {
                 waves: vec![
                     WaveDef {
-                        enemies: make_spawns(w1_sk, w1_gh, 0),
+                        enemies: w1,
                         trigger: WaveTrigger::Immediate,
                     },
                     WaveDef {
-                        enemies: make_spawns(w2_sk, w2_gh, w1_sk + w1_gh),
+                        enemies: w2,
                         trigger: WaveTrigger::OnEnemyCountBelow(2),
                     },
                 ],
             }
         }
         EncounterDifficulty::Boss => {
-            // Single wave: one Bone King boss
+            // Single wave: the floor's boss
             EncounterDef {
                 waves: vec![WaveDef {
                     enemies: vec![EnemySpawn {
-                        enemy_type: EnemyType::BoneKing,
+                        enemy_type: boss_for_floor(floor_number),
                         spawn_index: 0,
+                        affixes: Affixes::default(),
                     }],
                     trigger: WaveTrigger::Immediate,
                 }],
             }
         }
+    };
+    roll_elites(&mut encounter, floor_number, extra_elite_chance, seed);
+    encounter
+}
+
+/// Roll champion affixes onto an encounter's enemies. The first floor has
+/// none; each floor deeper rolls more elites, and late floors can stack two
+/// affixes on one enemy. `extra_chance` stacks on top, past the usual cap.
+fn roll_elites(encounter: &mut EncounterDef, floor_number: u32, extra_chance: f32, seed: u64) {
+    let floors_deep = floor_number.saturating_sub(1) as f32;
+    let chance = (floors_deep * ELITE_CHANCE_PER_FLOOR).min(ELITE_CHANCE_MAX) + extra_chance;
+    if chance <= 0.0 {
+        return;
+    }
+    let mut rng_state = (seed as u32)
+        .wrapping_mul(2654435761)
+        .wrapping_add(floor_number);
+    let mut rand = || {
+        rng_state = rng_state.wrapping_mul(1103515245).wrapping_add(12345);
+        (rng_state >> 16) as f32 / 65536.0
+    };
+
+    for spawn in encounter.waves.iter_mut().flat_map(|w| &mut w.enemies) {
+        if rand() >= chance {
+            continue;
+        }
+        let count = if floor_number >= DOUBLE_AFFIX_FLOOR && rand() < 0.5 {
+            2
+        } else {
+            1
+        };
+        while spawn.affixes.len() < count {
+            let pool: Vec<Affix> = Affix::ALL
+                .into_iter()
+                .filter(|&a| a.suits(spawn.enemy_type) && !spawn.affixes.has(a))
+                .collect();
+            if pool.is_empty() {
+                break;
+            }
+            let pick = ((rand() * pool.len() as f32) as usize).min(pool.len() - 1);
+            spawn.affixes = spawn.affixes.with(pool[pick]);
+        }
+    }
+}
+
+/// The boss waiting at the end of a floor. The Bone King holds the middle
+/// floor and the last one.
+fn boss_for_floor(floor_number: u32) -> EnemyType {
+    match floor_number {
+        1 => EnemyType::SlimeMother,
+        2 | 4 => EnemyType::Lich,
+        _ => EnemyType::BoneKing,
     }
 }
 
-/// Build a list of `EnemySpawn`s: skeletons first, then ghosts.
+/// Build a wave's `EnemySpawn`s from (type, count) pairs, in order, stopping
+/// once `max` enemies are placed. List the enemies that matter most first.
 /// `offset` shifts spawn_index so later waves use different spawn points.
-fn make_spawns(skeleton_count: usize, ghost_count: usize, offset: usize) -> Vec<EnemySpawn> {
-    let total = skeleton_count + ghost_count;
-    let mut spawns = Vec::with_capacity(total);
-    for i in 0..skeleton_count {
-        spawns.push(EnemySpawn {
-            enemy_type: EnemyType::Skeleton,
+fn make_spawns(roster: &[(EnemyType, usize)], offset: usize, max: usize) -> Vec<EnemySpawn> {
+    roster
+        .iter()
+        .flat_map(|&(enemy_type, count)| std::iter::repeat_n(enemy_type, count))
+        .take(max)
+        .enumerate()
+        .map(|(i, enemy_type)| EnemySpawn {
+            enemy_type,
             spawn_index: offset + i,
-        });
-    }
-    for i in 0..ghost_count {
-        spawns.push(EnemySpawn {
-            enemy_type: EnemyType::Ghost,
-            spawn_index: offset + skeleton_count + i,
-        });
-    }
-    spawns
+            affixes: Affixes::default(),
+        })
+        .collect()
 }
 
 /// Instantiate `Enemy` objects from a wave's spawn list using room spawn points.
@@ -167,12 +278,18 @@ pub fn instantiate_wave(
             .wrapping_add(base_seed as u32)
             .wrapping_add(i as u32 * 7919 + 1);
 
-        let enemy = match es.enemy_type {
+        let mut enemy = match es.enemy_type {
             EnemyType::Skeleton => Enemy::new_skeleton(px, py, seed),
             EnemyType::Ghost => Enemy::new_ghost(px, py, seed),
-            EnemyType::Slime => Enemy::new_slime(px, py),
+            EnemyType::SkeletonArcher => Enemy::new_skeleton_archer(px, py, seed),
+            EnemyType::Necromancer => Enemy::new_necromancer(px, py, seed),
+            EnemyType::ShieldBearer => Enemy::new_shield_bearer(px, py),
+            EnemyType::Slime(size) => Enemy::new_slime(px, py, size, seed),
             EnemyType::BoneKing => Enemy::new_bone_king(px, py, seed),
+            EnemyType::Lich => Enemy::new_lich(px, py, seed),
+            EnemyType::SlimeMother => Enemy::new_slime_mother(px, py),
         };
+        enemy.make_elite(es.affixes, seed);
         enemies.push(enemy);
     }
     enemies
@@ -232,3 +349,96 @@ impl WaveTracker {
         !self.has_more_waves() && alive_count == 0 && self.wave_spawned_count > 0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types(wave: &WaveDef) -> Vec<EnemyType> {
+        wave.enemies.iter().map(|e| e.enemy_type).collect()
+    }
+
+    #[test]
+    fn make_spawns_caps_at_spawn_points() {
+        let spawns = make_spawns(
+            &[(EnemyType::Necromancer, 1), (EnemyType::Skeleton, 5)],
+            3,
+            4,
+        );
+        assert_eq!(spawns.len(), 4);
+        assert!(spawns[0].enemy_type == EnemyType::Necromancer);
+        assert_eq!(spawns[3].spawn_index, 6);
+    }
+
+    #[test]
+    fn hard_encounters_bring_shield_and_necromancer() {
+        let enc = select_encounter(EncounterDifficulty::Hard, 1, 8, 0.0, 0);
+        assert!(types(&enc.waves[0]).contains(&EnemyType::ShieldBearer));
+        assert!(types(&enc.waves[1]).contains(&EnemyType::Necromancer));
+    }
+
+    #[test]
+    fn archers_appear_from_floor_two() {
+        let floor1 = select_encounter(EncounterDifficulty::Medium, 1, 8, 0.0, 1);
+        let floor2 = select_encounter(EncounterDifficulty::Medium, 2, 8, 0.0, 1);
+        assert!(!types(&floor1.waves[1]).contains(&EnemyType::SkeletonArcher));
+        assert!(types(&floor2.waves[1]).contains(&EnemyType::SkeletonArcher));
+    }
+
+    #[test]
+    fn slimes_appear_on_early_floors() {
+        let slime = EnemyType::Slime(SlimeSize::Large);
+        let easy = select_encounter(EncounterDifficulty::Easy, 1, 8, 0.0, 1);
+        let medium = select_encounter(EncounterDifficulty::Medium, 2, 8, 0.0, 2);
+        let late = select_encounter(EncounterDifficulty::Easy, 3, 8, 0.0, 1);
+        assert!(types(&easy.waves[0]).contains(&slime));
+        assert!(types(&medium.waves[0]).contains(&slime));
+        assert!(!types(&late.waves[0]).contains(&slime));
+    }
+
+    #[test]
+    fn each_floor_has_a_boss() {
+        let boss = |floor| {
+            types(&select_encounter(EncounterDifficulty::Boss, floor, 4, 0.0, 0).waves[0])[0]
+        };
+        let bosses: Vec<EnemyType> = (1..=5).map(boss).collect();
+        assert!(bosses.iter().all(|b| b.is_boss()));
+        assert!(bosses[0] != bosses[1] && bosses[1] != bosses[2]);
+        assert!(bosses[4] == EnemyType::BoneKing);
+    }
+
+    fn elites(difficulty: EncounterDifficulty, floor: u32, seed: u64) -> Vec<(EnemyType, Affixes)> {
+        let enc = select_encounter(difficulty, floor, 8, 0.0, seed);
+        enc.waves
+            .iter()
+            .flat_map(|w| &w.enemies)
+            .filter(|e| !e.affixes.is_empty())
+            .map(|e| (e.enemy_type, e.affixes))
+            .collect()
+    }
+
+    #[test]
+    fn elites_roll_only_past_the_first_floor() {
+        let all = EncounterDifficulty::Hard;
+        assert!((0..40).all(|seed| elites(all, 1, seed).is_empty()));
+        let deep: Vec<_> = (0..40).flat_map(|seed| elites(all, 5, seed)).collect();
+        assert!(!deep.is_empty());
+        assert!(deep.iter().any(|(_, a)| a.len() == 2));
+        assert!(deep.iter().all(|&(t, a)| a.iter().all(|x| x.suits(t))));
+        assert!(elites(EncounterDifficulty::Boss, 5, 3).is_empty());
+        assert!(elites(all, 4, 7) == elites(all, 4, 7));
+    }
+
+    #[test]
+    fn extra_elite_chance_reaches_the_first_floor() {
+        let surged = |seed| {
+            let enc = select_encounter(EncounterDifficulty::Hard, 1, 8, 0.3, seed);
+            enc.waves
+                .iter()
+                .flat_map(|w| &w.enemies)
+                .filter(|e| !e.affixes.is_empty())
+                .count()
+        };
+        assert!((0..40).map(surged).sum::<usize>() > 0);
+    }
+}
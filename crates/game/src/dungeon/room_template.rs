@@ -8,6 +8,9 @@ pub struct RoomTemplate {
     pub spawn_points: Vec<SpawnPoint>,
     pub entry_points: Vec<EntryPoint>,
     pub player_spawn: Option<(usize, usize)>,
+    /// Where the shopkeeper stands in shop rooms.
+    pub merchant: Option<(usize, usize)>,
+    pub traps: Vec<TrapPlacement>,
     pub room_type: RoomType,
 }
 
@@ -32,6 +35,24 @@ pub struct SpawnPoint {
     pub group: u8,
 }
 
+/// Kind of trap placed on a tile.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrapKind {
+    /// Floor plate that periodically thrusts spikes up.
+    Spikes,
+    /// Floor grate that periodically erupts in flame.
+    FireVent,
+    /// Wall slot that periodically shoots an arrow in `facing` direction.
+    ArrowShooter { facing: Direction },
+}
+
+/// A trap's tile position within a room.
+pub struct TrapPlacement {
+    pub x: usize,
+    pub y: usize,
+    pub kind: TrapKind,
+}
+
 /// A doorway connecting this room to another.
 pub struct EntryPoint {
     pub x: usize,
@@ -57,6 +78,11 @@ pub enum Direction {
 /// - `S` = Spawn point (floor tile underneath, group 0)
 /// - `P` = Player spawn (floor tile underneath)
 /// - `E` = Exit/stairs (floor tile underneath)
+/// - `M` = Merchant (floor tile underneath)
+/// - `X` = Pit
+/// - `^` = Spike trap (floor tile underneath)
+/// - `F` = Fire vent (floor tile underneath)
+/// - `A` = Arrow shooter (wall tile, fires away from the nearest room edge)
 ///
 /// Spawn groups can be specified with digits `0`-`9` instead of `S` for
 /// explicit wave groups. `S` defaults to group 0.
@@ -73,6 +99,8 @@ pub fn parse_template(layout: &[&str], room_type: RoomType) -> RoomTemplate {
     let mut spawn_points = Vec::new();
     let mut entry_points = Vec::new();
     let mut player_spawn = None;
+    let mut merchant = None;
+    let mut traps = Vec::new();
 
     for (y, row) in layout.iter().enumerate() {
         for (x, ch) in row.chars().enumerate() {
@@ -105,6 +133,41 @@ pub fn parse_template(layout: &[&str], room_type: RoomType) -> RoomTemplate {
                         player_spawn = Some((x, y));
                     }
                 }
+                'M' => {
+                    tiles[idx] = TileType::Floor;
+                    merchant = Some((x, y));
+                }
+                'X' => tiles[idx] = TileType::Pit,
+                '^' => {
+                    tiles[idx] = TileType::Floor;
+                    traps.push(TrapPlacement {
+                        x,
+                        y,
+                        kind: TrapKind::Spikes,
+                    });
+                }
+                'F' => {
+                    tiles[idx] = TileType::Floor;
+                    traps.push(TrapPlacement {
+                        x,
+                        y,
+                        kind: TrapKind::FireVent,
+                    });
+                }
+                'A' => {
+                    tiles[idx] = TileType::Wall;
+                    let facing = match infer_direction(x, y, width, height) {
+                        Direction::North => Direction::South,
+                        Direction::South => Direction::North,
+                        Direction::East => Direction::West,
+                        Direction::West => Direction::East,
+                    };
+                    traps.push(TrapPlacement {
+                        x,
+                        y,
+                        kind: TrapKind::ArrowShooter { facing },
+                    });
+                }
                 _ => tiles[idx] = TileType::Floor,
             }
         }
@@ -133,6 +196,8 @@ pub fn parse_template(layout: &[&str], room_type: RoomType) -> RoomTemplate {
         spawn_points,
         entry_points,
         player_spawn,
+        merchant,
+        traps,
         room_type,
     }
 }
@@ -206,6 +271,34 @@ mod tests {
         assert_eq!(room.spawn_points[2].group, 0); // 'S' defaults to 0
     }
 
+    #[test]
+    fn pits_and_traps() {
+        let layout = &[
+            "WWAWW",
+            "W.X^W",
+            "A.F.W",
+            "WWWWW",
+        ];
+        let room = parse_template(layout, RoomType::Combat);
+        assert_eq!(room.tiles[5 + 2], TileType::Pit);
+        assert_eq!(room.tiles[5 + 3], TileType::Floor);
+        assert_eq!(room.traps.len(), 4);
+
+        let kinds: Vec<TrapKind> = room.traps.iter().map(|t| t.kind).collect();
+        assert!(kinds.contains(&TrapKind::Spikes));
+        assert!(kinds.contains(&TrapKind::FireVent));
+        // Top-wall shooter fires down, west-wall shooter fires east
+        assert_eq!(
+            kinds[0],
+            TrapKind::ArrowShooter {
+                facing: Direction::South
+            }
+        );
+        assert!(kinds.contains(&TrapKind::ArrowShooter {
+            facing: Direction::East
+        }));
+    }
+
     #[test]
     fn door_directions() {
         let layout = &[
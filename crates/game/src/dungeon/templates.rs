@@ -20,7 +20,7 @@ pub fn start_room() -> RoomTemplate {
     parse_template(layout, RoomType::Start)
 }
 
-/// 2. Arena (20x14): Open combat room. 4 entries (one per side). 6 spawn points.
+/// 2. Arena (20x14): Open combat room. 4 entries (one per side). 6 spawn points, 4 spike plates.
 pub fn arena() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
@@ -28,11 +28,11 @@ pub fn arena() -> RoomTemplate {
         "W..................W",
         "W..S...........S..W",
         "W..................W",
-        "W..................W",
+        "W.....^......^.....W",
         "W......S..S........W",
         "D..................D",
         "W..................W",
-        "W..................W",
+        "W.....^......^.....W",
         "W..................W",
         "W..S...........S..W",
         "W..................W",
@@ -42,7 +42,7 @@ pub fn arena() -> RoomTemplate {
     parse_template(layout, RoomType::Combat)
 }
 
-/// 3. Pillared Hall (20x14): Combat room with 4 interior 2x2 pillars.
+/// 3. Pillared Hall (20x14): Combat room with 4 interior 2x2 pillars and a central pit.
 pub fn pillared_hall() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
@@ -52,8 +52,8 @@ pub fn pillared_hall() -> RoomTemplate {
         "W....WW....WW.....W",
         "W....WW....WW.....W",
         "W..S..........S...W",
-        "D..........S......D",
-        "W..............S..W",
+        "D......XX..S......D",
+        "W......XX......S..W",
         "W..................W",
         "W....WW....WW.....W",
         "W....WW....WW.....W",
@@ -64,11 +64,11 @@ pub fn pillared_hall() -> RoomTemplate {
     parse_template(layout, RoomType::Combat)
 }
 
-/// 4. Corridor-H (18x6): Horizontal corridor. East+west entries. 2 spawn points.
+/// 4. Corridor-H (18x6): Horizontal corridor. East+west entries. 2 spawn points, 2 arrow shooters in the north wall.
 pub fn corridor_h() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
-        "WWWWWWWWWWWWWWWWWW",
+        "WWWWAWWWWWWWWAWWWW",
         "W................W",
         "D.....S....S.....D",
         "D................D",
@@ -78,7 +78,7 @@ pub fn corridor_h() -> RoomTemplate {
     parse_template(layout, RoomType::Corridor)
 }
 
-/// 5. Corridor-V (6x18): Vertical corridor. North+south entries. 2 spawn points.
+/// 5. Corridor-V (6x18): Vertical corridor. North+south entries. 2 spawn points, 1 arrow shooter.
 pub fn corridor_v() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
@@ -90,7 +90,7 @@ pub fn corridor_v() -> RoomTemplate {
         "W....W",
         "W....W",
         "W....W",
-        "W....W",
+        "A....W",
         "W....W",
         "W....W",
         "W....W",
@@ -104,19 +104,19 @@ pub fn corridor_v() -> RoomTemplate {
     parse_template(layout, RoomType::Corridor)
 }
 
-/// 6. L-Shape (16x14): L-shaped room with walls creating the turn. 2 entries, 4 spawn points.
+/// 6. L-Shape (16x14): L-shaped room with walls creating the turn. 2 entries, 4 spawn points, 3 fire vents.
 pub fn l_shape() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
         "WWWWWWWWWWWWWWWW",
         "W..........WWWWW",
         "W..S.......WWWWW",
-        "W..........WWWWW",
+        "W.....F....WWWWW",
         "W..........WWWWW",
         "W..............W",
         "D......S...S...W",
         "W..............W",
-        "W..............W",
+        "W...F.....F....W",
         "W..............W",
         "W......S.......W",
         "W..............W",
@@ -170,7 +170,7 @@ pub fn boss_arena() -> RoomTemplate {
     parse_template(layout, RoomType::Boss)
 }
 
-/// 9. Shop (16x12): Counter-like wall structure. Single entry.
+/// 9. Shop (16x12): Counter-like wall structure with the merchant behind it. Single entry.
 pub fn shop() -> RoomTemplate {
     #[rustfmt::skip]
     let layout = &[
@@ -178,7 +178,7 @@ pub fn shop() -> RoomTemplate {
         "W..............W",
         "W..............W",
         "W..WWWWWWWWWW..W",
-        "W..W........W..W",
+        "W..W...M....W..W",
         "W..............W",
         "W..............W",
         "W..S...S...S...W",
@@ -285,6 +285,14 @@ mod tests {
         assert_eq!(room.entry_points.len(), 1);
     }
 
+    #[test]
+    fn shop_has_merchant_and_pedestals() {
+        let room = shop();
+        assert_eq!(room.room_type, RoomType::Shop);
+        assert_eq!(room.merchant, Some((7, 4)));
+        assert_eq!(room.spawn_points.len(), 3);
+    }
+
     #[test]
     fn exit_room_has_exit_marker() {
         let room = exit_room();
@@ -0,0 +1,173 @@
+const DETECT_RANGE: f32 = 110.0;
+const PREFERRED_DIST: f32 = 70.0;
+const TOO_CLOSE: f32 = 40.0;
+const MAX_SHOT_RANGE: f32 = 130.0;
+const APPROACH_SPEED: f32 = 28.0;
+/// Slower than the player walks, so pressing the archer closes the gap
+const RETREAT_SPEED: f32 = 32.0;
+const RETREAT_DURATION: f32 = 1.0;
+const DRAW_DURATION: f32 = 0.7;
+/// Drawing is abandoned if the player gets this close
+const DRAW_CANCEL_DISTANCE: f32 = 24.0;
+const LOOSE_COOLDOWN: f32 = 1.4;
+const STAGGER_DURATION: f32 = 0.3;
+const IDLE_MIN: f32 = 0.8;
+const IDLE_MAX: f32 = 1.6;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArcherState {
+    Idle,
+    Approach,
+    Retreat,
+    Draw,
+    Cooldown,
+    Stagger,
+}
+
+pub struct ArcherAI {
+    pub state: ArcherState,
+    timer: f32,
+    /// Direction locked when the bow is drawn
+    pub aim_dir_x: f32,
+    pub aim_dir_y: f32,
+    rng_state: u32,
+}
+
+impl ArcherAI {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            state: ArcherState::Idle,
+            timer: 1.0,
+            aim_dir_x: 1.0,
+            aim_dir_y: 0.0,
+            rng_state: seed,
+        }
+    }
+
+    fn rand_float(&mut self) -> f32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(1103515245)
+            .wrapping_add(12345);
+        (self.rng_state >> 16) as f32 / 65536.0
+    }
+
+    fn start_draw(&mut self, dx: f32, dy: f32, dist: f32) {
+        self.state = ArcherState::Draw;
+        self.timer = DRAW_DURATION;
+        self.aim_dir_x = dx / dist;
+        self.aim_dir_y = dy / dist;
+    }
+
+    /// Advance one tick and return movement and animation hints.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_x: f32,
+        my_y: f32,
+        player_x: f32,
+        player_y: f32,
+        staggered: bool,
+        alive: bool,
+    ) -> ArcherOutput {
+        if !alive {
+            return ArcherOutput::default();
+        }
+
+        if staggered && self.state != ArcherState::Stagger {
+            self.state = ArcherState::Stagger;
+            self.timer = STAGGER_DURATION;
+        }
+
+        self.timer -= dt;
+
+        let dx = player_x - my_x;
+        let dy = player_y - my_y;
+        let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+
+        let mut output = ArcherOutput {
+            facing_right: dx > 0.0,
+            ..ArcherOutput::default()
+        };
+
+        match self.state {
+            ArcherState::Idle => {
+                if dist < TOO_CLOSE {
+                    self.state = ArcherState::Retreat;
+                    self.timer = RETREAT_DURATION;
+                } else if dist < DETECT_RANGE && self.timer <= 0.0 {
+                    if dist > PREFERRED_DIST {
+                        self.state = ArcherState::Approach;
+                    } else {
+                        self.start_draw(dx, dy, dist);
+                    }
+                }
+            }
+            ArcherState::Approach => {
+                output.move_dx = dx / dist * APPROACH_SPEED * dt;
+                output.move_dy = dy / dist * APPROACH_SPEED * dt;
+                output.walking = true;
+
+                if dist <= PREFERRED_DIST {
+                    self.start_draw(dx, dy, dist);
+                } else if dist > DETECT_RANGE * 1.5 {
+                    self.state = ArcherState::Idle;
+                    self.timer = IDLE_MIN + self.rand_float() * (IDLE_MAX - IDLE_MIN);
+                }
+            }
+            ArcherState::Retreat => {
+                output.move_dx = -dx / dist * RETREAT_SPEED * dt;
+                output.move_dy = -dy / dist * RETREAT_SPEED * dt;
+                output.walking = true;
+
+                // Cornered archers shoot anyway once the retreat runs out
+                if dist >= PREFERRED_DIST || self.timer <= 0.0 {
+                    self.start_draw(dx, dy, dist);
+                }
+            }
+            ArcherState::Draw => {
+                output.drawing = true;
+                output.facing_right = self.aim_dir_x > 0.0;
+
+                if dist < DRAW_CANCEL_DISTANCE {
+                    self.state = ArcherState::Retreat;
+                    self.timer = RETREAT_DURATION;
+                } else if dist > MAX_SHOT_RANGE {
+                    self.state = ArcherState::Approach;
+                } else if self.timer <= 0.0 {
+                    output.fire_arrow = true;
+                    self.state = ArcherState::Cooldown;
+                    self.timer = LOOSE_COOLDOWN;
+                }
+            }
+            ArcherState::Cooldown => {
+                if dist < TOO_CLOSE {
+                    self.state = ArcherState::Retreat;
+                    self.timer = RETREAT_DURATION;
+                } else if self.timer <= 0.0 {
+                    self.state = ArcherState::Idle;
+                    self.timer = 0.0;
+                }
+            }
+            ArcherState::Stagger => {
+                if self.timer <= 0.0 {
+                    self.state = ArcherState::Idle;
+                    self.timer = IDLE_MIN * self.rand_float();
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[derive(Default)]
+pub struct ArcherOutput {
+    pub move_dx: f32,
+    pub move_dy: f32,
+    pub facing_right: bool,
+    pub walking: bool,
+    pub drawing: bool,
+    pub fire_arrow: bool,
+}
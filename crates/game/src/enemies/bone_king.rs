@@ -81,8 +81,6 @@ pub struct BoneKingAI {
     next_is_slam: bool,
     /// Simple RNG state
     rng_state: u32,
-    /// Track slam impact frame (one-shot per slam)
-    slam_impact_fired: bool,
     /// Track charge wall hit (one-shot per charge)
     charge_wall_hit_fired: bool,
     /// Dying flash counter for visual effect
@@ -102,7 +100,6 @@ impl BoneKingAI {
             phase2_triggered: false,
             next_is_slam: true,
             rng_state: seed,
-            slam_impact_fired: false,
             charge_wall_hit_fired: false,
             dying_flash_counter: 0.0,
         }
@@ -228,19 +225,10 @@ impl BoneKingAI {
                 if self.timer <= 0.0 {
                     self.state = BoneKingState::SlamActive;
                     self.timer = SLAM_ACTIVE;
-                    self.slam_impact_fired = false;
                 }
             }
             BoneKingState::SlamActive => {
                 output.facing_right = self.attack_dir_x > 0.0;
-                let facing = output.facing_right;
-                output.attack_hitbox = Some(self.slam_hitbox(my_cx, my_cy, facing));
-
-                if !self.slam_impact_fired {
-                    output.slam_impact = true;
-                    self.slam_impact_fired = true;
-                }
-
                 if self.timer <= 0.0 {
                     self.state = BoneKingState::SlamRecover;
                     self.timer = SLAM_RECOVER;
@@ -263,9 +251,6 @@ impl BoneKingAI {
             }
             BoneKingState::SweepActive => {
                 output.facing_right = self.attack_dir_x > 0.0;
-                let facing = output.facing_right;
-                output.attack_hitbox = Some(self.sweep_hitbox(my_cx, my_cy, facing));
-
                 if self.timer <= 0.0 {
                     self.state = BoneKingState::SweepRecover;
                     self.timer = SWEEP_RECOVER;
@@ -294,14 +279,6 @@ impl BoneKingAI {
                 output.dy = self.charge_vy * dt;
                 output.facing_right = self.charge_vx > 0.0;
 
-                // Body hitbox during charge
-                // We pass the boss sprite origin, not center, for hitbox computation
-                // The hitbox is defined relative to sprite origin
-                output.attack_hitbox = Some(CHARGE_HITBOX.at(
-                    my_cx - 10.0, // approximate sprite origin from center
-                    my_cy - 12.0,
-                ));
-
                 // Check wall collision
                 if wall_collision_x || wall_collision_y {
                     if !self.charge_wall_hit_fired {
@@ -377,6 +354,18 @@ impl BoneKingAI {
         self.next_is_slam = !self.next_is_slam;
     }
 
+    /// True while an attack is landing or recovering (hitbox animations play).
+    pub fn is_attacking(&self) -> bool {
+        matches!(
+            self.state,
+            BoneKingState::SlamActive
+                | BoneKingState::SlamRecover
+                | BoneKingState::SweepActive
+                | BoneKingState::SweepRecover
+                | BoneKingState::ChargeActive
+        )
+    }
+
     /// Lock attack direction toward player. Call before transitioning to an attack state.
     pub fn lock_direction(&mut self, dx: f32, dy: f32) {
         let dist = (dx * dx + dy * dy).sqrt().max(0.01);
@@ -384,38 +373,18 @@ impl BoneKingAI {
         self.attack_dir_y = dy / dist;
     }
 
-    fn slam_hitbox(&self, cx: f32, cy: f32, facing_right: bool) -> AABB {
-        // Convert center back to sprite origin for hitbox placement
-        let ox = cx - 10.0;
-        let oy = cy - 12.0;
-        if facing_right {
-            SLAM_HITBOX_RIGHT.at(ox, oy)
-        } else {
-            SLAM_HITBOX_LEFT.at(ox, oy)
-        }
-    }
-
-    fn sweep_hitbox(&self, cx: f32, cy: f32, facing_right: bool) -> AABB {
-        let ox = cx - 10.0;
-        let oy = cy - 12.0;
-        if facing_right {
-            SWEEP_HITBOX_RIGHT.at(ox, oy)
-        } else {
-            SWEEP_HITBOX_LEFT.at(ox, oy)
-        }
-    }
-
-    /// Returns the current attack hitbox in world-space, if active.
+    /// Hitbox shape for the current attack in world-space. The live window
+    /// is opened and closed by the attack animation's frame events.
     pub fn attack_hitbox(&self, x: f32, y: f32, facing_right: bool) -> Option<AABB> {
         match self.state {
-            BoneKingState::SlamActive => {
+            BoneKingState::SlamActive | BoneKingState::SlamRecover => {
                 if facing_right {
                     Some(SLAM_HITBOX_RIGHT.at(x, y))
                 } else {
                     Some(SLAM_HITBOX_LEFT.at(x, y))
                 }
             }
-            BoneKingState::SweepActive => {
+            BoneKingState::SweepActive | BoneKingState::SweepRecover => {
                 if facing_right {
                     Some(SWEEP_HITBOX_RIGHT.at(x, y))
                 } else {
@@ -433,14 +402,12 @@ pub struct BoneKingOutput {
     pub dx: f32,
     pub dy: f32,
     pub facing_right: bool,
-    pub attack_hitbox: Option<AABB>,
     pub phase2: bool,
     pub telegraph: bool,
     pub stunned: bool,
     pub roaring: bool,
     pub dying: bool,
     pub invulnerable: bool,
-    pub slam_impact: bool,
     pub charge_wall_hit: bool,
     pub death_finished: bool,
 }
@@ -0,0 +1,288 @@
+//! Elite enemies: champion affixes rolled onto regular enemies on deeper
+//! floors. Affixes stack, and each one changes how the enemy fights.
+
+use engine::Color;
+
+use super::EnemyType;
+
+/// Elites have this many times their usual HP.
+pub const ELITE_HP_MULT: i32 = 2;
+/// Hits a Shielded elite's barrier soaks before it breaks.
+const SHIELD_CHARGES: u32 = 2;
+/// A broken barrier regrows one charge after this long.
+const SHIELD_REGEN: f32 = 4.0;
+/// Fast elites cover this much more ground per step.
+pub const FAST_MOVE_BONUS: f32 = 0.5;
+/// Frenzied elites act this much faster once below half HP.
+const FRENZY_TIME_SCALE: f32 = 1.6;
+/// Delay between an Explosive elite dying and its blast.
+const FUSE_TIME: f32 = 0.6;
+/// How far from the player a Teleporting elite reappears.
+pub const BLINK_DIST: f32 = 36.0;
+/// Seconds between a Teleporting elite's blinks, before jitter.
+const BLINK_INTERVAL: f32 = 3.5;
+const BLINK_JITTER: f32 = 1.5;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Affix {
+    /// A barrier soaks the first hits, then slowly regrows
+    Shielded,
+    /// Moves half again as fast
+    Fast,
+    /// Blows up shortly after dying
+    Explosive,
+    /// Heals whenever it hurts the player
+    Vampiric,
+    /// Blinks to a new spot around the player every few seconds
+    Teleporting,
+    /// Speeds up when badly hurt
+    Frenzied,
+}
+
+impl Affix {
+    pub const ALL: [Affix; 6] = [
+        Affix::Shielded,
+        Affix::Fast,
+        Affix::Explosive,
+        Affix::Vampiric,
+        Affix::Teleporting,
+        Affix::Frenzied,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Affix::Shielded => "SHIELDED",
+            Affix::Fast => "FAST",
+            Affix::Explosive => "EXPLOSIVE",
+            Affix::Vampiric => "VAMPIRIC",
+            Affix::Teleporting => "TELEPORTING",
+            Affix::Frenzied => "FRENZIED",
+        }
+    }
+
+    /// Outline and name tag color.
+    pub fn color(self) -> Color {
+        match self {
+            Affix::Shielded => [120, 200, 255],
+            Affix::Fast => [255, 240, 120],
+            Affix::Explosive => [255, 150, 50],
+            Affix::Vampiric => [220, 50, 80],
+            Affix::Teleporting => [200, 130, 255],
+            Affix::Frenzied => [255, 70, 50],
+        }
+    }
+
+    /// Whether the affix does anything for this kind of enemy. Vampirism
+    /// feeds on melee and contact hits, which ranged enemies never land.
+    pub fn suits(self, enemy_type: EnemyType) -> bool {
+        if enemy_type.is_boss() {
+            return false;
+        }
+        match self {
+            Affix::Vampiric => !matches!(
+                enemy_type,
+                EnemyType::Ghost | EnemyType::SkeletonArcher | EnemyType::Necromancer
+            ),
+            _ => true,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of affixes.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Affixes(u8);
+
+impl Affixes {
+    pub fn with(self, affix: Affix) -> Self {
+        Self(self.0 | affix.bit())
+    }
+
+    pub fn has(self, affix: Affix) -> bool {
+        self.0 & affix.bit() != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Affix> {
+        Affix::ALL.into_iter().filter(move |&a| self.has(a))
+    }
+}
+
+/// Per-enemy elite state.
+pub struct Elite {
+    pub affixes: Affixes,
+    pub max_hp: i32,
+    /// Name tag drawn above the enemy, e.g. "FAST VAMPIRIC"
+    pub label: String,
+    shield: u32,
+    shield_regen: f32,
+    blink_timer: f32,
+    /// Counting down to the blast once an Explosive elite dies
+    fuse: Option<f32>,
+    rng_state: u32,
+}
+
+impl Elite {
+    pub fn new(affixes: Affixes, max_hp: i32, seed: u32) -> Self {
+        let names: Vec<&str> = affixes.iter().map(Affix::name).collect();
+        let label = names.join(" ");
+        let mut elite = Self {
+            affixes,
+            max_hp,
+            label,
+            shield: if affixes.has(Affix::Shielded) {
+                SHIELD_CHARGES
+            } else {
+                0
+            },
+            shield_regen: 0.0,
+            blink_timer: 0.0,
+            fuse: None,
+            rng_state: seed,
+        };
+        elite.blink_timer = elite.next_blink();
+        elite
+    }
+
+    fn rand_float(&mut self) -> f32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(1103515245)
+            .wrapping_add(12345);
+        (self.rng_state >> 16) as f32 / 65536.0
+    }
+
+    fn next_blink(&mut self) -> f32 {
+        BLINK_INTERVAL + self.rand_float() * BLINK_JITTER
+    }
+
+    /// Outline color: the barrier's while it holds, else the first other
+    /// affix's. A broken barrier with nothing else to show goes dull.
+    pub fn color(&self) -> Color {
+        if self.shield_up() {
+            return Affix::Shielded.color();
+        }
+        self.affixes
+            .iter()
+            .find(|&a| a != Affix::Shielded)
+            .map_or([90, 110, 140], Affix::color)
+    }
+
+    pub fn shield_up(&self) -> bool {
+        self.shield > 0
+    }
+
+    /// Let the barrier take a hit. Returns true if it soaked the blow.
+    pub fn absorb(&mut self) -> bool {
+        if self.shield == 0 {
+            return false;
+        }
+        self.shield -= 1;
+        self.shield_regen = SHIELD_REGEN;
+        true
+    }
+
+    /// How much faster than normal the enemy's AI runs at `hp`.
+    pub fn time_scale(&self, hp: i32) -> f32 {
+        if self.frenzied(hp) {
+            FRENZY_TIME_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    pub fn frenzied(&self, hp: i32) -> bool {
+        self.affixes.has(Affix::Frenzied) && hp * 2 <= self.max_hp
+    }
+
+    /// Light the fuse if this elite explodes on death.
+    pub fn on_death(&mut self) {
+        if self.affixes.has(Affix::Explosive) {
+            self.fuse = Some(FUSE_TIME);
+        }
+    }
+
+    pub fn fuse_lit(&self) -> bool {
+        self.fuse.is_some()
+    }
+
+    /// On and off while the fuse burns down.
+    pub fn fuse_flash(&self) -> bool {
+        self.fuse.is_some_and(|t| ((t * 12.0) as i32) % 2 == 0)
+    }
+
+    /// Advance timers. Returns (blink now, detonate now).
+    pub fn update(&mut self, dt: f32, alive: bool) -> (bool, bool) {
+        if self.affixes.has(Affix::Shielded) && self.shield < SHIELD_CHARGES {
+            self.shield_regen -= dt;
+            if self.shield_regen <= 0.0 {
+                self.shield += 1;
+                self.shield_regen = SHIELD_REGEN;
+            }
+        }
+
+        let mut blink = false;
+        if alive && self.affixes.has(Affix::Teleporting) {
+            self.blink_timer -= dt;
+            if self.blink_timer <= 0.0 {
+                self.blink_timer = self.next_blink();
+                blink = true;
+            }
+        }
+
+        let mut detonate = false;
+        if let Some(fuse) = &mut self.fuse {
+            *fuse -= dt;
+            if *fuse <= 0.0 {
+                self.fuse = None;
+                detonate = true;
+            }
+        }
+        (blink, detonate)
+    }
+
+    /// Random angle to blink towards, around the player.
+    pub fn blink_angle(&mut self) -> f32 {
+        self.rand_float() * std::f32::consts::TAU
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affixes_compose() {
+        let set = Affixes::default().with(Affix::Fast).with(Affix::Vampiric);
+        assert_eq!(set.len(), 2);
+        assert!(set.has(Affix::Fast) && !set.has(Affix::Shielded));
+        assert_eq!(Elite::new(set, 6, 1).label, "FAST VAMPIRIC");
+    }
+
+    #[test]
+    fn test_shield_soaks_then_regrows() {
+        let mut elite = Elite::new(Affixes::default().with(Affix::Shielded), 6, 1);
+        assert!(elite.absorb() && elite.absorb());
+        assert!(!elite.absorb());
+        elite.update(SHIELD_REGEN, true);
+        assert!(elite.shield_up());
+    }
+
+    #[test]
+    fn test_explosive_fuse_detonates_once() {
+        let mut elite = Elite::new(Affixes::default().with(Affix::Explosive), 6, 1);
+        elite.on_death();
+        assert_eq!(elite.update(FUSE_TIME, false), (false, true));
+        assert_eq!(elite.update(FUSE_TIME, false), (false, false));
+    }
+}
@@ -0,0 +1,410 @@
+use std::f32::consts::TAU;
+
+// --- Lich constants ---
+const LICH_HP: i32 = 18;
+const DRIFT_SPEED: f32 = 18.0;
+/// Drifts to stay about this far from the player
+const PREFERRED_DIST: f32 = 60.0;
+/// Blinks away when the player gets this close
+const PANIC_DIST: f32 = 28.0;
+/// Radius around the player the Lich reappears at.
+pub const TELEPORT_DIST: f32 = 70.0;
+
+// --- Phase timing ---
+const IDLE_DURATION: f32 = 1.0;
+const CAST_WINDUP: f32 = 0.6;
+const CAST_RECOVER: f32 = 0.5;
+const TELEPORT_OUT: f32 = 0.4;
+const TELEPORT_IN: f32 = 0.3;
+
+// Phase 2 reduced cooldowns
+const IDLE_DURATION_P2: f32 = 0.6;
+const CAST_WINDUP_P2: f32 = 0.45;
+
+// Roar
+const ROAR_DURATION: f32 = 1.0;
+
+// Death
+const DYING_DURATION: f32 = 1.5;
+
+// Phase 2 HP threshold
+const PHASE2_HP_THRESHOLD: i32 = 9;
+
+// --- Projectile patterns ---
+const FAN_SHOTS: usize = 5;
+/// Total angle covered by the fan, in radians
+const FAN_SPREAD: f32 = 0.9;
+const RING_SHOTS: usize = 8;
+const RING_SHOTS_P2: usize = 12;
+const SPIRAL_VOLLEYS: u32 = 6;
+const SPIRAL_ARMS: usize = 4;
+const SPIRAL_INTERVAL: f32 = 0.15;
+/// Rotation between spiral volleys, in radians
+const SPIRAL_TWIST: f32 = 0.3;
+/// Patterns cast between teleports
+const CASTS_PER_TELEPORT: u32 = 2;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LichPattern {
+    /// Aimed spread at the player
+    Fan,
+    /// Evenly spaced ring
+    Ring,
+    /// Rotating volleys (phase 2 only)
+    Spiral,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LichState {
+    Idle,
+    CastWindup,
+    Cast,
+    TeleportOut,
+    TeleportIn,
+    Roar,
+    Dying,
+}
+
+pub struct LichAI {
+    pub state: LichState,
+    timer: f32,
+    pattern: LichPattern,
+    /// Direction locked when the cast starts
+    aim_angle: f32,
+    /// Spiral volleys still to fire this cast
+    volleys_left: u32,
+    spiral_angle: f32,
+    casts_since_teleport: u32,
+    /// Sideways drift direction (+1 or -1)
+    strafe: f32,
+    /// Phase 2 activated
+    pub phase2: bool,
+    /// Phase 2 transition triggered (one-shot)
+    phase2_triggered: bool,
+    /// Simple RNG state
+    rng_state: u32,
+    /// Dying flash counter for visual effect
+    pub dying_flash_counter: f32,
+}
+
+impl LichAI {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            state: LichState::Idle,
+            timer: IDLE_DURATION,
+            pattern: LichPattern::Fan,
+            aim_angle: 0.0,
+            volleys_left: 0,
+            spiral_angle: 0.0,
+            casts_since_teleport: 0,
+            strafe: 1.0,
+            phase2: false,
+            phase2_triggered: false,
+            rng_state: seed,
+            dying_flash_counter: 0.0,
+        }
+    }
+
+    fn rand_float(&mut self) -> f32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(1103515245)
+            .wrapping_add(12345);
+        (self.rng_state >> 16) as f32 / 65536.0
+    }
+
+    pub fn max_hp(&self) -> i32 {
+        LICH_HP
+    }
+
+    /// Check if we should enter phase 2 (called externally after damage).
+    pub fn check_phase_transition(&mut self, current_hp: i32) -> bool {
+        if !self.phase2_triggered && current_hp <= PHASE2_HP_THRESHOLD && current_hp > 0 {
+            self.phase2_triggered = true;
+            self.phase2 = true;
+            self.state = LichState::Roar;
+            self.timer = ROAR_DURATION;
+            return true;
+        }
+        false
+    }
+
+    /// Start dying sequence.
+    pub fn start_dying(&mut self) {
+        self.state = LichState::Dying;
+        self.timer = DYING_DURATION;
+        self.dying_flash_counter = 0.0;
+    }
+
+    /// Roaring and dying ignore damage.
+    pub fn invulnerable(&self) -> bool {
+        matches!(self.state, LichState::Roar | LichState::Dying)
+    }
+
+    /// Flickers out on alternate frames while teleporting.
+    pub fn flicker_hidden(&self) -> bool {
+        matches!(self.state, LichState::TeleportOut | LichState::TeleportIn)
+            && ((self.timer * 20.0) as i32) % 2 == 0
+    }
+
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_cx: f32,
+        my_cy: f32,
+        player_x: f32,
+        player_y: f32,
+        alive: bool,
+    ) -> LichOutput {
+        if !alive && self.state != LichState::Dying {
+            return LichOutput::default();
+        }
+
+        self.timer -= dt;
+
+        let dx = player_x - my_cx;
+        let dy = player_y - my_cy;
+        let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+
+        let mut output = LichOutput {
+            phase2: self.phase2,
+            facing_right: dx > 0.0,
+            ..Default::default()
+        };
+
+        let idle_dur = if self.phase2 {
+            IDLE_DURATION_P2
+        } else {
+            IDLE_DURATION
+        };
+
+        match self.state {
+            LichState::Idle => {
+                // Hold range, sliding sideways around the player
+                let (nx, ny) = (dx / dist, dy / dist);
+                let approach = ((dist - PREFERRED_DIST) / PREFERRED_DIST).clamp(-1.0, 1.0);
+                output.dx = (nx * approach - ny * self.strafe * 0.6) * DRIFT_SPEED * dt;
+                output.dy = (ny * approach + nx * self.strafe * 0.6) * DRIFT_SPEED * dt;
+
+                let casts_per_teleport = if self.phase2 { 1 } else { CASTS_PER_TELEPORT };
+                if dist < PANIC_DIST || self.casts_since_teleport >= casts_per_teleport {
+                    self.start_teleport();
+                } else if self.timer <= 0.0 {
+                    self.start_cast(dy.atan2(dx));
+                }
+            }
+            LichState::CastWindup => {
+                output.telegraph = true;
+                output.casting = true;
+                if self.timer <= 0.0 {
+                    self.fire_pattern(&mut output);
+                    self.state = LichState::Cast;
+                }
+            }
+            LichState::Cast => {
+                output.casting = true;
+                if self.timer <= 0.0 {
+                    if self.volleys_left > 0 {
+                        self.fire_pattern(&mut output);
+                    } else {
+                        self.casts_since_teleport += 1;
+                        self.state = LichState::Idle;
+                        self.timer = idle_dur;
+                    }
+                }
+            }
+            LichState::TeleportOut => {
+                if self.timer <= 0.0 {
+                    output.teleport_angle = Some(self.rand_float() * TAU);
+                    self.state = LichState::TeleportIn;
+                    self.timer = TELEPORT_IN;
+                }
+            }
+            LichState::TeleportIn => {
+                if self.timer <= 0.0 {
+                    self.strafe = if self.rand_float() < 0.5 { 1.0 } else { -1.0 };
+                    self.casts_since_teleport = 0;
+                    // Arriving, the Lich casts straight away
+                    self.start_cast(dy.atan2(dx));
+                }
+            }
+            LichState::Roar => {
+                output.roaring = true;
+                output.invulnerable = true;
+                if self.timer <= 0.0 {
+                    self.start_teleport();
+                }
+            }
+            LichState::Dying => {
+                output.dying = true;
+                self.dying_flash_counter += dt * 15.0;
+                if self.timer <= 0.0 {
+                    output.death_finished = true;
+                }
+            }
+        }
+
+        output
+    }
+
+    fn start_teleport(&mut self) {
+        self.state = LichState::TeleportOut;
+        self.timer = TELEPORT_OUT;
+    }
+
+    fn start_cast(&mut self, aim_angle: f32) {
+        self.aim_angle = aim_angle;
+        self.state = LichState::CastWindup;
+        self.timer = if self.phase2 {
+            CAST_WINDUP_P2
+        } else {
+            CAST_WINDUP
+        };
+        // Phase 1 alternates fan and ring; phase 2 works the spiral in
+        self.pattern = match (self.pattern, self.phase2) {
+            (LichPattern::Fan, false) => LichPattern::Ring,
+            (LichPattern::Fan, true) => LichPattern::Spiral,
+            (LichPattern::Spiral, _) => LichPattern::Ring,
+            (LichPattern::Ring, _) => LichPattern::Fan,
+        };
+        if self.pattern == LichPattern::Spiral {
+            self.volleys_left = SPIRAL_VOLLEYS;
+            self.spiral_angle = aim_angle;
+        }
+    }
+
+    /// Emit the current pattern's shots (one volley for the spiral) and set
+    /// the timer for what follows.
+    fn fire_pattern(&mut self, output: &mut LichOutput) {
+        match self.pattern {
+            LichPattern::Fan => {
+                let step = FAN_SPREAD / (FAN_SHOTS - 1) as f32;
+                let start = self.aim_angle - FAN_SPREAD / 2.0;
+                output.shots = (0..FAN_SHOTS).map(|i| start + step * i as f32).collect();
+                self.timer = CAST_RECOVER;
+            }
+            LichPattern::Ring => {
+                let count = if self.phase2 {
+                    RING_SHOTS_P2
+                } else {
+                    RING_SHOTS
+                };
+                let step = TAU / count as f32;
+                output.shots = (0..count)
+                    .map(|i| self.aim_angle + step * i as f32)
+                    .collect();
+                self.timer = CAST_RECOVER;
+            }
+            LichPattern::Spiral => {
+                let step = TAU / SPIRAL_ARMS as f32;
+                let base = self.spiral_angle;
+                output.shots = (0..SPIRAL_ARMS).map(|i| base + step * i as f32).collect();
+                self.spiral_angle += SPIRAL_TWIST;
+                self.volleys_left = self.volleys_left.saturating_sub(1);
+                self.timer = if self.volleys_left > 0 {
+                    SPIRAL_INTERVAL
+                } else {
+                    CAST_RECOVER
+                };
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct LichOutput {
+    pub dx: f32,
+    pub dy: f32,
+    pub facing_right: bool,
+    pub phase2: bool,
+    pub telegraph: bool,
+    pub casting: bool,
+    pub roaring: bool,
+    pub dying: bool,
+    pub invulnerable: bool,
+    pub death_finished: bool,
+    /// Angles (radians) of projectiles fired this tick
+    pub shots: Vec<f32>,
+    /// Reappear around the player at this angle
+    pub teleport_angle: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    /// What the Lich did on a tick worth noting.
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Shots(usize),
+        Teleport,
+    }
+
+    /// Run the AI for `secs` with the player `player_dist` to the right.
+    fn events_over(ai: &mut LichAI, secs: f32, player_dist: f32) -> Vec<Event> {
+        let mut events = Vec::new();
+        for _ in 0..(secs / DT) as usize {
+            let out = ai.update(DT, 0.0, 0.0, player_dist, 0.0, true);
+            if !out.shots.is_empty() {
+                events.push(Event::Shots(out.shots.len()));
+            }
+            if out.teleport_angle.is_some() {
+                events.push(Event::Teleport);
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn test_phase2_starts_at_threshold() {
+        let mut ai = LichAI::new(1);
+        assert!(!ai.check_phase_transition(PHASE2_HP_THRESHOLD + 1));
+        assert!(!ai.phase2);
+
+        assert!(ai.check_phase_transition(PHASE2_HP_THRESHOLD));
+        assert!(ai.phase2);
+        assert!(ai.state == LichState::Roar);
+        assert!(ai.invulnerable());
+        assert!(!ai.check_phase_transition(PHASE2_HP_THRESHOLD - 1));
+    }
+
+    #[test]
+    fn test_phase1_alternates_ring_and_fan_then_teleports() {
+        let mut ai = LichAI::new(1);
+        let events = events_over(&mut ai, 6.0, PREFERRED_DIST);
+        assert_eq!(
+            events[..4],
+            [
+                Event::Shots(RING_SHOTS),
+                Event::Shots(FAN_SHOTS),
+                Event::Teleport,
+                Event::Shots(RING_SHOTS),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phase2_works_the_spiral_in() {
+        let mut ai = LichAI::new(1);
+        ai.check_phase_transition(PHASE2_HP_THRESHOLD);
+        let events = events_over(&mut ai, 10.0, PREFERRED_DIST);
+        // Roars, then teleports after every cast
+        assert_eq!(events[0], Event::Teleport);
+        assert!(events.contains(&Event::Shots(RING_SHOTS_P2)));
+        assert!(events.contains(&Event::Shots(FAN_SHOTS)));
+        let spiral_volleys = events
+            .iter()
+            .filter(|&e| *e == Event::Shots(SPIRAL_ARMS))
+            .count();
+        assert!(spiral_volleys >= SPIRAL_VOLLEYS as usize);
+    }
+
+    #[test]
+    fn test_blinks_away_when_the_player_closes_in() {
+        let mut ai = LichAI::new(1);
+        let events = events_over(&mut ai, TELEPORT_OUT + 0.1, PANIC_DIST / 2.0);
+        assert_eq!(events, [Event::Teleport]);
+    }
+}
@@ -1,28 +1,54 @@
+pub mod archer;
 pub mod bone_king;
+pub mod elite;
 pub mod ghost;
+pub mod lich;
+pub mod necromancer;
+pub mod shield_bearer;
 pub mod skeleton;
+pub mod slime;
+pub mod slime_mother;
 
-use engine::animation::AnimationPlayer;
+use engine::animation::{AnimationData, AnimationPlayer};
 use engine::collision::AABB;
-use engine::tilemap::TileMap;
+use engine::tilemap::{TileMap, TileType};
 use engine::types::Transform;
-use engine::FrameBuffer;
+use engine::{FrameBuffer, SpriteData};
 
 use crate::sprites;
+use crate::status::{StatusEffects, StatusKind};
+use archer::{ArcherAI, ArcherOutput};
 use bone_king::{BoneKingAI, BoneKingOutput};
+use elite::{Affix, Affixes, Elite};
 use ghost::{GhostAI, GhostOutput};
+use lich::{LichAI, LichOutput, TELEPORT_DIST};
+use necromancer::{NecromancerAI, NecromancerOutput, RAISE_RANGE};
+use shield_bearer::{ShieldBearerAI, ShieldBearerOutput};
 use skeleton::{SkeletonAI, SkeletonOutput};
+use slime::{SlimeAI, SlimeOutput, SlimeSize};
+use slime_mother::{SlimeMotherAI, SlimeMotherOutput};
 
 const FLASH_DURATION: f32 = 0.12;
 const KNOCKBACK_FRICTION: f32 = 0.85;
 const STAGGER_DURATION: f32 = 0.2;
+/// Knockback speed (px/s) an enemy must still carry into a wall to be slammed.
+pub const WALL_SLAM_MIN_SPEED: f32 = 40.0;
 
-// --- Slime constants (10x10 sprite) ---
+// --- Slime constants (14x12 large, 10x10 medium, 6x6 small sprites) ---
+const SLIME_BIG_HURTBOX: AABB = AABB::new(2.0, 2.0, 10.0, 9.0);
 const SLIME_HURTBOX: AABB = AABB::new(2.0, 1.0, 6.0, 8.0);
+const SLIME_SMALL_HURTBOX: AABB = AABB::new(1.0, 1.0, 4.0, 5.0);
+const SLIME_BIG_COLLISION: (f32, f32, f32, f32) = (2.0, 8.0, 10.0, 4.0);
 const SLIME_COLLISION_W: f32 = 6.0;
 const SLIME_COLLISION_H: f32 = 4.0;
 const SLIME_COLLISION_OFFSET_X: f32 = 2.0;
 const SLIME_COLLISION_OFFSET_Y: f32 = 6.0;
+const SLIME_SMALL_COLLISION: (f32, f32, f32, f32) = (1.0, 3.0, 4.0, 3.0);
+/// Sideways speed the two halves of a split slime pop apart at; under
+/// `WALL_SLAM_MIN_SPEED` so a split next to a wall doesn't slam them.
+const SLIME_SPLIT_POP: f32 = 35.0;
+/// Beat after a split before the halves start hopping.
+const SLIME_SPLIT_STUN: f32 = 0.3;
 
 // --- Skeleton constants (10x14 sprite) ---
 const SKEL_HURTBOX: AABB = AABB::new(2.0, 3.0, 6.0, 8.0);
@@ -38,6 +64,20 @@ const GHOST_COLLISION_H: f32 = 4.0;
 const GHOST_COLLISION_OFFSET_X: f32 = 2.0;
 const GHOST_COLLISION_OFFSET_Y: f32 = 6.0;
 
+// --- Necromancer constants (10x14 sprite, robe is wider than a skeleton) ---
+const NECRO_HURTBOX: AABB = AABB::new(2.0, 1.0, 7.0, 12.0);
+
+// --- Shield bearer constants (12x14 sprite, shield included) ---
+const SHIELD_HURTBOX: AABB = AABB::new(2.0, 2.0, 8.0, 10.0);
+const SHIELD_COLLISION_W: f32 = 10.0;
+const SHIELD_COLLISION_H: f32 = 4.0;
+const SHIELD_COLLISION_OFFSET_X: f32 = 1.0;
+const SHIELD_COLLISION_OFFSET_Y: f32 = 10.0;
+/// Push a shield bearer gets from a blocked hit; too weak to slam it.
+const SHIELD_BLOCK_PUSH: f32 = 25.0;
+/// Beat after a raised skeleton stands up before it can act.
+const RAISE_STUN: f32 = 0.5;
+
 // --- Bone King constants (20x24 sprite) ---
 const BOSS_HURTBOX: AABB = AABB::new(4.0, 4.0, 12.0, 16.0);
 const BOSS_COLLISION_W: f32 = 12.0;
@@ -45,21 +85,163 @@ const BOSS_COLLISION_H: f32 = 6.0;
 const BOSS_COLLISION_OFFSET_X: f32 = 4.0;
 const BOSS_COLLISION_OFFSET_Y: f32 = 18.0;
 
+// --- Lich constants (16x20 sprite) ---
+const LICH_HURTBOX: AABB = AABB::new(4.0, 1.0, 8.0, 15.0);
+const LICH_COLLISION: (f32, f32, f32, f32) = (4.0, 15.0, 8.0, 4.0);
+
+// --- Slime Mother constants (24x18 sprite) ---
+const MOTHER_HURTBOX: AABB = AABB::new(2.0, 4.0, 20.0, 12.0);
+const MOTHER_COLLISION: (f32, f32, f32, f32) = (3.0, 12.0, 18.0, 5.0);
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum EnemyType {
-    #[allow(dead_code)] // Used when slime waves are added
-    Slime,
+    Slime(SlimeSize),
     Skeleton,
     Ghost,
+    SkeletonArcher,
+    Necromancer,
+    ShieldBearer,
     BoneKing,
+    Lich,
+    SlimeMother,
+}
+
+impl EnemyType {
+    pub fn is_boss(self) -> bool {
+        matches!(
+            self,
+            EnemyType::BoneKing | EnemyType::Lich | EnemyType::SlimeMother
+        )
+    }
+
+    /// Name for run summaries and the run history.
+    pub fn name(self) -> &'static str {
+        match self {
+            EnemyType::Slime(_) => "SLIME",
+            EnemyType::Skeleton => "SKELETON",
+            EnemyType::Ghost => "GHOST",
+            EnemyType::SkeletonArcher => "SKELETON ARCHER",
+            EnemyType::Necromancer => "NECROMANCER",
+            EnemyType::ShieldBearer => "SHIELD BEARER",
+            EnemyType::BoneKing => "BONE KING",
+            EnemyType::Lich => "THE LICH",
+            EnemyType::SlimeMother => "SLIME MOTHER",
+        }
+    }
+
+    /// First idle frame, for screens that show the enemy outside a room.
+    pub fn portrait(self) -> &'static SpriteData {
+        let idle = match self {
+            EnemyType::Slime(size) => slime_anims(size).idle,
+            EnemyType::Skeleton => &sprites::SKEL_IDLE_ANIM,
+            EnemyType::Ghost => &sprites::GHOST_IDLE_ANIM,
+            EnemyType::SkeletonArcher => &sprites::ARCHER_IDLE_ANIM,
+            EnemyType::Necromancer => &sprites::NECRO_IDLE_ANIM,
+            EnemyType::ShieldBearer => &sprites::SHIELD_IDLE_ANIM,
+            EnemyType::BoneKing => &sprites::boss::BONE_KING_IDLE_ANIM,
+            EnemyType::Lich => &sprites::lich::LICH_IDLE_ANIM,
+            EnemyType::SlimeMother => &sprites::slime_mother::MOTHER_IDLE_ANIM,
+        };
+        idle.frames[0]
+    }
+
+    /// Name shown over the boss health bar.
+    pub fn boss_name(self) -> Option<&'static str> {
+        match self {
+            EnemyType::BoneKing => Some("BONE KING"),
+            EnemyType::Lich => Some("THE LICH"),
+            EnemyType::SlimeMother => Some("SLIME MOTHER"),
+            _ => None,
+        }
+    }
+
+    /// Status effect this enemy's hits leave on the player.
+    pub fn inflicts(self) -> Option<StatusKind> {
+        match self {
+            EnemyType::Slime(_) | EnemyType::SlimeMother => Some(StatusKind::Slow),
+            EnemyType::Ghost => Some(StatusKind::Weakness),
+            EnemyType::ShieldBearer => Some(StatusKind::Stun),
+            EnemyType::Lich => Some(StatusKind::Poison),
+            _ => None,
+        }
+    }
+}
+
+/// What knockback ran an enemy into this tick (consumed by combat).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum KnockbackEvent {
+    /// Hit a wall at `WALL_SLAM_MIN_SPEED` or faster.
+    WallSlam,
+    /// Pushed over the edge of a pit.
+    FellInPit,
 }
 
 enum AIState {
-    #[allow(dead_code)] // Used when slime waves are added
-    None,
+    Slime(SlimeAI),
     Skeleton(SkeletonAI),
     Ghost(GhostAI),
+    Archer(ArcherAI),
+    Necromancer(NecromancerAI),
+    ShieldBearer(ShieldBearerAI),
     BoneKing(BoneKingAI),
+    Lich(LichAI),
+    SlimeMother(SlimeMotherAI),
+}
+
+/// Walking movement check from one position to another: walls always block,
+/// and walkers won't step onto a pit unless they're already over one.
+fn walk_blocked(
+    tilemap: &TileMap,
+    from: (f32, f32),
+    to: (f32, f32),
+    (ox, oy, w, h): (f32, f32, f32, f32),
+) -> bool {
+    tilemap.collides(to.0 + ox, to.1 + oy, w, h)
+        || (tilemap.overlaps_tile(to.0 + ox, to.1 + oy, w, h, TileType::Pit)
+            && !tilemap.overlaps_tile(from.0 + ox, from.1 + oy, w, h, TileType::Pit))
+}
+
+/// Move a walker by (dx, dy), one axis at a time so it slides along walls.
+fn walk(tilemap: &TileMap, transform: &mut Transform, dx: f32, dy: f32, col: (f32, f32, f32, f32)) {
+    let pos = &mut transform.position;
+    let (x, y) = (pos.x, pos.y);
+    if dx != 0.0 && !walk_blocked(tilemap, (x, y), (x + dx, y), col) {
+        pos.x += dx;
+    }
+    let x = pos.x;
+    if dy != 0.0 && !walk_blocked(tilemap, (x, y), (x, y + dy), col) {
+        pos.y += dy;
+    }
+}
+
+/// Where to put a teleporting enemy so its center lands `radius` from
+/// `around`, starting at `angle` and trying the other seven compass points
+/// until the body fits on open floor. Returns the new sprite origin.
+fn open_spot_around(
+    tilemap: &TileMap,
+    around: (f32, f32),
+    radius: f32,
+    angle: f32,
+    (center_ox, center_oy): (f32, f32),
+    (ox, oy, w, h): (f32, f32, f32, f32),
+) -> Option<(f32, f32)> {
+    (0..8)
+        .map(|k| angle + k as f32 * std::f32::consts::TAU / 8.0)
+        .map(|a| {
+            (
+                around.0 + a.cos() * radius - center_ox,
+                around.1 + a.sin() * radius - center_oy,
+            )
+        })
+        .find(|&(x, y)| {
+            // Off the map reads as open to `collides`, so bound it first
+            x + ox >= 0.0
+                && y + oy >= 0.0
+                && x + ox + w <= tilemap.pixel_width() as f32
+                && y + oy + h <= tilemap.pixel_height() as f32
+                && !tilemap.collides(x + ox, y + oy, w, h)
+                && !tilemap.overlaps_tile(x + ox, y + oy, w, h, TileType::Pit)
+        })
 }
 
 pub struct Enemy {
@@ -80,11 +262,11 @@ pub struct Enemy {
     /// Cached aim direction for projectile spawning
     pub aim_dir_x: f32,
     pub aim_dir_y: f32,
+    /// Angles (radians) of a boss projectile pattern fired this frame
+    pub volley: Vec<f32>,
     /// Cooldown before this enemy can deal contact damage again
     pub contact_damage_cooldown: f32,
     // --- Boss-specific output flags (set during update, consumed by main loop) ---
-    /// True on the frame slam lands (for particles/shake)
-    pub boss_slam_impact: bool,
     /// True on the frame boss hits a wall during charge
     pub boss_charge_wall_hit: bool,
     /// True when boss is roaring (phase transition)
@@ -101,29 +283,60 @@ pub struct Enemy {
     pub boss_stunned: bool,
     /// True when boss is invulnerable
     pub boss_invulnerable: bool,
+    /// Set on the frame knockback slams this enemy into a wall or a pit
+    pub knockback_event: Option<KnockbackEvent>,
+    /// Fell into a pit: dead and no longer drawn
+    fallen: bool,
+    /// Necromancers: nearest raisable corpse, refreshed each tick by `find_corpses`
+    corpse_target: Option<(f32, f32)>,
+    /// Stood back up by a necromancer: worth no gold and no kill
+    raised: bool,
+    /// Bones left once no necromancer remains to raise them: no longer drawn
+    buried: bool,
+    /// Set on the frame a necromancer finishes raising (consumed by main loop)
+    pub raise_corpse: bool,
+    /// Set on the frame a slime lands from a hop (consumed by main loop)
+    pub slime_landed: bool,
+    /// A slime was killed and hasn't burst yet (consumed by `burst_slimes`)
+    slime_burst: bool,
+    /// Champion affixes and their state; `None` for ordinary enemies
+    elite: Option<Elite>,
+    /// Set on the frame a teleporting elite blinks: where it vanished from
+    pub elite_blinked: Option<(f32, f32)>,
+    /// Set on the frame an explosive elite's fuse runs out (consumed by combat)
+    pub elite_detonated: bool,
+    status: StatusEffects,
+    /// Damage status effects dealt this tick (consumed by combat)
+    pub status_damage: i32,
 }
 
 impl Enemy {
-    #[allow(dead_code)] // Used when slime waves are added
-    pub fn new_slime(x: f32, y: f32) -> Self {
+    fn spawn(
+        enemy_type: EnemyType,
+        x: f32,
+        y: f32,
+        hp: i32,
+        animation: &'static AnimationData,
+        ai: AIState,
+    ) -> Self {
         Self {
             transform: Transform::new(x, y),
-            animation: AnimationPlayer::new(&sprites::ENEMY_IDLE_ANIM),
+            animation: AnimationPlayer::new(animation),
             facing_right: true,
-            hp: 3,
+            hp,
             alive: true,
             hit_this_attack: false,
-            enemy_type: EnemyType::Slime,
+            enemy_type,
             flash_timer: 0.0,
             knockback_vx: 0.0,
             knockback_vy: 0.0,
             stagger_timer: 0.0,
-            ai: AIState::None,
+            ai,
             fired_projectile: false,
             aim_dir_x: 0.0,
             aim_dir_y: 0.0,
+            volley: Vec::new(),
             contact_damage_cooldown: 0.0,
-            boss_slam_impact: false,
             boss_charge_wall_hit: false,
             boss_roaring: false,
             boss_dying: false,
@@ -132,110 +345,129 @@ impl Enemy {
             boss_telegraph: false,
             boss_stunned: false,
             boss_invulnerable: false,
+            knockback_event: None,
+            fallen: false,
+            corpse_target: None,
+            raised: false,
+            buried: false,
+            raise_corpse: false,
+            slime_landed: false,
+            slime_burst: false,
+            elite: None,
+            elite_blinked: None,
+            elite_detonated: false,
+            status: if enemy_type.is_boss() {
+                StatusEffects::unstoppable()
+            } else {
+                StatusEffects::default()
+            },
+            status_damage: 0,
         }
     }
 
+    pub fn new_slime(x: f32, y: f32, size: SlimeSize, seed: u32) -> Self {
+        let ai = AIState::Slime(SlimeAI::new(size, seed));
+        let anim = slime_anims(size).idle;
+        Self::spawn(EnemyType::Slime(size), x, y, size.hp(), anim, ai)
+    }
+
     pub fn new_skeleton(x: f32, y: f32, seed: u32) -> Self {
-        Self {
-            transform: Transform::new(x, y),
-            animation: AnimationPlayer::new(&sprites::SKEL_IDLE_ANIM),
-            facing_right: true,
-            hp: 3,
-            alive: true,
-            hit_this_attack: false,
-            enemy_type: EnemyType::Skeleton,
-            flash_timer: 0.0,
-            knockback_vx: 0.0,
-            knockback_vy: 0.0,
-            stagger_timer: 0.0,
-            ai: AIState::Skeleton(SkeletonAI::new(seed)),
-            fired_projectile: false,
-            aim_dir_x: 0.0,
-            aim_dir_y: 0.0,
-            contact_damage_cooldown: 0.0,
-            boss_slam_impact: false,
-            boss_charge_wall_hit: false,
-            boss_roaring: false,
-            boss_dying: false,
-            boss_death_finished: false,
-            boss_phase2: false,
-            boss_telegraph: false,
-            boss_stunned: false,
-            boss_invulnerable: false,
-        }
+        let ai = AIState::Skeleton(SkeletonAI::new(seed));
+        Self::spawn(EnemyType::Skeleton, x, y, 3, &sprites::SKEL_IDLE_ANIM, ai)
     }
 
     pub fn new_ghost(x: f32, y: f32, seed: u32) -> Self {
-        Self {
-            transform: Transform::new(x, y),
-            animation: AnimationPlayer::new(&sprites::GHOST_IDLE_ANIM),
-            facing_right: true,
-            hp: 2,
-            alive: true,
-            hit_this_attack: false,
-            enemy_type: EnemyType::Ghost,
-            flash_timer: 0.0,
-            knockback_vx: 0.0,
-            knockback_vy: 0.0,
-            stagger_timer: 0.0,
-            ai: AIState::Ghost(GhostAI::new(seed)),
-            fired_projectile: false,
-            aim_dir_x: 0.0,
-            aim_dir_y: 0.0,
-            contact_damage_cooldown: 0.0,
-            boss_slam_impact: false,
-            boss_charge_wall_hit: false,
-            boss_roaring: false,
-            boss_dying: false,
-            boss_death_finished: false,
-            boss_phase2: false,
-            boss_telegraph: false,
-            boss_stunned: false,
-            boss_invulnerable: false,
-        }
+        let ai = AIState::Ghost(GhostAI::new(seed));
+        Self::spawn(EnemyType::Ghost, x, y, 2, &sprites::GHOST_IDLE_ANIM, ai)
+    }
+
+    pub fn new_skeleton_archer(x: f32, y: f32, seed: u32) -> Self {
+        let ai = AIState::Archer(ArcherAI::new(seed));
+        let anim = &sprites::ARCHER_IDLE_ANIM;
+        Self::spawn(EnemyType::SkeletonArcher, x, y, 2, anim, ai)
+    }
+
+    pub fn new_necromancer(x: f32, y: f32, seed: u32) -> Self {
+        let ai = AIState::Necromancer(NecromancerAI::new(seed));
+        let anim = &sprites::NECRO_IDLE_ANIM;
+        Self::spawn(EnemyType::Necromancer, x, y, 4, anim, ai)
+    }
+
+    pub fn new_shield_bearer(x: f32, y: f32) -> Self {
+        let ai = AIState::ShieldBearer(ShieldBearerAI::new());
+        let anim = &sprites::SHIELD_IDLE_ANIM;
+        Self::spawn(EnemyType::ShieldBearer, x, y, 5, anim, ai)
     }
 
     pub fn new_bone_king(x: f32, y: f32, seed: u32) -> Self {
-        let ai = BoneKingAI::new(seed);
-        Self {
-            transform: Transform::new(x, y),
-            animation: AnimationPlayer::new(&sprites::boss::BONE_KING_IDLE_ANIM),
-            facing_right: true,
-            hp: 20,
-            alive: true,
-            hit_this_attack: false,
-            enemy_type: EnemyType::BoneKing,
-            flash_timer: 0.0,
-            knockback_vx: 0.0,
-            knockback_vy: 0.0,
-            stagger_timer: 0.0,
-            ai: AIState::BoneKing(ai),
-            fired_projectile: false,
-            aim_dir_x: 0.0,
-            aim_dir_y: 0.0,
-            contact_damage_cooldown: 0.0,
-            boss_slam_impact: false,
-            boss_charge_wall_hit: false,
-            boss_roaring: false,
-            boss_dying: false,
-            boss_death_finished: false,
-            boss_phase2: false,
-            boss_telegraph: false,
-            boss_stunned: false,
-            boss_invulnerable: false,
+        let ai = AIState::BoneKing(BoneKingAI::new(seed));
+        let anim = &sprites::boss::BONE_KING_IDLE_ANIM;
+        Self::spawn(EnemyType::BoneKing, x, y, 20, anim, ai)
+    }
+
+    pub fn new_lich(x: f32, y: f32, seed: u32) -> Self {
+        let ai = LichAI::new(seed);
+        let hp = ai.max_hp();
+        let anim = &sprites::lich::LICH_IDLE_ANIM;
+        Self::spawn(EnemyType::Lich, x, y, hp, anim, AIState::Lich(ai))
+    }
+
+    pub fn new_slime_mother(x: f32, y: f32) -> Self {
+        let mother = SlimeMotherAI::new();
+        let hp = mother.max_hp();
+        let anim = &sprites::slime_mother::MOTHER_IDLE_ANIM;
+        let ai = AIState::SlimeMother(mother);
+        Self::spawn(EnemyType::SlimeMother, x, y, hp, anim, ai)
+    }
+
+    /// Promote to an elite: tougher, with the affixes layered over its AI.
+    pub fn make_elite(&mut self, affixes: Affixes, seed: u32) {
+        if affixes.is_empty() {
+            return;
+        }
+        self.hp *= elite::ELITE_HP_MULT;
+        self.elite = Some(Elite::new(affixes, self.hp, seed));
+    }
+
+    /// Scale a freshly spawned enemy's health, keeping an elite's max HP in
+    /// step.
+    pub fn scale_hp(&mut self, mult: f32) {
+        self.hp = (self.hp as f32 * mult).ceil() as i32;
+        if let Some(elite) = self.elite.as_mut() {
+            elite.max_hp = self.hp;
         }
     }
 
+    pub fn is_elite(&self) -> bool {
+        self.elite.is_some()
+    }
+
+    fn has_affix(&self, affix: Affix) -> bool {
+        self.elite.as_ref().is_some_and(|e| e.affixes.has(affix))
+    }
+
     pub fn center(&self) -> (f32, f32) {
         match self.enemy_type {
-            EnemyType::Slime => (
+            EnemyType::Slime(SlimeSize::Large) => (
+                self.transform.position.x + 7.0,
+                self.transform.position.y + 6.0,
+            ),
+            EnemyType::Slime(SlimeSize::Medium) => (
                 self.transform.position.x + 5.0,
                 self.transform.position.y + 5.0,
             ),
-            EnemyType::Skeleton => (
+            EnemyType::Slime(SlimeSize::Small) => (
+                self.transform.position.x + 3.0,
+                self.transform.position.y + 3.0,
+            ),
+            EnemyType::Skeleton | EnemyType::SkeletonArcher | EnemyType::Necromancer => (
                 self.transform.position.x + 5.0,
                 self.transform.position.y + 7.0,
             ),
+            EnemyType::ShieldBearer => (
+                self.transform.position.x + 6.0,
+                self.transform.position.y + 7.0,
+            ),
             EnemyType::Ghost => (
                 self.transform.position.x + 5.0,
                 self.transform.position.y + 6.0,
@@ -244,28 +476,44 @@ impl Enemy {
                 self.transform.position.x + 10.0,
                 self.transform.position.y + 12.0,
             ),
+            EnemyType::Lich => (
+                self.transform.position.x + 8.0,
+                self.transform.position.y + 10.0,
+            ),
+            EnemyType::SlimeMother => (
+                self.transform.position.x + 12.0,
+                self.transform.position.y + 10.0,
+            ),
         }
     }
 
     pub fn world_hurtbox(&self) -> AABB {
         let hb = match self.enemy_type {
-            EnemyType::Slime => SLIME_HURTBOX,
-            EnemyType::Skeleton => SKEL_HURTBOX,
+            EnemyType::Slime(SlimeSize::Large) => SLIME_BIG_HURTBOX,
+            EnemyType::Slime(SlimeSize::Medium) => SLIME_HURTBOX,
+            EnemyType::Slime(SlimeSize::Small) => SLIME_SMALL_HURTBOX,
+            EnemyType::Skeleton | EnemyType::SkeletonArcher => SKEL_HURTBOX,
             EnemyType::Ghost => GHOST_HURTBOX,
+            EnemyType::Necromancer => NECRO_HURTBOX,
+            EnemyType::ShieldBearer => SHIELD_HURTBOX,
             EnemyType::BoneKing => BOSS_HURTBOX,
+            EnemyType::Lich => LICH_HURTBOX,
+            EnemyType::SlimeMother => MOTHER_HURTBOX,
         };
         hb.at(self.transform.position.x, self.transform.position.y)
     }
 
     fn collision_params(&self) -> (f32, f32, f32, f32) {
         match self.enemy_type {
-            EnemyType::Slime => (
+            EnemyType::Slime(SlimeSize::Large) => SLIME_BIG_COLLISION,
+            EnemyType::Slime(SlimeSize::Small) => SLIME_SMALL_COLLISION,
+            EnemyType::Slime(SlimeSize::Medium) => (
                 SLIME_COLLISION_OFFSET_X,
                 SLIME_COLLISION_OFFSET_Y,
                 SLIME_COLLISION_W,
                 SLIME_COLLISION_H,
             ),
-            EnemyType::Skeleton => (
+            EnemyType::Skeleton | EnemyType::SkeletonArcher | EnemyType::Necromancer => (
                 SKEL_COLLISION_OFFSET_X,
                 SKEL_COLLISION_OFFSET_Y,
                 SKEL_COLLISION_W,
@@ -277,31 +525,33 @@ impl Enemy {
                 GHOST_COLLISION_W,
                 GHOST_COLLISION_H,
             ),
+            EnemyType::ShieldBearer => (
+                SHIELD_COLLISION_OFFSET_X,
+                SHIELD_COLLISION_OFFSET_Y,
+                SHIELD_COLLISION_W,
+                SHIELD_COLLISION_H,
+            ),
             EnemyType::BoneKing => (
                 BOSS_COLLISION_OFFSET_X,
                 BOSS_COLLISION_OFFSET_Y,
                 BOSS_COLLISION_W,
                 BOSS_COLLISION_H,
             ),
+            EnemyType::Lich => LICH_COLLISION,
+            EnemyType::SlimeMother => MOTHER_COLLISION,
         }
     }
 
     pub fn take_damage_with_knockback(&mut self, dmg: i32, kb_dir_x: f32, kb_dir_y: f32, kb_force: f32) {
         // Boss invulnerability check
-        if let AIState::BoneKing(ref ai) = self.ai {
-            if matches!(
-                ai.state,
-                bone_king::BoneKingState::Roar | bone_king::BoneKingState::Dying
-            ) {
-                return;
-            }
+        if self.boss_invulnerable_now() {
+            return;
         }
 
-        self.hp -= dmg;
         self.flash_timer = FLASH_DURATION;
 
-        // Boss has reduced knockback
-        if self.enemy_type == EnemyType::BoneKing {
+        // Bosses have reduced knockback
+        if self.enemy_type.is_boss() {
             self.knockback_vx = kb_dir_x * kb_force * 0.3;
             self.knockback_vy = kb_dir_y * kb_force * 0.3;
             self.stagger_timer = STAGGER_DURATION * 0.5;
@@ -310,58 +560,228 @@ impl Enemy {
             self.knockback_vy = kb_dir_y * kb_force;
             self.stagger_timer = STAGGER_DURATION;
         }
+        self.lose_hp(dmg);
+    }
 
+    /// Damage over time from status effects: no flash, stagger or knockback.
+    pub fn take_status_damage(&mut self, dmg: i32) {
+        if !self.boss_invulnerable_now() {
+            self.lose_hp(dmg);
+        }
+    }
+
+    fn lose_hp(&mut self, dmg: i32) {
+        self.hp -= dmg;
         if self.hp <= 0 {
-            match self.enemy_type {
-                EnemyType::BoneKing => {
-                    // Boss enters dying state through AI, not instant death
-                    if let AIState::BoneKing(ref mut ai) = self.ai {
-                        ai.start_dying();
-                    }
-                }
+            self.status.clear();
+            match &mut self.ai {
+                // Bosses enter dying state through AI, not instant death
+                AIState::BoneKing(ai) => ai.start_dying(),
+                AIState::Lich(ai) => ai.start_dying(),
+                AIState::SlimeMother(ai) => ai.start_dying(),
                 _ => {
                     self.alive = false;
+                    if let Some(elite) = &mut self.elite {
+                        elite.on_death();
+                    }
                     match self.enemy_type {
-                        EnemyType::Slime => self.animation.play(&sprites::ENEMY_DEATH_ANIM),
-                        EnemyType::Skeleton => self.animation.play(&sprites::SKEL_DEATH_ANIM),
+                        EnemyType::Slime(size) => {
+                            self.animation.play(slime_anims(size).death);
+                            self.slime_burst = true;
+                        }
+                        EnemyType::Skeleton | EnemyType::SkeletonArcher => {
+                            self.animation.play(&sprites::SKEL_DEATH_ANIM)
+                        }
                         EnemyType::Ghost => self.animation.play(&sprites::GHOST_DEATH_ANIM),
-                        EnemyType::BoneKing => unreachable!(),
+                        EnemyType::Necromancer => self.animation.play(&sprites::NECRO_DEATH_ANIM),
+                        EnemyType::ShieldBearer => self.animation.play(&sprites::SHIELD_DEATH_ANIM),
+                        EnemyType::BoneKing | EnemyType::Lich | EnemyType::SlimeMother => {
+                            unreachable!()
+                        }
                     }
                 }
             }
-        } else if self.enemy_type == EnemyType::BoneKing {
+        } else {
             // Check for phase transition
-            if let AIState::BoneKing(ref mut ai) = self.ai {
-                ai.check_phase_transition(self.hp);
+            match &mut self.ai {
+                AIState::BoneKing(ai) => {
+                    ai.check_phase_transition(self.hp);
+                }
+                AIState::Lich(ai) => {
+                    ai.check_phase_transition(self.hp);
+                }
+                AIState::SlimeMother(ai) => {
+                    ai.check_phase_transition(self.hp);
+                }
+                _ => {}
             }
         }
     }
 
-    /// Returns true if this enemy can deal contact damage (alive Slime with cooldown expired).
-    #[allow(dead_code)] // Used when slime waves are added
+    /// A boss mid-roar or dying: hits pass straight through.
+    fn boss_invulnerable_now(&self) -> bool {
+        match &self.ai {
+            AIState::BoneKing(ai) => matches!(
+                ai.state,
+                bone_king::BoneKingState::Roar | bone_king::BoneKingState::Dying
+            ),
+            AIState::Lich(ai) => ai.invulnerable(),
+            AIState::SlimeMother(ai) => ai.invulnerable(),
+            _ => false,
+        }
+    }
+
+    /// A shield bearer with its guard up, facing an attack coming from `from_x`.
+    pub fn shield_blocks(&self, from_x: f32) -> bool {
+        let AIState::ShieldBearer(ai) = &self.ai else {
+            return false;
+        };
+        if !self.alive || self.stagger_timer > 0.0 || !ai.guarding() {
+            return false;
+        }
+        let (cx, _) = self.center();
+        if ai.facing_right {
+            from_x >= cx
+        } else {
+            from_x <= cx
+        }
+    }
+
+    /// A hit glanced off the shield: no damage, just a shove.
+    pub fn block_hit(&mut self, kb_dir_x: f32, kb_dir_y: f32) {
+        self.knockback_vx = kb_dir_x * SHIELD_BLOCK_PUSH;
+        self.knockback_vy = kb_dir_y * SHIELD_BLOCK_PUSH;
+    }
+
+    /// Skeleton remains a necromancer can raise.
+    pub fn is_corpse(&self) -> bool {
+        !self.alive
+            && !self.fallen
+            && !self.buried
+            && matches!(
+                self.enemy_type,
+                EnemyType::Skeleton | EnemyType::SkeletonArcher
+            )
+    }
+
+    /// Stood back up by a necromancer rather than spawned with the room.
+    pub fn is_raised(&self) -> bool {
+        self.raised
+    }
+
+    /// Still sliding from a hit.
+    pub fn is_knocked_back(&self) -> bool {
+        self.knockback_vx.abs() > 0.5 || self.knockback_vy.abs() > 0.5
+    }
+
+    /// Hold the enemy in its stagger state for `duration` seconds.
+    pub fn stun(&mut self, duration: f32) {
+        self.stagger_timer = self.stagger_timer.max(duration);
+    }
+
+    /// Afflict the enemy with `kind`. Slow piled past its cap freezes the
+    /// enemy solid instead.
+    pub fn apply_status(&mut self, kind: StatusKind) {
+        if !self.alive || self.hp <= 0 {
+            return;
+        }
+        let slow = StatusKind::Slow;
+        let overflow = kind == slow && self.status.stacks(slow) == slow.max_stacks();
+        if overflow && self.status.apply(StatusKind::Freeze) {
+            self.status.cure(slow);
+            return;
+        }
+        self.status.apply(kind);
+    }
+
+    /// Scale damage this enemy deals for Weakness.
+    pub fn weaken(&self, damage: i32) -> i32 {
+        self.status.weaken(damage)
+    }
+
+    /// Whether knockback can slam this enemy into walls or drop it into pits.
+    /// Ghosts float over pits; bosses barely budge.
+    fn affected_by_terrain(&self) -> bool {
+        self.enemy_type != EnemyType::Ghost && !self.enemy_type.is_boss()
+    }
+
+    /// A shielded elite's barrier soaks the blow. Returns true if it did.
+    pub fn absorb_hit(&mut self) -> bool {
+        let absorbed = self.alive && self.elite.as_mut().is_some_and(Elite::absorb);
+        if absorbed {
+            self.flash_timer = FLASH_DURATION;
+        }
+        absorbed
+    }
+
+    /// A vampiric elite heals off damage it dealt. Returns the HP regained.
+    pub fn feed(&mut self, damage: i32) -> i32 {
+        let Some(elite) = &self.elite else {
+            return 0;
+        };
+        if !self.alive || !self.has_affix(Affix::Vampiric) {
+            return 0;
+        }
+        let healed = damage.min(elite.max_hp - self.hp).max(0);
+        self.hp += healed;
+        healed
+    }
+
+    /// Teleporting elites reappear around the player, if there's room.
+    fn blink(&mut self, tilemap: &TileMap, around: (f32, f32)) {
+        let col = self.collision_params();
+        let (cx, cy) = self.center();
+        let pos = self.transform.position;
+        let Some(elite) = &mut self.elite else {
+            return;
+        };
+        let angle = elite.blink_angle();
+        let offset = (cx - pos.x, cy - pos.y);
+        let spot = open_spot_around(tilemap, around, elite::BLINK_DIST, angle, offset, col);
+        if let Some((x, y)) = spot {
+            self.elite_blinked = Some((cx, cy));
+            self.transform = Transform::new(x, y);
+        }
+    }
+
+    /// Returns true if this enemy can deal contact damage (alive, unstaggered
+    /// Slime with cooldown expired).
     pub fn can_deal_contact_damage(&self) -> bool {
-        self.alive && self.enemy_type == EnemyType::Slime && self.contact_damage_cooldown <= 0.0
+        self.alive
+            && matches!(self.enemy_type, EnemyType::Slime(_))
+            && self.stagger_timer <= 0.0
+            && self.status.can_act()
+            && self.contact_damage_cooldown <= 0.0
     }
 
     /// Puts contact damage on cooldown (0.5s).
-    #[allow(dead_code)] // Used when slime waves are added
     pub fn apply_contact_damage_cooldown(&mut self) {
         self.contact_damage_cooldown = 0.5;
     }
 
     /// Returns this enemy's active attack hitbox, if any.
+    /// The window is driven by HitboxOn/HitboxOff events on the attack animation.
     pub fn attack_hitbox(&self) -> Option<AABB> {
+        if !self.animation.hitbox_active() || !self.status.can_act() {
+            return None;
+        }
         match &self.ai {
-            AIState::Skeleton(ai) => ai.attack_hitbox(
+            AIState::Skeleton(ai) => Some(ai.attack_hitbox(
                 self.transform.position.x,
                 self.transform.position.y,
                 self.facing_right,
-            ),
+            )),
             AIState::BoneKing(ai) => ai.attack_hitbox(
                 self.transform.position.x,
                 self.transform.position.y,
                 self.facing_right,
             ),
+            AIState::ShieldBearer(ai) => {
+                Some(ai.attack_hitbox(self.transform.position.x, self.transform.position.y))
+            }
+            AIState::SlimeMother(ai) => {
+                ai.attack_hitbox(self.transform.position.x, self.transform.position.y)
+            }
             _ => None,
         }
     }
@@ -371,11 +791,15 @@ impl Enemy {
         let dt_f32 = dt as f32;
 
         self.fired_projectile = false;
-        self.boss_slam_impact = false;
+        self.volley.clear();
         self.boss_charge_wall_hit = false;
         self.boss_roaring = false;
         self.boss_dying = false;
         self.boss_death_finished = false;
+        self.knockback_event = None;
+        self.raise_corpse = false;
+        self.slime_landed = false;
+        self.elite_blinked = None;
 
         if self.flash_timer > 0.0 {
             self.flash_timer -= dt_f32;
@@ -386,14 +810,26 @@ impl Enemy {
         if self.contact_damage_cooldown > 0.0 {
             self.contact_damage_cooldown -= dt_f32;
         }
+        let (blink, detonate) = match &mut self.elite {
+            Some(elite) => elite.update(dt_f32, self.alive),
+            None => (false, false),
+        };
+        self.elite_detonated = detonate;
+        self.status_damage = if self.alive {
+            self.status.update(dt_f32)
+        } else {
+            0
+        };
 
-        let (col_ox, col_oy, col_w, col_h) = self.collision_params();
+        let col = self.collision_params();
+        let (col_ox, col_oy, col_w, col_h) = col;
 
         // Apply knockback velocity
-        if self.knockback_vx.abs() > 0.5 || self.knockback_vy.abs() > 0.5 {
+        if self.is_knocked_back() {
             let friction = KNOCKBACK_FRICTION.powf(dt_f32 * 30.0);
             self.knockback_vx *= friction;
             self.knockback_vy *= friction;
+            let mut impact: f32 = 0.0;
 
             let move_x = self.knockback_vx * dt_f32;
             let try_x = self.transform.position.x + move_x;
@@ -401,6 +837,7 @@ impl Enemy {
             {
                 self.transform.position.x = try_x;
             } else {
+                impact = impact.max(self.knockback_vx.abs());
                 self.knockback_vx = 0.0;
             }
 
@@ -410,12 +847,60 @@ impl Enemy {
             {
                 self.transform.position.y = try_y;
             } else {
+                impact = impact.max(self.knockback_vy.abs());
                 self.knockback_vy = 0.0;
             }
+
+            if self.alive && self.affected_by_terrain() {
+                let feet_x = self.transform.position.x + col_ox + col_w / 2.0;
+                let feet_y = self.transform.position.y + col_oy + col_h / 2.0;
+                if tilemap.tile_at(feet_x, feet_y) == TileType::Pit {
+                    self.fall_into_pit();
+                } else if impact >= WALL_SLAM_MIN_SPEED {
+                    self.knockback_event = Some(KnockbackEvent::WallSlam);
+                }
+            }
         }
 
+        if blink && !self.is_knocked_back() {
+            self.blink(tilemap, (player_x, player_y));
+        }
+
+        // Frenzied elites run their AI and animations fast; slowed enemies run
+        // them slow, and frozen or stunned ones not at all
+        let frenzy = self.elite.as_ref().map_or(1.0, |e| e.time_scale(self.hp));
+        let time_scale = frenzy * self.status.speed_mult();
+        let dt_f32 = dt_f32 * time_scale;
+        let before_ai = self.transform.position;
+
         // Type-specific AI
+        let center = self.center();
         match &mut self.ai {
+            AIState::Slime(ai) => {
+                if self.alive {
+                    let (cx, cy) = center;
+                    let out: SlimeOutput = ai.update(
+                        dt_f32, cx, cy, player_x, player_y,
+                        self.stagger_timer > 0.0,
+                        self.alive,
+                    );
+
+                    if self.stagger_timer <= 0.0 {
+                        walk(tilemap, &mut self.transform, out.move_dx, out.move_dy, col);
+                    }
+                    self.facing_right = out.facing_right;
+                    self.slime_landed = out.landed;
+
+                    let anims = slime_anims(ai.size);
+                    if out.airborne {
+                        self.animation.play(anims.stretch);
+                    } else if out.squashed {
+                        self.animation.play(anims.squash);
+                    } else {
+                        self.animation.play(anims.idle);
+                    }
+                }
+            }
             AIState::Skeleton(ai) => {
                 if self.alive {
                     let (cx, cy) = (
@@ -429,13 +914,14 @@ impl Enemy {
                     );
 
                     if (out.move_dx != 0.0 || out.move_dy != 0.0) && self.stagger_timer <= 0.0 {
-                        let try_x = self.transform.position.x + out.move_dx;
-                        if !tilemap.collides(try_x + col_ox, self.transform.position.y + col_oy, col_w, col_h) {
-                            self.transform.position.x = try_x;
+                        let pos = &mut self.transform.position;
+                        let (x, y) = (pos.x, pos.y);
+                        if !walk_blocked(tilemap, (x, y), (x + out.move_dx, y), col) {
+                            pos.x += out.move_dx;
                         }
-                        let try_y = self.transform.position.y + out.move_dy;
-                        if !tilemap.collides(self.transform.position.x + col_ox, try_y + col_oy, col_w, col_h) {
-                            self.transform.position.y = try_y;
+                        let x = pos.x;
+                        if !walk_blocked(tilemap, (x, y), (x, y + out.move_dy), col) {
+                            pos.y += out.move_dy;
                         }
                     }
 
@@ -496,6 +982,96 @@ impl Enemy {
                     }
                 }
             }
+            AIState::Archer(ai) => {
+                if self.alive {
+                    let (cx, cy) = center;
+                    let out: ArcherOutput = ai.update(
+                        dt_f32, cx, cy, player_x, player_y,
+                        self.stagger_timer > 0.0,
+                        self.alive,
+                    );
+
+                    if self.stagger_timer <= 0.0 {
+                        walk(tilemap, &mut self.transform, out.move_dx, out.move_dy, col);
+                    }
+                    self.facing_right = out.facing_right;
+
+                    if out.fire_arrow {
+                        self.fired_projectile = true;
+                        self.aim_dir_x = ai.aim_dir_x;
+                        self.aim_dir_y = ai.aim_dir_y;
+                    }
+
+                    if self.stagger_timer > 0.0 {
+                        self.animation.play(&sprites::ARCHER_STAGGER_ANIM);
+                    } else if out.drawing {
+                        self.animation.play(&sprites::ARCHER_DRAW_ANIM);
+                    } else if out.walking {
+                        self.animation.play(&sprites::ARCHER_WALK_ANIM);
+                    } else {
+                        self.animation.play(&sprites::ARCHER_IDLE_ANIM);
+                    }
+                }
+            }
+            AIState::Necromancer(ai) => {
+                if self.alive {
+                    let (cx, cy) = center;
+                    let corpse = if ai.can_raise() {
+                        self.corpse_target
+                    } else {
+                        None
+                    };
+                    let out: NecromancerOutput = ai.update(
+                        dt_f32, cx, cy, player_x, player_y,
+                        corpse,
+                        self.stagger_timer > 0.0,
+                        self.alive,
+                    );
+
+                    if self.stagger_timer <= 0.0 {
+                        walk(tilemap, &mut self.transform, out.move_dx, out.move_dy, col);
+                    }
+                    self.facing_right = out.facing_right;
+                    self.raise_corpse = out.raise;
+
+                    if self.stagger_timer > 0.0 {
+                        self.animation.play(&sprites::NECRO_STAGGER_ANIM);
+                    } else if out.casting {
+                        self.animation.play(&sprites::NECRO_CAST_ANIM);
+                    } else if out.walking {
+                        self.animation.play(&sprites::NECRO_WALK_ANIM);
+                    } else {
+                        self.animation.play(&sprites::NECRO_IDLE_ANIM);
+                    }
+                }
+            }
+            AIState::ShieldBearer(ai) => {
+                if self.alive {
+                    let (cx, cy) = center;
+                    let out: ShieldBearerOutput = ai.update(
+                        dt_f32, cx, cy, player_x, player_y,
+                        self.stagger_timer > 0.0,
+                        self.alive,
+                    );
+
+                    if self.stagger_timer <= 0.0 {
+                        walk(tilemap, &mut self.transform, out.move_dx, out.move_dy, col);
+                    }
+                    self.facing_right = out.facing_right;
+
+                    if self.stagger_timer > 0.0 {
+                        self.animation.play(&sprites::SHIELD_STAGGER_ANIM);
+                    } else if out.attacking {
+                        self.animation.play(&sprites::SHIELD_BASH_ANIM);
+                    } else if out.winding_up {
+                        self.animation.play(&sprites::SHIELD_WINDUP_ANIM);
+                    } else if out.walking {
+                        self.animation.play(&sprites::SHIELD_WALK_ANIM);
+                    } else {
+                        self.animation.play(&sprites::SHIELD_IDLE_ANIM);
+                    }
+                }
+            }
             AIState::BoneKing(ai) => {
                 // Boss update runs even during dying state
                 if self.alive || ai.state == bone_king::BoneKingState::Dying {
@@ -535,20 +1111,20 @@ impl Enemy {
 
                     // Apply movement with wall collision
                     if (out.dx != 0.0 || out.dy != 0.0) && self.stagger_timer <= 0.0 {
-                        let try_x = self.transform.position.x + out.dx;
-                        if !tilemap.collides(try_x + col_ox, self.transform.position.y + col_oy, col_w, col_h) {
-                            self.transform.position.x = try_x;
+                        let pos = &mut self.transform.position;
+                        let (x, y) = (pos.x, pos.y);
+                        if !walk_blocked(tilemap, (x, y), (x + out.dx, y), col) {
+                            pos.x += out.dx;
                         }
-                        let try_y = self.transform.position.y + out.dy;
-                        if !tilemap.collides(self.transform.position.x + col_ox, try_y + col_oy, col_w, col_h) {
-                            self.transform.position.y = try_y;
+                        let x = pos.x;
+                        if !walk_blocked(tilemap, (x, y), (x, y + out.dy), col) {
+                            pos.y += out.dy;
                         }
                     }
 
                     self.facing_right = out.facing_right;
 
                     // Copy boss flags
-                    self.boss_slam_impact = out.slam_impact;
                     self.boss_charge_wall_hit = out.charge_wall_hit;
                     self.boss_roaring = out.roaring;
                     self.boss_dying = out.dying;
@@ -568,17 +1144,28 @@ impl Enemy {
                         self.animation.play(&sprites::boss::BONE_KING_STUNNED_ANIM);
                     } else if out.roaring {
                         self.animation.play(&sprites::boss::BONE_KING_ROAR_ANIM);
-                    } else if out.stunned || self.stagger_timer > 0.0 {
+                    } else if out.stunned || (self.stagger_timer > 0.0 && !ai.is_attacking()) {
+                        // Attacks resist the stagger pose so a hit can't restart
+                        // the attack animation and replay its impact events
                         self.animation.play(&sprites::boss::BONE_KING_STUNNED_ANIM);
                     } else {
                         match ai.state {
-                            bone_king::BoneKingState::SlamWindup | bone_king::BoneKingState::SlamActive => {
+                            bone_king::BoneKingState::SlamWindup => {
+                                self.animation.play(&sprites::boss::BONE_KING_SLAM_WINDUP_ANIM);
+                            }
+                            bone_king::BoneKingState::SlamActive | bone_king::BoneKingState::SlamRecover => {
                                 self.animation.play(&sprites::boss::BONE_KING_SLAM_ANIM);
                             }
-                            bone_king::BoneKingState::SweepWindup | bone_king::BoneKingState::SweepActive => {
+                            bone_king::BoneKingState::SweepWindup => {
+                                self.animation.play(&sprites::boss::BONE_KING_SWEEP_WINDUP_ANIM);
+                            }
+                            bone_king::BoneKingState::SweepActive | bone_king::BoneKingState::SweepRecover => {
                                 self.animation.play(&sprites::boss::BONE_KING_SWEEP_ANIM);
                             }
-                            bone_king::BoneKingState::ChargeWindup | bone_king::BoneKingState::ChargeActive => {
+                            bone_king::BoneKingState::ChargeWindup => {
+                                self.animation.play(&sprites::boss::BONE_KING_CHARGE_WINDUP_ANIM);
+                            }
+                            bone_king::BoneKingState::ChargeActive => {
                                 self.animation.play(&sprites::boss::BONE_KING_CHARGE_ANIM);
                             }
                             bone_king::BoneKingState::Chase => {
@@ -591,27 +1178,159 @@ impl Enemy {
                     }
                 }
             }
-            AIState::None => {}
+            AIState::Lich(ai) => {
+                if self.alive || ai.state == lich::LichState::Dying {
+                    let (cx, cy) = center;
+                    let out: LichOutput = ai.update(dt_f32, cx, cy, player_x, player_y, self.alive);
+
+                    // Floats over pits and traps like a ghost; only walls stop it
+                    if (out.dx != 0.0 || out.dy != 0.0) && self.stagger_timer <= 0.0 {
+                        let try_x = self.transform.position.x + out.dx;
+                        if !tilemap.collides(try_x + col_ox, self.transform.position.y + col_oy, col_w, col_h) {
+                            self.transform.position.x = try_x;
+                        }
+                        let try_y = self.transform.position.y + out.dy;
+                        if !tilemap.collides(self.transform.position.x + col_ox, try_y + col_oy, col_w, col_h) {
+                            self.transform.position.y = try_y;
+                        }
+                    }
+
+                    if let Some(angle) = out.teleport_angle {
+                        let pos = self.transform.position;
+                        let offset = (cx - pos.x, cy - pos.y);
+                        let around = (player_x, player_y);
+                        let spot =
+                            open_spot_around(tilemap, around, TELEPORT_DIST, angle, offset, col);
+                        if let Some(spot) = spot {
+                            // Snap, don't slide: no interpolation across the blink
+                            self.transform = Transform::new(spot.0, spot.1);
+                        }
+                    }
+
+                    self.facing_right = out.facing_right;
+                    self.volley = out.shots;
+
+                    self.boss_roaring = out.roaring;
+                    self.boss_dying = out.dying;
+                    self.boss_death_finished = out.death_finished;
+                    self.boss_phase2 = out.phase2;
+                    self.boss_telegraph = out.telegraph;
+                    self.boss_invulnerable = out.invulnerable;
+
+                    if out.death_finished {
+                        self.alive = false;
+                    }
+
+                    if out.dying || out.roaring {
+                        self.animation.play(&sprites::lich::LICH_ROAR_ANIM);
+                    } else if out.telegraph {
+                        self.animation.play(&sprites::lich::LICH_CAST_WINDUP_ANIM);
+                    } else if out.casting {
+                        self.animation.play(&sprites::lich::LICH_CAST_ANIM);
+                    } else {
+                        self.animation.play(&sprites::lich::LICH_IDLE_ANIM);
+                    }
+                }
+            }
+            AIState::SlimeMother(ai) => {
+                if self.alive || ai.state == slime_mother::SlimeMotherState::Dying {
+                    let (cx, cy) = center;
+                    let out: SlimeMotherOutput =
+                        ai.update(dt_f32, cx, cy, player_x, player_y, self.alive);
+
+                    walk(tilemap, &mut self.transform, out.dx, out.dy, col);
+                    self.facing_right = out.facing_right;
+                    self.slime_landed = out.landed;
+                    if out.split {
+                        self.slime_burst = true;
+                    }
+
+                    self.boss_roaring = out.roaring;
+                    self.boss_dying = out.dying;
+                    self.boss_death_finished = out.death_finished;
+                    self.boss_phase2 = out.phase2;
+                    self.boss_telegraph = out.telegraph;
+                    self.boss_invulnerable = out.invulnerable;
+
+                    if out.death_finished {
+                        self.alive = false;
+                    }
+
+                    use sprites::slime_mother as anims;
+                    if out.dying || out.roaring {
+                        self.animation.play(&anims::MOTHER_ROAR_ANIM);
+                    } else if out.landed {
+                        // The landing animation opens and closes the splash hitbox
+                        self.animation.play(if out.slam {
+                            &anims::MOTHER_SLAM_ANIM
+                        } else {
+                            &anims::MOTHER_LAND_ANIM
+                        });
+                    } else if ai.state == slime_mother::SlimeMotherState::Land {
+                        // Let the landing play out
+                    } else if out.airborne {
+                        self.animation.play(&anims::MOTHER_STRETCH_ANIM);
+                    } else if out.squashed {
+                        self.animation.play(&anims::MOTHER_SQUASH_ANIM);
+                    } else {
+                        self.animation.play(&anims::MOTHER_IDLE_ANIM);
+                    }
+                }
+            }
+        }
+
+        // Fast elites stretch every step the AI took
+        if self.alive && self.has_affix(Affix::Fast) {
+            let dx = (self.transform.position.x - before_ai.x) * elite::FAST_MOVE_BONUS;
+            let dy = (self.transform.position.y - before_ai.y) * elite::FAST_MOVE_BONUS;
+            walk(tilemap, &mut self.transform, dx, dy, col);
         }
 
         self.animation.set_flipped(!self.facing_right);
-        self.animation.update(dt);
+        self.animation.update(dt * time_scale as f64);
+    }
+
+    /// Drop out of the room: the enemy dies without a corpse.
+    fn fall_into_pit(&mut self) {
+        self.hp = 0;
+        self.alive = false;
+        self.fallen = true;
+        self.knockback_vx = 0.0;
+        self.knockback_vy = 0.0;
+        self.knockback_event = Some(KnockbackEvent::FellInPit);
+        // Death animation still runs so wave tracking sees the enemy finish
+        match self.enemy_type {
+            EnemyType::Skeleton => self.animation.play(&sprites::SKEL_DEATH_ANIM),
+            _ => self.animation.play(&sprites::ENEMY_DEATH_ANIM),
+        }
     }
 
     pub fn render(&self, fb: &mut FrameBuffer, alpha: f32, cam_x: i32, cam_y: i32) {
+        if self.fallen {
+            return;
+        }
         // Boss dying: keep rendering until death_finished
-        if self.enemy_type == EnemyType::BoneKing {
+        if self.enemy_type.is_boss() {
             if !self.alive && !self.boss_dying {
                 return;
             }
-        } else if !self.alive && self.animation.is_finished() {
-            return;
+            if let AIState::Lich(ref ai) = self.ai {
+                if ai.flicker_hidden() {
+                    return;
+                }
+            }
+        } else if !self.alive && self.animation.is_finished() && !self.is_corpse() {
+            // Skeleton bones stay on the floor for necromancers to raise, and
+            // an explosive elite's remains until they go off
+            if !self.elite.as_ref().is_some_and(Elite::fuse_lit) {
+                return;
+            }
         }
 
         let sprite = self.animation.current_sprite();
         let pos = self.transform.interpolated(alpha);
         let px = pos.x as i32 - cam_x;
-        let py = pos.y as i32 - cam_y;
+        let py = pos.y as i32 - cam_y - self.hop_lift();
         let flipped = self.animation.is_flipped();
 
         // Determine tint for this frame
@@ -619,22 +1338,55 @@ impl Enemy {
             Some([255, 255, 255])
         } else if self.boss_dying {
             // Rapid flashing during death: alternate white/red
-            if let AIState::BoneKing(ref ai) = self.ai {
-                if (ai.dying_flash_counter as u32).is_multiple_of(2) {
-                    Some([255, 80, 80])
+            let counter = match &self.ai {
+                AIState::BoneKing(ai) => Some(ai.dying_flash_counter),
+                AIState::Lich(ai) => Some(ai.dying_flash_counter),
+                AIState::SlimeMother(ai) => Some(ai.dying_flash_counter),
+                _ => None,
+            };
+            counter.map(|c| {
+                if (c as u32).is_multiple_of(2) {
+                    [255, 80, 80]
                 } else {
-                    Some([255, 255, 255])
+                    [255, 255, 255]
                 }
+            })
+        } else if let Some(tint) = self.status.tint() {
+            Some(tint)
+        } else if self.boss_phase2 {
+            // Phase 2 tint: each boss glows its own color
+            match self.enemy_type {
+                EnemyType::BoneKing => Some([255, 100, 100]),
+                EnemyType::Lich => Some([150, 130, 255]),
+                EnemyType::SlimeMother => Some([255, 150, 110]),
+                _ => None,
+            }
+        } else if let Some(elite) = &self.elite {
+            if elite.fuse_lit() {
+                // Sputtering fuse: flicker orange
+                elite.fuse_flash().then_some([255, 160, 60])
+            } else if elite.frenzied(self.hp) {
+                Some([255, 130, 130])
             } else {
                 None
             }
-        } else if self.boss_phase2 && self.enemy_type == EnemyType::BoneKing {
-            // Phase 2: red tint
-            Some([255, 100, 100])
         } else {
             None
         };
 
+        // Elites: outline in the first affix's color, drawn under the sprite
+        let elite = self.elite.as_ref().filter(|_| self.alive);
+        if let Some(elite) = elite {
+            let color = elite.color();
+            for (ox, oy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                if flipped {
+                    fb.blit_sprite_flipped_tinted(sprite, px + ox, py + oy, color);
+                } else {
+                    fb.blit_sprite_tinted(sprite, px + ox, py + oy, color);
+                }
+            }
+        }
+
         match (flipped, tint) {
             (false, None) => fb.blit_sprite(sprite, px, py),
             (true, None) => fb.blit_sprite_flipped(sprite, px, py),
@@ -653,14 +1405,290 @@ impl Enemy {
                 }
             }
         }
+
+        if let Some(elite) = elite {
+            let tw = sprites::font::text_width(&elite.label);
+            let tx = px + sprite.width as i32 / 2 - tw / 2;
+            sprites::font::render_text(fb, &elite.label, tx, py - 7, elite.color());
+        }
+    }
+
+    /// Pixels a hopping slime (or the Slime Mother) is drawn above the ground.
+    fn hop_lift(&self) -> i32 {
+        match &self.ai {
+            AIState::Slime(ai) if self.alive => ai.hop_height().round() as i32,
+            AIState::SlimeMother(ai) if self.alive => ai.hop_height().round() as i32,
+            _ => 0,
+        }
+    }
+
+    /// Middle of the collision box: where the enemy stands.
+    pub fn feet(&self) -> (f32, f32) {
+        let (ox, oy, w, h) = self.collision_params();
+        (
+            self.transform.position.x + ox + w / 2.0,
+            self.transform.position.y + oy + h / 2.0,
+        )
     }
 
     /// Returns the boss max HP, if this enemy is a boss.
     pub fn boss_max_hp(&self) -> Option<i32> {
-        if let AIState::BoneKing(ref ai) = self.ai {
-            Some(ai.max_hp())
-        } else {
-            None
+        match &self.ai {
+            AIState::BoneKing(ai) => Some(ai.max_hp()),
+            AIState::Lich(ai) => Some(ai.max_hp()),
+            AIState::SlimeMother(ai) => Some(ai.max_hp()),
+            _ => None,
         }
     }
 }
+
+/// Point each necromancer at the nearest corpse within reach, if any. Once a
+/// necromancer has died and none is left alive, the corpses are buried; bones
+/// from before a necromancer arrives stay for it to raise.
+pub fn find_corpses(enemies: &mut [Enemy]) {
+    let is_necromancer = |e: &Enemy| e.enemy_type == EnemyType::Necromancer;
+    if !enemies.iter().any(|e| is_necromancer(e) && e.alive) {
+        if enemies.iter().any(is_necromancer) {
+            bury_corpses(enemies);
+        }
+        return;
+    }
+    for i in 0..enemies.len() {
+        if enemies[i].enemy_type != EnemyType::Necromancer || !enemies[i].alive {
+            continue;
+        }
+        enemies[i].corpse_target =
+            nearest_corpse(enemies, enemies[i].center()).map(|j| enemies[j].center());
+    }
+}
+
+/// Bury every corpse left lying: nothing will raise them now (e.g. the room
+/// is cleared). Buried bones stop being drawn.
+pub fn bury_corpses(enemies: &mut [Enemy]) {
+    for enemy in enemies.iter_mut().filter(|e| e.is_corpse()) {
+        enemy.buried = true;
+    }
+}
+
+fn nearest_corpse(enemies: &[Enemy], (x, y): (f32, f32)) -> Option<usize> {
+    enemies
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.is_corpse())
+        .map(|(j, e)| {
+            let (cx, cy) = e.center();
+            (j, (cx - x) * (cx - x) + (cy - y) * (cy - y))
+        })
+        .filter(|&(_, d2)| d2 <= RAISE_RANGE * RAISE_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(j, _)| j)
+}
+
+/// Stand up the corpse nearest each necromancer that finished raising this tick.
+/// The corpse is replaced in place by a fresh skeleton, marked as raised so
+/// killing it again pays nothing. Returns the indices of the risen skeletons.
+pub fn raise_corpses(enemies: &mut [Enemy], seed: u32) -> Vec<usize> {
+    let mut raised = Vec::new();
+    for i in 0..enemies.len() {
+        if !enemies[i].raise_corpse {
+            continue;
+        }
+        let Some(j) = nearest_corpse(enemies, enemies[i].center()) else {
+            continue;
+        };
+        let pos = enemies[j].transform.position;
+        let mut skeleton = Enemy::new_skeleton(pos.x, pos.y, seed.wrapping_add(j as u32 * 7919));
+        skeleton.facing_right = enemies[i].facing_right;
+        skeleton.raised = true;
+        skeleton.stun(RAISE_STUN);
+        enemies[j] = skeleton;
+        raised.push(j);
+    }
+    raised
+}
+
+struct SlimeAnims {
+    idle: &'static AnimationData,
+    squash: &'static AnimationData,
+    stretch: &'static AnimationData,
+    death: &'static AnimationData,
+}
+
+fn slime_anims(size: SlimeSize) -> SlimeAnims {
+    match size {
+        SlimeSize::Large => SlimeAnims {
+            idle: &sprites::SLIME_BIG_IDLE_ANIM,
+            squash: &sprites::SLIME_BIG_SQUASH_ANIM,
+            stretch: &sprites::SLIME_BIG_STRETCH_ANIM,
+            death: &sprites::SLIME_BIG_DEATH_ANIM,
+        },
+        SlimeSize::Medium => SlimeAnims {
+            idle: &sprites::ENEMY_IDLE_ANIM,
+            squash: &sprites::ENEMY_SQUASH_ANIM,
+            stretch: &sprites::ENEMY_STRETCH_ANIM,
+            death: &sprites::ENEMY_DEATH_ANIM,
+        },
+        SlimeSize::Small => SlimeAnims {
+            idle: &sprites::SLIME_SMALL_IDLE_ANIM,
+            squash: &sprites::SLIME_SMALL_SQUASH_ANIM,
+            stretch: &sprites::SLIME_SMALL_STRETCH_ANIM,
+            death: &sprites::SLIME_SMALL_DEATH_ANIM,
+        },
+    }
+}
+
+/// Burst every slime killed since the last call, and every Slime Mother
+/// splitting off a brood. Big enough slimes split into two smaller ones that
+/// pop apart from where the parent stood; the new slimes are appended to
+/// `enemies`. Returns where each slime burst and its size.
+pub fn burst_slimes(enemies: &mut Vec<Enemy>, seed: u32) -> Vec<(f32, f32, SlimeSize)> {
+    let mut bursts = Vec::new();
+    let mut children = Vec::new();
+    for (i, enemy) in enemies.iter_mut().enumerate() {
+        let (size, child_size) = match enemy.enemy_type {
+            EnemyType::Slime(size) => (size, size.split_into()),
+            // The mother lives on; her brood comes off medium-sized
+            EnemyType::SlimeMother => (SlimeSize::Large, Some(SlimeSize::Medium)),
+            _ => continue,
+        };
+        if !enemy.slime_burst {
+            continue;
+        }
+        enemy.slime_burst = false;
+        let (fx, fy) = enemy.feet();
+        bursts.push((fx, fy, size));
+        let Some(child_size) = child_size else {
+            continue;
+        };
+        for side in [-1.0, 1.0] {
+            let child_seed = seed.wrapping_add((i * 2 + children.len()) as u32 * 7919);
+            let mut child = Enemy::new_slime(0.0, 0.0, child_size, child_seed);
+            // Feet on the parent's feet: the smaller box fits where the bigger one did
+            let (ox, oy, w, h) = child.collision_params();
+            child.transform = Transform::new(fx - ox - w / 2.0, fy - oy - h / 2.0);
+            child.facing_right = side > 0.0;
+            child.knockback_vx = side * SLIME_SPLIT_POP;
+            child.stun(SLIME_SPLIT_STUN);
+            children.push(child);
+        }
+    }
+    enemies.extend(children);
+    bursts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raise_corpses_stands_up_a_skeleton_worth_nothing() {
+        let mut enemies = vec![
+            Enemy::new_necromancer(40.0, 40.0, 1),
+            Enemy::new_skeleton(60.0, 40.0, 2),
+            Enemy::new_ghost(50.0, 40.0, 3),
+        ];
+        enemies[1].take_status_damage(10);
+        enemies[2].take_status_damage(10);
+        assert!(enemies[1].is_corpse() && !enemies[2].is_corpse());
+
+        // Nothing rises until a necromancer finishes its raise
+        assert!(raise_corpses(&mut enemies, 7).is_empty());
+        enemies[0].raise_corpse = true;
+        assert_eq!(raise_corpses(&mut enemies, 7), vec![1]);
+        let risen = &enemies[1];
+        assert!(risen.enemy_type == EnemyType::Skeleton && risen.alive && risen.is_raised());
+        assert!(!enemies[2].alive && !enemies[2].is_raised());
+
+        // No corpse left to raise
+        assert!(raise_corpses(&mut enemies, 7).is_empty());
+    }
+
+    #[test]
+    fn test_corpses_are_buried_with_the_last_necromancer() {
+        let mut enemies = vec![
+            Enemy::new_necromancer(40.0, 40.0, 1),
+            Enemy::new_skeleton(60.0, 40.0, 2),
+        ];
+        enemies[1].take_status_damage(10);
+        find_corpses(&mut enemies);
+        assert!(enemies[1].is_corpse());
+
+        enemies[0].take_status_damage(10);
+        find_corpses(&mut enemies);
+        assert!(!enemies[1].is_corpse());
+    }
+
+    #[test]
+    fn test_corpses_wait_for_a_later_necromancer() {
+        let mut enemies = vec![Enemy::new_skeleton(60.0, 40.0, 2)];
+        enemies[0].take_status_damage(10);
+        find_corpses(&mut enemies);
+        assert!(enemies[0].is_corpse());
+
+        // The necromancer arrives with the next wave
+        enemies.push(Enemy::new_necromancer(40.0, 40.0, 1));
+        find_corpses(&mut enemies);
+        assert!(enemies[1].corpse_target.is_some());
+        enemies[1].raise_corpse = true;
+        assert_eq!(raise_corpses(&mut enemies, 3), [0]);
+        assert!(enemies[0].alive && enemies[0].is_raised());
+    }
+
+    #[test]
+    fn test_room_clear_buries_corpses() {
+        let mut enemies = vec![Enemy::new_skeleton(60.0, 40.0, 2)];
+        enemies[0].take_status_damage(10);
+        bury_corpses(&mut enemies);
+        assert!(!enemies[0].is_corpse());
+    }
+
+    fn slime_hps(enemies: &[Enemy]) -> Vec<i32> {
+        let hp = |e: &Enemy| match e.enemy_type {
+            EnemyType::Slime(size) => size.hp(),
+            _ => 0,
+        };
+        enemies.iter().filter(|e| e.alive).map(hp).collect()
+    }
+
+    #[test]
+    fn test_slimes_split_down_to_the_smallest_size() {
+        let mut enemies = vec![Enemy::new_slime(40.0, 40.0, SlimeSize::Large, 1)];
+        let feet = enemies[0].feet();
+        enemies[0].take_status_damage(10);
+        let bursts = burst_slimes(&mut enemies, 7);
+        assert!(bursts.len() == 1 && bursts[0].2 == SlimeSize::Large);
+        assert_eq!(slime_hps(&enemies), [3, 3]);
+        // The halves land on the parent's feet, popping apart
+        for child in &enemies[1..] {
+            let (fx, fy) = child.feet();
+            assert!((fx - feet.0).abs() < 0.01 && (fy - feet.1).abs() < 0.01);
+        }
+        assert!(enemies[1].knockback_vx < 0.0 && enemies[2].knockback_vx > 0.0);
+
+        // A burst is only reported once
+        assert!(burst_slimes(&mut enemies, 7).is_empty());
+
+        enemies[1].take_status_damage(10);
+        burst_slimes(&mut enemies, 7);
+        assert_eq!(slime_hps(&enemies), [3, 1, 1]);
+
+        let before = enemies.len();
+        enemies[3].take_status_damage(10);
+        let bursts = burst_slimes(&mut enemies, 7);
+        assert!(bursts.len() == 1 && bursts[0].2 == SlimeSize::Small);
+        assert_eq!(enemies.len(), before);
+    }
+
+    #[test]
+    fn test_slime_mother_broods_without_dying() {
+        let mut enemies = vec![Enemy::new_slime_mother(40.0, 40.0)];
+        enemies[0].slime_burst = true;
+        burst_slimes(&mut enemies, 7);
+        assert!(enemies[0].alive);
+        let brood: Vec<bool> = enemies[1..]
+            .iter()
+            .map(|e| e.enemy_type == EnemyType::Slime(SlimeSize::Medium))
+            .collect();
+        assert_eq!(brood, [true, true]);
+    }
+}
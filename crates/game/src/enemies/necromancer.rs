@@ -0,0 +1,168 @@
+/// How far a necromancer can reach to raise a corpse.
+pub const RAISE_RANGE: f32 = 70.0;
+/// Corpses a single necromancer can raise before it runs dry.
+const MAX_RAISES: u32 = 3;
+
+const KEEP_AWAY: f32 = 55.0;
+const FLEE_SPEED: f32 = 30.0;
+const FLEE_DURATION: f32 = 0.8;
+const WANDER_SPEED: f32 = 15.0;
+const CAST_DURATION: f32 = 1.2;
+const CAST_COOLDOWN: f32 = 3.0;
+const STAGGER_DURATION: f32 = 0.3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum NecromancerState {
+    Idle,
+    Flee,
+    Cast,
+    Cooldown,
+    Stagger,
+}
+
+pub struct NecromancerAI {
+    pub state: NecromancerState,
+    timer: f32,
+    raises_left: u32,
+    wander_dx: f32,
+    wander_dy: f32,
+    rng_state: u32,
+}
+
+impl NecromancerAI {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            state: NecromancerState::Idle,
+            timer: 1.0,
+            raises_left: MAX_RAISES,
+            wander_dx: 0.0,
+            wander_dy: 0.0,
+            rng_state: seed,
+        }
+    }
+
+    fn rand_float(&mut self) -> f32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(1103515245)
+            .wrapping_add(12345);
+        (self.rng_state >> 16) as f32 / 65536.0
+    }
+
+    /// Whether this necromancer still wants corpses.
+    pub fn can_raise(&self) -> bool {
+        self.raises_left > 0
+    }
+
+    /// Advance one tick. `corpse` is the nearest raisable corpse in range, if any.
+    /// A hit while casting interrupts the raise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_x: f32,
+        my_y: f32,
+        player_x: f32,
+        player_y: f32,
+        corpse: Option<(f32, f32)>,
+        staggered: bool,
+        alive: bool,
+    ) -> NecromancerOutput {
+        if !alive {
+            return NecromancerOutput::default();
+        }
+
+        if staggered && self.state != NecromancerState::Stagger {
+            self.state = NecromancerState::Stagger;
+            self.timer = STAGGER_DURATION;
+        }
+
+        self.timer -= dt;
+
+        let dx = player_x - my_x;
+        let dy = player_y - my_y;
+        let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+
+        let mut output = NecromancerOutput {
+            facing_right: dx > 0.0,
+            ..NecromancerOutput::default()
+        };
+
+        match self.state {
+            NecromancerState::Idle => {
+                if dist < KEEP_AWAY {
+                    self.state = NecromancerState::Flee;
+                    self.timer = FLEE_DURATION;
+                } else if corpse.is_some() && self.can_raise() && self.timer <= 0.0 {
+                    self.state = NecromancerState::Cast;
+                    self.timer = CAST_DURATION;
+                } else {
+                    if self.timer <= 0.0 {
+                        let angle = self.rand_float() * std::f32::consts::TAU;
+                        self.wander_dx = angle.cos();
+                        self.wander_dy = angle.sin();
+                        self.timer = 1.0 + self.rand_float();
+                    }
+                    output.move_dx = self.wander_dx * WANDER_SPEED * dt;
+                    output.move_dy = self.wander_dy * WANDER_SPEED * dt;
+                    output.walking = true;
+                }
+            }
+            NecromancerState::Flee => {
+                output.move_dx = -dx / dist * FLEE_SPEED * dt;
+                output.move_dy = -dy / dist * FLEE_SPEED * dt;
+                output.walking = true;
+                if self.timer <= 0.0 {
+                    self.state = NecromancerState::Idle;
+                    self.timer = 0.0;
+                }
+            }
+            NecromancerState::Cast => {
+                output.casting = true;
+                if let Some((cx, _)) = corpse {
+                    output.facing_right = cx > my_x;
+                }
+                if corpse.is_none() {
+                    // Someone else got to the corpse first
+                    self.state = NecromancerState::Idle;
+                    self.timer = 0.5;
+                } else if self.timer <= 0.0 {
+                    output.raise = true;
+                    self.raises_left -= 1;
+                    self.state = NecromancerState::Cooldown;
+                    self.timer = CAST_COOLDOWN;
+                }
+            }
+            NecromancerState::Cooldown => {
+                if dist < KEEP_AWAY {
+                    output.move_dx = -dx / dist * FLEE_SPEED * dt;
+                    output.move_dy = -dy / dist * FLEE_SPEED * dt;
+                    output.walking = true;
+                }
+                if self.timer <= 0.0 {
+                    self.state = NecromancerState::Idle;
+                    self.timer = 0.0;
+                }
+            }
+            NecromancerState::Stagger => {
+                if self.timer <= 0.0 {
+                    self.state = NecromancerState::Idle;
+                    self.timer = 0.5;
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[derive(Default)]
+pub struct NecromancerOutput {
+    pub move_dx: f32,
+    pub move_dy: f32,
+    pub facing_right: bool,
+    pub walking: bool,
+    pub casting: bool,
+    /// The cast completed: raise the targeted corpse this tick.
+    pub raise: bool,
+}
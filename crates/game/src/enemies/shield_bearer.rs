@@ -0,0 +1,187 @@
+use engine::collision::AABB;
+
+const DETECT_RANGE: f32 = 90.0;
+const ATTACK_RANGE: f32 = 18.0;
+const CHASE_SPEED: f32 = 22.0;
+/// How long the player must stay behind before the bearer turns around
+const TURN_DELAY: f32 = 0.6;
+const BASH_DISTANCE: f32 = 10.0;
+const WINDUP_DURATION: f32 = 0.5;
+const ATTACK_DURATION: f32 = 0.15;
+/// Shield is lowered after a bash, leaving an opening
+const COOLDOWN_DURATION: f32 = 0.9;
+const STAGGER_DURATION: f32 = 0.3;
+
+/// Bash hitbox: the shield face, just past the front of the sprite
+const BASH_HITBOX_RIGHT: AABB = AABB::new(9.0, 2.0, 8.0, 10.0);
+const BASH_HITBOX_LEFT: AABB = AABB::new(-5.0, 2.0, 8.0, 10.0);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ShieldBearerState {
+    Idle,
+    Chase,
+    WindUp,
+    Attack,
+    Cooldown,
+    Stagger,
+}
+
+pub struct ShieldBearerAI {
+    pub state: ShieldBearerState,
+    timer: f32,
+    /// The bearer turns slowly; this is the way the shield faces.
+    pub facing_right: bool,
+    /// Time the player has spent behind the shield
+    turn_timer: f32,
+    attack_dir_x: f32,
+    attack_dir_y: f32,
+}
+
+impl Default for ShieldBearerAI {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShieldBearerAI {
+    pub fn new() -> Self {
+        Self {
+            state: ShieldBearerState::Idle,
+            timer: 0.0,
+            facing_right: true,
+            turn_timer: 0.0,
+            attack_dir_x: 1.0,
+            attack_dir_y: 0.0,
+        }
+    }
+
+    /// Shield is up and blocks hits from the front.
+    pub fn guarding(&self) -> bool {
+        matches!(
+            self.state,
+            ShieldBearerState::Idle | ShieldBearerState::Chase | ShieldBearerState::WindUp
+        )
+    }
+
+    /// Advance one tick and return movement and animation hints.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_x: f32,
+        my_y: f32,
+        player_x: f32,
+        player_y: f32,
+        staggered: bool,
+        alive: bool,
+    ) -> ShieldBearerOutput {
+        if !alive {
+            return ShieldBearerOutput::default();
+        }
+
+        if staggered && self.state != ShieldBearerState::Stagger {
+            self.state = ShieldBearerState::Stagger;
+            self.timer = STAGGER_DURATION;
+        }
+
+        self.timer -= dt;
+
+        let dx = player_x - my_x;
+        let dy = player_y - my_y;
+        let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+        let player_in_front = (dx > 0.0) == self.facing_right;
+
+        // Turn only once the player has lingered behind (not mid-swing)
+        if !matches!(
+            self.state,
+            ShieldBearerState::WindUp | ShieldBearerState::Attack
+        ) {
+            if player_in_front {
+                self.turn_timer = 0.0;
+            } else {
+                self.turn_timer += dt;
+                if self.turn_timer >= TURN_DELAY {
+                    self.facing_right = !self.facing_right;
+                    self.turn_timer = 0.0;
+                }
+            }
+        }
+
+        let mut output = ShieldBearerOutput::default();
+
+        match self.state {
+            ShieldBearerState::Idle => {
+                if dist < DETECT_RANGE {
+                    self.state = ShieldBearerState::Chase;
+                }
+            }
+            ShieldBearerState::Chase => {
+                // Plants its feet while the player is behind it
+                if player_in_front {
+                    output.move_dx = dx / dist * CHASE_SPEED * dt;
+                    output.move_dy = dy / dist * CHASE_SPEED * dt;
+                    output.walking = true;
+                }
+
+                if dist < ATTACK_RANGE && player_in_front {
+                    self.state = ShieldBearerState::WindUp;
+                    self.timer = WINDUP_DURATION;
+                    self.attack_dir_x = dx / dist;
+                    self.attack_dir_y = dy / dist;
+                } else if dist > DETECT_RANGE * 1.5 {
+                    self.state = ShieldBearerState::Idle;
+                }
+            }
+            ShieldBearerState::WindUp => {
+                output.winding_up = true;
+                if self.timer <= 0.0 {
+                    self.state = ShieldBearerState::Attack;
+                    self.timer = ATTACK_DURATION;
+                }
+            }
+            ShieldBearerState::Attack => {
+                output.attacking = true;
+                let bash_speed = BASH_DISTANCE / ATTACK_DURATION;
+                output.move_dx = self.attack_dir_x * bash_speed * dt;
+                output.move_dy = self.attack_dir_y * bash_speed * dt;
+                if self.timer <= 0.0 {
+                    self.state = ShieldBearerState::Cooldown;
+                    self.timer = COOLDOWN_DURATION;
+                }
+            }
+            ShieldBearerState::Cooldown | ShieldBearerState::Stagger => {
+                if self.timer <= 0.0 {
+                    self.state = if dist < DETECT_RANGE {
+                        ShieldBearerState::Chase
+                    } else {
+                        ShieldBearerState::Idle
+                    };
+                }
+            }
+        }
+
+        output.facing_right = self.facing_right;
+        output.guarding = self.guarding();
+        output
+    }
+
+    /// World-space bash hitbox, live while the bash animation says so.
+    pub fn attack_hitbox(&self, x: f32, y: f32) -> AABB {
+        if self.facing_right {
+            BASH_HITBOX_RIGHT.at(x, y)
+        } else {
+            BASH_HITBOX_LEFT.at(x, y)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ShieldBearerOutput {
+    pub move_dx: f32,
+    pub move_dy: f32,
+    pub facing_right: bool,
+    pub walking: bool,
+    pub winding_up: bool,
+    pub attacking: bool,
+    pub guarding: bool,
+}
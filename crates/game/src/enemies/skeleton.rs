@@ -62,7 +62,7 @@ impl SkeletonAI {
         (self.rng_state >> 16) as f32 / 65536.0
     }
 
-    /// Returns (move_dx, move_dy, facing_right_hint, attack_hitbox_active)
+    /// Advance one tick and return movement and animation hints.
     #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self,
@@ -167,9 +167,6 @@ impl SkeletonAI {
                 output.move_dy = self.attack_dir_y * lunge_speed * dt;
                 self.lunge_progress += dt;
 
-                // Hitbox active during attack
-                output.hitbox_active = true;
-
                 if self.timer <= 0.0 {
                     self.state = SkeletonState::Cooldown;
                     self.timer = COOLDOWN_DURATION;
@@ -200,15 +197,13 @@ impl SkeletonAI {
         output
     }
 
-    /// Returns the world-space attack hitbox for this skeleton, if active.
-    pub fn attack_hitbox(&self, x: f32, y: f32, facing_right: bool) -> Option<AABB> {
-        if self.state != SkeletonState::Attack {
-            return None;
-        }
+    /// World-space attack hitbox. Whether it is live is decided by the
+    /// attack animation's frame events, not by the AI state.
+    pub fn attack_hitbox(&self, x: f32, y: f32, facing_right: bool) -> AABB {
         if facing_right {
-            Some(SKEL_ATTACK_HITBOX_RIGHT.at(x, y))
+            SKEL_ATTACK_HITBOX_RIGHT.at(x, y)
         } else {
-            Some(SKEL_ATTACK_HITBOX_LEFT.at(x, y))
+            SKEL_ATTACK_HITBOX_LEFT.at(x, y)
         }
     }
 }
@@ -221,6 +216,5 @@ pub struct SkeletonOutput {
     pub walking: bool,
     pub winding_up: bool,
     pub attacking: bool,
-    pub hitbox_active: bool,
     pub telegraph: bool,
 }
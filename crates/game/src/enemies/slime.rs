@@ -0,0 +1,221 @@
+const DETECT_RANGE: f32 = 100.0;
+/// Hops aim this far (radians) either side of the player, so slimes spread out
+const AIM_JITTER: f32 = 0.35;
+const CROUCH_DURATION: f32 = 0.25;
+const LAND_DURATION: f32 = 0.15;
+const STAGGER_DURATION: f32 = 0.3;
+
+/// Slime sizes, largest first. Each size splits into two of the next one down.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SlimeSize {
+    Large,
+    Medium,
+    Small,
+}
+
+struct HopStats {
+    distance: f32,
+    air_time: f32,
+    /// Peak height of the hop arc, in pixels
+    height: f32,
+    rest_min: f32,
+    rest_max: f32,
+}
+
+impl SlimeSize {
+    pub fn hp(self) -> i32 {
+        match self {
+            SlimeSize::Large => 4,
+            SlimeSize::Medium => 3,
+            SlimeSize::Small => 1,
+        }
+    }
+
+    /// What this slime splits into when it dies, if it's big enough to split.
+    pub fn split_into(self) -> Option<SlimeSize> {
+        match self {
+            SlimeSize::Large => Some(SlimeSize::Medium),
+            SlimeSize::Medium => Some(SlimeSize::Small),
+            SlimeSize::Small => None,
+        }
+    }
+
+    /// Smaller slimes hop shorter but more often.
+    fn hop(self) -> HopStats {
+        match self {
+            SlimeSize::Large => HopStats {
+                distance: 24.0,
+                air_time: 0.45,
+                height: 5.0,
+                rest_min: 0.8,
+                rest_max: 1.3,
+            },
+            SlimeSize::Medium => HopStats {
+                distance: 20.0,
+                air_time: 0.35,
+                height: 4.0,
+                rest_min: 0.5,
+                rest_max: 1.0,
+            },
+            SlimeSize::Small => HopStats {
+                distance: 14.0,
+                air_time: 0.25,
+                height: 3.0,
+                rest_min: 0.3,
+                rest_max: 0.7,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SlimeState {
+    Rest,
+    /// Squashing down before take-off
+    Crouch,
+    Hop,
+    /// Squashed flat on touchdown
+    Land,
+    Stagger,
+}
+
+pub struct SlimeAI {
+    pub state: SlimeState,
+    pub size: SlimeSize,
+    timer: f32,
+    hop_dx: f32,
+    hop_dy: f32,
+    rng_state: u32,
+}
+
+impl SlimeAI {
+    pub fn new(size: SlimeSize, seed: u32) -> Self {
+        let mut ai = Self {
+            state: SlimeState::Rest,
+            size,
+            timer: 0.0,
+            hop_dx: 1.0,
+            hop_dy: 0.0,
+            rng_state: seed,
+        };
+        // Stagger the first hop so a group doesn't bounce in lockstep
+        ai.timer = 0.4 + ai.rand_float() * 0.6;
+        ai
+    }
+
+    fn rand_float(&mut self) -> f32 {
+        self.rng_state = self
+            .rng_state
+            .wrapping_mul(1103515245)
+            .wrapping_add(12345);
+        (self.rng_state >> 16) as f32 / 65536.0
+    }
+
+    /// Height above the ground mid-hop, for drawing the arc.
+    pub fn hop_height(&self) -> f32 {
+        if self.state != SlimeState::Hop {
+            return 0.0;
+        }
+        let hop = self.size.hop();
+        let t = 1.0 - (self.timer / hop.air_time).clamp(0.0, 1.0);
+        (t * std::f32::consts::PI).sin() * hop.height
+    }
+
+    /// Advance one tick and return movement and animation hints.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_x: f32,
+        my_y: f32,
+        player_x: f32,
+        player_y: f32,
+        staggered: bool,
+        alive: bool,
+    ) -> SlimeOutput {
+        if !alive {
+            return SlimeOutput::default();
+        }
+
+        if staggered && self.state != SlimeState::Stagger {
+            self.state = SlimeState::Stagger;
+            self.timer = STAGGER_DURATION;
+        }
+
+        self.timer -= dt;
+
+        let dx = player_x - my_x;
+        let dy = player_y - my_y;
+        let dist = (dx * dx + dy * dy).sqrt();
+        let hop = self.size.hop();
+
+        let mut output = SlimeOutput {
+            facing_right: self.hop_dx > 0.0,
+            ..SlimeOutput::default()
+        };
+
+        match self.state {
+            SlimeState::Rest => {
+                if self.timer <= 0.0 {
+                    // Hop at the player once it's close, otherwise bounce about
+                    let angle = if dist < DETECT_RANGE {
+                        dy.atan2(dx) + (self.rand_float() * 2.0 - 1.0) * AIM_JITTER
+                    } else {
+                        self.rand_float() * std::f32::consts::TAU
+                    };
+                    self.hop_dx = angle.cos();
+                    self.hop_dy = angle.sin();
+                    self.state = SlimeState::Crouch;
+                    self.timer = CROUCH_DURATION;
+                }
+            }
+            SlimeState::Crouch => {
+                output.squashed = true;
+                if self.timer <= 0.0 {
+                    self.state = SlimeState::Hop;
+                    self.timer = hop.air_time;
+                }
+            }
+            SlimeState::Hop => {
+                output.airborne = true;
+                let speed = hop.distance / hop.air_time;
+                output.move_dx = self.hop_dx * speed * dt;
+                output.move_dy = self.hop_dy * speed * dt;
+                if self.timer <= 0.0 {
+                    output.landed = true;
+                    self.state = SlimeState::Land;
+                    self.timer = LAND_DURATION;
+                }
+            }
+            SlimeState::Land => {
+                output.squashed = true;
+                if self.timer <= 0.0 {
+                    self.state = SlimeState::Rest;
+                    self.timer = hop.rest_min + self.rand_float() * (hop.rest_max - hop.rest_min);
+                }
+            }
+            SlimeState::Stagger => {
+                output.squashed = true;
+                if self.timer <= 0.0 {
+                    self.state = SlimeState::Rest;
+                    self.timer = hop.rest_min;
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[derive(Default)]
+pub struct SlimeOutput {
+    pub move_dx: f32,
+    pub move_dy: f32,
+    pub facing_right: bool,
+    /// Crouching, landing or staggered: draw the wide, flat pose
+    pub squashed: bool,
+    /// Mid-hop: draw the tall, narrow pose
+    pub airborne: bool,
+    /// Touched down this tick
+    pub landed: bool,
+}
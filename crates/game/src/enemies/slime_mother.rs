@@ -0,0 +1,342 @@
+use engine::collision::AABB;
+
+// --- Slime Mother constants ---
+const MOTHER_HP: i32 = 24;
+/// HP marks at which she splits off a brood. The first starts phase 2.
+const SPLIT_THRESHOLDS: [i32; 2] = [16, 8];
+const HOP_DISTANCE: f32 = 26.0;
+const HOP_AIR_TIME: f32 = 0.5;
+const HOP_HEIGHT: f32 = 6.0;
+const LEAP_AIR_TIME: f32 = 0.9;
+const LEAP_HEIGHT: f32 = 16.0;
+/// Longest leap; a player further away is leapt towards
+const LEAP_MAX_DISTANCE: f32 = 90.0;
+/// Hops between leaps, less one per brood split off
+const HOPS_PER_LEAP: u32 = 3;
+
+// --- Phase timing ---
+const REST_DURATION: f32 = 0.7;
+const CROUCH_DURATION: f32 = 0.35;
+const LEAP_CROUCH_DURATION: f32 = 0.6;
+const LAND_DURATION: f32 = 0.35;
+
+// Phase 2 reduced cooldowns
+const REST_DURATION_P2: f32 = 0.45;
+const CROUCH_DURATION_P2: f32 = 0.25;
+
+// Splitting off a brood
+const SPLIT_DURATION: f32 = 0.9;
+
+// Death
+const DYING_DURATION: f32 = 1.5;
+
+// --- Attack hitbox (local-space, relative to the 24x18 sprite origin) ---
+// Landing splash: spills a little past her sides at the base
+const LAND_HITBOX: AABB = AABB::new(-3.0, 8.0, 30.0, 10.0);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SlimeMotherState {
+    Rest,
+    Crouch,
+    Hop,
+    /// Telegraphed crouch before a leap
+    LeapCrouch,
+    Leap,
+    Land,
+    /// Roaring while a brood splits off
+    Split,
+    Dying,
+}
+
+pub struct SlimeMotherAI {
+    pub state: SlimeMotherState,
+    timer: f32,
+    /// Velocity locked at take-off
+    hop_vx: f32,
+    hop_vy: f32,
+    /// Air time of the jump in progress
+    air_time: f32,
+    hops_since_leap: u32,
+    /// Thresholds passed so far
+    splits: usize,
+    /// Broods still to split off; one big hit can pass several thresholds
+    broods_pending: usize,
+    /// Phase 2 activated
+    pub phase2: bool,
+    /// Dying flash counter for visual effect
+    pub dying_flash_counter: f32,
+}
+
+impl Default for SlimeMotherAI {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SlimeMotherAI {
+    pub fn new() -> Self {
+        Self {
+            state: SlimeMotherState::Rest,
+            timer: REST_DURATION,
+            hop_vx: 0.0,
+            hop_vy: 0.0,
+            air_time: HOP_AIR_TIME,
+            hops_since_leap: 0,
+            splits: 0,
+            broods_pending: 0,
+            phase2: false,
+            dying_flash_counter: 0.0,
+        }
+    }
+
+    pub fn max_hp(&self) -> i32 {
+        MOTHER_HP
+    }
+
+    /// Split off a brood if damage took her past the next threshold
+    /// (called externally after damage).
+    pub fn check_phase_transition(&mut self, current_hp: i32) -> bool {
+        let crossed = SPLIT_THRESHOLDS[self.splits..]
+            .iter()
+            .take_while(|&&threshold| current_hp <= threshold)
+            .count();
+        if crossed > 0 && current_hp > 0 {
+            self.splits += crossed;
+            self.broods_pending += crossed;
+            self.phase2 = true;
+            self.state = SlimeMotherState::Split;
+            self.timer = SPLIT_DURATION;
+            return true;
+        }
+        false
+    }
+
+    /// Start dying sequence.
+    pub fn start_dying(&mut self) {
+        self.state = SlimeMotherState::Dying;
+        self.timer = DYING_DURATION;
+        self.dying_flash_counter = 0.0;
+    }
+
+    /// Splitting and dying ignore damage.
+    pub fn invulnerable(&self) -> bool {
+        matches!(
+            self.state,
+            SlimeMotherState::Split | SlimeMotherState::Dying
+        )
+    }
+
+    /// Height above the ground mid-jump, for drawing the arc.
+    pub fn hop_height(&self) -> f32 {
+        let peak = match self.state {
+            SlimeMotherState::Hop => HOP_HEIGHT,
+            SlimeMotherState::Leap => LEAP_HEIGHT,
+            _ => return 0.0,
+        };
+        let t = 1.0 - (self.timer / self.air_time).clamp(0.0, 1.0);
+        (t * std::f32::consts::PI).sin() * peak
+    }
+
+    pub fn update(
+        &mut self,
+        dt: f32,
+        my_cx: f32,
+        my_cy: f32,
+        player_x: f32,
+        player_y: f32,
+        alive: bool,
+    ) -> SlimeMotherOutput {
+        if !alive && self.state != SlimeMotherState::Dying {
+            return SlimeMotherOutput::default();
+        }
+
+        let entering_split = self.state == SlimeMotherState::Split && self.timer >= SPLIT_DURATION;
+        self.timer -= dt;
+
+        let dx = player_x - my_cx;
+        let dy = player_y - my_cy;
+        let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+
+        let mut output = SlimeMotherOutput {
+            phase2: self.phase2,
+            facing_right: dx > 0.0,
+            ..Default::default()
+        };
+
+        match self.state {
+            SlimeMotherState::Rest => {
+                if self.timer <= 0.0 {
+                    let hops_per_leap = HOPS_PER_LEAP.saturating_sub(self.splits as u32).max(1);
+                    if self.hops_since_leap >= hops_per_leap {
+                        self.state = SlimeMotherState::LeapCrouch;
+                        self.timer = LEAP_CROUCH_DURATION;
+                    } else {
+                        self.state = SlimeMotherState::Crouch;
+                        self.timer = if self.phase2 {
+                            CROUCH_DURATION_P2
+                        } else {
+                            CROUCH_DURATION
+                        };
+                    }
+                }
+            }
+            SlimeMotherState::Crouch => {
+                output.squashed = true;
+                if self.timer <= 0.0 {
+                    self.hop_vx = dx / dist * HOP_DISTANCE / HOP_AIR_TIME;
+                    self.hop_vy = dy / dist * HOP_DISTANCE / HOP_AIR_TIME;
+                    self.air_time = HOP_AIR_TIME;
+                    self.hops_since_leap += 1;
+                    self.state = SlimeMotherState::Hop;
+                    self.timer = HOP_AIR_TIME;
+                }
+            }
+            SlimeMotherState::LeapCrouch => {
+                output.squashed = true;
+                output.telegraph = true;
+                if self.timer <= 0.0 {
+                    // Land where the player stood at take-off
+                    let reach = dist.min(LEAP_MAX_DISTANCE);
+                    self.hop_vx = dx / dist * reach / LEAP_AIR_TIME;
+                    self.hop_vy = dy / dist * reach / LEAP_AIR_TIME;
+                    self.air_time = LEAP_AIR_TIME;
+                    self.hops_since_leap = 0;
+                    self.state = SlimeMotherState::Leap;
+                    self.timer = LEAP_AIR_TIME;
+                }
+            }
+            SlimeMotherState::Hop | SlimeMotherState::Leap => {
+                output.airborne = true;
+                output.dx = self.hop_vx * dt;
+                output.dy = self.hop_vy * dt;
+                output.facing_right = self.hop_vx > 0.0;
+                if self.timer <= 0.0 {
+                    output.landed = true;
+                    output.slam = self.state == SlimeMotherState::Leap;
+                    self.state = SlimeMotherState::Land;
+                    self.timer = LAND_DURATION;
+                }
+            }
+            SlimeMotherState::Land => {
+                output.squashed = true;
+                if self.timer <= 0.0 {
+                    self.state = SlimeMotherState::Rest;
+                    self.timer = if self.phase2 {
+                        REST_DURATION_P2
+                    } else {
+                        REST_DURATION
+                    };
+                }
+            }
+            SlimeMotherState::Split => {
+                output.roaring = true;
+                output.invulnerable = true;
+                if entering_split {
+                    output.split = true;
+                    self.broods_pending = self.broods_pending.saturating_sub(1);
+                }
+                if self.timer <= 0.0 {
+                    if self.broods_pending > 0 {
+                        // Roar again for the next brood
+                        self.timer = SPLIT_DURATION;
+                    } else {
+                        self.state = SlimeMotherState::Rest;
+                        self.timer = REST_DURATION_P2;
+                    }
+                }
+            }
+            SlimeMotherState::Dying => {
+                output.dying = true;
+                self.dying_flash_counter += dt * 15.0;
+                if self.timer <= 0.0 {
+                    output.death_finished = true;
+                }
+            }
+        }
+
+        output
+    }
+
+    /// World-space landing splash. The live window is opened and closed by
+    /// the landing animation's frame events.
+    pub fn attack_hitbox(&self, x: f32, y: f32) -> Option<AABB> {
+        match self.state {
+            SlimeMotherState::Land => Some(LAND_HITBOX.at(x, y)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SlimeMotherOutput {
+    pub dx: f32,
+    pub dy: f32,
+    pub facing_right: bool,
+    pub phase2: bool,
+    pub telegraph: bool,
+    /// Crouching or landing: draw the wide, flat pose
+    pub squashed: bool,
+    /// Mid-jump: draw the tall, narrow pose
+    pub airborne: bool,
+    /// Touched down this tick
+    pub landed: bool,
+    /// The landing was a leap: play the heavy impact
+    pub slam: bool,
+    pub roaring: bool,
+    /// A brood splits off this tick
+    pub split: bool,
+    pub dying: bool,
+    pub invulnerable: bool,
+    pub death_finished: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Run the AI for `secs` with the player off to the right; returns how
+    /// many broods split off.
+    fn broods_over(ai: &mut SlimeMotherAI, secs: f32) -> usize {
+        let mut broods = 0;
+        for _ in 0..(secs / DT) as usize {
+            if ai.update(DT, 0.0, 0.0, 50.0, 0.0, true).split {
+                broods += 1;
+            }
+        }
+        broods
+    }
+
+    #[test]
+    fn test_splits_once_at_each_threshold() {
+        let mut ai = SlimeMotherAI::new();
+        assert!(!ai.check_phase_transition(SPLIT_THRESHOLDS[0] + 1));
+        assert!(!ai.phase2);
+
+        assert!(ai.check_phase_transition(SPLIT_THRESHOLDS[0]));
+        assert!(ai.phase2);
+        assert!(ai.invulnerable());
+        assert_eq!(broods_over(&mut ai, 2.0), 1);
+        assert!(!ai.check_phase_transition(SPLIT_THRESHOLDS[0] - 1));
+
+        assert!(ai.check_phase_transition(SPLIT_THRESHOLDS[1]));
+        assert_eq!(broods_over(&mut ai, 2.0), 1);
+        assert!(!ai.check_phase_transition(1));
+    }
+
+    #[test]
+    fn test_one_big_hit_splits_off_both_broods() {
+        let mut ai = SlimeMotherAI::new();
+        assert!(ai.check_phase_transition(SPLIT_THRESHOLDS[1] - 2));
+        assert_eq!(broods_over(&mut ai, 3.0), 2);
+        assert!(!ai.check_phase_transition(1));
+    }
+
+    #[test]
+    fn test_killing_blow_splits_nothing() {
+        let mut ai = SlimeMotherAI::new();
+        assert!(!ai.check_phase_transition(0));
+        assert_eq!(broods_over(&mut ai, 2.0), 0);
+    }
+}
@@ -0,0 +1,173 @@
+//! Run history: every finished run, one JSON object per line in
+//! `~/.cryptfall/history.jsonl`. Runs are only ever appended, so the file
+//! never has to be rewritten, and a line that fails to parse costs that run
+//! alone.
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::save::data_path;
+use crate::weapons::{all_weapons, WeaponId};
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// What a run ended with, as written to the history file.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RunRecord {
+    /// Day the run ended, in days since 1970-01-01 (UTC).
+    pub day: u32,
+    pub seed: u64,
+    pub weapon: WeaponId,
+    /// Names of the boons taken, in order. An upgrade shows up again.
+    pub boons: Vec<String>,
+    pub floor: u32,
+    pub victory: bool,
+    /// What killed the player; "ABANDONED" for a run quit from the pause
+    /// menu, None for a win.
+    pub cause: Option<String>,
+    pub duration_secs: u32,
+    pub damage_dealt: u32,
+    pub damage_taken: u32,
+    /// Gold picked up during the run, before spending.
+    pub gold: u32,
+}
+
+/// Runs and wins with one weapon.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WeaponRecord {
+    pub weapon: WeaponId,
+    pub runs: u32,
+    pub wins: u32,
+}
+
+/// Every run in the history file, oldest first.
+#[derive(Default)]
+pub struct History {
+    pub runs: Vec<RunRecord>,
+}
+
+impl History {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(data_path(HISTORY_FILE)) {
+            Ok(contents) => Self::parse(&contents),
+            Err(_) => Self::default(),
+        }
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut runs = Vec::new();
+        let mut skipped = 0;
+        for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+            match serde_json::from_str(line) {
+                Ok(run) => runs.push(run),
+                Err(_) => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            eprintln!("Warning: skipped {skipped} unreadable run(s) in the history file");
+        }
+        Self { runs }
+    }
+
+    /// Add a finished run, writing it to the end of the history file.
+    pub fn append(&mut self, run: RunRecord) {
+        if let Err(e) = write_run(&run) {
+            eprintln!("Warning: could not write history file: {e}");
+        }
+        self.runs.push(run);
+    }
+
+    /// Runs and wins for every weapon, in weapon-select order.
+    pub fn weapon_records(&self) -> Vec<WeaponRecord> {
+        all_weapons()
+            .iter()
+            .map(|w| {
+                let played = self.runs.iter().filter(|r| r.weapon == w.id);
+                let wins = played.clone().filter(|r| r.victory).count() as u32;
+                WeaponRecord {
+                    weapon: w.id,
+                    runs: played.count() as u32,
+                    wins,
+                }
+            })
+            .collect()
+    }
+}
+
+fn write_run(run: &RunRecord) -> std::io::Result<()> {
+    let path = data_path(HISTORY_FILE);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(run)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{line}")
+}
+
+impl WeaponRecord {
+    /// Share of runs won, as a whole percentage.
+    pub fn win_rate(&self) -> u32 {
+        (self.wins * 100).checked_div(self.runs).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(weapon: WeaponId, victory: bool) -> RunRecord {
+        RunRecord {
+            day: 20_000,
+            seed: 42,
+            weapon,
+            boons: vec!["Iron Shield".to_string(), "Life Steal".to_string()],
+            floor: if victory { 5 } else { 2 },
+            victory,
+            cause: (!victory).then(|| "SKELETON".to_string()),
+            duration_secs: 600,
+            damage_dealt: 250,
+            damage_taken: 7,
+            gold: 80,
+        }
+    }
+
+    #[test]
+    fn lines_roundtrip() {
+        let runs = [run(WeaponId::Sword, true), run(WeaponId::Bow, false)];
+        let line = |r: &RunRecord| serde_json::to_string(r).unwrap();
+        let history = History::parse(&format!("{}\n{}", line(&runs[0]), line(&runs[1])));
+        assert_eq!(history.runs, runs);
+    }
+
+    #[test]
+    fn bad_lines_are_skipped() {
+        let good = serde_json::to_string(&run(WeaponId::Spear, false)).unwrap();
+        let contents = format!("{good}\n{{\"day\":\n\n{good}\n");
+        assert_eq!(History::parse(&contents).runs.len(), 2);
+    }
+
+    #[test]
+    fn weapon_records_count_runs_and_wins() {
+        let history = History {
+            runs: vec![
+                run(WeaponId::Sword, true),
+                run(WeaponId::Sword, false),
+                run(WeaponId::Sword, false),
+                run(WeaponId::Sword, true),
+                run(WeaponId::Bow, false),
+            ],
+        };
+        let records = history.weapon_records();
+        assert_eq!(records.len(), all_weapons().len());
+        let record = |id| *records.iter().find(|r| r.weapon == id).unwrap();
+        let sword = record(WeaponId::Sword);
+        assert_eq!((sword.runs, sword.wins, sword.win_rate()), (4, 2, 50));
+        let bow = record(WeaponId::Bow);
+        assert_eq!((bow.runs, bow.win_rate()), (1, 0));
+        let staff = record(WeaponId::Staff);
+        assert_eq!((staff.runs, staff.win_rate()), (0, 0));
+    }
+}
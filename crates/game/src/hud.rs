@@ -1,10 +1,16 @@
 use engine::color::Color;
-use engine::FrameBuffer;
+use engine::{FrameBuffer, ProfileSnapshot};
 
+use crate::boon_select::{boon_icon, rarity_color};
+use crate::boons::effects::PlayerBoons;
 use crate::dungeon::floor_gen::FloorLayout;
 use crate::dungeon::room_template::RoomType;
-use crate::sprites::effects::{HEART_EMPTY, HEART_FULL};
-use crate::sprites::font::render_digit;
+use crate::sprites::effects::{
+    HEART_EMPTY, HEART_FULL, STATUS_BURN, STATUS_FREEZE, STATUS_POISON, STATUS_SLOW, STATUS_STUN,
+    STATUS_WEAKNESS,
+};
+use crate::sprites::font::{render_digit, render_text, text_width};
+use crate::status::{StatusEffects, StatusKind};
 
 pub struct DamageNumber {
     pub value: i32,
@@ -92,6 +98,110 @@ pub fn render_hearts(fb: &mut FrameBuffer, hp: i32, max_hp: i32, sx: i32, sy: i3
     }
 }
 
+fn status_icon(kind: StatusKind) -> &'static engine::SpriteData {
+    match kind {
+        StatusKind::Burn => &STATUS_BURN,
+        StatusKind::Poison => &STATUS_POISON,
+        StatusKind::Slow => &STATUS_SLOW,
+        StatusKind::Freeze => &STATUS_FREEZE,
+        StatusKind::Stun => &STATUS_STUN,
+        StatusKind::Weakness => &STATUS_WEAKNESS,
+    }
+}
+
+/// Render status effect icons left to right from a screen position, each
+/// followed by its stack count when stacked. Icons blink as they wear off.
+pub fn render_status_icons(fb: &mut FrameBuffer, status: &StatusEffects, sx: i32, sy: i32) {
+    let mut x = sx;
+    for effect in status.iter() {
+        let blink = effect.remaining < 1.0 && ((effect.remaining * 8.0) as i32) % 2 == 0;
+        if !blink {
+            fb.blit_sprite(status_icon(effect.kind), x, sy);
+        }
+        x += 6;
+        if effect.stacks > 1 {
+            render_digit(fb, effect.stacks as u8, x, sy, effect.kind.tint());
+            x += 4;
+        }
+        x += 1;
+    }
+}
+
+/// Height of one row of boon icons: 8px icon, a gap and the rarity line.
+pub const BOON_ROW_H: i32 = 11;
+
+/// Width a boon takes in the boon strip: its icon, then its level once
+/// it's above 1.
+fn boon_slot_width(level: u32) -> i32 {
+    if level > 1 {
+        10 + text_width(&level.to_string()) + 1
+    } else {
+        10
+    }
+}
+
+/// Width of the boon strip's widest row when wrapped at `max_w`.
+pub fn boons_width(boons: &PlayerBoons, max_w: i32) -> i32 {
+    let total: i32 = boons
+        .active
+        .iter()
+        .map(|&id| boon_slot_width(boons.level(id)))
+        .sum();
+    (total - 2).clamp(0, max_w)
+}
+
+/// Height of the boon strip when wrapped at `max_w`, matching what
+/// `render_boons` draws.
+pub fn boons_height(boons: &PlayerBoons, max_w: i32) -> i32 {
+    let mut x = 0;
+    let mut rows = 0;
+    for &id in &boons.active {
+        let w = boon_slot_width(boons.level(id));
+        if rows == 0 || (x > 0 && x + w - 2 > max_w) {
+            x = 0;
+            rows += 1;
+        }
+        x += w;
+    }
+    rows * BOON_ROW_H
+}
+
+/// Render owned boons left to right from a screen position, wrapping rows at
+/// `max_w`. Each icon is underlined in its current rarity color and followed
+/// by its level once upgraded. Returns the height drawn.
+pub fn render_boons(
+    fb: &mut FrameBuffer,
+    boons: &PlayerBoons,
+    sx: i32,
+    sy: i32,
+    max_w: i32,
+) -> i32 {
+    let mut x = sx;
+    let mut y = sy;
+    for &id in &boons.active {
+        let level = boons.level(id);
+        let w = boon_slot_width(level);
+        if x > sx && x + w - 2 > sx + max_w {
+            x = sx;
+            y += BOON_ROW_H;
+        }
+        fb.blit_sprite(boon_icon(id), x, y);
+        let rc = rarity_color(boons.rarity(id));
+        for px in x..x + 8 {
+            fb.set_pixel_safe(px, y + 9, rc);
+        }
+        if level > 1 {
+            render_text(fb, &level.to_string(), x + 9, y + 3, [220, 220, 220]);
+        }
+        x += w;
+    }
+    if boons.active.is_empty() {
+        0
+    } else {
+        y - sy + BOON_ROW_H
+    }
+}
+
 /// Render a boss health bar centered at the top of the screen.
 pub fn render_boss_bar(fb: &mut FrameBuffer, name: &str, hp: i32, max_hp: i32) {
     let fw = fb.width() as i32;
@@ -141,16 +251,49 @@ pub fn render_boss_bar(fb: &mut FrameBuffer, name: &str, hp: i32, max_hp: i32) {
     }
 }
 
+/// Seconds an achievement toast takes to slide in or out.
+const TOAST_SLIDE: f32 = 0.25;
+const TOAST_H: i32 = 16;
+/// Top of the toast once it's fully in, clear of the boss bar.
+const TOAST_Y: i32 = 18;
+
+/// Render an achievement unlock banner at the top of the screen. It slides
+/// down from above when it appears and back up before `remaining` runs out.
+pub fn render_achievement_toast(fb: &mut FrameBuffer, name: &str, remaining: f32, total: f32) {
+    let fw = fb.width() as i32;
+    let header = "ACHIEVEMENT";
+    let name = name.to_uppercase();
+    let w = text_width(header).max(text_width(&name)) + 8;
+    let x = (fw - w) / 2;
+
+    let shown = (total - remaining).min(remaining);
+    let slide = (shown / TOAST_SLIDE).clamp(0.0, 1.0);
+    let y = -TOAST_H + ((TOAST_Y + TOAST_H) as f32 * slide) as i32;
+
+    let border: Color = [255, 200, 50];
+    for py in y..y + TOAST_H {
+        for px in x..x + w {
+            let edge = py == y || py == y + TOAST_H - 1 || px == x || px == x + w - 1;
+            fb.set_pixel_safe(px, py, if edge { border } else { [20, 18, 30] });
+        }
+    }
+    render_text(fb, header, x + (w - text_width(header)) / 2, y + 3, border);
+    let name_x = x + (w - text_width(&name)) / 2;
+    render_text(fb, &name, name_x, y + 9, [255, 255, 255]);
+}
+
 /// Render the floor minimap in the top-right corner.
 ///
 /// Each room is drawn as a small colored rectangle. Connections are shown as
 /// single-pixel lines between room centers. Only discovered rooms are shown;
-/// rooms adjacent to discovered rooms show as dim outlines.
+/// rooms adjacent to discovered rooms show as dim outlines. With
+/// `reveal_treasure` (TreasureSense), treasure rooms are always shown.
 pub fn render_minimap(
     fb: &mut FrameBuffer,
     floor: &FloorLayout,
     current_room: usize,
     visible: bool,
+    reveal_treasure: bool,
 ) {
     if !visible {
         return;
@@ -196,6 +339,16 @@ pub fn render_minimap(
         }
     }
 
+    // TreasureSense: treasure rooms show up before they're discovered
+    if reveal_treasure {
+        for (i, room) in floor.rooms.iter().enumerate() {
+            if room.room_type == RoomType::Treasure {
+                room_visible[i] = true;
+                room_dim[i] = false;
+            }
+        }
+    }
+
     // Compute bounding box of visible rooms
     for (i, room) in floor.rooms.iter().enumerate() {
         if room_visible[i] {
@@ -305,6 +458,65 @@ pub fn render_minimap(
     }
 }
 
+/// Render the profiler overlay in the bottom-left corner: a rolling
+/// frame-time graph with the 30Hz budget marked, then per-scope timings.
+pub fn render_profiler(fb: &mut FrameBuffer, snap: &ProfileSnapshot, message: Option<&str>) {
+    use crate::sprites::font::render_text;
+
+    const GRAPH_H: i32 = 16;
+    const GRAPH_MAX_US: u64 = 50_000;
+    const BUDGET_US: u64 = 33_333;
+    const LINE_H: i32 = 6;
+
+    let fw = fb.width() as i32;
+    let fh = fb.height() as i32;
+    let graph_w = (engine::profiler::HISTORY_LEN as i32).min(fw - 4);
+    let rows = snap.scopes.len() as i32 + message.is_some() as i32;
+    let panel_h = GRAPH_H + 3 + rows * LINE_H;
+    let x0 = 2;
+    let y0 = fh - panel_h - 2;
+
+    // Panel background
+    for y in (y0 - 1)..(y0 + panel_h + 1) {
+        for x in (x0 - 1)..(x0 + graph_w + 1) {
+            fb.set_pixel_safe(x, y, [10, 10, 15]);
+        }
+    }
+
+    // Frame-time graph, newest sample on the right
+    let graph_bottom = y0 + GRAPH_H;
+    let skip = snap.frame_times.len().saturating_sub(graph_w as usize);
+    let start_x = x0 + graph_w - (snap.frame_times.len() - skip) as i32;
+    for (i, &us) in snap.frame_times[skip..].iter().enumerate() {
+        let h = ((us.min(GRAPH_MAX_US) * GRAPH_H as u64) / GRAPH_MAX_US).max(1) as i32;
+        let c: Color = if us > BUDGET_US {
+            [255, 60, 60]
+        } else if us > BUDGET_US / 2 {
+            [255, 200, 50]
+        } else {
+            [60, 220, 60]
+        };
+        for y in (graph_bottom - h)..graph_bottom {
+            fb.set_pixel_safe(start_x + i as i32, y, c);
+        }
+    }
+    let budget_y = graph_bottom - (BUDGET_US * GRAPH_H as u64 / GRAPH_MAX_US) as i32;
+    for x in (x0..x0 + graph_w).step_by(2) {
+        fb.set_pixel_safe(x, budget_y, [120, 120, 140]);
+    }
+
+    // Scope timings in microseconds
+    let mut ty = graph_bottom + 2;
+    for &(name, us) in &snap.scopes {
+        let label = format!("{} {}", name.replace('_', " "), us);
+        render_text(fb, &label, x0, ty, [180, 180, 200]);
+        ty += LINE_H;
+    }
+    if let Some(msg) = message {
+        render_text(fb, msg, x0, ty, [255, 220, 50]);
+    }
+}
+
 /// Return the minimap color for a room type.
 fn room_color(room_type: RoomType, cleared: bool) -> Color {
     match room_type {
@@ -1,31 +1,44 @@
+mod achievements;
 mod boon_select;
 mod boons;
+mod chest;
 mod combat;
+mod daily;
+mod defs;
 mod dungeon;
 mod enemies;
+mod history;
 mod hud;
+mod pact;
+mod pact_select;
 mod pickup;
 mod player;
 mod projectile;
+mod puddles;
 mod run_state;
+mod run_summary;
 mod save;
+mod seed;
+mod shop;
 mod sprites;
+mod status;
 mod tiles;
+mod traps;
 mod tuning;
 mod weapon_select;
 mod weapons;
 
 
+use boons::boon_defs;
 use boons::effects::PlayerBoons;
-use boons::selection::select_boon_options;
-use boons::BOON_DEFS;
+use boons::selection::{offer_upgrade, select_boon_options, select_rare_boon_options};
 use dungeon::encounters::{
     self, EncounterDifficulty, WaveTracker,
 };
 use dungeon::world::{self, DungeonWorld, TransitionEvent};
 use engine::{
-    color, render_tilemap, BurstConfig, Camera, Color, FrameBuffer, FrameInfo, Game, GameKey,
-    InputState, ParticleSystem, TileMap,
+    color, render_tilemap, AnimEvent, BurstConfig, Camera, Color, EmitterId, FrameBuffer,
+    FrameInfo, Game, GameKey, InputState, ParticleSystem, TileMap,
 };
 use pickup::Pickup;
 use player::Player;
@@ -37,9 +50,14 @@ use weapons::WeaponId;
 
 const DASH_TRAIL_COLORS: &[Color] = &[[100, 160, 255], [150, 200, 255], [200, 230, 255]];
 
+const DASH_TRAIL_GRADIENT: &[Color] = &[[200, 230, 255], [100, 160, 255], [20, 40, 90]];
+
+/// Dash trail particles per second while dashing.
+const DASH_TRAIL_RATE: f32 = 75.0;
+
 const DASH_TRAIL_CONFIG: BurstConfig = BurstConfig {
-    count_min: 2,
-    count_max: 3,
+    count_min: 1,
+    count_max: 1,
     speed_min: 5.0,
     speed_max: 15.0,
     lifetime_min: 0.1,
@@ -49,6 +67,10 @@ const DASH_TRAIL_CONFIG: BurstConfig = BurstConfig {
     friction: 0.8,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: DASH_TRAIL_GRADIENT,
+    size_start: 2.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const DUST_PUFF_COLORS: &[Color] = &[[120, 100, 70], [140, 120, 90], [100, 80, 60]];
@@ -65,6 +87,28 @@ const DUST_PUFF_CONFIG: BurstConfig = BurstConfig {
     friction: 0.85,
     angle_spread: std::f32::consts::PI,
     base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const FOOTSTEP_DUST_CONFIG: BurstConfig = BurstConfig {
+    count_min: 1,
+    count_max: 2,
+    speed_min: 4.0,
+    speed_max: 10.0,
+    lifetime_min: 0.15,
+    lifetime_max: 0.25,
+    colors: DUST_PUFF_COLORS,
+    gravity: -8.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::PI,
+    base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const PICKUP_COLLECT_COLORS: &[Color] = &[[255, 100, 100], [255, 200, 200], [255, 150, 150]];
@@ -81,10 +125,63 @@ const PICKUP_COLLECT_CONFIG: BurstConfig = BurstConfig {
     friction: 0.85,
     angle_spread: std::f32::consts::TAU,
     base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const CHEST_SPARKLE_COLORS: &[Color] = &[[255, 200, 50], [255, 240, 150], [255, 255, 255]];
+
+const CHEST_SPARKLE_CONFIG: BurstConfig = BurstConfig {
+    count_min: 10,
+    count_max: 14,
+    speed_min: 20.0,
+    speed_max: 50.0,
+    lifetime_min: 0.3,
+    lifetime_max: 0.6,
+    colors: CHEST_SPARKLE_COLORS,
+    gravity: 40.0,
+    friction: 0.9,
+    angle_spread: std::f32::consts::PI,
+    base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: Some(0.4),
+};
+
+const PIT_FALL_COLORS: &[Color] = &[[60, 55, 50], [40, 35, 45], [25, 20, 30]];
+
+const PIT_FALL_CONFIG: BurstConfig = BurstConfig {
+    count_min: 8,
+    count_max: 12,
+    speed_min: 10.0,
+    speed_max: 35.0,
+    lifetime_min: 0.2,
+    lifetime_max: 0.4,
+    colors: PIT_FALL_COLORS,
+    gravity: 30.0,
+    friction: 0.85,
+    angle_spread: std::f32::consts::TAU,
+    base_angle: 0.0,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 const FRAC_1_SQRT_2: f32 = std::f32::consts::FRAC_1_SQRT_2;
 
+/// Seconds the "BLESSED" banner stays up after a chest raises a boon.
+const BLESSING_BANNER_TIME: f32 = 2.0;
+
+/// How long an achievement toast stays up.
+const ACHIEVEMENT_TOAST_TIME: f32 = 3.0;
+
+/// Boss souls, wherever they're counted.
+const SOUL_COLOR: Color = [170, 110, 255];
+
 // --- Title screen ember particles ---
 
 const EMBER_COLORS: &[Color] = &[
@@ -107,6 +204,10 @@ const EMBER_CONFIG: BurstConfig = BurstConfig {
     friction: 0.98,
     angle_spread: std::f32::consts::PI * 0.5,
     base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
 };
 
 // --- Demo mode auto-play ---
@@ -198,16 +299,35 @@ enum RoomState {
 enum GameScreen {
     Title,
     WeaponSelect,
+    PactSelect,
     Playing,
     BoonSelect,
     UpgradeShop,
+    Leaderboard,
+    Achievements,
+    History,
     RunEnd,
 }
 
 // --- Title screen state ---
 
+const TITLE_MENU: [&str; 7] = [
+    "NEW RUN",
+    "DAILY",
+    "LEADERBOARD",
+    "UPGRADES",
+    "ACHIEVEMENTS",
+    "HISTORY",
+    "QUIT",
+];
+
+/// Runs listed at once on the history screen.
+const HISTORY_ROWS: usize = 5;
+/// Boon lines shown for the highlighted run before the rest are elided.
+const HISTORY_BOON_LINES: usize = 2;
+
 struct TitleState {
-    selected: usize, // 0=NEW RUN, 1=UPGRADES, 2=QUIT
+    selected: usize, // index into TITLE_MENU
     particles: ParticleSystem,
     ember_timer: f32,
     ember_seed: u32,
@@ -241,12 +361,6 @@ impl UpgradeShopState {
     }
 }
 
-// --- Run end state ---
-
-struct RunEndState {
-    victory: bool,
-}
-
 // --- Gameplay state (only exists during Playing screen) ---
 
 struct PlayingState {
@@ -256,6 +370,7 @@ struct PlayingState {
     tilemap: TileMap,
     camera: Camera,
     particles: ParticleSystem,
+    dash_trail: EmitterId,
     damage_numbers: Vec<hud::DamageNumber>,
     dungeon: DungeonWorld,
     room_state: RoomState,
@@ -279,23 +394,44 @@ struct PlayingState {
     boons: PlayerBoons,
     combat_rooms_cleared: u32,
     boon_seed: u64,
+    /// Seed the run started with. Floors, encounters, drops, boon offers
+    /// and shop stock all derive from it.
+    run_seed: u64,
+    /// Stock of this floor's shop, created on first visit.
+    shop: Option<shop::Shop>,
+    /// Chests in this floor's treasure room, created on first visit.
+    chests: Vec<chest::Chest>,
+    /// Boon rerolls left this run.
+    rerolls: u32,
+    /// Dash Strike damage for the dash in progress (0 = none).
+    dash_strike_damage: i32,
+    /// Traps in the current room.
+    traps: Vec<traps::Trap>,
+    /// Slime goo on the floor of the current room.
+    puddles: Vec<puddles::Puddle>,
+    /// Boon a chest blessing just raised, and how long its banner stays up.
+    blessing_banner: Option<(boons::BoonId, f32)>,
+    weapon_id: WeaponId,
+    /// Pact ranks taken for this run.
+    pact: pact::Pact,
+    /// The player has taken a hit since arriving on this floor.
+    hit_this_floor: bool,
+    /// Achievement events since the last frame, recorded by the game.
+    achievement_events: Vec<achievements::Event>,
 }
 
 impl PlayingState {
-    fn new(weapon_id: WeaponId, save_data: &save::SaveData) -> Self {
-        let seed = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(42);
-
+    fn new(weapon_id: WeaponId, save_data: &save::SaveData, pact: pact::Pact, seed: u64) -> Self {
         let dungeon = DungeonWorld::new(1, seed);
         let tilemap = dungeon.build_tilemap();
+        let room_traps = traps::spawn_traps(&dungeon.current_room().template);
         let (px, py) = dungeon.player_spawn_position(None);
         let mut player = Player::new(px, py);
         player.equip_weapon(weapon_id);
 
         // Apply permanent upgrades
-        let (bonus_hp, _bonus_dmg, _dash_charges, _rerolls) = save_data.upgrades.stat_bonuses();
+        let (bonus_hp, _bonus_dmg, _dash_charges, rerolls) = save_data.upgrades.stat_bonuses();
+        let rerolls = if pact.allows_rerolls() { rerolls } else { 0 };
         player.max_hp += bonus_hp;
         player.hp = player.max_hp;
 
@@ -307,13 +443,21 @@ impl PlayingState {
         camera.snap();
         camera.clamp_to_bounds(tilemap.pixel_width() as f32, tilemap.pixel_height() as f32);
 
+        let mut particles = ParticleSystem::new();
+        let dash_trail = particles.add_emitter(cx, cy, &DASH_TRAIL_CONFIG, DASH_TRAIL_RATE);
+        particles.set_emitter_active(dash_trail, false);
+
+        let mut boons = PlayerBoons::new();
+        boons.seed_rng(seed);
+
         Self {
             player,
             enemies: Vec::new(),
             projectiles: projectile::ProjectileSystem::new(),
             tilemap,
             camera,
-            particles: ParticleSystem::new(),
+            particles,
+            dash_trail,
             damage_numbers: Vec::new(),
             dungeon,
             room_state: RoomState::Peaceful,
@@ -334,9 +478,50 @@ impl PlayingState {
             sealed_flash_timer: 0.0,
             paused: false,
             minimap_visible: true,
-            boons: PlayerBoons::new(),
+            boons,
             combat_rooms_cleared: 0,
             boon_seed: seed.wrapping_mul(7919),
+            run_seed: seed,
+            shop: None,
+            chests: Vec::new(),
+            rerolls,
+            dash_strike_damage: 0,
+            traps: room_traps,
+            puddles: Vec::new(),
+            blessing_banner: None,
+            weapon_id,
+            pact,
+            hit_this_floor: false,
+            achievement_events: Vec::new(),
+        }
+    }
+
+    /// Add a boon and re-apply its stat and max HP effects.
+    fn add_boon(&mut self, boon_id: boons::BoonId, save_data: &save::SaveData) {
+        self.boons.add(boon_id);
+        self.apply_boon_stats(save_data);
+    }
+
+    /// Copy boon stat modifiers onto the player after the boon set changes.
+    fn apply_boon_stats(&mut self, save_data: &save::SaveData) {
+        self.player.move_speed_mult = self.boons.move_speed_mult;
+        self.player.attack_speed_mult = self.boons.attack_speed_mult;
+        self.player.dash_distance_mult = self.boons.dash_distance_mult;
+
+        // Apply max HP bonus from boons (e.g. ToughSkin)
+        let base_max_hp = 5 + save_data.upgrades.stat_bonuses().0;
+        let hp_bonus = self.boons.effective_max_hp_bonus(base_max_hp);
+        let new_max = base_max_hp + hp_bonus;
+        let old_max = self.player.max_hp;
+        self.player.max_hp = new_max;
+        // If max HP increased, give the bonus HP
+        if new_max > old_max {
+            self.player.hp += new_max - old_max;
+        }
+        // If deaths bargain, clamp HP
+        if self.boons.has_deaths_bargain {
+            self.player.max_hp = 1;
+            self.player.hp = self.player.hp.min(1);
         }
     }
 }
@@ -350,53 +535,165 @@ struct CryptfallGame {
     // Screen-specific state
     title: TitleState,
     weapon_select: weapon_select::WeaponSelectScreen,
+    pact_select: Option<pact_select::PactSelectScreen>,
+    /// Pact taken last run; the pact screen opens with it.
+    last_pact: pact::Pact,
+    leaderboard: daily::Leaderboard,
+    /// Day of the official daily attempt in progress, if any.
+    daily_run: Option<u32>,
+    /// Seed given with `--seed`; pre-fills the weapon screen's seed field.
+    cli_seed: Option<u64>,
+    /// Row highlighted on the achievements screen.
+    achievements_selected: usize,
+    /// Every finished run, oldest first.
+    history: history::History,
+    /// Run highlighted on the history screen, counting back from the newest.
+    history_selected: usize,
+    /// The history screen shows per-weapon win rates instead of runs.
+    history_by_weapon: bool,
+    /// Achievements unlocked but not yet announced; the first one is on
+    /// screen with the time it has left.
+    achievement_toasts: Vec<(&'static achievements::AchievementDef, f32)>,
     boon_select: Option<boon_select::BoonSelectScreen>,
     upgrade_shop: UpgradeShopState,
-    run_end: RunEndState,
+    run_end: run_summary::RunSummary,
     // Gameplay state (only valid during Playing/BoonSelect)
     playing: Option<PlayingState>,
+    /// Profiler status message and remaining display time
+    profiler_toast: Option<(&'static str, f32)>,
 }
 
 impl CryptfallGame {
-    fn new() -> Self {
+    fn new(cli_seed: Option<u64>) -> Self {
         let save_data = save::SaveData::load();
+        let locked = save_data.locked_weapons();
         Self {
             screen: GameScreen::Title,
             save_data,
             run_state: run_state::RunState::new(),
             title: TitleState::new(),
-            weapon_select: weapon_select::WeaponSelectScreen::new(),
+            weapon_select: weapon_select::WeaponSelectScreen::new(cli_seed, locked),
+            pact_select: None,
+            last_pact: pact::Pact::default(),
+            leaderboard: daily::Leaderboard::load(),
+            daily_run: None,
+            cli_seed,
+            achievements_selected: 0,
+            history: history::History::load(),
+            history_selected: 0,
+            history_by_weapon: false,
+            achievement_toasts: Vec::new(),
             boon_select: None,
             upgrade_shop: UpgradeShopState::new(),
-            run_end: RunEndState { victory: false },
+            run_end: run_summary::RunSummary::default(),
             playing: None,
+            profiler_toast: None,
         }
     }
 
-    fn start_new_run(&mut self, weapon_id: WeaponId) {
+    fn start_new_run(&mut self, weapon_id: WeaponId, pact: pact::Pact, seed: u64) {
         self.run_state = run_state::RunState::new();
         self.run_state.floor_reached = 1;
-        self.playing = Some(PlayingState::new(weapon_id, &self.save_data));
+        self.playing = Some(PlayingState::new(weapon_id, &self.save_data, pact, seed));
         self.screen = GameScreen::Playing;
     }
 
     fn end_run(&mut self, victory: bool) {
+        if let Some(ps) = self.playing.as_mut().filter(|_| victory) {
+            ps.achievement_events
+                .push(achievements::Event::Victory(ps.weapon_id));
+        }
+        self.record_achievements();
         // Commit run stats to save data
         self.save_data.total_runs += 1;
         self.save_data.total_kills += self.run_state.kills;
         if !victory {
             self.save_data.total_deaths += 1;
         }
-        self.save_data.total_gold += self.run_state.gold_earned;
-        if self.run_state.floor_reached > self.save_data.best_floor {
-            self.save_data.best_floor = self.run_state.floor_reached;
-        }
+        let seed = self.playing.as_ref().map_or(0, |ps| ps.run_seed);
+        let (gold_banked, heat, heat_record) = match self.playing {
+            Some(ref ps) => {
+                let heat = ps.pact.heat();
+                let record = victory && self.save_data.record_heat(ps.weapon_id, heat);
+                (ps.pact.scale_gold(self.run_state.gold()), heat, record)
+            }
+            None => (self.run_state.gold(), 0, false),
+        };
+        self.save_data.total_gold += gold_banked;
+        self.save_data.souls += self.run_state.souls;
+        let records = self.save_data.record_bests(&self.run_state, victory);
+        let unlocked = match self.playing {
+            Some(ref ps) => self
+                .save_data
+                .record_floor(ps.weapon_id, self.run_state.floor_reached),
+            None => Vec::new(),
+        };
         self.save_data.save();
 
-        self.run_end = RunEndState { victory };
+        if let Some(ref ps) = self.playing {
+            let rs = &self.run_state;
+            let cause = rs.death_cause.map_or("ABANDONED", DeathCause::label);
+            self.history.append(history::RunRecord {
+                day: daily::today(),
+                seed,
+                weapon: ps.weapon_id,
+                boons: rs
+                    .boons_taken
+                    .iter()
+                    .map(|&id| boons::boon_def(id).name.to_string())
+                    .collect(),
+                floor: rs.floor_reached,
+                victory,
+                cause: (!victory).then(|| cause.to_string()),
+                duration_secs: rs.elapsed_secs as u32,
+                damage_dealt: rs.damage_dealt,
+                damage_taken: rs.damage_taken,
+                gold: rs.gold_earned,
+            });
+        }
+
+        let daily_score = self.daily_run.take().map(|day| {
+            let rs = &self.run_state;
+            let secs = rs.elapsed_secs as u32;
+            let score = daily::score(rs.floor_reached, rs.kills, secs, victory);
+            self.leaderboard.finish(daily::DailyEntry {
+                day,
+                score,
+                time_secs: secs,
+                floor: rs.floor_reached,
+                kills: rs.kills,
+                victory,
+            });
+            self.leaderboard.save();
+            score
+        });
+
+        self.run_end = run_summary::RunSummary {
+            victory,
+            gold_banked,
+            heat,
+            heat_record,
+            daily_score,
+            seed,
+            unlocked,
+            records,
+        };
         self.screen = GameScreen::RunEnd;
     }
 
+    /// Count the run's pending achievement events and queue a toast for
+    /// each unlock.
+    fn record_achievements(&mut self) {
+        let Some(ps) = self.playing.as_mut() else {
+            return;
+        };
+        for event in ps.achievement_events.drain(..) {
+            for def in self.save_data.achievements.record(event) {
+                self.achievement_toasts.push((def, ACHIEVEMENT_TOAST_TIME));
+            }
+        }
+    }
+
     fn return_to_title(&mut self) {
         self.playing = None;
         self.title = TitleState::new();
@@ -417,17 +714,65 @@ impl Game for CryptfallGame {
     fn update(&mut self, input: &InputState, dt: f64) -> bool {
         let dt_f32 = dt as f32;
 
+        self.update_profiler_keys(input, dt_f32);
+        if let Some((_, timer)) = self.achievement_toasts.first_mut() {
+            *timer -= dt_f32;
+            if *timer <= 0.0 {
+                self.achievement_toasts.remove(0);
+            }
+        }
+
         match self.screen {
             GameScreen::Title => self.update_title(input, dt_f32),
             GameScreen::WeaponSelect => self.update_weapon_select(input, dt_f32),
+            GameScreen::PactSelect => self.update_pact_select(input),
             GameScreen::BoonSelect => self.update_boon_select(input, dt_f32),
             GameScreen::UpgradeShop => self.update_upgrade_shop(input, dt_f32),
+            GameScreen::Leaderboard => self.update_leaderboard(input),
+            GameScreen::Achievements => self.update_achievements(input),
+            GameScreen::History => self.update_history(input),
             GameScreen::RunEnd => self.update_run_end(input, dt_f32),
             GameScreen::Playing => self.update_playing(input, dt, dt_f32),
         }
     }
 
     fn render(&mut self, fb: &mut FrameBuffer, info: &FrameInfo, alpha: f32) {
+        self.render_screen(fb, info, alpha);
+
+        if let Some(&(def, timer)) = self.achievement_toasts.first() {
+            hud::render_achievement_toast(fb, def.name, timer, ACHIEVEMENT_TOAST_TIME);
+        }
+
+        if engine::profiler::is_enabled() {
+            let message = self.profiler_toast.map(|(msg, _)| msg);
+            hud::render_profiler(fb, &engine::profiler::snapshot(), message);
+        }
+    }
+}
+
+impl CryptfallGame {
+    /// F3 toggles the profiler overlay; F4 dumps the captured trace.
+    fn update_profiler_keys(&mut self, input: &InputState, dt: f32) {
+        if input.is_pressed(GameKey::Profiler) {
+            engine::profiler::set_enabled(!engine::profiler::is_enabled());
+            self.profiler_toast = None;
+        }
+        if input.is_pressed(GameKey::TraceDump) && engine::profiler::is_enabled() {
+            let msg = match engine::profiler::write_chrome_trace(&save::data_path("trace.json")) {
+                Ok(()) => "TRACE SAVED",
+                Err(_) => "TRACE FAILED",
+            };
+            self.profiler_toast = Some((msg, 2.0));
+        }
+        if let Some((_, ref mut timer)) = self.profiler_toast {
+            *timer -= dt;
+            if *timer <= 0.0 {
+                self.profiler_toast = None;
+            }
+        }
+    }
+
+    fn render_screen(&mut self, fb: &mut FrameBuffer, info: &FrameInfo, alpha: f32) {
         match self.screen {
             GameScreen::Title => {
                 self.render_title(fb);
@@ -437,6 +782,12 @@ impl Game for CryptfallGame {
                 self.weapon_select.render(fb);
                 return;
             }
+            GameScreen::PactSelect => {
+                if let Some(ref screen) = self.pact_select {
+                    screen.render(fb);
+                }
+                return;
+            }
             GameScreen::BoonSelect => {
                 // Render the playing screen underneath, then overlay boon select
                 self.render_playing(fb, info, alpha);
@@ -449,8 +800,21 @@ impl Game for CryptfallGame {
                 self.render_upgrade_shop(fb);
                 return;
             }
+            GameScreen::Leaderboard => {
+                self.render_leaderboard(fb);
+                return;
+            }
+            GameScreen::Achievements => {
+                self.render_achievements(fb);
+                return;
+            }
+            GameScreen::History => {
+                self.render_history(fb);
+                return;
+            }
             GameScreen::RunEnd => {
-                self.render_run_end(fb);
+                let boons = self.playing.as_ref().map(|ps| &ps.boons);
+                self.run_end.render(fb, &self.run_state, boons);
                 return;
             }
             GameScreen::Playing => {}
@@ -481,7 +845,7 @@ impl CryptfallGame {
         if input.is_pressed(GameKey::Up) && self.title.selected > 0 {
             self.title.selected -= 1;
         }
-        if input.is_pressed(GameKey::Down) && self.title.selected < 2 {
+        if input.is_pressed(GameKey::Down) && self.title.selected + 1 < TITLE_MENU.len() {
             self.title.selected += 1;
         }
 
@@ -489,15 +853,36 @@ impl CryptfallGame {
             match self.title.selected {
                 0 => {
                     // NEW RUN -> weapon select
-                    self.weapon_select = weapon_select::WeaponSelectScreen::new();
+                    let locked = self.save_data.locked_weapons();
+                    self.weapon_select =
+                        weapon_select::WeaponSelectScreen::new(self.cli_seed, locked);
                     self.screen = GameScreen::WeaponSelect;
                 }
                 1 => {
+                    // DAILY -> briefing; only the day's first run counts
+                    let day = daily::today();
+                    let official = !self.leaderboard.attempted(day);
+                    self.pact_select = Some(pact_select::PactSelectScreen::daily(day, official));
+                    self.screen = GameScreen::PactSelect;
+                }
+                2 => {
+                    self.screen = GameScreen::Leaderboard;
+                }
+                3 => {
                     // UPGRADES
                     self.upgrade_shop = UpgradeShopState::new();
                     self.screen = GameScreen::UpgradeShop;
                 }
-                2 => {
+                4 => {
+                    self.achievements_selected = 0;
+                    self.screen = GameScreen::Achievements;
+                }
+                5 => {
+                    self.history_selected = 0;
+                    self.history_by_weapon = false;
+                    self.screen = GameScreen::History;
+                }
+                6 => {
                     // QUIT
                     return false;
                 }
@@ -509,13 +894,106 @@ impl CryptfallGame {
     }
 
     fn update_weapon_select(&mut self, input: &InputState, dt: f32) -> bool {
-        if input.is_pressed(GameKey::Pause) {
+        if input.is_pressed(GameKey::Pause) && !self.weapon_select.is_editing_seed() {
             self.screen = GameScreen::Title;
             return true;
         }
 
         if let Some(weapon_id) = self.weapon_select.update(input, dt) {
-            self.start_new_run(weapon_id);
+            let seed = self.weapon_select.seed();
+            if self.save_data.upgrades_maxed() {
+                // Pacts open up once there's nothing left to buy
+                let best = self.save_data.heat_record(weapon_id);
+                let pact = self.last_pact.clone();
+                self.pact_select = Some(pact_select::PactSelectScreen::new(
+                    weapon_id, pact, best, seed,
+                ));
+                self.screen = GameScreen::PactSelect;
+            } else {
+                let seed = seed.unwrap_or_else(seed::clock_seed);
+                self.start_new_run(weapon_id, pact::Pact::default(), seed);
+            }
+        }
+        true
+    }
+
+    fn update_pact_select(&mut self, input: &InputState) -> bool {
+        let Some(screen) = self.pact_select.as_mut() else {
+            self.screen = GameScreen::WeaponSelect;
+            return true;
+        };
+        if input.is_pressed(GameKey::Pause) {
+            if screen.daily.is_some() {
+                self.pact_select = None;
+                self.screen = GameScreen::Title;
+                return true;
+            }
+            self.last_pact = screen.pact.clone();
+            let locked = self.save_data.locked_weapons();
+            self.weapon_select = weapon_select::WeaponSelectScreen::new(screen.seed, locked);
+            self.pact_select = None;
+            self.screen = GameScreen::WeaponSelect;
+            return true;
+        }
+
+        if screen.update(input) {
+            let (weapon_id, pact) = (screen.weapon, screen.pact.clone());
+            let seed = screen.seed.unwrap_or_else(seed::clock_seed);
+            match screen.daily {
+                Some((day, official)) => {
+                    self.daily_run = official.then_some(day);
+                    if official {
+                        self.leaderboard.record(daily::DailyEntry::started(day));
+                        self.leaderboard.save();
+                    }
+                }
+                None => self.last_pact = pact.clone(),
+            }
+            self.pact_select = None;
+            self.start_new_run(weapon_id, pact, seed);
+        }
+        true
+    }
+
+    fn update_leaderboard(&mut self, input: &InputState) -> bool {
+        if input.is_pressed(GameKey::Pause)
+            || input.is_pressed(GameKey::Quit)
+            || input.is_pressed(GameKey::Attack)
+        {
+            self.screen = GameScreen::Title;
+        }
+        true
+    }
+
+    fn update_achievements(&mut self, input: &InputState) -> bool {
+        if input.is_pressed(GameKey::Pause) || input.is_pressed(GameKey::Quit) {
+            self.screen = GameScreen::Title;
+            return true;
+        }
+        if input.is_pressed(GameKey::Up) && self.achievements_selected > 0 {
+            self.achievements_selected -= 1;
+        }
+        if input.is_pressed(GameKey::Down)
+            && self.achievements_selected + 1 < achievements::ACHIEVEMENTS.len()
+        {
+            self.achievements_selected += 1;
+        }
+        true
+    }
+
+    fn update_history(&mut self, input: &InputState) -> bool {
+        if input.is_pressed(GameKey::Pause) || input.is_pressed(GameKey::Quit) {
+            self.screen = GameScreen::Title;
+            return true;
+        }
+        if input.is_pressed(GameKey::Left) || input.is_pressed(GameKey::Right) {
+            self.history_by_weapon = !self.history_by_weapon;
+        }
+        if input.is_pressed(GameKey::Up) && self.history_selected > 0 {
+            self.history_selected -= 1;
+        }
+        if input.is_pressed(GameKey::Down) && self.history_selected + 1 < self.history.runs.len() {
+            self.history_selected += 1;
         }
         true
     }
@@ -528,28 +1006,25 @@ impl CryptfallGame {
             return true;
         };
 
+        // Reroll: draw a fresh set of options with the next boon seed
+        if let (Some(bs), Some(ps)) = (self.boon_select.as_mut(), self.playing.as_mut()) {
+            if bs.take_reroll_request() {
+                ps.boon_seed = ps.boon_seed.wrapping_add(7919);
+                let options =
+                    select_boon_options(boon_defs(), &ps.boons.active, ps.boons.lucky, ps.boon_seed);
+                let mut ids: Vec<boons::BoonId> = options.iter().map(|b| b.id).collect();
+                offer_upgrade(&mut ids, &ps.boons.upgradable(), ps.boon_seed);
+                bs.reroll(ids, &ps.boons);
+                ps.rerolls = bs.rerolls;
+            }
+        }
+
         if let Some(boon_id) = selected_boon {
             // Add boon to player and apply effects
             if let Some(ref mut ps) = self.playing {
-                ps.boons.add(boon_id);
-
-                // Apply max HP bonus from boons (e.g. ToughSkin)
-                let base_max_hp = 5 + self.save_data.upgrades.stat_bonuses().0;
-                let hp_bonus = ps.boons.effective_max_hp_bonus(base_max_hp);
-                let new_max = base_max_hp + hp_bonus;
-                let old_max = ps.player.max_hp;
-                ps.player.max_hp = new_max;
-                // If max HP increased, give the bonus HP
-                if new_max > old_max {
-                    ps.player.hp += new_max - old_max;
-                }
-                // If deaths bargain, clamp HP
-                if ps.boons.has_deaths_bargain {
-                    ps.player.max_hp = 1;
-                    ps.player.hp = ps.player.hp.min(1);
-                }
+                ps.add_boon(boon_id, &self.save_data);
             }
-            self.run_state.boons_collected += 1;
+            self.run_state.record_boon(boon_id);
             self.boon_select = None;
             self.screen = GameScreen::Playing;
         }
@@ -558,7 +1033,8 @@ impl CryptfallGame {
 
     fn update_upgrade_shop(&mut self, input: &InputState, dt: f32) -> bool {
         let _ = dt;
-        let max_idx = save::UPGRADES.len().saturating_sub(1);
+        let tree = save::upgrade_tree(save::upgrades());
+        let max_idx = tree.len().saturating_sub(1);
 
         if input.is_pressed(GameKey::Pause) || input.is_pressed(GameKey::Quit) {
             self.screen = GameScreen::Title;
@@ -573,23 +1049,8 @@ impl CryptfallGame {
         }
 
         if input.is_pressed(GameKey::Attack) {
-            let idx = self.upgrade_shop.selected;
-            let upgrade = &save::UPGRADES[idx];
-            let current_level = (upgrade.current_level_fn)(&self.save_data.upgrades);
-            if current_level < upgrade.max_level && self.save_data.can_afford(upgrade.cost) {
-                self.save_data.spend_gold(upgrade.cost);
-                // Apply the upgrade
-                match idx {
-                    0 => self.save_data.upgrades.vitality_level = (self.save_data.upgrades.vitality_level + 1).min(3),
-                    1 => self.save_data.upgrades.vitality_level = (self.save_data.upgrades.vitality_level + 1).min(3),
-                    2 => self.save_data.upgrades.vitality_level = (self.save_data.upgrades.vitality_level + 1).min(3),
-                    3 => self.save_data.upgrades.strength_level = (self.save_data.upgrades.strength_level + 1).min(2),
-                    4 => self.save_data.upgrades.strength_level = (self.save_data.upgrades.strength_level + 1).min(2),
-                    5 => self.save_data.upgrades.twin_dash = true,
-                    6 => self.save_data.upgrades.boon_reroll_level = (self.save_data.upgrades.boon_reroll_level + 1).min(2),
-                    7 => self.save_data.upgrades.boon_reroll_level = (self.save_data.upgrades.boon_reroll_level + 1).min(2),
-                    _ => {}
-                }
+            let (idx, _) = tree[self.upgrade_shop.selected];
+            if self.save_data.buy_upgrade(&save::upgrades()[idx]) {
                 self.save_data.save();
             }
         }
@@ -605,6 +1066,7 @@ impl CryptfallGame {
     }
 
     fn update_playing(&mut self, input: &InputState, dt: f64, dt_f32: f32) -> bool {
+        self.record_achievements();
         let ps = match self.playing.as_mut() {
             Some(ps) => ps,
             None => return true,
@@ -639,7 +1101,7 @@ impl CryptfallGame {
                 ps.death_timer += dt_f32;
                 ps.player
                     .update_with_input(0.0, 0.0, false, false, dt, &ps.tilemap);
-                ps.particles.update(dt_f32);
+                ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
                 ps.camera.update(dt);
                 if ps.player.animation.is_finished() {
                     ps.death_phase = DeathPhase::FadeOut;
@@ -649,7 +1111,7 @@ impl CryptfallGame {
             }
             DeathPhase::FadeOut => {
                 ps.death_timer += dt_f32;
-                ps.particles.update(dt_f32);
+                ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
                 ps.camera.update(dt);
                 if ps.death_timer >= DEATH_FADE_DURATION {
                     ps.death_phase = DeathPhase::Dead;
@@ -679,11 +1141,11 @@ impl CryptfallGame {
                 }
                 self.advance_floor();
                 if let Some(ref mut ps) = self.playing {
-                    ps.particles.update(dt_f32);
+                    ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
                 }
                 return true;
             }
-            ps.particles.update(dt_f32);
+            ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
             return true;
         }
 
@@ -700,7 +1162,7 @@ impl CryptfallGame {
                 }
             }
             let ps = self.playing.as_mut().unwrap();
-            ps.particles.update(dt_f32);
+            ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
             ps.camera.update(dt);
             return true;
         }
@@ -717,31 +1179,35 @@ impl CryptfallGame {
 
         let was_attacking = matches!(ps.player.state, player::PlayerState::Attacking);
         let was_dashing = ps.player.is_dashing();
+        let (fx, fy) = ps.player.feet();
+        ps.player.ground_speed_mult = if puddles::slows(&ps.puddles, fx, fy) {
+            puddles::PUDDLE_SLOW
+        } else {
+            1.0
+        };
 
         if ps.demo.is_some() || ps.idle_timer >= DEMO_IDLE_THRESHOLD {
             let demo = ps.demo.get_or_insert_with(DemoState::new);
             let (dx, dy, attack, dash) = demo.update(dt_f32);
             ps.player
                 .update_with_input(dx, dy, attack, dash, dt, &ps.tilemap);
-
-            if attack && ps.player.attack_cooldown > 0.0 {
-                ps.flash_timer = FLASH_FRAMES;
-                ps.camera.shake(3.0);
-            }
-            if dash {
-                ps.camera.shake(6.0);
-            }
         } else {
             ps.player.update(input, dt, &ps.tilemap);
+        }
 
-            if input.is_pressed(GameKey::Attack) && ps.player.attack_cooldown > 0.0 {
-                ps.flash_timer = FLASH_FRAMES;
-                ps.camera.shake(3.0);
-            }
+        combat::spawn_player_shots(&ps.player, &mut ps.projectiles);
 
-            if input.is_pressed(GameKey::Dash) {
-                ps.camera.shake(6.0);
+        // Dash boon triggers
+        let is_dashing = ps.player.is_dashing();
+        if is_dashing && !was_dashing {
+            ps.camera.shake(6.0);
+            ps.dash_strike_damage = ps.boons.on_dash_start();
+            for enemy in &mut ps.enemies {
+                enemy.hit_this_attack = false;
             }
+        } else if was_dashing && !is_dashing {
+            ps.dash_strike_damage = 0;
+            ps.player.dash_cooldown = ps.boons.on_dash_end(player::DASH_COOLDOWN);
         }
 
         // Room entry invincibility countdown
@@ -751,76 +1217,113 @@ impl CryptfallGame {
         if ps.sealed_flash_timer > 0.0 {
             ps.sealed_flash_timer -= dt_f32;
         }
-
-        // Dash trail particles
-        if ps.player.is_dashing() {
-            let (cx, cy) = ps.player.center();
-            ps.particles.burst(cx, cy, &DASH_TRAIL_CONFIG);
+        if let Some((_, ref mut t)) = ps.blessing_banner {
+            *t -= dt_f32;
+            if *t <= 0.0 {
+                ps.blessing_banner = None;
+            }
         }
-        if ps.player.is_dashing() && !was_dashing {
-            let (cx, cy) = ps.player.center();
-            ps.particles.burst(cx, cy + 4.0, &DUST_PUFF_CONFIG);
+
+        // Dash trail emitter follows the player while dashing
+        let (cx, cy) = ps.player.center();
+        ps.particles.set_emitter_position(ps.dash_trail, cx, cy);
+        ps.particles
+            .set_emitter_active(ps.dash_trail, ps.player.is_dashing());
+
+        // Player animation frame events
+        for event in ps.player.animation.events() {
+            match *event {
+                AnimEvent::SpawnParticle("dust_puff") => {
+                    ps.particles.burst(cx, cy + 4.0, &DUST_PUFF_CONFIG);
+                }
+                AnimEvent::Footstep => {
+                    ps.particles.burst(cx, cy + 6.0, &FOOTSTEP_DUST_CONFIG);
+                }
+                // No audio backend yet; sound cues are ignored
+                _ => {}
+            }
         }
 
         // Reset hit tracking when player starts a new attack
         let is_attacking = matches!(ps.player.state, player::PlayerState::Attacking);
         if is_attacking && !was_attacking {
+            ps.flash_timer = FLASH_FRAMES;
+            ps.camera.shake(if ps.player.heavy { 5.0 } else { 3.0 });
             for enemy in &mut ps.enemies {
                 enemy.hit_this_attack = false;
             }
+            if ps.boons.has_projectile_attack {
+                combat::spawn_slash_projectile(&ps.player, &mut ps.projectiles);
+            }
         }
 
         // Combat: player attacks enemies
+        let combat_scope = engine::profiler::scope("combat");
         let effects = combat::check_player_attacks(
             &ps.player,
+            &mut ps.boons,
             &mut ps.enemies,
             &mut ps.particles,
             &mut ps.damage_numbers,
         );
-        if effects.hit_pause_frames > 0 {
-            ps.hit_pause_frames = effects.hit_pause_frames;
-        }
-        if effects.camera_shake > 0.0 {
-            ps.camera.shake(effects.camera_shake);
-        }
-
-        // Track kills and gold for enemies that just died
-        {
-            let mut newly_dead = Vec::new();
-            for enemy in &ps.enemies {
-                if !enemy.alive && enemy.hp <= 0 && enemy.hit_this_attack {
-                    let gold = match enemy.enemy_type {
-                        enemies::EnemyType::Skeleton => GOLD_SKELETON,
-                        enemies::EnemyType::Ghost => GOLD_GHOST,
-                        enemies::EnemyType::BoneKing => GOLD_BONE_KING,
-                        enemies::EnemyType::Slime => GOLD_SKELETON,
-                    };
-                    newly_dead.push(gold);
-                }
-            }
-            for gold in newly_dead {
-                self.run_state.record_kill(gold);
-                if let Some(ps) = self.playing.as_mut() {
-                    ps.boons.record_kill();
-                }
-            }
-        }
-
-        let ps = self.playing.as_mut().unwrap();
+        apply_combat_effects(ps, &mut self.run_state, effects);
+        let effects = combat::check_dash_strike(
+            &ps.player,
+            ps.dash_strike_damage,
+            &mut ps.boons,
+            &mut ps.enemies,
+            &mut ps.particles,
+            &mut ps.damage_numbers,
+        );
+        apply_combat_effects(ps, &mut self.run_state, effects);
+        drop(combat_scope);
 
         // Update enemies
         let (pcx, pcy) = ps.player.center();
+        let ai_scope = engine::profiler::scope("ai");
+        enemies::find_corpses(&mut ps.enemies);
+        let boss_dt = dt * ps.pact.boss_speed();
         for enemy in &mut ps.enemies {
+            let dt = if enemy.enemy_type.is_boss() {
+                boss_dt
+            } else {
+                dt
+            };
             enemy.update(dt, &ps.tilemap, pcx, pcy);
+            if enemy.slime_landed {
+                let (fx, fy) = enemy.feet();
+                let puddle = match enemy.enemy_type {
+                    enemies::EnemyType::Slime(size) => puddles::Puddle::landing(fx, fy, size),
+                    // The Slime Mother lands hard enough to leave a full splash
+                    _ => puddles::Puddle::burst(fx, fy, enemies::slime::SlimeSize::Large),
+                };
+                puddles::add_puddle(&mut ps.puddles, puddle);
+            }
+            if let Some((fx, fy)) = enemy.elite_blinked {
+                let (ex, ey) = enemy.center();
+                ps.particles.burst(fx, fy, &combat::ELITE_BLINK_CONFIG);
+                ps.particles.burst(ex, ey, &combat::ELITE_BLINK_CONFIG);
+            }
+        }
+        let raise_seed = (ps.spawn_seed as u32).wrapping_add(self.run_state.kills * 7919);
+        for j in enemies::raise_corpses(&mut ps.enemies, raise_seed) {
+            ps.pact.harden(std::slice::from_mut(&mut ps.enemies[j]));
+            let (rx, ry) = ps.enemies[j].center();
+            ps.particles.burst(rx, ry, &combat::RAISE_BURST_CONFIG);
         }
+        drop(ai_scope);
 
         // Boss-specific effects
         for enemy in &ps.enemies {
-            if enemy.enemy_type != enemies::EnemyType::BoneKing {
+            if !enemy.enemy_type.is_boss() {
                 continue;
             }
             let (ecx, ecy) = enemy.center();
-            if enemy.boss_slam_impact {
+            if enemy
+                .animation
+                .events()
+                .contains(&AnimEvent::SpawnParticle("slam_impact"))
+            {
                 ps.camera.shake(5.0);
                 ps.particles
                     .burst(ecx, ecy + 8.0, &combat::BOSS_SLAM_BURST_CONFIG);
@@ -841,10 +1344,13 @@ impl CryptfallGame {
                     .burst(ecx, ecy, &combat::BOSS_DEATH_BURST_CONFIG);
                 ps.particles
                     .burst(ecx, ecy, &combat::BOSS_DEATH_BURST_CONFIG);
+                let souls = pickup::PickupType::Soul(run_state::SOULS_PER_BOSS);
+                ps.pickups.push(Pickup::new(ecx - 2.5, ecy - 3.0, souls));
             }
         }
 
         // Projectile spawning and physics
+        let combat_scope = engine::profiler::scope("combat");
         combat::spawn_enemy_projectiles(&ps.enemies, &mut ps.projectiles);
         combat::update_projectiles(
             &mut ps.projectiles,
@@ -852,27 +1358,95 @@ impl CryptfallGame {
             &mut ps.particles,
             dt_f32,
         );
+        let effects = combat::check_player_projectiles(
+            &mut ps.boons,
+            &mut ps.enemies,
+            &mut ps.projectiles,
+            &mut ps.particles,
+            &mut ps.damage_numbers,
+        );
+        apply_combat_effects(ps, &mut self.run_state, effects);
+
+        // Traps and pits
+        traps::update_traps(
+            &mut ps.traps,
+            dt_f32,
+            &mut ps.projectiles,
+            &mut ps.particles,
+        );
+        if ps.player.fell {
+            let (pcx, pcy) = ps.player.center();
+            ps.particles.burst(pcx, pcy + 4.0, &PIT_FALL_CONFIG);
+            ps.camera.shake(3.0);
+        }
+        let hazard = if ps.player.respawned {
+            Some((ps.player.center(), DeathCause::Pit))
+        } else if ps.room_entry_invincibility <= 0.0 {
+            let at = traps::hazard_at(&ps.traps, &ps.player.world_hurtbox());
+            at.map(|at| (at, DeathCause::Trap))
+        } else {
+            None
+        };
+        if let Some((at, cause)) = hazard {
+            let effects = combat::apply_hazard_damage(
+                &mut ps.player,
+                &mut ps.boons,
+                traps::TRAP_DAMAGE,
+                cause,
+                at,
+                &mut ps.particles,
+                &mut ps.damage_numbers,
+            );
+            apply_combat_effects(ps, &mut self.run_state, effects);
+        }
+        let effects = combat::resolve_knockback(
+            &mut ps.boons,
+            &mut ps.enemies,
+            &ps.traps,
+            &mut ps.particles,
+            &mut ps.damage_numbers,
+        );
+        apply_combat_effects(ps, &mut self.run_state, effects);
 
         // Combat: enemies attack player
         if ps.room_entry_invincibility <= 0.0 {
             let effects = combat::check_enemy_attacks(
                 &mut ps.player,
-                &ps.enemies,
+                &mut ps.boons,
+                &mut ps.enemies,
                 &mut ps.projectiles,
                 &mut ps.particles,
                 &mut ps.damage_numbers,
             );
-            if effects.hit_pause_frames > 0 {
-                ps.hit_pause_frames = effects.hit_pause_frames;
-            }
-            if effects.camera_shake > 0.0 {
-                ps.camera.shake(effects.camera_shake);
-            }
-            if effects.player_died {
-                ps.death_phase = DeathPhase::Dying;
-                ps.death_timer = 0.0;
-            }
+            apply_combat_effects(ps, &mut self.run_state, effects);
+            let effects = combat::detonate_elites(
+                &mut ps.player,
+                &mut ps.boons,
+                &ps.enemies,
+                &mut ps.particles,
+                &mut ps.damage_numbers,
+            );
+            apply_combat_effects(ps, &mut self.run_state, effects);
+        }
+        let effects = combat::apply_status_damage(
+            &mut ps.player,
+            &mut ps.boons,
+            &mut ps.enemies,
+            &mut ps.particles,
+            &mut ps.damage_numbers,
+        );
+        apply_combat_effects(ps, &mut self.run_state, effects);
+
+        // Slimes killed this tick split and splash goo
+        let split_seed = (ps.spawn_seed as u32).wrapping_add(self.run_state.kills * 104729);
+        let before_split = ps.enemies.len();
+        for (sx, sy, size) in enemies::burst_slimes(&mut ps.enemies, split_seed) {
+            ps.particles.burst(sx, sy, &combat::SLIME_SPLAT_CONFIG);
+            puddles::add_puddle(&mut ps.puddles, puddles::Puddle::burst(sx, sy, size));
         }
+        ps.pact.harden(&mut ps.enemies[before_split..]);
+        puddles::update_puddles(&mut ps.puddles, dt_f32);
+        drop(combat_scope);
 
         // Heart flash on HP loss
         if ps.player.hp < ps.last_hp {
@@ -884,7 +1458,9 @@ impl CryptfallGame {
         }
 
         // Update particles and damage numbers
-        ps.particles.update(dt_f32);
+        let particles_scope = engine::profiler::scope("particles");
+        ps.particles.update_with_tilemap(dt_f32, &ps.tilemap);
+        drop(particles_scope);
         for dn in &mut ps.damage_numbers {
             dn.update(dt_f32);
         }
@@ -903,9 +1479,15 @@ impl CryptfallGame {
             let py = ps.player.transform.position.y;
             for p in &mut ps.pickups {
                 if p.check_collection(px, py, 10.0, 14.0) {
-                    let heal = p.heal_amount();
-                    if heal > 0 {
-                        ps.player.hp = (ps.player.hp + heal).min(ps.player.max_hp);
+                    let collected = ps.boons.on_pickup(p);
+                    if collected.heal > 0 {
+                        ps.player.heal(ps.pact.scale_heal(collected.heal));
+                    }
+                    if collected.gold > 0 {
+                        self.run_state.record_gold(collected.gold);
+                    }
+                    if collected.souls > 0 {
+                        self.run_state.record_souls(collected.souls);
                     }
                     ps.particles
                         .burst(p.x + 3.0, p.y + 3.0, &PICKUP_COLLECT_CONFIG);
@@ -915,9 +1497,93 @@ impl CryptfallGame {
             ps.pickups.retain(|p| p.alive);
         }
 
-        // Room state progression
+        // Shop purchases
+        let room_index = ps.dungeon.current_room_index;
+        let mut bought = None;
+        if let Some(shop) = ps.shop.as_mut().filter(|s| s.room_index == room_index) {
+            shop.update(dt_f32);
+            let (pcx, pcy) = ps.player.center();
+            if let Some(idx) = shop
+                .item_near(pcx, pcy)
+                .filter(|_| input.is_pressed(GameKey::Attack))
+            {
+                let item = &shop.items[idx];
+                let (ix, iy) = (item.x, item.y);
+                if item.kind == shop::ShopItemKind::Heal && ps.player.hp >= ps.player.max_hp {
+                    shop.deny(idx);
+                } else if let Some(kind) = shop.try_buy(idx, &mut self.run_state) {
+                    bought = Some((kind, ix, iy));
+                }
+            }
+        }
+        if let Some((kind, ix, iy)) = bought {
+            match kind {
+                shop::ShopItemKind::Heal => {
+                    ps.player.heal(ps.pact.scale_heal(shop::SHOP_HEAL_AMOUNT));
+                }
+                shop::ShopItemKind::Boon(boon_id) => {
+                    ps.add_boon(boon_id, &self.save_data);
+                    self.run_state.record_boon(boon_id);
+                }
+                shop::ShopItemKind::Reroll => ps.rerolls += 1,
+            }
+            ps.particles.burst(ix, iy, &PICKUP_COLLECT_CONFIG);
+        }
+
         let mut boon_ids_for_select: Vec<boons::BoonId> = Vec::new();
-        let mut encounter_just_cleared = false;
+        let mut blessed = false;
+
+        // Treasure chests
+        let (pcx, pcy) = ps.player.center();
+        for c in ps.chests.iter_mut().filter(|c| c.room_index == room_index) {
+            if input.is_pressed(GameKey::Attack) && c.can_open_from(pcx, pcy) {
+                c.open();
+                ps.camera.shake(2.0);
+            }
+            c.update(dt);
+            if !c.loot_released() {
+                continue;
+            }
+            let (cx, cy) = c.center();
+            ps.particles.burst(cx, cy, &CHEST_SPARKLE_CONFIG);
+            ps.pickups.extend(c.loot_pickups());
+            if c.loot == chest::ChestLoot::RareBoon {
+                ps.boon_seed = ps.boon_seed.wrapping_add(4241);
+                let options = select_rare_boon_options(
+                    boon_defs(),
+                    &ps.boons.active,
+                    ps.boons.lucky,
+                    ps.boon_seed,
+                );
+                boon_ids_for_select = options.iter().map(|b| b.id).collect();
+                if boon_ids_for_select.is_empty() {
+                    // Every rare boon already owned: fall back to a heal
+                    let heal = Pickup::new(cx - 3.5, cy + 8.0, pickup::PickupType::BigHeal);
+                    ps.pickups.push(heal);
+                }
+            }
+            if c.loot == chest::ChestLoot::Blessing {
+                let raisable = ps.boons.raisable();
+                if raisable.is_empty() {
+                    // Nothing left to raise: fall back to a heal
+                    let heal = Pickup::new(cx - 3.5, cy + 8.0, pickup::PickupType::BigHeal);
+                    ps.pickups.push(heal);
+                } else {
+                    ps.boon_seed = ps.boon_seed.wrapping_add(4241);
+                    let id = raisable[(ps.boon_seed % raisable.len() as u64) as usize];
+                    ps.boons.raise_rarity(id);
+                    blessed = true;
+                    ps.blessing_banner = Some((id, BLESSING_BANNER_TIME));
+                    ps.particles.burst(cx, cy, &PICKUP_COLLECT_CONFIG);
+                }
+            }
+        }
+        if blessed {
+            ps.apply_boon_stats(&self.save_data);
+        }
+
+        // Room state progression
+        let mut room_clear_gold = None;
 
         match ps.room_state {
             RoomState::Peaceful => {
@@ -938,12 +1604,13 @@ impl CryptfallGame {
                         let room_index = ps.dungeon.current_room_index;
                         let spawn_points = &ps.dungeon.current_room().template.spawn_points;
                         if let Some(wave) = tracker.advance() {
-                            let new_enemies = encounters::instantiate_wave(
+                            let mut new_enemies = encounters::instantiate_wave(
                                 wave,
                                 spawn_points,
                                 room_index,
                                 ps.spawn_seed,
                             );
+                            ps.pact.harden(&mut new_enemies);
                             ps.enemies.extend(new_enemies);
                         }
                     }
@@ -958,6 +1625,7 @@ impl CryptfallGame {
                 if encounter_done && all_animations_done {
                     ps.room_state = RoomState::Cleared;
                     ps.dungeon.mark_room_cleared(ps.dungeon.current_room_index);
+                    enemies::bury_corpses(&mut ps.enemies);
                     world::set_doors(&mut ps.tilemap, true);
                     ps.camera.shake(4.0);
                     ps.wave_tracker = None;
@@ -965,18 +1633,20 @@ impl CryptfallGame {
                     ps.combat_rooms_cleared += 1;
                     let combat_rooms_cleared = ps.combat_rooms_cleared;
 
-                    ps.boons.reset_room_state();
+                    room_clear_gold = Some(ps.boons.on_room_clear(GOLD_ROOM_CLEAR_BONUS));
 
                     if combat_rooms_cleared > 0 && combat_rooms_cleared.is_multiple_of(2) {
                         ps.boon_seed =
                             ps.boon_seed.wrapping_add(combat_rooms_cleared as u64 * 997);
                         let options = select_boon_options(
-                            BOON_DEFS,
+                            boon_defs(),
                             &ps.boons.active,
                             ps.boons.lucky,
                             ps.boon_seed,
                         );
                         boon_ids_for_select = options.iter().map(|b| b.id).collect();
+                        let upgradable = ps.boons.upgradable();
+                        offer_upgrade(&mut boon_ids_for_select, &upgradable, ps.boon_seed);
                     }
 
                     let drop_seed = ps
@@ -991,7 +1661,6 @@ impl CryptfallGame {
                             .push(Pickup::new(cx, cy, pickup::PickupType::SmallHeal));
                     }
 
-                    encounter_just_cleared = true;
                 }
             }
             RoomState::Cleared => {
@@ -1012,13 +1681,18 @@ impl CryptfallGame {
             .clamp_to_bounds(ps.tilemap.pixel_width() as f32, ps.tilemap.pixel_height() as f32);
 
         // Deferred encounter clear: update fields that need &mut self
-        if encounter_just_cleared {
-            self.run_state.record_room_clear(GOLD_ROOM_CLEAR_BONUS);
-            if !boon_ids_for_select.is_empty() {
-                self.boon_select = Some(boon_select::BoonSelectScreen::new(boon_ids_for_select));
-                self.screen = GameScreen::BoonSelect;
-                return true;
-            }
+        if let Some(gold) = room_clear_gold {
+            self.run_state.record_room_clear(gold);
+        }
+        if !boon_ids_for_select.is_empty() {
+            let ps = self.playing.as_ref().unwrap();
+            self.boon_select = Some(boon_select::BoonSelectScreen::new(
+                boon_ids_for_select,
+                &ps.boons,
+                ps.rerolls,
+            ));
+            self.screen = GameScreen::BoonSelect;
+            return true;
         }
 
         true
@@ -1060,9 +1734,8 @@ impl CryptfallGame {
         sprites::font::render_text(fb, tagline, tx, logo_y + 14, [100, 100, 140]);
 
         // Menu items
-        let menu_items = ["NEW RUN", "UPGRADES", "QUIT"];
-        let menu_y = fh / 2 + 2;
-        for (i, item) in menu_items.iter().enumerate() {
+        let menu_y = fh / 2 - 4;
+        for (i, item) in TITLE_MENU.iter().enumerate() {
             let is_selected = i == self.title.selected;
             let color: Color = if is_selected {
                 [255, 220, 100]
@@ -1071,7 +1744,7 @@ impl CryptfallGame {
             };
             let iw = sprites::font::text_width(item);
             let ix = (fw - iw) / 2;
-            let iy = menu_y + i as i32 * 8;
+            let iy = menu_y + i as i32 * 6;
 
             // Selection indicator
             if is_selected {
@@ -1112,29 +1785,48 @@ impl CryptfallGame {
         let tx = (fw - tw) / 2;
         sprites::font::render_text(fb, title, tx, 4, [255, 220, 100]);
 
-        // Gold display
+        // Gold on the right, souls on the left
         let gold_str = format!("GOLD: {}", self.save_data.total_gold);
         let gw = sprites::font::text_width(&gold_str);
         sprites::font::render_text(fb, &gold_str, fw - gw - 4, 4, [255, 200, 50]);
+        let souls_str = format!("SOULS: {}", self.save_data.souls);
+        sprites::font::render_text(fb, &souls_str, 4, 4, SOUL_COLOR);
 
-        // Upgrade list
+        // Upgrade tree: each upgrade sits under the one it requires
         let start_y = 16;
-        for (i, upgrade) in save::UPGRADES.iter().enumerate() {
+        let tree = save::upgrade_tree(save::upgrades());
+        for (i, &(idx, depth)) in tree.iter().enumerate() {
+            let upgrade = &save::upgrades()[idx];
             let is_selected = i == self.upgrade_shop.selected;
             let current_level = (upgrade.current_level_fn)(&self.save_data.upgrades);
             let is_owned = current_level >= upgrade.max_level;
-            let can_afford = self.save_data.can_afford(upgrade.cost);
+            let is_available = self.save_data.upgrade_available(upgrade);
+            let can_afford =
+                is_available && self.save_data.can_afford(upgrade.currency, upgrade.cost);
 
             let y = start_y + i as i32 * 7;
+            let x = 8 + depth as i32 * 6;
 
             // Selection indicator
             if is_selected {
                 sprites::font::render_text(fb, ">", 2, y, [255, 220, 100]);
             }
 
+            // Branch line back to the parent
+            if depth > 0 {
+                let branch_color: Color = [50, 50, 60];
+                for dy in -2..=2 {
+                    fb.set_pixel_safe(x - 4, y + dy, branch_color);
+                }
+                fb.set_pixel_safe(x - 3, y + 2, branch_color);
+                fb.set_pixel_safe(x - 2, y + 2, branch_color);
+            }
+
             // Name
             let name_color: Color = if is_owned {
                 [80, 180, 80]
+            } else if !is_available {
+                [55, 55, 65]
             } else if is_selected && can_afford {
                 [255, 255, 255]
             } else if is_selected {
@@ -1144,12 +1836,14 @@ impl CryptfallGame {
             } else {
                 [80, 80, 90]
             };
-            sprites::font::render_text(fb, upgrade.name, 8, y, name_color);
+            sprites::font::render_text(fb, upgrade.name, x, y, name_color);
 
             // Description
-            let desc_x = 8 + sprites::font::text_width(upgrade.name) + 4;
+            let desc_x = x + sprites::font::text_width(upgrade.name) + 4;
             let desc_color: Color = if is_owned {
                 [60, 120, 60]
+            } else if !is_available {
+                [55, 55, 65]
             } else {
                 [100, 100, 110]
             };
@@ -1161,10 +1855,13 @@ impl CryptfallGame {
                 let ow = sprites::font::text_width(owned_str);
                 sprites::font::render_text(fb, owned_str, fw - ow - 4, y, [80, 180, 80]);
             } else {
-                let cost_str = format!("{}G", upgrade.cost);
+                let (cost_str, full_color) = match upgrade.currency {
+                    save::Currency::Gold => (format!("{}G", upgrade.cost), [255, 200, 50]),
+                    save::Currency::Souls => (format!("{}S", upgrade.cost), SOUL_COLOR),
+                };
                 let cw = sprites::font::text_width(&cost_str);
                 let cost_color: Color = if can_afford {
-                    [255, 200, 50]
+                    full_color
                 } else {
                     [100, 60, 60]
                 };
@@ -1172,6 +1869,18 @@ impl CryptfallGame {
             }
         }
 
+        // What a locked upgrade is waiting on
+        let (idx, _) = tree[self.upgrade_shop.selected];
+        let upgrade = &save::upgrades()[idx];
+        if let Some(parent) = upgrade
+            .requires
+            .filter(|_| !self.save_data.upgrade_available(upgrade))
+        {
+            let needs = format!("REQUIRES {}", parent.to_uppercase());
+            let nw = sprites::font::text_width(&needs);
+            sprites::font::render_text(fb, &needs, (fw - nw) / 2, fh - 13, [180, 80, 80]);
+        }
+
         // Back hint
         let hint = "ESC - BACK";
         let hw = sprites::font::text_width(hint);
@@ -1179,58 +1888,269 @@ impl CryptfallGame {
         sprites::font::render_text(fb, hint, hx, fh - 6, [80, 80, 90]);
     }
 
-    fn render_run_end(&self, fb: &mut FrameBuffer) {
+    fn render_leaderboard(&self, fb: &mut FrameBuffer) {
         let fw = fb.width() as i32;
         let fh = fb.height() as i32;
 
-        // Dark background
         for y in 0..fh {
             for x in 0..fw {
-                fb.set_pixel_safe(x, y, [5, 5, 8]);
+                fb.set_pixel_safe(x, y, [10, 10, 15]);
             }
         }
 
-        // Header
-        let (header, header_color): (&str, Color) = if self.run_end.victory {
-            ("RUN COMPLETE", [255, 220, 50])
+        let title = "DAILY LEADERBOARD";
+        let tw = sprites::font::text_width(title);
+        sprites::font::render_text(fb, title, (fw - tw) / 2, 4, [255, 220, 100]);
+
+        // Whether today's official attempt is still open
+        let today = daily::today();
+        let status = if self.leaderboard.attempted(today) {
+            format!("{} PLAYED", daily::date_label(today))
         } else {
-            ("YOU DIED", [200, 30, 30])
+            format!("{} OPEN", daily::date_label(today))
         };
-        let hw = sprites::font::text_width(header);
-        let hx = (fw - hw) / 2;
-        sprites::font::render_text(fb, header, hx, fh / 4, header_color);
-
-        // Stats
-        let stats_y = fh / 4 + 12;
-        let stats = [
-            format!("FLOOR: {}", self.run_state.floor_reached),
-            format!("KILLS: {}", self.run_state.kills),
-            format!("ROOMS: {}", self.run_state.rooms_cleared),
-            format!("BOONS: {}", self.run_state.boons_collected),
-            format!("GOLD: +{}", self.run_state.gold_earned),
-        ];
+        let sw = sprites::font::text_width(&status);
+        sprites::font::render_text(fb, &status, (fw - sw) / 2, 11, [140, 140, 160]);
+
+        let ranked = self.leaderboard.ranked();
+        if ranked.is_empty() {
+            let empty = "NO ATTEMPTS YET";
+            let ew = sprites::font::text_width(empty);
+            sprites::font::render_text(fb, empty, (fw - ew) / 2, 22, [100, 100, 110]);
+        }
+        for (i, entry) in ranked.iter().take(daily::LEADERBOARD_SHOWN).enumerate() {
+            let y = 22 + i as i32 * 7;
+            let color: Color = if entry.day == today {
+                [255, 255, 255]
+            } else {
+                [160, 160, 170]
+            };
+            let left = format!("{} {}", daily::date_label(entry.day), entry.score);
+            sprites::font::render_text(fb, &left, 4, y, color);
+
+            let (mins, secs) = (entry.time_secs / 60, entry.time_secs % 60);
+            let right = format!("F{} K{} {mins}:{secs:02}", entry.floor, entry.kills);
+            let rw = sprites::font::text_width(&right);
+            sprites::font::render_text(fb, &right, fw - rw - 4, y, [120, 120, 130]);
+        }
+
+        let hint = "ESC - BACK";
+        let hw = sprites::font::text_width(hint);
+        sprites::font::render_text(fb, hint, (fw - hw) / 2, fh - 6, [80, 80, 90]);
+    }
+
+    fn render_achievements(&self, fb: &mut FrameBuffer) {
+        let fw = fb.width() as i32;
+        let fh = fb.height() as i32;
+
+        for y in 0..fh {
+            for x in 0..fw {
+                fb.set_pixel_safe(x, y, [10, 10, 15]);
+            }
+        }
+
+        let title = "ACHIEVEMENTS";
+        let tw = sprites::font::text_width(title);
+        sprites::font::render_text(fb, title, (fw - tw) / 2, 4, [255, 220, 100]);
 
-        // Time display
-        let secs = self.run_state.elapsed_secs as u32;
-        let mins = secs / 60;
-        let remaining_secs = secs % 60;
-        let time_str = format!("TIME: {}:{:02}", mins, remaining_secs);
+        let progress = &self.save_data.achievements;
+        let total = achievements::ACHIEVEMENTS.len();
+        let count = format!("{}/{total}", progress.unlocked_count());
+        let cw = sprites::font::text_width(&count);
+        sprites::font::render_text(fb, &count, fw - cw - 4, 4, [140, 140, 160]);
 
-        for (i, stat) in stats.iter().enumerate() {
-            let sw = sprites::font::text_width(stat);
-            let sx = (fw - sw) / 2;
-            sprites::font::render_text(fb, stat, sx, stats_y + i as i32 * 7, [180, 180, 200]);
+        let start_y = 16;
+        for (i, def) in achievements::ACHIEVEMENTS.iter().enumerate() {
+            let is_selected = i == self.achievements_selected;
+            let unlocked = progress.is_unlocked(def);
+            let y = start_y + i as i32 * 7;
+
+            if is_selected {
+                sprites::font::render_text(fb, ">", 2, y, [255, 220, 100]);
+            }
+
+            let name_color: Color = if unlocked {
+                [80, 180, 80]
+            } else if is_selected {
+                [255, 255, 255]
+            } else {
+                [120, 120, 130]
+            };
+            sprites::font::render_text(fb, def.name, 8, y, name_color);
+
+            // Count toward the goal, or DONE
+            let (state, state_color): (String, Color) = if unlocked {
+                ("DONE".to_string(), [80, 180, 80])
+            } else {
+                let (done, target) = (progress.progress(def), def.goal.target());
+                (format!("{done}/{target}"), [100, 100, 110])
+            };
+            let sw = sprites::font::text_width(&state);
+            sprites::font::render_text(fb, &state, fw - sw - 4, y, state_color);
         }
 
-        let tw = sprites::font::text_width(&time_str);
-        let tx = (fw - tw) / 2;
-        sprites::font::render_text(fb, &time_str, tx, stats_y + stats.len() as i32 * 7, [180, 180, 200]);
+        // What the highlighted achievement asks for
+        let def = &achievements::ACHIEVEMENTS[self.achievements_selected];
+        let info_y = start_y + achievements::ACHIEVEMENTS.len() as i32 * 7 + 3;
+        let desc = def.description.to_uppercase();
+        let dw = sprites::font::text_width(&desc);
+        sprites::font::render_text(fb, &desc, (fw - dw) / 2, info_y, [160, 160, 170]);
+
+        let hint = "ESC - BACK";
+        let hw = sprites::font::text_width(hint);
+        sprites::font::render_text(fb, hint, (fw - hw) / 2, fh - 6, [80, 80, 90]);
+    }
+
+    fn render_history(&self, fb: &mut FrameBuffer) {
+        let fw = fb.width() as i32;
+        let fh = fb.height() as i32;
+
+        for y in 0..fh {
+            for x in 0..fw {
+                fb.set_pixel_safe(x, y, [10, 10, 15]);
+            }
+        }
+
+        let title = if self.history_by_weapon {
+            "WIN RATES"
+        } else {
+            "RUN HISTORY"
+        };
+        let tw = sprites::font::text_width(title);
+        sprites::font::render_text(fb, title, (fw - tw) / 2, 4, [255, 220, 100]);
+
+        let runs = &self.history.runs;
+        let count = format!("{} RUNS", runs.len());
+        let cw = sprites::font::text_width(&count);
+        sprites::font::render_text(fb, &count, fw - cw - 4, 4, [140, 140, 160]);
+
+        let hint = if self.history_by_weapon {
+            "LEFT/RIGHT - RUNS  ESC - BACK"
+        } else {
+            "LEFT/RIGHT - WIN RATES  ESC - BACK"
+        };
+        let hw = sprites::font::text_width(hint);
+        sprites::font::render_text(fb, hint, (fw - hw) / 2, fh - 6, [80, 80, 90]);
+
+        let start_y = 16;
+        if self.history_by_weapon {
+            self.render_win_rates(fb, start_y);
+            return;
+        }
+        if runs.is_empty() {
+            let empty = "NO RUNS YET";
+            let ew = sprites::font::text_width(empty);
+            sprites::font::render_text(fb, empty, (fw - ew) / 2, fh / 2 - 2, [120, 120, 130]);
+            return;
+        }
 
-        // Continue hint
-        let hint = "PRESS ATTACK";
-        let hiw = sprites::font::text_width(hint);
-        let hix = (fw - hiw) / 2;
-        sprites::font::render_text(fb, hint, hix, fh - 10, [120, 120, 130]);
+        // Newest first, scrolled so the highlighted run stays in view
+        let first = self.history_selected.saturating_sub(HISTORY_ROWS - 1);
+        let newest_first = runs.iter().enumerate().rev();
+        for (row, (i, run)) in newest_first.skip(first).take(HISTORY_ROWS).enumerate() {
+            let is_selected = first + row == self.history_selected;
+            let y = start_y + row as i32 * 7;
+
+            if is_selected {
+                sprites::font::render_text(fb, ">", 2, y, [255, 220, 100]);
+            }
+
+            let weapon = weapons::get_weapon(run.weapon).name.to_uppercase();
+            let label = format!("{} {weapon} F{}", i + 1, run.floor);
+            let label_color: Color = if is_selected {
+                [255, 255, 255]
+            } else {
+                [120, 120, 130]
+            };
+            sprites::font::render_text(fb, &label, 8, y, label_color);
+
+            let (end, end_color): (&str, Color) = match run.cause.as_deref() {
+                None => ("WON", [80, 180, 80]),
+                Some(cause) => (cause, [170, 70, 70]),
+            };
+            let ew = sprites::font::text_width(end);
+            sprites::font::render_text(fb, end, fw - ew - 4, y, end_color);
+        }
+
+        // Details of the highlighted run
+        let run = &runs[runs.len() - 1 - self.history_selected];
+        let info_y = start_y + HISTORY_ROWS as i32 * 7 + 3;
+        let (date, secs) = (daily::date_label(run.day), run.duration_secs);
+        let (mins, seed_label) = (secs / 60, seed::format_seed(run.seed));
+        let lines = [
+            format!("{date}  TIME {mins}:{:02}  SEED {seed_label}", secs % 60),
+            format!(
+                "DEALT {}  TAKEN {}  GOLD {}",
+                run.damage_dealt, run.damage_taken, run.gold
+            ),
+        ];
+        for (i, line) in lines.iter().enumerate() {
+            let y = info_y + i as i32 * 7;
+            sprites::font::render_text(fb, line, 8, y, [160, 160, 170]);
+        }
+
+        // Boons in the order they were taken, wrapped to the screen
+        let boons_y = info_y + lines.len() as i32 * 7 + 2;
+        let max_w = fw - 16;
+        // Each row with the number of boons on it
+        let mut rows: Vec<(String, usize)> = Vec::new();
+        for name in &run.boons {
+            let name = name.to_uppercase();
+            match rows.last_mut() {
+                Some((row, n)) if sprites::font::text_width(&format!("{row}  {name}")) <= max_w => {
+                    row.push_str("  ");
+                    row.push_str(&name);
+                    *n += 1;
+                }
+                _ => rows.push((name, 1)),
+            }
+        }
+        if rows.len() > HISTORY_BOON_LINES {
+            rows.truncate(HISTORY_BOON_LINES - 1);
+            let shown: usize = rows.iter().map(|(_, n)| n).sum();
+            rows.push((format!("AND {} MORE", run.boons.len() - shown), 0));
+        }
+        if rows.is_empty() {
+            rows.push(("NO BOONS".to_string(), 0));
+        }
+        for (i, (row, _)) in rows.iter().enumerate() {
+            let y = boons_y + i as i32 * 7;
+            sprites::font::render_text(fb, row, 8, y, [140, 120, 200]);
+        }
+    }
+
+    /// Runs, wins and a win-rate bar for each weapon.
+    fn render_win_rates(&self, fb: &mut FrameBuffer, start_y: i32) {
+        let fw = fb.width() as i32;
+        let bar_w = 30;
+        for (i, record) in self.history.weapon_records().iter().enumerate() {
+            let y = start_y + i as i32 * 8;
+            let name = weapons::get_weapon(record.weapon).name.to_uppercase();
+            sprites::font::render_text(fb, &name, 8, y, [200, 200, 210]);
+
+            let bar_x = fw - bar_w - 4;
+            if record.runs == 0 {
+                let none = "NO RUNS";
+                let nw = sprites::font::text_width(none);
+                sprites::font::render_text(fb, none, bar_x + bar_w - nw, y, [90, 90, 100]);
+                continue;
+            }
+            let score = format!("{}/{}", record.wins, record.runs);
+            let sw = sprites::font::text_width(&score);
+            sprites::font::render_text(fb, &score, bar_x - sw - 4, y, [160, 160, 170]);
+
+            let filled = bar_w * record.win_rate() as i32 / 100;
+            for bx in 0..bar_w {
+                let color: Color = if bx < filled {
+                    [80, 180, 80]
+                } else {
+                    [40, 40, 50]
+                };
+                for by in 1..4 {
+                    fb.set_pixel_safe(bar_x + bx, y + by, color);
+                }
+            }
+        }
     }
 
     fn render_playing(&mut self, fb: &mut FrameBuffer, info: &FrameInfo, alpha: f32) {
@@ -1248,13 +2168,33 @@ impl CryptfallGame {
         let (cam_x, cam_y) = ps.camera.offset();
 
         // --- Draw tile map ---
+        let tilemap_scope = engine::profiler::scope("tilemap");
         render_tilemap(fb, &ps.tilemap, tiles::tile_sprite, cam_x, cam_y);
+        for trap in &ps.traps {
+            trap.render(fb, cam_x, cam_y);
+        }
+        for puddle in &ps.puddles {
+            puddle.render(fb, cam_x, cam_y);
+        }
+        drop(tilemap_scope);
 
         // --- Draw pickups ---
         for p in &ps.pickups {
             p.render(fb, cam_x, cam_y);
         }
 
+        // --- Draw chests ---
+        let room_index = ps.dungeon.current_room_index;
+        for c in ps.chests.iter().filter(|c| c.room_index == room_index) {
+            c.render(fb, cam_x, cam_y);
+        }
+
+        // --- Draw shop ---
+        let shop = ps.shop.as_ref().filter(|s| s.room_index == room_index);
+        if let Some(shop) = shop {
+            shop.render(fb, cam_x, cam_y, self.run_state.gold());
+        }
+
         // --- Draw enemies ---
         for enemy in &ps.enemies {
             enemy.render(fb, alpha, cam_x, cam_y);
@@ -1283,6 +2223,8 @@ impl CryptfallGame {
                 } else {
                     ps.player.render(fb, alpha, cam_x, cam_y);
                 }
+            } else if let Some(tint) = ps.player.status.tint() {
+                ps.player.render_tinted(fb, alpha, cam_x, cam_y, tint);
             } else {
                 ps.player.render(fb, alpha, cam_x, cam_y);
             }
@@ -1292,7 +2234,9 @@ impl CryptfallGame {
         ps.projectiles.render(fb, cam_x, cam_y);
 
         // --- Draw particles ---
+        let particles_scope = engine::profiler::scope("particles");
         ps.particles.render(fb, cam_x, cam_y);
+        drop(particles_scope);
 
         // --- Draw damage numbers ---
         for dn in &ps.damage_numbers {
@@ -1321,6 +2265,17 @@ impl CryptfallGame {
             sprites::font::render_text(fb, text, tx, ty, [brightness, 40, 40]);
         }
 
+        // --- Blessing banner: which boon a chest just raised ---
+        if let Some((id, t)) = ps.blessing_banner {
+            let text = format!("{} BLESSED", boons::boon_def(id).name);
+            let tw = sprites::font::text_width(&text);
+            let tx = (fw as i32 - tw) / 2;
+            let rc = boon_select::rarity_color(ps.boons.rarity(id));
+            if t > 0.5 || ((t * 8.0) as i32) % 2 == 0 {
+                sprites::font::render_text(fb, &text, tx, 12, rc);
+            }
+        }
+
         // --- Death fade overlay ---
         match ps.death_phase {
             DeathPhase::FadeOut => {
@@ -1367,6 +2322,7 @@ impl CryptfallGame {
         }
 
         // --- HUD ---
+        let hud_scope = engine::profiler::scope("hud");
         let bar_h = 8;
         for y in 0..bar_h.min(fh) {
             for x in 0..fw {
@@ -1375,6 +2331,8 @@ impl CryptfallGame {
         }
 
         hud::render_hearts(fb, ps.player.hp, ps.player.max_hp, 2, 1);
+        let status_x = 2 + ps.player.max_hp * 6 + 2;
+        hud::render_status_icons(fb, &ps.player.status, status_x, 1);
 
         // Heart flash overlay on damage
         if ps.heart_flash_timer > 0.0 {
@@ -1412,7 +2370,7 @@ impl CryptfallGame {
         sprites::font::render_text(fb, floor_text, ftx, 1, [180, 180, 180]);
 
         // Gold in HUD (right side, below perf bars)
-        let gold_str = format!("{}G", self.run_state.gold_earned);
+        let gold_str = format!("{}G", self.run_state.gold());
         let gsw = sprites::font::text_width(&gold_str);
         sprites::font::render_text(fb, &gold_str, fw as i32 - gsw - 2, 5, [255, 200, 50]);
 
@@ -1442,12 +2400,19 @@ impl CryptfallGame {
         draw_timing_bar(fb, 2, info.input_us, [0, 255, 255]);
         draw_timing_bar(fb, 3, info.render_us, [255, 80, 80]);
 
+        // Owned boons along the bottom-left, wrapping onto rows above
+        let boons_w = fw as i32 - 4;
+        let boons_h = hud::boons_height(&ps.boons, boons_w);
+        hud::render_boons(fb, &ps.boons, 2, fh as i32 - boons_h, boons_w);
+
         // --- Boss health bar ---
         for enemy in &ps.enemies {
-            if enemy.enemy_type == enemies::EnemyType::BoneKing && (enemy.alive || enemy.boss_dying)
-            {
+            let Some(name) = enemy.enemy_type.boss_name() else {
+                continue;
+            };
+            if enemy.alive || enemy.boss_dying {
                 if let Some(max_hp) = enemy.boss_max_hp() {
-                    hud::render_boss_bar(fb, "BONE KING", enemy.hp, max_hp);
+                    hud::render_boss_bar(fb, name, enemy.hp, max_hp);
                 }
                 break;
             }
@@ -1459,8 +2424,32 @@ impl CryptfallGame {
             &ps.dungeon.floor,
             ps.dungeon.current_room_index,
             ps.minimap_visible,
+            ps.boons.treasure_sense,
         );
 
+        // Shop item prompt
+        let (pcx, pcy) = ps.player.center();
+        if let Some(shop) = ps.shop.as_ref().filter(|s| s.room_index == room_index) {
+            if let Some(idx) = shop.item_near(pcx, pcy) {
+                shop.render_prompt(fb, idx);
+            }
+        }
+
+        // Chest prompt
+        let near_chest = ps
+            .chests
+            .iter()
+            .any(|c| c.room_index == room_index && c.can_open_from(pcx, pcy));
+        if near_chest {
+            let hint = "ATTACK - OPEN";
+            let hw = sprites::font::text_width(hint);
+            let hx = (fw as i32 - hw) / 2;
+            // Sit above the boon strip
+            let hy = fh as i32 - 7 - boons_h;
+            sprites::font::render_text(fb, hint, hx, hy, [255, 220, 100]);
+        }
+        drop(hud_scope);
+
         // --- Pause overlay (rendered last so it covers everything) ---
         if ps.paused {
             fb.overlay([0, 0, 0], 0.6);
@@ -1495,16 +2484,21 @@ impl CryptfallGame {
             let rx = (fw as i32 - rw) / 2;
             sprites::font::render_text(fb, &rooms_str, rx, ty + 26, [140, 140, 140]);
 
-            // Boon count
-            let boon_str = format!("BOONS: {}", ps.boons.active.len());
-            let bw = sprites::font::text_width(&boon_str);
+            // Owned boons with their levels
+            let max_w = fw as i32 - 16;
+            let bw = hud::boons_width(&ps.boons, max_w);
             let bx = (fw as i32 - bw) / 2;
-            sprites::font::render_text(fb, &boon_str, bx, ty + 34, [140, 140, 140]);
+            let boons_h = hud::render_boons(fb, &ps.boons, bx, ty + 34, max_w);
 
             let hint = "ESC - RESUME  Q - QUIT";
             let hw = sprites::font::text_width(hint);
             let hx = (fw as i32 - hw) / 2;
-            sprites::font::render_text(fb, hint, hx, ty + 44, [100, 100, 100]);
+            sprites::font::render_text(fb, hint, hx, ty + 36 + boons_h, [100, 100, 100]);
+
+            let seed_str = format!("SEED {}", seed::format_seed(ps.run_seed));
+            let sw = sprites::font::text_width(&seed_str);
+            let sx = (fw as i32 - sw) / 2;
+            sprites::font::render_text(fb, &seed_str, sx, ty + 44 + boons_h, [100, 100, 100]);
 
             // Full-size minimap on pause screen
             hud::render_minimap(
@@ -1512,6 +2506,7 @@ impl CryptfallGame {
                 &ps.dungeon.floor,
                 ps.dungeon.current_room_index,
                 true,
+                ps.boons.treasure_sense,
             );
         }
     }
@@ -1529,6 +2524,7 @@ impl CryptfallGame {
 
         ps.dungeon.swap_to_room(to_room);
         ps.tilemap = ps.dungeon.build_tilemap();
+        ps.traps = traps::spawn_traps(&ps.dungeon.current_room().template);
 
         let (px, py) = ps.dungeon.player_spawn_position(Some(direction));
         ps.player.transform.position.x = px;
@@ -1536,11 +2532,15 @@ impl CryptfallGame {
         ps.player.transform.commit();
 
         ps.enemies.clear();
+        ps.puddles.clear();
         ps.projectiles.clear();
         ps.particles.clear();
+        ps.particles.set_emitter_active(ps.dash_trail, false);
         ps.damage_numbers.clear();
         ps.pickups.clear();
         ps.wave_tracker = None;
+        // Nothing lingers on the player from one room to the next
+        ps.player.status.clear();
 
         let (cx, cy) = ps.player.center();
         ps.camera.follow(cx, cy);
@@ -1556,6 +2556,10 @@ impl CryptfallGame {
 
         // Check if this is the exit room
         if ps.dungeon.is_exit_room() {
+            if !ps.floor_clear && !ps.hit_this_floor {
+                let flawless = achievements::Event::FlawlessFloor;
+                ps.achievement_events.push(flawless);
+            }
             ps.floor_clear = true;
             self.run_state.floor_reached = ps.dungeon.floor_number;
             return;
@@ -1570,10 +2574,40 @@ impl CryptfallGame {
         }
 
         match room.room_type {
-            dungeon::room_template::RoomType::Start
-            | dungeon::room_template::RoomType::Treasure
-            | dungeon::room_template::RoomType::Shop
-            | dungeon::room_template::RoomType::Exit => {
+            dungeon::room_template::RoomType::Start | dungeon::room_template::RoomType::Exit => {
+                ps.room_state = RoomState::Peaceful;
+            }
+            dungeon::room_template::RoomType::Treasure => {
+                if !ps.chests.iter().any(|c| c.room_index == room_index) {
+                    let floor = ps.dungeon.floor_number;
+                    let chest_seed = ps
+                        .run_seed
+                        .wrapping_add(floor as u64 * 6151)
+                        .wrapping_add(room_index as u64 * 31337);
+                    // TreasureSense adds a second chest
+                    let count = 1 + usize::from(ps.boons.treasure_sense);
+                    ps.chests =
+                        chest::spawn_chests(room_index, &room.template, floor, chest_seed, count);
+                }
+                ps.room_state = RoomState::Peaceful;
+            }
+            dungeon::room_template::RoomType::Shop => {
+                if ps.shop.as_ref().map(|s| s.room_index) != Some(room_index) {
+                    let floor = ps.dungeon.floor_number;
+                    let shop_seed = ps
+                        .run_seed
+                        .wrapping_add(floor as u64 * 7919)
+                        .wrapping_add(room_index as u64 * 31337);
+                    ps.shop = Some(shop::Shop::new(
+                        room_index,
+                        &room.template,
+                        floor,
+                        shop_seed,
+                        &ps.boons.active,
+                        ps.boons.lucky,
+                        ps.pact.allows_rerolls(),
+                    ));
+                }
                 ps.room_state = RoomState::Peaceful;
             }
             dungeon::room_template::RoomType::Combat
@@ -1602,6 +2636,7 @@ impl CryptfallGame {
                     difficulty,
                     ps.dungeon.floor_number,
                     num_sp,
+                    ps.pact.extra_elite_chance(),
                     encounter_seed,
                 );
 
@@ -1615,6 +2650,7 @@ impl CryptfallGame {
                         room_index,
                         ps.spawn_seed,
                     );
+                    ps.pact.harden(&mut ps.enemies);
                 }
 
                 ps.wave_tracker = Some(tracker);
@@ -1632,21 +2668,30 @@ impl CryptfallGame {
         let ps = self.playing.as_mut().unwrap();
         ps.dungeon.next_floor();
         ps.tilemap = ps.dungeon.build_tilemap();
+        ps.traps = traps::spawn_traps(&ps.dungeon.current_room().template);
         let (px, py) = ps.dungeon.player_spawn_position(None);
         ps.player.transform.position.x = px;
         ps.player.transform.position.y = py;
         ps.player.transform.commit();
+        ps.player.status.clear();
         ps.enemies.clear();
+        ps.puddles.clear();
         ps.projectiles.clear();
         ps.particles.clear();
+        ps.particles.set_emitter_active(ps.dash_trail, false);
         ps.damage_numbers.clear();
         ps.pickups.clear();
         ps.wave_tracker = None;
         ps.room_state = RoomState::Peaceful;
         ps.floor_clear = false;
+        ps.hit_this_floor = false;
+        ps.shop = None;
+        ps.chests.clear();
 
         // Update floor tracking
         self.run_state.floor_reached = ps.dungeon.floor_number;
+        let reached = achievements::Event::FloorReached(ps.dungeon.floor_number);
+        ps.achievement_events.push(reached);
 
         // Reset per-floor boon state
         ps.boons.reset_floor_state();
@@ -1659,6 +2704,44 @@ impl CryptfallGame {
     }
 }
 
+/// Apply combat feedback, boon heals and kill gold from one combat check.
+fn apply_combat_effects(
+    ps: &mut PlayingState,
+    run_state: &mut run_state::RunState,
+    effects: combat::CombatEffects,
+) {
+    if effects.hit_pause_frames > 0 {
+        ps.hit_pause_frames = effects.hit_pause_frames;
+    }
+    if effects.camera_shake > 0.0 {
+        ps.camera.shake(effects.camera_shake);
+    }
+    if effects.heal > 0 && !ps.player.is_dead() {
+        ps.player.heal(ps.pact.scale_heal(effects.heal));
+    }
+    for gold in effects.kill_gold {
+        run_state.record_kill(gold);
+    }
+    run_state.record_damage_dealt(effects.damage_dealt);
+    run_state.record_damage_taken(effects.damage_taken);
+    if effects.killed_by.is_some() {
+        run_state.death_cause = effects.killed_by;
+    }
+    for kind in effects.killed {
+        ps.achievement_events.push(achievements::Event::Kill(kind));
+    }
+    ps.hit_this_floor |= effects.player_hit;
+    for (x, y) in effects.elite_drops {
+        ps.pickups
+            .push(Pickup::new(x - 2.5, y - 2.5, pickup::PickupType::SmallHeal));
+    }
+    run_state.record_knockback(effects.wall_slams, effects.environment_kills);
+    if effects.player_died {
+        ps.death_phase = DeathPhase::Dying;
+        ps.death_timer = 0.0;
+    }
+}
+
 fn render_debug_hitboxes(ps: &PlayingState, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
     let phb = ps.player.world_hurtbox();
     draw_aabb_outline(fb, &phb, cam_x, cam_y, color::GREEN);
@@ -1697,9 +2780,34 @@ fn draw_aabb_outline(
     }
 }
 
+/// Seed passed as `--seed <SEED>` or `--seed=<SEED>`.
+fn seed_arg() -> Option<u64> {
+    let mut args = std::env::args().skip(1);
+    let text = loop {
+        let arg = args.next()?;
+        if arg == "--seed" {
+            break args.next().unwrap_or_default();
+        }
+        if let Some(text) = arg.strip_prefix("--seed=") {
+            break text.to_string();
+        }
+    };
+    let seed = seed::parse_seed(&text);
+    if seed.is_none() {
+        eprintln!("Warning: ignoring --seed {text:?}: seeds are 0-9 and A-Z, up to 13 characters");
+    }
+    seed
+}
+
 fn main() -> std::io::Result<()> {
+    // Report bad definition files and arguments before the terminal takes
+    // over the screen
+    for err in defs::load() {
+        eprintln!("Warning: ignoring {err}");
+    }
+    let cli_seed = seed_arg();
     let mut terminal = engine::Terminal::new()?;
-    let mut game = CryptfallGame::new();
+    let mut game = CryptfallGame::new(cli_seed);
     engine::run(&mut terminal, &mut game);
     Ok(())
 }
@@ -0,0 +1,229 @@
+//! Pact modifiers: optional handicaps chosen before a run once every
+//! permanent upgrade is owned. Each rank adds heat, and heat raises the gold
+//! banked at the end of the run.
+
+use crate::enemies::Enemy;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PactId {
+    HardenedFoes,
+    EliteSurge,
+    Scarcity,
+    BossFrenzy,
+    NoRerolls,
+}
+
+pub struct PactDef {
+    pub id: PactId,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub max_rank: u32,
+    pub heat_per_rank: u32,
+}
+
+pub const PACTS: &[PactDef] = &[
+    PactDef {
+        id: PactId::HardenedFoes,
+        name: "Hardened Foes",
+        description: "+25% enemy HP",
+        max_rank: 4,
+        heat_per_rank: 1,
+    },
+    PactDef {
+        id: PactId::EliteSurge,
+        name: "Elite Surge",
+        description: "+10% elite chance",
+        max_rank: 3,
+        heat_per_rank: 1,
+    },
+    PactDef {
+        id: PactId::Scarcity,
+        name: "Scarcity",
+        description: "Heals restore less",
+        max_rank: 2,
+        heat_per_rank: 2,
+    },
+    PactDef {
+        id: PactId::BossFrenzy,
+        name: "Boss Frenzy",
+        description: "Bosses act 20% faster",
+        max_rank: 3,
+        heat_per_rank: 1,
+    },
+    PactDef {
+        id: PactId::NoRerolls,
+        name: "No Rerolls",
+        description: "No boon rerolls",
+        max_rank: 1,
+        heat_per_rank: 2,
+    },
+];
+
+/// Extra gold banked per point of heat, in percent.
+pub const HEAT_GOLD_PERCENT: u32 = 10;
+/// Non-boss enemy HP gained per Hardened Foes rank.
+const HARDENED_HP_PER_RANK: f32 = 0.25;
+/// Elite chance added per Elite Surge rank.
+const ELITE_CHANCE_PER_RANK: f32 = 0.10;
+/// Boss speed gained per Boss Frenzy rank.
+const BOSS_SPEED_PER_RANK: f64 = 0.2;
+
+pub fn pact_def(id: PactId) -> &'static PactDef {
+    PACTS
+        .iter()
+        .find(|p| p.id == id)
+        .expect("every pact has a def")
+}
+
+/// The ranks taken in each pact for one run.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Pact {
+    ranks: [u32; PACTS.len()],
+}
+
+impl Pact {
+    fn index(id: PactId) -> usize {
+        PACTS
+            .iter()
+            .position(|p| p.id == id)
+            .expect("every pact has a def")
+    }
+
+    pub fn rank(&self, id: PactId) -> u32 {
+        self.ranks[Self::index(id)]
+    }
+
+    /// Take one more rank. Returns false if the pact is already at its max.
+    pub fn raise(&mut self, id: PactId) -> bool {
+        let rank = &mut self.ranks[Self::index(id)];
+        if *rank >= pact_def(id).max_rank {
+            return false;
+        }
+        *rank += 1;
+        true
+    }
+
+    /// Drop one rank. Returns false if the pact wasn't taken.
+    pub fn lower(&mut self, id: PactId) -> bool {
+        let rank = &mut self.ranks[Self::index(id)];
+        if *rank == 0 {
+            return false;
+        }
+        *rank -= 1;
+        true
+    }
+
+    /// Total heat of every rank taken.
+    pub fn heat(&self) -> u32 {
+        PACTS
+            .iter()
+            .map(|def| self.rank(def.id) * def.heat_per_rank)
+            .sum()
+    }
+
+    /// Gold banked at the end of a run, after the heat bonus.
+    pub fn scale_gold(&self, gold: u32) -> u32 {
+        gold * (100 + self.heat() * HEAT_GOLD_PERCENT) / 100
+    }
+
+    /// Elite chance added on top of the floor's own.
+    pub fn extra_elite_chance(&self) -> f32 {
+        self.rank(PactId::EliteSurge) as f32 * ELITE_CHANCE_PER_RANK
+    }
+
+    /// HP restored by a heal after Scarcity: split by rank + 1, rounded up.
+    pub fn scale_heal(&self, amount: i32) -> i32 {
+        if amount <= 0 {
+            return amount;
+        }
+        let rank = self.rank(PactId::Scarcity) as i32;
+        ((amount + rank) / (1 + rank)).max(1)
+    }
+
+    /// Time scale for boss updates.
+    pub fn boss_speed(&self) -> f64 {
+        1.0 + self.rank(PactId::BossFrenzy) as f64 * BOSS_SPEED_PER_RANK
+    }
+
+    pub fn allows_rerolls(&self) -> bool {
+        self.rank(PactId::NoRerolls) == 0
+    }
+
+    /// Toughen freshly spawned enemies. Bosses keep their health so their
+    /// phase thresholds and health bars still line up.
+    pub fn harden(&self, enemies: &mut [Enemy]) {
+        let rank = self.rank(PactId::HardenedFoes);
+        if rank == 0 {
+            return;
+        }
+        let mult = 1.0 + rank as f32 * HARDENED_HP_PER_RANK;
+        for enemy in enemies.iter_mut().filter(|e| !e.enemy_type.is_boss()) {
+            enemy.scale_hp(mult);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pact(ranks: &[(PactId, u32)]) -> Pact {
+        let mut pact = Pact::default();
+        for &(id, n) in ranks {
+            for _ in 0..n {
+                assert!(pact.raise(id));
+            }
+        }
+        pact
+    }
+
+    #[test]
+    fn ranks_stop_at_their_max() {
+        let mut p = pact(&[(PactId::NoRerolls, 1)]);
+        assert!(!p.raise(PactId::NoRerolls));
+        assert!(!p.allows_rerolls());
+        assert!(p.lower(PactId::NoRerolls));
+        assert!(!p.lower(PactId::NoRerolls));
+        assert!(p.allows_rerolls());
+    }
+
+    #[test]
+    fn heat_sums_every_rank() {
+        assert_eq!(Pact::default().heat(), 0);
+        let p = pact(&[(PactId::HardenedFoes, 2), (PactId::Scarcity, 1)]);
+        assert_eq!(p.heat(), 4);
+        let max: u32 = PACTS.iter().map(|d| d.max_rank * d.heat_per_rank).sum();
+        let all: Vec<_> = PACTS.iter().map(|d| (d.id, d.max_rank)).collect();
+        assert_eq!(pact(&all).heat(), max);
+    }
+
+    #[test]
+    fn heat_multiplies_banked_gold() {
+        assert_eq!(Pact::default().scale_gold(57), 57);
+        let p = pact(&[(PactId::Scarcity, 2), (PactId::BossFrenzy, 1)]);
+        assert_eq!(p.scale_gold(100), 150);
+    }
+
+    #[test]
+    fn scarcity_shrinks_heals_but_keeps_one() {
+        let p = pact(&[(PactId::Scarcity, 1)]);
+        assert_eq!(p.scale_heal(3), 2);
+        assert_eq!(p.scale_heal(1), 1);
+        assert_eq!(p.scale_heal(0), 0);
+        let p = pact(&[(PactId::Scarcity, 2)]);
+        assert_eq!(p.scale_heal(3), 1);
+        assert_eq!(Pact::default().scale_heal(3), 3);
+    }
+
+    #[test]
+    fn hardened_foes_spare_bosses() {
+        let mut enemies = vec![
+            Enemy::new_skeleton(0.0, 0.0, 1),
+            Enemy::new_bone_king(0.0, 0.0, 1),
+        ];
+        let before: Vec<i32> = enemies.iter().map(|e| e.hp).collect();
+        pact(&[(PactId::HardenedFoes, 2)]).harden(&mut enemies);
+        assert_eq!(enemies[0].hp, (before[0] as f32 * 1.5).ceil() as i32);
+        assert_eq!(enemies[1].hp, before[1]);
+    }
+}
@@ -0,0 +1,139 @@
+use engine::{Color, FrameBuffer, GameKey, InputState};
+
+use crate::daily;
+use crate::pact::{Pact, HEAT_GOLD_PERCENT, PACTS};
+use crate::sprites::font::{render_text, text_width};
+use crate::weapons::{get_weapon, WeaponId};
+
+/// Heat readout color.
+const HEAT_COLOR: Color = [255, 120, 50];
+
+/// Pre-run screen for taking pact ranks. Up/down picks a pact, left/right
+/// changes its rank. Daily runs show their dealt pact here, locked.
+pub struct PactSelectScreen {
+    pub weapon: WeaponId,
+    pub pact: Pact,
+    selected: usize,
+    /// Best heat already won with this weapon.
+    best_heat: u32,
+    /// Daily challenge day, and whether this is its official attempt.
+    pub daily: Option<(u32, bool)>,
+    /// Seed the run was asked to use; None rolls a fresh one at the start.
+    pub seed: Option<u64>,
+}
+
+impl PactSelectScreen {
+    pub fn new(weapon: WeaponId, pact: Pact, best_heat: u32, seed: Option<u64>) -> Self {
+        Self {
+            weapon,
+            pact,
+            selected: 0,
+            best_heat,
+            daily: None,
+            seed,
+        }
+    }
+
+    /// Briefing for a day's challenge: its weapon and pact, locked in.
+    pub fn daily(day: u32, official: bool) -> Self {
+        let seed = Some(daily::seed(day));
+        Self {
+            daily: Some((day, official)),
+            ..Self::new(daily::weapon(day), daily::pact(day), 0, seed)
+        }
+    }
+
+    /// Handle input. Returns true once the player starts the run.
+    pub fn update(&mut self, input: &InputState) -> bool {
+        if input.is_pressed(GameKey::Up) && self.selected > 0 {
+            self.selected -= 1;
+        }
+        if input.is_pressed(GameKey::Down) && self.selected + 1 < PACTS.len() {
+            self.selected += 1;
+        }
+
+        // Everyone gets the same pact on a daily
+        if self.daily.is_none() {
+            let id = PACTS[self.selected].id;
+            if input.is_pressed(GameKey::Right) {
+                self.pact.raise(id);
+            }
+            if input.is_pressed(GameKey::Left) {
+                self.pact.lower(id);
+            }
+        }
+
+        input.is_pressed(GameKey::Attack)
+    }
+
+    pub fn render(&self, fb: &mut FrameBuffer) {
+        let fw = fb.width() as i32;
+        let fh = fb.height() as i32;
+
+        for y in 0..fh {
+            for x in 0..fw {
+                fb.set_pixel_safe(x, y, [12, 8, 10]);
+            }
+        }
+
+        let title = match self.daily {
+            Some((day, true)) => format!("DAILY {}", daily::date_label(day)),
+            Some((day, false)) => format!("PRACTICE {}", daily::date_label(day)),
+            None => "PACTS".to_string(),
+        };
+        let tw = text_width(&title);
+        render_text(fb, &title, (fw - tw) / 2, 4, [255, 220, 100]);
+
+        let heat = self.pact.heat();
+        let heat_str = format!("HEAT {heat}");
+        let hw = text_width(&heat_str);
+        render_text(fb, &heat_str, fw - hw - 4, 4, HEAT_COLOR);
+
+        let start_y = 16;
+        for (i, def) in PACTS.iter().enumerate() {
+            let is_selected = i == self.selected;
+            let rank = self.pact.rank(def.id);
+            let y = start_y + i as i32 * 7;
+
+            if is_selected {
+                render_text(fb, ">", 2, y, [255, 220, 100]);
+            }
+
+            let name_color: Color = if rank > 0 {
+                HEAT_COLOR
+            } else if is_selected {
+                [255, 255, 255]
+            } else {
+                [120, 120, 130]
+            };
+            render_text(fb, def.name, 8, y, name_color);
+
+            let rank_str = format!("{rank}/{}", def.max_rank);
+            let rw = text_width(&rank_str);
+            let rank_color: Color = if rank > 0 { HEAT_COLOR } else { [80, 80, 90] };
+            render_text(fb, &rank_str, fw - rw - 4, y, rank_color);
+        }
+
+        // What the highlighted pact does, and what each rank is worth
+        let def = &PACTS[self.selected];
+        let info_y = start_y + PACTS.len() as i32 * 7 + 3;
+        render_text(fb, def.description, 8, info_y, [160, 160, 170]);
+        let per_rank = format!("+{} HEAT PER RANK", def.heat_per_rank);
+        render_text(fb, &per_rank, 8, info_y + 7, [100, 100, 110]);
+
+        let bonus = format!("GOLD +{}%", heat * HEAT_GOLD_PERCENT);
+        render_text(fb, &bonus, 8, info_y + 16, [255, 200, 50]);
+        // A daily names its weapon; otherwise show the record to beat
+        let best = if self.daily.is_some() {
+            get_weapon(self.weapon).name.to_string()
+        } else {
+            format!("BEST HEAT {}", self.best_heat)
+        };
+        let bw = text_width(&best);
+        render_text(fb, &best, fw - bw - 4, info_y + 16, [140, 140, 160]);
+
+        let hint = "ATTACK - START  ESC - BACK";
+        let hw = text_width(hint);
+        render_text(fb, hint, (fw - hw) / 2, fh - 6, [80, 80, 90]);
+    }
+}
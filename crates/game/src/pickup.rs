@@ -7,10 +7,10 @@ use crate::sprites::pickups;
 /// Type of pickup item.
 pub enum PickupType {
     SmallHeal,
-    #[allow(dead_code)] // Phase 4: treasure rooms and boss drops
     BigHeal,
-    #[allow(dead_code)] // Phase 4: currency system
     Gold(u32),
+    /// Boss souls, banked into the save at the end of the run.
+    Soul(u32),
 }
 
 /// A collectible item in the world.
@@ -54,6 +54,7 @@ impl Pickup {
             PickupType::SmallHeal => &pickups::PICKUP_HEART_SMALL,
             PickupType::BigHeal => &pickups::PICKUP_HEART_BIG,
             PickupType::Gold(_) => &pickups::PICKUP_COIN,
+            PickupType::Soul(_) => &pickups::PICKUP_SOUL,
         };
 
         let px = self.x as i32 - cam_x;
@@ -71,6 +72,7 @@ impl Pickup {
             PickupType::SmallHeal => &pickups::PICKUP_HEART_SMALL,
             PickupType::BigHeal => &pickups::PICKUP_HEART_BIG,
             PickupType::Gold(_) => &pickups::PICKUP_COIN,
+            PickupType::Soul(_) => &pickups::PICKUP_SOUL,
         };
 
         let cx = self.x + sprite.width as f32 / 2.0;
@@ -88,7 +90,7 @@ impl Pickup {
         match self.pickup_type {
             PickupType::SmallHeal => 1,
             PickupType::BigHeal => 3,
-            PickupType::Gold(_) => 0,
+            PickupType::Gold(_) | PickupType::Soul(_) => 0,
         }
     }
 }
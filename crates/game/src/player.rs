@@ -1,16 +1,19 @@
-use engine::animation::AnimationPlayer;
+use engine::animation::{AnimEvent, AnimationPlayer};
 use engine::collision::AABB;
 use engine::input::{GameKey, InputState};
-use engine::tilemap::TileMap;
+use engine::tilemap::{TileMap, TileType};
 use engine::types::Transform;
 use engine::{Color, FrameBuffer};
 
 use crate::sprites;
-use crate::weapons::{self, WeaponDef, WeaponId};
+use crate::status::StatusEffects;
+use crate::weapons::{self, WeaponClass, WeaponDef, WeaponId};
 
 const PLAYER_SPEED: f32 = 60.0; // pixels per second
 const DASH_SPEED: f32 = 200.0; // pixels per second
 const DASH_DURATION: f32 = 0.15; // seconds
+/// Time after a dash ends before the next one, before boon reductions.
+pub const DASH_COOLDOWN: f32 = 0.3;
 
 // Collision box: 8×4 pixels at the feet of the 10×14 sprite
 const COLLISION_W: f32 = 8.0;
@@ -22,16 +25,43 @@ const PLAYER_HURTBOX: AABB = AABB::new(2.0, 3.0, 6.0, 8.0);
 const PLAYER_KNOCKBACK_SPEED: f32 = 100.0;
 const PLAYER_KNOCKBACK_FRICTION: f32 = 0.85;
 const DAMAGE_INVINCIBILITY: f32 = 1.0;
+/// How long the player is gone after falling into a pit.
+const FALL_DURATION: f32 = 0.5;
+
+/// Attacks pressed this long before they're allowed still come out.
+const ATTACK_BUFFER: f32 = 0.25;
+/// A hold shows as charging once it outlasts a tap.
+const CHARGE_SHOW_TIME: f32 = 0.2;
+/// Walking speed factor while charging a heavy attack.
+const CHARGE_MOVE_MULT: f32 = 0.6;
+
+const DRAW_COLOR: Color = [200, 170, 110];
+const DRAW_FULL_COLOR: Color = [255, 240, 150];
+const CHARGE_COLOR: Color = [180, 120, 255];
+const GAUGE_EMPTY_COLOR: Color = [50, 45, 60];
 
 pub enum PlayerState {
     Idle,
     Walking,
     Dashing,
     Attacking,
+    /// Holding a bow shot; pressing attack again looses it.
+    Drawing,
     Hit,
+    /// Dropped into a pit; respawns at the last safe tile.
+    Falling,
     Dead,
 }
 
+/// A ranged attack the player let fly on the last update; the caller spawns it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shot {
+    /// Bow arrow, after drawing for `draw` seconds
+    Arrow { draw: f32 },
+    /// Staff spell burst
+    Burst,
+}
+
 pub struct Player {
     pub transform: Transform,
     pub state: PlayerState,
@@ -45,9 +75,53 @@ pub struct Player {
     pub invincible_timer: f32,
     pub hp: i32,
     pub max_hp: i32,
+    /// Time until the next dash is allowed.
+    pub dash_cooldown: f32,
+    // Stat multipliers from boons
+    pub move_speed_mult: f32,
+    pub attack_speed_mult: f32,
+    pub dash_distance_mult: f32,
+    /// Walking speed factor from the ground underfoot (slime puddles), set by the caller.
+    pub ground_speed_mult: f32,
+    /// Last position with the feet fully on solid ground.
+    last_safe: (f32, f32),
+    fall_timer: f32,
+    /// Fell into a pit on the last update.
+    pub fell: bool,
+    /// Climbed back out of a pit on the last update; the caller applies the damage.
+    pub respawned: bool,
     knockback_vx: f32,
     knockback_vy: f32,
     weapon: &'static WeaponDef,
+    pub status: StatusEffects,
+    /// Damage status effects dealt on the last update; the caller applies it.
+    pub status_damage: i32,
+    /// Last direction moved in; ranged shots fly this way.
+    aim_x: f32,
+    aim_y: f32,
+    /// Seconds the current bow shot has been drawn.
+    draw_time: f32,
+    /// Draw time of the shot being loosed.
+    loosed_draw: f32,
+    /// Staff casts left before waiting for a recharge.
+    pub staff_charges: u32,
+    staff_recharge: f32,
+    pub shot: Option<Shot>,
+    /// Attack is being held this update; set by `update`.
+    attack_held: bool,
+    /// Seconds Attack has been held since its latest press.
+    hold_time: f32,
+    /// Time left on a buffered attack press.
+    attack_buffer: f32,
+    /// Time left on a heavy attack released while another action played out.
+    heavy_buffer: f32,
+    /// Next swing in the weapon's combo, and how long the chain stays open.
+    combo_step: usize,
+    combo_timer: f32,
+    /// Combo step of the current swing.
+    swing: usize,
+    /// The current swing is a heavy attack.
+    pub heavy: bool,
 }
 
 impl Player {
@@ -65,9 +139,35 @@ impl Player {
             invincible_timer: 0.0,
             hp: 5,
             max_hp: 5,
+            dash_cooldown: 0.0,
+            move_speed_mult: 1.0,
+            attack_speed_mult: 1.0,
+            dash_distance_mult: 1.0,
+            ground_speed_mult: 1.0,
+            last_safe: (x, y),
+            fall_timer: 0.0,
+            fell: false,
+            respawned: false,
             knockback_vx: 0.0,
             knockback_vy: 0.0,
             weapon: weapons::get_weapon(WeaponId::Sword),
+            status: StatusEffects::default(),
+            status_damage: 0,
+            aim_x: 1.0,
+            aim_y: 0.0,
+            draw_time: 0.0,
+            loosed_draw: 0.0,
+            staff_charges: weapons::STAFF_CHARGES,
+            staff_recharge: weapons::STAFF_RECHARGE,
+            shot: None,
+            attack_held: false,
+            hold_time: 0.0,
+            attack_buffer: 0.0,
+            heavy_buffer: 0.0,
+            combo_step: 0,
+            combo_timer: 0.0,
+            swing: 0,
+            heavy: false,
         }
     }
 
@@ -77,6 +177,36 @@ impl Player {
 
     pub fn equip_weapon(&mut self, id: WeaponId) {
         self.weapon = weapons::get_weapon(id);
+        self.combo_step = 0;
+    }
+
+    /// Damage of the current swing before boons.
+    pub fn attack_damage(&self) -> i32 {
+        if self.heavy {
+            self.weapon.base_damage * weapons::HEAVY_DAMAGE_MULT
+        } else {
+            self.weapon.base_damage + self.weapon.combo[self.swing].bonus_damage
+        }
+    }
+
+    /// Knockback of the current swing.
+    pub fn attack_knockback(&self) -> f32 {
+        let mult = if self.heavy {
+            weapons::HEAVY_KNOCKBACK_MULT
+        } else {
+            self.weapon.combo[self.swing].knockback_mult
+        };
+        self.weapon.knockback_force * mult
+    }
+
+    /// Charging a heavy attack: Attack held past a tap with a melee weapon.
+    pub fn is_charging(&self) -> bool {
+        self.weapon.class == WeaponClass::Melee && self.hold_time >= CHARGE_SHOW_TIME
+    }
+
+    /// How charged the heavy attack is, from 0 to 1.
+    pub fn charge_fraction(&self) -> f32 {
+        (self.hold_time / weapons::HEAVY_CHARGE_TIME).min(1.0)
     }
 
     /// Player center in world pixels (for camera following).
@@ -87,6 +217,14 @@ impl Player {
         )
     }
 
+    /// Middle of the collision box at the player's feet.
+    pub fn feet(&self) -> (f32, f32) {
+        (
+            self.transform.position.x + COLLISION_OFFSET_X + COLLISION_W / 2.0,
+            self.transform.position.y + COLLISION_OFFSET_Y + COLLISION_H / 2.0,
+        )
+    }
+
     pub fn is_dashing(&self) -> bool {
         matches!(self.state, PlayerState::Dashing)
     }
@@ -96,12 +234,35 @@ impl Player {
         matches!(self.state, PlayerState::Attacking)
     }
 
+    pub fn is_drawing(&self) -> bool {
+        matches!(self.state, PlayerState::Drawing)
+    }
+
+    /// How far the bow is drawn, from 0 to 1.
+    pub fn draw_fraction(&self) -> f32 {
+        (self.draw_time / weapons::BOW_FULL_DRAW).min(1.0)
+    }
+
+    /// Unit direction ranged shots travel.
+    pub fn aim(&self) -> (f32, f32) {
+        (self.aim_x, self.aim_y)
+    }
+
+    /// Restore `amount` HP, up to max. Heals pass through the pact first.
+    pub fn heal(&mut self, amount: i32) {
+        self.hp = (self.hp + amount).min(self.max_hp);
+    }
+
     pub fn is_dead(&self) -> bool {
         matches!(self.state, PlayerState::Dead)
     }
 
+    pub fn is_falling(&self) -> bool {
+        matches!(self.state, PlayerState::Falling)
+    }
+
     pub fn is_invincible(&self) -> bool {
-        self.invincible_timer > 0.0 || self.is_dashing()
+        self.invincible_timer > 0.0 || self.is_dashing() || self.is_falling()
     }
 
     /// Apply damage to the player. Returns true if the player died.
@@ -126,14 +287,34 @@ impl Player {
         }
     }
 
+    /// Damage over time from status effects: no i-frames or knockback, and it
+    /// never takes the last heart. Returns the HP actually lost.
+    pub fn take_status_damage(&mut self, dmg: i32) -> i32 {
+        if self.is_dead() {
+            return 0;
+        }
+        let lost = dmg.min(self.hp - 1).max(0);
+        self.hp -= lost;
+        lost
+    }
+
+    /// A hit was absorbed (Iron Shield): i-frames and knockback, no damage.
+    pub fn block_hit(&mut self, kb_dir_x: f32, kb_dir_y: f32) {
+        self.invincible_timer = DAMAGE_INVINCIBILITY;
+        self.knockback_vx = kb_dir_x * PLAYER_KNOCKBACK_SPEED * 0.5;
+        self.knockback_vy = kb_dir_y * PLAYER_KNOCKBACK_SPEED * 0.5;
+    }
+
     pub fn update(&mut self, input: &InputState, dt: f64, tilemap: &TileMap) {
         let (dx, dy) = input.direction();
         let attack = input.is_pressed(GameKey::Attack);
         let dash = input.is_pressed(GameKey::Dash);
+        self.attack_held = input.is_held(GameKey::Attack);
         self.update_with_input(dx, dy, attack, dash, dt, tilemap);
     }
 
     /// Core update logic with explicit inputs (used by both normal play and demo mode).
+    /// Demo mode never holds Attack, so heavy attacks only come from `update`.
     pub fn update_with_input(
         &mut self,
         dx: f32,
@@ -146,6 +327,10 @@ impl Player {
         self.transform.commit();
 
         let dt_f32 = dt as f32;
+        self.fell = false;
+        self.respawned = false;
+        self.status_damage = 0;
+        self.shot = None;
 
         // Apply knockback velocity (works in all states including Hit/Dead)
         if self.knockback_vx.abs() > 0.5 || self.knockback_vy.abs() > 0.5 {
@@ -163,6 +348,47 @@ impl Player {
             return;
         }
 
+        self.status_damage = self.status.update(dt_f32);
+        // Frozen or stunned: inputs are ignored and the current pose holds
+        let can_act = self.status.can_act();
+        let attack_held = std::mem::take(&mut self.attack_held);
+        let (dx, dy, attack, attack_held, dash) = if can_act {
+            (dx, dy, attack, attack_held, dash)
+        } else {
+            (0.0, 0.0, false, false, false)
+        };
+
+        // Terminals only report presses and repeats, so a hold is timed from
+        // the latest press (the first repeat, for a key held down) for as
+        // long as repeats keep coming. Letting go of a full charge queues a
+        // heavy attack.
+        if attack {
+            self.attack_buffer = ATTACK_BUFFER;
+            self.hold_time = 0.0;
+        } else if attack_held {
+            self.hold_time += dt_f32;
+        } else {
+            if self.is_charging() && self.charge_fraction() >= 1.0 {
+                self.heavy_buffer = ATTACK_BUFFER;
+            }
+            self.hold_time = 0.0;
+        }
+
+        if self.is_falling() {
+            self.fall_timer -= dt_f32;
+            if self.fall_timer <= 0.0 {
+                self.transform.position.x = self.last_safe.0;
+                self.transform.position.y = self.last_safe.1;
+                self.transform.commit();
+                self.knockback_vx = 0.0;
+                self.knockback_vy = 0.0;
+                self.state = PlayerState::Idle;
+                self.animation.play(&sprites::IDLE_ANIM);
+                self.respawned = true;
+            }
+            return;
+        }
+
         // Tick cooldowns
         if self.attack_cooldown > 0.0 {
             self.attack_cooldown -= dt_f32;
@@ -170,6 +396,25 @@ impl Player {
         if self.invincible_timer > 0.0 {
             self.invincible_timer -= dt_f32;
         }
+        if self.dash_cooldown > 0.0 {
+            self.dash_cooldown -= dt_f32;
+        }
+        if self.attack_buffer > 0.0 {
+            self.attack_buffer -= dt_f32;
+        }
+        if self.heavy_buffer > 0.0 {
+            self.heavy_buffer -= dt_f32;
+        }
+        if self.combo_timer > 0.0 {
+            self.combo_timer -= dt_f32;
+        }
+        if self.staff_charges < weapons::STAFF_CHARGES {
+            self.staff_recharge -= dt_f32;
+            if self.staff_recharge <= 0.0 {
+                self.staff_charges += 1;
+                self.staff_recharge = weapons::STAFF_RECHARGE;
+            }
+        }
 
         // Update facing direction (not during attack or hit — keep facing)
         if !matches!(self.state, PlayerState::Attacking | PlayerState::Hit) {
@@ -178,6 +423,10 @@ impl Player {
             } else if dx < 0.0 {
                 self.facing_right = false;
             }
+            if dx != 0.0 || dy != 0.0 {
+                self.aim_x = dx;
+                self.aim_y = dy;
+            }
         }
 
         // Determine movement based on state
@@ -185,6 +434,7 @@ impl Player {
             PlayerState::Attacking => {
                 // Locked in place during attack; wait for animation to finish
                 if self.animation.is_finished() {
+                    self.combo_timer = weapons::COMBO_WINDOW;
                     if dx != 0.0 || dy != 0.0 {
                         self.state = PlayerState::Walking;
                         self.animation.play(&sprites::WALK_ANIM);
@@ -207,7 +457,25 @@ impl Player {
                 }
                 (0.0, 0.0)
             }
-            PlayerState::Dead => (0.0, 0.0),
+            PlayerState::Dead | PlayerState::Falling => (0.0, 0.0),
+            PlayerState::Drawing => {
+                self.draw_time += dt_f32;
+                if dash && (dx != 0.0 || dy != 0.0) && self.dash_cooldown <= 0.0 {
+                    // Dashing out drops the shot
+                    self.start_dash(dx, dy, dt_f32)
+                } else if attack {
+                    self.loosed_draw = self.draw_time;
+                    self.start_attack();
+                    (0.0, 0.0)
+                } else {
+                    let speed = PLAYER_SPEED
+                        * weapons::BOW_DRAW_MOVE_MULT
+                        * self.move_speed_mult
+                        * self.ground_speed_mult
+                        * self.status.speed_mult();
+                    (dx * speed * dt_f32, dy * speed * dt_f32)
+                }
+            }
             PlayerState::Dashing => {
                 self.dash_timer -= dt_f32;
                 let mx = self.dash_dx * DASH_SPEED * dt_f32;
@@ -224,23 +492,45 @@ impl Player {
                 (mx, my)
             }
             _ => {
-                // Attack takes priority over dash (only if cooldown expired)
-                if attack && self.attack_cooldown <= 0.0 {
-                    self.state = PlayerState::Attacking;
-                    self.attack_cooldown = self.weapon.attack_cooldown;
-                    self.animation.play(&sprites::ATTACK_ANIM);
+                // Attack takes priority over dash (only if cooldown expired).
+                // Presses buffered during a dash or recovery land here.
+                let out_of_charges =
+                    self.weapon.class == WeaponClass::Staff && self.staff_charges == 0;
+                let ready = self.attack_cooldown <= 0.0;
+                if self.heavy_buffer > 0.0 && ready {
+                    self.start_heavy();
+                    (0.0, 0.0)
+                } else if self.attack_buffer > 0.0 && ready && !out_of_charges {
+                    self.attack_buffer = 0.0;
+                    match self.weapon.class {
+                        WeaponClass::Bow => {
+                            self.state = PlayerState::Drawing;
+                            self.draw_time = 0.0;
+                            self.animation.play(&sprites::BOW_DRAW_ANIM);
+                        }
+                        WeaponClass::Staff => {
+                            self.staff_charges -= 1;
+                            self.start_attack();
+                        }
+                        WeaponClass::Melee => self.start_attack(),
+                    }
                     (0.0, 0.0)
-                } else if dash && (dx != 0.0 || dy != 0.0) {
-                    self.state = PlayerState::Dashing;
-                    self.dash_timer = DASH_DURATION;
-                    self.dash_dx = dx;
-                    self.dash_dy = dy;
-                    self.animation.play(&sprites::DASH_ANIM);
-                    (dx * DASH_SPEED * dt_f32, dy * DASH_SPEED * dt_f32)
+                } else if dash && (dx != 0.0 || dy != 0.0) && self.dash_cooldown <= 0.0 {
+                    self.start_dash(dx, dy, dt_f32)
                 } else if dx != 0.0 || dy != 0.0 {
                     self.state = PlayerState::Walking;
                     self.animation.play(&sprites::WALK_ANIM);
-                    (dx * PLAYER_SPEED * dt_f32, dy * PLAYER_SPEED * dt_f32)
+                    let charge_mult = if self.is_charging() {
+                        CHARGE_MOVE_MULT
+                    } else {
+                        1.0
+                    };
+                    let speed = PLAYER_SPEED
+                        * self.move_speed_mult
+                        * self.ground_speed_mult
+                        * self.status.speed_mult()
+                        * charge_mult;
+                    (dx * speed * dt_f32, dy * speed * dt_f32)
                 } else {
                     self.state = PlayerState::Idle;
                     self.animation.play(&sprites::IDLE_ANIM);
@@ -252,14 +542,86 @@ impl Player {
         // Apply movement with collision (wall sliding)
         self.try_move(move_x, move_y, tilemap);
 
+        // Pits: dashes carry the player over, anything else drops in
+        if !self.is_dashing() {
+            let feet_x = self.transform.position.x + COLLISION_OFFSET_X;
+            let feet_y = self.transform.position.y + COLLISION_OFFSET_Y;
+            let (fcx, fcy) = self.feet();
+            if tilemap.tile_at(fcx, fcy) == TileType::Pit {
+                self.state = PlayerState::Falling;
+                self.fall_timer = FALL_DURATION;
+                self.knockback_vx = 0.0;
+                self.knockback_vy = 0.0;
+                self.fell = true;
+                return;
+            } else if !tilemap.overlaps_tile(
+                feet_x,
+                feet_y,
+                COLLISION_W,
+                COLLISION_H,
+                TileType::Pit,
+            ) {
+                self.last_safe = (self.transform.position.x, self.transform.position.y);
+            }
+        }
+
         self.animation.set_flipped(!self.facing_right);
-        self.animation.update(dt);
+        if can_act {
+            self.animation.update(dt);
+        }
+
+        // Hitbox window comes from the weapon animation's frame events;
+        // for ranged weapons the same event marks when the shot leaves
+        let attacking = matches!(self.state, PlayerState::Attacking);
+        let melee = self.weapon.class == WeaponClass::Melee;
+        self.attack_active = attacking && melee && self.animation.hitbox_active();
+        if attacking && self.animation.events().contains(&AnimEvent::HitboxOn) {
+            self.shot = match self.weapon.class {
+                WeaponClass::Bow => Some(Shot::Arrow {
+                    draw: self.loosed_draw,
+                }),
+                WeaponClass::Staff => Some(Shot::Burst),
+                WeaponClass::Melee => None,
+            };
+        }
+    }
+
+    /// Swing the next step of the weapon's combo, or the first if the chain lapsed.
+    fn start_attack(&mut self) {
+        if self.combo_timer <= 0.0 {
+            self.combo_step = 0;
+        }
+        self.swing = self.combo_step;
+        self.combo_step = (self.combo_step + 1) % self.weapon.combo.len();
+        self.combo_timer = 0.0;
+        self.heavy = false;
+        self.attack_buffer = 0.0;
+        self.state = PlayerState::Attacking;
+        self.attack_cooldown = self.weapon.attack_cooldown * self.attack_speed_mult;
+        self.animation.play(self.weapon.combo[self.swing].anim);
+    }
 
-        // Track whether the attack hitbox is active this frame
-        let frame = self.animation.current_frame();
-        self.attack_active = matches!(self.state, PlayerState::Attacking)
-            && frame >= self.weapon.active_frame_start
-            && frame <= self.weapon.active_frame_end;
+    /// Unleash a charged heavy attack. It breaks any combo in progress.
+    fn start_heavy(&mut self) {
+        self.combo_step = 0;
+        self.combo_timer = 0.0;
+        self.heavy = true;
+        self.heavy_buffer = 0.0;
+        self.attack_buffer = 0.0;
+        self.state = PlayerState::Attacking;
+        self.attack_cooldown = self.weapon.attack_cooldown * self.attack_speed_mult;
+        self.animation.play(&sprites::HEAVY_ATTACK_ANIM);
+    }
+
+    /// Begin a dash along (dx, dy). Returns this tick's movement.
+    fn start_dash(&mut self, dx: f32, dy: f32, dt: f32) -> (f32, f32) {
+        self.state = PlayerState::Dashing;
+        // Longer dashes cover more ground at the same speed
+        self.dash_timer = DASH_DURATION * self.dash_distance_mult;
+        self.dash_dx = dx;
+        self.dash_dy = dy;
+        self.animation.play(&sprites::DASH_ANIM);
+        (dx * DASH_SPEED * dt, dy * DASH_SPEED * dt)
     }
 
     /// Returns the world-space attack hitbox, only when the active frame is live.
@@ -269,7 +631,12 @@ impl Player {
         }
         let px = self.transform.position.x;
         let py = self.transform.position.y;
-        let w = self.weapon.hitbox_w;
+        let reach = if self.heavy {
+            weapons::HEAVY_REACH_MULT
+        } else {
+            1.0
+        };
+        let w = self.weapon.hitbox_w * reach;
         let h = self.weapon.hitbox_h;
         let ox = self.weapon.hitbox_offset_x;
         let oy = self.weapon.hitbox_offset_y;
@@ -333,6 +700,9 @@ impl Player {
         cam_y: i32,
         tint: Option<Color>,
     ) {
+        if self.is_falling() {
+            return;
+        }
         let sprite = self.animation.current_sprite();
         let pos = self.transform.interpolated(alpha);
         let px = pos.x as i32 - cam_x;
@@ -345,5 +715,157 @@ impl Player {
             (false, Some(t)) => fb.blit_sprite_tinted(sprite, px, py, t),
             (true, Some(t)) => fb.blit_sprite_flipped_tinted(sprite, px, py, t),
         }
+
+        // Bow draw and heavy charge gauges fill over the head; staff
+        // charges show as pips
+        let gauge = if self.is_drawing() {
+            Some(self.draw_fraction())
+        } else if self.is_charging() {
+            Some(self.charge_fraction())
+        } else {
+            None
+        };
+        if let Some(fraction) = gauge {
+            let filled = (fraction * 10.0) as i32;
+            let color = if filled >= 10 {
+                DRAW_FULL_COLOR
+            } else {
+                DRAW_COLOR
+            };
+            for i in 0..10 {
+                let c = if i < filled { color } else { GAUGE_EMPTY_COLOR };
+                fb.set_pixel_safe(px + i, py - 3, c);
+            }
+        } else if self.weapon.class == WeaponClass::Staff
+            && self.staff_charges < weapons::STAFF_CHARGES
+        {
+            for i in 0..weapons::STAFF_CHARGES {
+                let c = if i < self.staff_charges {
+                    CHARGE_COLOR
+                } else {
+                    GAUGE_EMPTY_COLOR
+                };
+                fb.set_pixel_safe(px + 1 + i as i32 * 2, py - 3, c);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f64 = 1.0 / 60.0;
+
+    fn player(id: WeaponId) -> (Player, TileMap) {
+        let mut player = Player::new(40.0, 40.0);
+        player.equip_weapon(id);
+        (player, TileMap::new(20, 20))
+    }
+
+    /// One update standing still; `held` is Attack still down from a press.
+    fn tick(player: &mut Player, map: &TileMap, attack: bool, held: bool) {
+        player.attack_held = held;
+        player.update_with_input(0.0, 0.0, attack, false, DT, map);
+    }
+
+    fn idle_for(player: &mut Player, map: &TileMap, secs: f32) {
+        for _ in 0..(secs / DT as f32).ceil() as u32 {
+            tick(player, map, false, false);
+        }
+    }
+
+    fn attacking(player: &Player) -> bool {
+        matches!(player.state, PlayerState::Attacking)
+    }
+
+    #[test]
+    fn test_press_during_recovery_is_buffered_into_the_next_swing() {
+        let (mut player, map) = player(WeaponId::Sword);
+        tick(&mut player, &map, true, false);
+        assert!(attacking(&player) && player.swing == 0);
+
+        idle_for(&mut player, &map, 0.2);
+        assert!(attacking(&player) && player.attack_cooldown > 0.0);
+        tick(&mut player, &map, true, false);
+        assert_eq!(player.swing, 0, "the press waits for the swing to end");
+
+        for _ in 0..(ATTACK_BUFFER / DT as f32) as u32 {
+            tick(&mut player, &map, false, false);
+            if attacking(&player) && player.swing == 1 {
+                return;
+            }
+        }
+        panic!("buffered press never swung");
+    }
+
+    #[test]
+    fn test_combo_resets_once_the_window_lapses() {
+        let (mut player, map) = player(WeaponId::Sword);
+        // Long enough for a swing to finish and its cooldown to run out
+        let recovery = player.weapon.attack_cooldown + 0.05;
+        tick(&mut player, &map, true, false);
+        idle_for(&mut player, &map, recovery);
+        tick(&mut player, &map, true, false);
+        assert_eq!(player.swing, 1);
+
+        idle_for(&mut player, &map, recovery + weapons::COMBO_WINDOW);
+        tick(&mut player, &map, true, false);
+        assert!(attacking(&player));
+        assert_eq!(player.swing, 0);
+    }
+
+    #[test]
+    fn test_hold_past_charge_time_releases_a_heavy_attack() {
+        let (mut player, map) = player(WeaponId::Sword);
+        tick(&mut player, &map, true, false);
+        let hold_ticks = ((weapons::HEAVY_CHARGE_TIME + 0.1) / DT as f32) as u32;
+        for _ in 0..hold_ticks {
+            tick(&mut player, &map, false, true);
+        }
+        assert!(player.is_charging() && player.charge_fraction() >= 1.0);
+        assert!(!player.heavy);
+
+        tick(&mut player, &map, false, false);
+        assert!(attacking(&player) && player.heavy);
+        assert_eq!(player.attack_damage(), 2 * weapons::HEAVY_DAMAGE_MULT);
+    }
+
+    #[test]
+    fn test_short_hold_is_just_a_tap() {
+        let (mut player, map) = player(WeaponId::Sword);
+        tick(&mut player, &map, true, false);
+        for _ in 0..((weapons::HEAVY_CHARGE_TIME / 2.0) / DT as f32) as u32 {
+            tick(&mut player, &map, false, true);
+        }
+        idle_for(&mut player, &map, 0.5);
+        assert!(!player.heavy && !attacking(&player));
+    }
+
+    #[test]
+    fn test_staff_charges_run_out_and_regenerate() {
+        let (mut player, map) = player(WeaponId::Staff);
+        let mut casts = Vec::new();
+        let mut emptied = false;
+        for t in 0..(2.0 / DT) as u32 {
+            let was_attacking = attacking(&player);
+            tick(&mut player, &map, true, false);
+            if !was_attacking && attacking(&player) {
+                casts.push(t as f32 * DT as f32);
+            }
+            emptied |= player.staff_charges == 0;
+        }
+        assert!(emptied);
+        // Mashing spends the full staff, then waits on the recharge
+        let charges = weapons::STAFF_CHARGES as usize;
+        assert!(casts.len() > charges);
+        assert!(casts[charges] - casts[0] >= weapons::STAFF_RECHARGE);
+
+        // One charge more than empty covers the last mashed press still buffered
+        let refill = weapons::STAFF_RECHARGE * (charges + 1) as f32;
+        idle_for(&mut player, &map, refill + 0.1);
+        assert_eq!(player.staff_charges, weapons::STAFF_CHARGES);
+        idle_for(&mut player, &map, weapons::STAFF_RECHARGE);
+        assert_eq!(player.staff_charges, weapons::STAFF_CHARGES);
     }
 }
@@ -1,11 +1,17 @@
 use engine::collision::AABB;
 use engine::tilemap::TileMap;
-use engine::FrameBuffer;
+use engine::{FrameBuffer, SpriteData};
 
-use crate::sprites::effects::PROJECTILE_ORB;
+use crate::enemies::{Enemy, EnemyType};
+use crate::sprites::effects::{ARROW_HEAD, PROJECTILE_ORB, SLASH_ORB, SPELL_BOLT};
+use crate::status::StatusKind;
 
 const PROJECTILE_SPEED: f32 = 80.0;
 const PROJECTILE_LIFETIME: f32 = 2.0;
+const ARROW_SPEED: f32 = 180.0;
+const BOLT_SPEED: f32 = 110.0;
+/// Staff bolts fizzle out after this long, which sets their short range.
+const BOLT_LIFETIME: f32 = 0.35;
 const PROJECTILE_HITBOX: AABB = AABB::new(0.0, 0.0, 3.0, 3.0);
 const TRAIL_INTERVAL: f32 = 0.05;
 
@@ -15,9 +21,21 @@ pub struct Projectile {
     vx: f32,
     vy: f32,
     pub lifetime: f32,
+    max_lifetime: f32,
     pub alive: bool,
     trail_timer: f32,
     pub damage: i32,
+    /// Fired by the player: hits enemies instead of the player.
+    pub friendly: bool,
+    /// Effect left on whoever it hits.
+    pub status: Option<StatusKind>,
+    /// Enemy that fired it; None for traps and the player.
+    pub shooter: Option<EnemyType>,
+    /// Enemies it can still pass through before stopping.
+    pierce: u32,
+    /// Indices of enemies already pierced, so each is hit once.
+    pierced: Vec<usize>,
+    sprite: &'static SpriteData,
 }
 
 impl Projectile {
@@ -28,9 +46,32 @@ impl Projectile {
             vx: dir_x * PROJECTILE_SPEED,
             vy: dir_y * PROJECTILE_SPEED,
             lifetime: 0.0,
+            max_lifetime: PROJECTILE_LIFETIME,
             alive: true,
             trail_timer: 0.0,
             damage: 1,
+            friendly: false,
+            status: None,
+            shooter: None,
+            pierce: 0,
+            pierced: Vec::new(),
+            sprite: &PROJECTILE_ORB,
+        }
+    }
+
+    fn friendly(
+        x: f32,
+        y: f32,
+        dir_x: f32,
+        dir_y: f32,
+        damage: i32,
+        sprite: &'static SpriteData,
+    ) -> Self {
+        Self {
+            damage,
+            friendly: true,
+            sprite,
+            ..Self::new(x, y, dir_x, dir_y)
         }
     }
 
@@ -55,7 +96,7 @@ impl Projectile {
         }
 
         // Check lifetime
-        if self.lifetime >= PROJECTILE_LIFETIME {
+        if self.lifetime >= self.max_lifetime {
             self.alive = false;
             return false;
         }
@@ -76,7 +117,7 @@ impl Projectile {
         }
         let px = self.x as i32 - cam_x;
         let py = self.y as i32 - cam_y;
-        fb.blit_sprite(&PROJECTILE_ORB, px, py);
+        fb.blit_sprite(self.sprite, px, py);
     }
 }
 
@@ -95,6 +136,47 @@ impl ProjectileSystem {
         self.projectiles.push(Projectile::new(x, y, dir_x, dir_y));
     }
 
+    /// Spawn a projectile fired by `shooter`, leaving its status effect (if
+    /// any) on the player.
+    pub fn spawn_enemy(&mut self, x: f32, y: f32, dir_x: f32, dir_y: f32, shooter: EnemyType) {
+        let mut proj = Projectile::new(x, y, dir_x, dir_y);
+        proj.status = shooter.inflicts();
+        proj.shooter = Some(shooter);
+        self.projectiles.push(proj);
+    }
+
+    /// Spawn a player projectile that damages enemies.
+    pub fn spawn_friendly(&mut self, x: f32, y: f32, dir_x: f32, dir_y: f32, damage: i32) {
+        self.projectiles
+            .push(Projectile::friendly(x, y, dir_x, dir_y, damage, &SLASH_ORB));
+    }
+
+    /// Spawn a player arrow that passes through `pierce` enemies before stopping.
+    pub fn spawn_arrow(
+        &mut self,
+        x: f32,
+        y: f32,
+        dir_x: f32,
+        dir_y: f32,
+        damage: i32,
+        pierce: u32,
+    ) {
+        let mut proj = Projectile::friendly(x, y, dir_x, dir_y, damage, &ARROW_HEAD);
+        proj.vx = dir_x * ARROW_SPEED;
+        proj.vy = dir_y * ARROW_SPEED;
+        proj.pierce = pierce;
+        self.projectiles.push(proj);
+    }
+
+    /// Spawn a short-lived player spell bolt.
+    pub fn spawn_bolt(&mut self, x: f32, y: f32, dir_x: f32, dir_y: f32, damage: i32) {
+        let mut proj = Projectile::friendly(x, y, dir_x, dir_y, damage, &SPELL_BOLT);
+        proj.vx = dir_x * BOLT_SPEED;
+        proj.vy = dir_y * BOLT_SPEED;
+        proj.max_lifetime = BOLT_LIFETIME;
+        self.projectiles.push(proj);
+    }
+
     /// Update all projectiles. Returns positions where trail particles should spawn
     /// and positions where impact particles should spawn (wall hits).
     #[allow(clippy::type_complexity)]
@@ -124,22 +206,56 @@ impl ProjectileSystem {
         (trail_positions, impact_positions)
     }
 
-    /// Check projectiles against the player hurtbox. Returns total damage and
-    /// positions of hits for particle effects.
-    pub fn check_player_hits(&mut self, player_hurtbox: &AABB) -> Vec<(f32, f32, i32)> {
+    /// Check projectiles against the player hurtbox. Returns the position,
+    /// damage and status effect of each hit.
+    #[allow(clippy::type_complexity)]
+    pub fn check_player_hits(
+        &mut self,
+        player_hurtbox: &AABB,
+    ) -> Vec<(f32, f32, i32, Option<StatusKind>, Option<EnemyType>)> {
         let mut hits = Vec::with_capacity(8);
         for proj in &mut self.projectiles {
-            if !proj.alive {
+            if !proj.alive || proj.friendly {
                 continue;
             }
             if proj.world_hitbox().overlaps(player_hurtbox) {
-                hits.push((proj.x + 1.5, proj.y + 1.5, proj.damage));
+                let at = (proj.x + 1.5, proj.y + 1.5);
+                hits.push((at.0, at.1, proj.damage, proj.status, proj.shooter));
                 proj.alive = false;
             }
         }
         hits
     }
 
+    /// Check player projectiles against enemy hurtboxes. Each projectile hits
+    /// at most one enemy per check, and stops at the first one unless it
+    /// pierces. Returns (enemy index, hit x, hit y, damage).
+    pub fn check_enemy_hits(&mut self, enemies: &[Enemy]) -> Vec<(usize, f32, f32, i32)> {
+        let mut hits = Vec::new();
+        for proj in &mut self.projectiles {
+            if !proj.alive || !proj.friendly {
+                continue;
+            }
+            let hitbox = proj.world_hitbox();
+            let target = enemies.iter().enumerate().position(|(i, e)| {
+                e.alive
+                    && e.hp > 0
+                    && !proj.pierced.contains(&i)
+                    && hitbox.overlaps(&e.world_hurtbox())
+            });
+            if let Some(idx) = target {
+                hits.push((idx, proj.x + 1.5, proj.y + 1.5, proj.damage));
+                if proj.pierce > 0 {
+                    proj.pierce -= 1;
+                    proj.pierced.push(idx);
+                } else {
+                    proj.alive = false;
+                }
+            }
+        }
+        hits
+    }
+
     pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
         for proj in &self.projectiles {
             proj.render(fb, cam_x, cam_y);
@@ -155,3 +271,34 @@ impl ProjectileSystem {
         self.projectiles.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piercing_arrow_hits_each_enemy_once_then_stops() {
+        let enemies: Vec<Enemy> = (0..4).map(|i| Enemy::new_skeleton(40.0, 40.0, i)).collect();
+        let (cx, cy) = enemies[0].world_hurtbox().center();
+        let mut system = ProjectileSystem::new();
+        system.spawn_arrow(cx - 1.5, cy - 1.5, 1.0, 0.0, 2, 2);
+
+        // Passes through two, stops in the third and never touches the fourth
+        let mut struck = Vec::new();
+        for _ in 0..5 {
+            struck.extend(system.check_enemy_hits(&enemies).iter().map(|h| h.0));
+        }
+        assert_eq!(struck, [0, 1, 2]);
+        assert!(!system.projectiles[0].alive);
+    }
+
+    #[test]
+    fn test_arrow_without_pierce_stops_at_the_first_enemy() {
+        let enemies = vec![Enemy::new_skeleton(40.0, 40.0, 1)];
+        let (cx, cy) = enemies[0].world_hurtbox().center();
+        let mut system = ProjectileSystem::new();
+        system.spawn_arrow(cx - 1.5, cy - 1.5, 1.0, 0.0, 2, 0);
+        assert_eq!(system.check_enemy_hits(&enemies).len(), 1);
+        assert!(!system.projectiles[0].alive);
+    }
+}
@@ -0,0 +1,148 @@
+//! Slime puddles: goo left where slimes land and burst. Walking through it
+//! slows the player; dashes skim straight over.
+
+use engine::{Color, FrameBuffer};
+
+use crate::enemies::slime::SlimeSize;
+
+/// Walking speed multiplier while standing in goo.
+pub const PUDDLE_SLOW: f32 = 0.5;
+/// Past this many, the oldest puddle dries up to make room.
+const MAX_PUDDLES: usize = 24;
+const LANDING_LIFETIME: f32 = 2.5;
+const BURST_LIFETIME: f32 = 6.0;
+/// Puddles fade out over their last second.
+const FADE_TIME: f32 = 1.0;
+const GOO_COLOR: Color = [60, 160, 60];
+const GOO_OPACITY: f32 = 0.55;
+
+pub struct Puddle {
+    /// Center in world pixels.
+    pub x: f32,
+    pub y: f32,
+    /// Horizontal radius; puddles are half as tall as they are wide.
+    pub radius: f32,
+    timer: f32,
+}
+
+impl Puddle {
+    pub fn new(x: f32, y: f32, radius: f32, lifetime: f32) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            timer: lifetime,
+        }
+    }
+
+    /// Small smear left where a slime lands from a hop.
+    pub fn landing(x: f32, y: f32, size: SlimeSize) -> Self {
+        Self::new(x, y, size_radius(size) * 0.6, LANDING_LIFETIME)
+    }
+
+    /// Splash left where a slime bursts.
+    pub fn burst(x: f32, y: f32, size: SlimeSize) -> Self {
+        Self::new(x, y, size_radius(size), BURST_LIFETIME)
+    }
+
+    pub fn alive(&self) -> bool {
+        self.timer > 0.0
+    }
+
+    pub fn contains(&self, px: f32, py: f32) -> bool {
+        let dx = (px - self.x) / self.radius;
+        let dy = (py - self.y) / (self.radius * 0.5);
+        dx * dx + dy * dy <= 1.0
+    }
+
+    /// Translucent goo blended over whatever floor is underneath.
+    pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
+        let opacity = GOO_OPACITY * (self.timer / FADE_TIME).min(1.0);
+        let r = self.radius.ceil() as i32;
+        let cx = self.x.round() as i32;
+        let cy = self.y.round() as i32;
+        for dy in -r / 2..=r / 2 {
+            for dx in -r..=r {
+                let (wx, wy) = (cx + dx, cy + dy);
+                if !self.contains(wx as f32, wy as f32) {
+                    continue;
+                }
+                let (sx, sy) = (wx - cam_x, wy - cam_y);
+                if sx < 0 || sy < 0 {
+                    continue;
+                }
+                let under = fb
+                    .get_pixel(sx as usize, sy as usize)
+                    .unwrap_or(fb.background());
+                let mut color = under;
+                for (c, goo) in color.iter_mut().zip(GOO_COLOR) {
+                    *c = (*c as f32 + (goo as f32 - *c as f32) * opacity) as u8;
+                }
+                fb.set_pixel_safe(sx, sy, color);
+            }
+        }
+    }
+}
+
+fn size_radius(size: SlimeSize) -> f32 {
+    match size {
+        SlimeSize::Large => 8.0,
+        SlimeSize::Medium => 6.0,
+        SlimeSize::Small => 4.0,
+    }
+}
+
+pub fn add_puddle(puddles: &mut Vec<Puddle>, puddle: Puddle) {
+    if puddles.len() >= MAX_PUDDLES {
+        puddles.remove(0);
+    }
+    puddles.push(puddle);
+}
+
+/// Dry puddles out and drop the ones that are gone.
+pub fn update_puddles(puddles: &mut Vec<Puddle>, dt: f32) {
+    for p in puddles.iter_mut() {
+        p.timer -= dt;
+    }
+    puddles.retain(|p| p.alive());
+}
+
+/// Whether a point (the player's feet) is standing in goo.
+pub fn slows(puddles: &[Puddle], x: f32, y: f32) -> bool {
+    puddles.iter().any(|p| p.contains(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puddles_are_wider_than_tall() {
+        let puddles = [Puddle::new(50.0, 50.0, 8.0, 1.0)];
+        assert!(slows(&puddles, 57.0, 50.0));
+        assert!(slows(&puddles, 50.0, 53.0));
+        assert!(!slows(&puddles, 50.0, 57.0));
+    }
+
+    #[test]
+    fn test_puddles_dry_up() {
+        let mut puddles = vec![
+            Puddle::landing(0.0, 0.0, SlimeSize::Small),
+            Puddle::burst(0.0, 0.0, SlimeSize::Small),
+        ];
+        update_puddles(&mut puddles, LANDING_LIFETIME + 0.1);
+        assert_eq!(puddles.len(), 1);
+        update_puddles(&mut puddles, BURST_LIFETIME);
+        assert!(puddles.is_empty());
+    }
+
+    #[test]
+    fn test_oldest_puddle_makes_room() {
+        let mut puddles = Vec::new();
+        for i in 0..=MAX_PUDDLES {
+            add_puddle(&mut puddles, Puddle::new(i as f32, 0.0, 4.0, 1.0));
+        }
+        assert_eq!(puddles.len(), MAX_PUDDLES);
+        assert_eq!(puddles[0].x, 1.0);
+    }
+}
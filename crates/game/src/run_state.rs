@@ -1,18 +1,75 @@
+use crate::boons::BoonId;
+use crate::enemies::EnemyType;
+
 /// Gold reward values for each enemy type.
+/// Per slime of any size; a large slime splits into six more.
+pub const GOLD_SLIME: u32 = 1;
 pub const GOLD_SKELETON: u32 = 2;
 pub const GOLD_GHOST: u32 = 3;
+pub const GOLD_ARCHER: u32 = 3;
+pub const GOLD_SHIELD_BEARER: u32 = 4;
+pub const GOLD_NECROMANCER: u32 = 5;
 pub const GOLD_BONE_KING: u32 = 25;
+pub const GOLD_LICH: u32 = 25;
+pub const GOLD_SLIME_MOTHER: u32 = 20;
 pub const GOLD_ROOM_CLEAR_BONUS: u32 = 5;
+/// Souls dropped by a slain boss.
+pub const SOULS_PER_BOSS: u32 = 1;
+/// Elites are worth this many times their base gold.
+pub const ELITE_GOLD_MULT: u32 = 3;
+
+/// Base gold dropped by an enemy, before boon bonuses.
+pub fn enemy_gold(enemy_type: EnemyType) -> u32 {
+    match enemy_type {
+        EnemyType::Slime(_) => GOLD_SLIME,
+        EnemyType::Skeleton => GOLD_SKELETON,
+        EnemyType::Ghost => GOLD_GHOST,
+        EnemyType::SkeletonArcher => GOLD_ARCHER,
+        EnemyType::ShieldBearer => GOLD_SHIELD_BEARER,
+        EnemyType::Necromancer => GOLD_NECROMANCER,
+        EnemyType::BoneKing => GOLD_BONE_KING,
+        EnemyType::Lich => GOLD_LICH,
+        EnemyType::SlimeMother => GOLD_SLIME_MOTHER,
+    }
+}
+
+/// What killed the player.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DeathCause {
+    Enemy(EnemyType),
+    /// Spikes, flame vents and trap arrows.
+    Trap,
+    Pit,
+}
+
+impl DeathCause {
+    pub fn label(self) -> &'static str {
+        match self {
+            DeathCause::Enemy(kind) => kind.name(),
+            DeathCause::Trap => "TRAP",
+            DeathCause::Pit => "PIT",
+        }
+    }
+}
 
 /// Tracks per-run statistics for end-of-run summary and save integration.
 pub struct RunState {
     pub kills: u32,
-    #[allow(dead_code)] // Tracked for run-end summary screen
     pub damage_dealt: u32,
-    #[allow(dead_code)] // Tracked for run-end summary screen
     pub damage_taken: u32,
     pub gold_earned: u32,
+    /// Gold spent at merchants; only the unspent remainder is banked.
+    pub gold_spent: u32,
+    /// Boss souls picked up; all of them are banked.
+    pub souls: u32,
     pub boons_collected: u32,
+    /// Every boon gained, in the order it was taken.
+    pub boons_taken: Vec<BoonId>,
+    pub death_cause: Option<DeathCause>,
+    /// Enemies knocked into walls hard enough to slam.
+    pub wall_slams: u32,
+    /// Kills by pits, traps and wall slams.
+    pub environment_kills: u32,
     pub rooms_cleared: u32,
     pub floor_reached: u32,
     pub elapsed_secs: f32,
@@ -25,7 +82,13 @@ impl RunState {
             damage_dealt: 0,
             damage_taken: 0,
             gold_earned: 0,
+            gold_spent: 0,
+            souls: 0,
             boons_collected: 0,
+            boons_taken: Vec::new(),
+            death_cause: None,
+            wall_slams: 0,
+            environment_kills: 0,
             rooms_cleared: 0,
             floor_reached: 0,
             elapsed_secs: 0.0,
@@ -37,20 +100,51 @@ impl RunState {
         self.gold_earned += enemy_gold;
     }
 
-    #[allow(dead_code)] // Will be called from combat system for run-end stats
+    /// Knockback effects: slams and kills dealt by the room itself.
+    pub fn record_knockback(&mut self, wall_slams: u32, environment_kills: u32) {
+        self.wall_slams += wall_slams;
+        self.environment_kills += environment_kills;
+    }
+
     pub fn record_damage_dealt(&mut self, amount: u32) {
         self.damage_dealt += amount;
     }
 
-    #[allow(dead_code)] // Will be called from combat system for run-end stats
     pub fn record_damage_taken(&mut self, amount: u32) {
         self.damage_taken += amount;
     }
 
+    pub fn record_boon(&mut self, id: BoonId) {
+        self.boons_collected += 1;
+        self.boons_taken.push(id);
+    }
+
     pub fn record_room_clear(&mut self, bonus_gold: u32) {
         self.rooms_cleared += 1;
         self.gold_earned += bonus_gold;
     }
+
+    pub fn record_gold(&mut self, amount: u32) {
+        self.gold_earned += amount;
+    }
+
+    pub fn record_souls(&mut self, amount: u32) {
+        self.souls += amount;
+    }
+
+    /// Gold currently in hand.
+    pub fn gold(&self) -> u32 {
+        self.gold_earned.saturating_sub(self.gold_spent)
+    }
+
+    /// Spend run gold. Returns false (and spends nothing) if the player can't afford it.
+    pub fn spend_gold(&mut self, amount: u32) -> bool {
+        if self.gold() < amount {
+            return false;
+        }
+        self.gold_spent += amount;
+        true
+    }
 }
 
 #[cfg(test)]
@@ -64,7 +158,13 @@ mod tests {
         assert_eq!(rs.damage_dealt, 0);
         assert_eq!(rs.damage_taken, 0);
         assert_eq!(rs.gold_earned, 0);
+        assert_eq!(rs.gold_spent, 0);
+        assert_eq!(rs.souls, 0);
         assert_eq!(rs.boons_collected, 0);
+        assert!(rs.boons_taken.is_empty());
+        assert!(rs.death_cause.is_none());
+        assert_eq!(rs.wall_slams, 0);
+        assert_eq!(rs.environment_kills, 0);
         assert_eq!(rs.rooms_cleared, 0);
         assert_eq!(rs.floor_reached, 0);
         assert!((rs.elapsed_secs - 0.0).abs() < f32::EPSILON);
@@ -102,6 +202,31 @@ mod tests {
         assert_eq!(rs.damage_taken, 3);
     }
 
+    #[test]
+    fn test_record_boon_keeps_order() {
+        let mut rs = RunState::new();
+        rs.record_boon(BoonId::LifeSteal);
+        rs.record_boon(BoonId::IronShield);
+        assert_eq!(rs.boons_collected, 2);
+        assert_eq!(rs.boons_taken, [BoonId::LifeSteal, BoonId::IronShield]);
+    }
+
+    #[test]
+    fn test_death_cause_labels() {
+        assert_eq!(DeathCause::Enemy(EnemyType::Lich).label(), "THE LICH");
+        assert_eq!(DeathCause::Pit.label(), "PIT");
+    }
+
+    #[test]
+    fn test_record_knockback() {
+        let mut rs = RunState::new();
+        rs.record_knockback(2, 0);
+        rs.record_knockback(1, 1);
+        assert_eq!(rs.wall_slams, 3);
+        assert_eq!(rs.environment_kills, 1);
+        assert_eq!(rs.kills, 0); // the kill itself is recorded with its gold
+    }
+
     #[test]
     fn test_record_room_clear() {
         let mut rs = RunState::new();
@@ -114,6 +239,27 @@ mod tests {
         assert_eq!(rs.gold_earned, 10);
     }
 
+    #[test]
+    fn test_record_gold() {
+        let mut rs = RunState::new();
+        rs.record_gold(12);
+        assert_eq!(rs.gold_earned, 12);
+        assert_eq!(rs.kills, 0);
+    }
+
+    #[test]
+    fn test_spend_gold() {
+        let mut rs = RunState::new();
+        rs.record_room_clear(GOLD_ROOM_CLEAR_BONUS);
+        assert!(!rs.spend_gold(6));
+        assert_eq!(rs.gold(), 5);
+
+        assert!(rs.spend_gold(3));
+        assert_eq!(rs.gold(), 2);
+        assert_eq!(rs.gold_earned, 5); // spending doesn't rewrite earnings
+        assert_eq!(rs.gold_spent, 3);
+    }
+
     #[test]
     fn test_gold_constants() {
         assert_eq!(GOLD_SKELETON, 2);
@@ -122,6 +268,16 @@ mod tests {
         assert_eq!(GOLD_ROOM_CLEAR_BONUS, 5);
     }
 
+    #[test]
+    fn test_enemy_gold_rewards_tougher_enemies() {
+        assert_eq!(enemy_gold(EnemyType::Skeleton), GOLD_SKELETON);
+        assert!(enemy_gold(EnemyType::SkeletonArcher) > GOLD_SKELETON);
+        assert!(enemy_gold(EnemyType::ShieldBearer) > GOLD_ARCHER);
+        assert!(enemy_gold(EnemyType::Necromancer) > GOLD_SHIELD_BEARER);
+        let small = EnemyType::Slime(crate::enemies::slime::SlimeSize::Small);
+        assert!(enemy_gold(small) < GOLD_SKELETON);
+    }
+
     #[test]
     fn test_mixed_operations() {
         let mut rs = RunState::new();
@@ -0,0 +1,249 @@
+//! Run-end summary: the run's stats, what ended it and the boon build it
+//! finished with, each in its own panel, with personal bests picked out.
+
+use engine::{Color, FrameBuffer, SpriteData};
+
+use crate::boons::effects::PlayerBoons;
+use crate::hud;
+use crate::run_state::{DeathCause, RunState};
+use crate::save::Record;
+use crate::seed::format_seed;
+use crate::sprites;
+use crate::sprites::font::{render_text, text_width};
+use crate::weapons::{get_weapon, WeaponId};
+
+const BACKGROUND: Color = [5, 5, 8];
+const PANEL_FILL: Color = [14, 12, 20];
+const PANEL_BORDER: Color = [55, 50, 75];
+const TITLE_COLOR: Color = [150, 140, 180];
+const LABEL_COLOR: Color = [110, 110, 130];
+const VALUE_COLOR: Color = [200, 200, 215];
+const RECORD_COLOR: Color = [255, 215, 60];
+
+/// Top of the panels, below the header.
+const PANELS_Y: i32 = 12;
+/// Gap between panels and around the screen edge.
+const GAP: i32 = 2;
+/// Space a panel's title takes above its contents.
+const TITLE_H: i32 = 9;
+const STAT_ROW_H: i32 = 6;
+
+/// What the run-end screen shows beyond the run's own stats.
+#[derive(Default)]
+pub struct RunSummary {
+    pub victory: bool,
+    /// Gold banked, after the pact's heat bonus.
+    pub gold_banked: u32,
+    pub heat: u32,
+    /// The run beat the weapon's heat record.
+    pub heat_record: bool,
+    /// Score of an official daily attempt.
+    pub daily_score: Option<u32>,
+    pub seed: u64,
+    /// Weapons whose milestones the run met.
+    pub unlocked: Vec<WeaponId>,
+    /// Personal bests the run beat.
+    pub records: Vec<Record>,
+}
+
+/// One line of the stats panel.
+struct Stat {
+    label: &'static str,
+    value: String,
+    record: bool,
+}
+
+impl RunSummary {
+    /// Draw the summary of `run`. `boons` is the build it ended with.
+    pub fn render(&self, fb: &mut FrameBuffer, run: &RunState, boons: Option<&PlayerBoons>) {
+        let fw = fb.width() as i32;
+        let fh = fb.height() as i32;
+
+        for y in 0..fh {
+            for x in 0..fw {
+                fb.set_pixel_safe(x, y, BACKGROUND);
+            }
+        }
+
+        let (header, header_color): (&str, Color) = if self.victory {
+            ("RUN COMPLETE", [255, 220, 50])
+        } else {
+            ("YOU DIED", [200, 30, 30])
+        };
+        render_text(fb, header, (fw - text_width(header)) / 2, 3, header_color);
+        if let Some(score) = self.daily_score {
+            let score_str = format!("DAILY {score}");
+            let sw = text_width(&score_str);
+            render_text(fb, &score_str, fw - sw - GAP - 1, 3, [90, 230, 210]);
+        }
+
+        // Stats down the left half; the ending and the build share the right
+        let col_w = (fw - GAP * 3) / 2;
+        let right_x = GAP * 2 + col_w;
+        let stats = self.stats(run);
+        let stats_h = TITLE_H + stats.len() as i32 * STAT_ROW_H + 1;
+        draw_panel(fb, GAP, PANELS_Y, col_w, stats_h, "STATS");
+        for (i, stat) in stats.iter().enumerate() {
+            let y = PANELS_Y + TITLE_H + i as i32 * STAT_ROW_H;
+            render_stat(fb, stat, GAP + 3, y, col_w - 6);
+        }
+
+        let ending_h = self.render_ending(fb, run, right_x, col_w);
+
+        let build_y = PANELS_Y + ending_h + GAP;
+        let boons_w = col_w - 6;
+        let boons_h = boons.map_or(0, |b| hud::boons_height(b, boons_w));
+        let build_h = (TITLE_H + boons_h.max(6) + 2).max(PANELS_Y + stats_h - build_y);
+        draw_panel(fb, right_x, build_y, col_w, build_h, "BUILD");
+        match boons.filter(|b| !b.active.is_empty()) {
+            Some(boons) => {
+                hud::render_boons(fb, boons, right_x + 3, build_y + TITLE_H, boons_w);
+            }
+            None => render_text(fb, "NO BOONS", right_x + 3, build_y + TITLE_H, LABEL_COLOR),
+        }
+
+        let mut y = PANELS_Y + stats_h.max(build_y + build_h - PANELS_Y) + 3;
+        if !self.unlocked.is_empty() {
+            let names: Vec<&str> = self
+                .unlocked
+                .iter()
+                .map(|&id| get_weapon(id).name)
+                .collect();
+            let unlock_str = format!("UNLOCKED {}", names.join(" ").to_uppercase());
+            let uw = text_width(&unlock_str);
+            render_text(fb, &unlock_str, (fw - uw) / 2, y, [80, 220, 120]);
+            y += 7;
+        }
+        if !self.records.is_empty() {
+            let beaten = match self.records.len() {
+                1 => "NEW PERSONAL BEST".to_string(),
+                n => format!("{n} NEW PERSONAL BESTS"),
+            };
+            let bw = text_width(&beaten);
+            render_text(fb, &beaten, (fw - bw) / 2, y, RECORD_COLOR);
+        }
+
+        let seed_str = format!("SEED {}", format_seed(self.seed));
+        let sw = text_width(&seed_str);
+        render_text(fb, &seed_str, (fw - sw) / 2, fh - 17, [100, 100, 110]);
+
+        let hint = "PRESS ATTACK";
+        let hw = text_width(hint);
+        render_text(fb, hint, (fw - hw) / 2, fh - 10, [120, 120, 130]);
+    }
+
+    fn stats(&self, run: &RunState) -> Vec<Stat> {
+        let beat = |record| self.records.contains(&record);
+        let secs = run.elapsed_secs as u32;
+        let mut stats = vec![
+            Stat {
+                label: "FLOOR",
+                value: run.floor_reached.to_string(),
+                record: beat(Record::Floor),
+            },
+            Stat {
+                label: "KILLS",
+                value: run.kills.to_string(),
+                record: beat(Record::Kills),
+            },
+            Stat {
+                label: "ROOMS",
+                value: run.rooms_cleared.to_string(),
+                record: false,
+            },
+            Stat {
+                label: "TIME",
+                value: format!("{}:{:02}", secs / 60, secs % 60),
+                record: beat(Record::FastestWin),
+            },
+            Stat {
+                label: "DEALT",
+                value: run.damage_dealt.to_string(),
+                record: beat(Record::DamageDealt),
+            },
+            Stat {
+                label: "TAKEN",
+                value: run.damage_taken.to_string(),
+                record: false,
+            },
+            Stat {
+                label: "GOLD",
+                value: format!("+{}", self.gold_banked),
+                record: false,
+            },
+        ];
+        if run.souls > 0 {
+            stats.push(Stat {
+                label: "SOULS",
+                value: format!("+{}", run.souls),
+                record: false,
+            });
+        }
+        if self.heat > 0 {
+            stats.push(Stat {
+                label: "HEAT",
+                value: self.heat.to_string(),
+                record: self.heat_record,
+            });
+        }
+        stats
+    }
+
+    /// The panel saying how the run ended, with a portrait of the killer.
+    /// Returns its height.
+    fn render_ending(&self, fb: &mut FrameBuffer, run: &RunState, x: i32, w: i32) -> i32 {
+        let (title, name, portrait): (&str, &str, Option<&SpriteData>) = if self.victory {
+            ("ENDING", "VICTORY", Some(sprites::IDLE_ANIM.frames[0]))
+        } else {
+            match run.death_cause {
+                Some(DeathCause::Enemy(kind)) => ("SLAIN BY", kind.name(), Some(kind.portrait())),
+                Some(DeathCause::Trap) => ("SLAIN BY", "A TRAP", Some(&sprites::traps::SPIKES_UP)),
+                Some(DeathCause::Pit) => ("SLAIN BY", "A FALL", None),
+                None => ("ENDING", "ABANDONED", None),
+            }
+        };
+        let portrait_h = portrait.map_or(0, |p| p.height as i32 + 2);
+        let h = TITLE_H + portrait_h + 8;
+        draw_panel(fb, x, PANELS_Y, w, h, title);
+        if let Some(sprite) = portrait {
+            let px = x + (w - sprite.width as i32) / 2;
+            fb.blit_sprite(sprite, px, PANELS_Y + TITLE_H);
+        }
+        let name_color: Color = if self.victory {
+            [255, 220, 50]
+        } else {
+            [220, 90, 80]
+        };
+        let name_y = PANELS_Y + TITLE_H + portrait_h;
+        render_text(fb, name, x + (w - text_width(name)) / 2, name_y, name_color);
+        h
+    }
+}
+
+/// Filled box with a border and a title along its top.
+fn draw_panel(fb: &mut FrameBuffer, x: i32, y: i32, w: i32, h: i32, title: &str) {
+    for py in y..y + h {
+        for px in x..x + w {
+            let edge = py == y || py == y + h - 1 || px == x || px == x + w - 1;
+            fb.set_pixel_safe(px, py, if edge { PANEL_BORDER } else { PANEL_FILL });
+        }
+    }
+    render_text(fb, title, x + 3, y + 2, TITLE_COLOR);
+}
+
+/// Label on the left, value on the right; a new best is marked and lit up.
+fn render_stat(fb: &mut FrameBuffer, stat: &Stat, x: i32, y: i32, w: i32) {
+    let (label_color, value_color) = if stat.record {
+        (RECORD_COLOR, RECORD_COLOR)
+    } else {
+        (LABEL_COLOR, VALUE_COLOR)
+    };
+    render_text(fb, stat.label, x, y, label_color);
+    let vw = text_width(&stat.value);
+    let value_x = x + w - vw;
+    render_text(fb, &stat.value, value_x, y, value_color);
+    if stat.record {
+        let tag = "NEW";
+        render_text(fb, tag, value_x - text_width(tag) - 3, y, RECORD_COLOR);
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
+use crate::achievements::Achievements;
+use crate::run_state::RunState;
+use crate::weapons::{WeaponId, STARTER_WEAPON, WEAPON_UNLOCKS};
+
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct SaveData {
     pub total_gold: u32,
@@ -9,17 +14,63 @@ pub struct SaveData {
     pub total_kills: u32,
     pub total_deaths: u32,
     pub upgrades: PermanentUpgrades,
+    /// Highest pact heat won with, per weapon.
+    #[serde(default)]
+    pub heat_records: BTreeMap<WeaponId, u32>,
+    /// Boss souls, the rare currency the deepest upgrades cost.
+    #[serde(default)]
+    pub souls: u32,
+    /// Deepest floor reached with each weapon.
+    #[serde(default)]
+    pub weapon_floors: BTreeMap<WeaponId, u32>,
+    /// Weapons unlocked by milestones. The starter is always available, and
+    /// saves from before unlocks existed keep every weapon.
+    #[serde(default = "every_weapon")]
+    pub unlocked_weapons: BTreeSet<WeaponId>,
+    #[serde(default)]
+    pub achievements: Achievements,
+    #[serde(default)]
+    pub bests: PersonalBests,
+}
+
+/// Single-run bests. The deepest floor lives on as `best_floor`.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct PersonalBests {
+    pub kills: u32,
+    pub damage_dealt: u32,
+    pub fastest_win_secs: Option<u32>,
+}
+
+/// A personal best a finished run can beat.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Record {
+    Floor,
+    Kills,
+    DamageDealt,
+    FastestWin,
+}
+
+/// Raise `best` to `value`. True if that beat a best already set.
+fn raise_best(best: &mut u32, value: u32) -> bool {
+    let beaten = *best > 0 && value > *best;
+    *best = (*best).max(value);
+    beaten
+}
+
+fn every_weapon() -> BTreeSet<WeaponId> {
+    WEAPON_UNLOCKS.iter().map(|u| u.weapon).collect()
 }
 
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct PermanentUpgrades {
-    /// 0-3, each level grants +1 max HP
+    /// 0-4, each level grants +1 max HP
     pub vitality_level: u32,
     /// 0-2, each level grants +1 damage
     pub strength_level: u32,
     /// Unlocks 2 dash charges
     pub twin_dash: bool,
-    /// 0-2, each level grants +1 reroll per run
+    /// 0-3, each level grants +1 reroll per run
     pub boon_reroll_level: u32,
 }
 
@@ -35,12 +86,25 @@ impl PermanentUpgrades {
     }
 }
 
+/// What an upgrade is paid for with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Currency {
+    Gold,
+    Souls,
+}
+
+#[derive(Clone)]
 pub struct UpgradeDef {
     pub name: &'static str,
     pub description: &'static str,
     pub cost: u32,
+    pub currency: Currency,
+    /// Upgrade that has to be owned first; None for the root of a branch.
+    pub requires: Option<&'static str>,
     pub max_level: u32,
     pub current_level_fn: fn(&PermanentUpgrades) -> u32,
+    /// Grant one level of the upgrade.
+    pub apply_fn: fn(&mut PermanentUpgrades),
 }
 
 pub const UPGRADES: &[UpgradeDef] = &[
@@ -48,64 +112,139 @@ pub const UPGRADES: &[UpgradeDef] = &[
         name: "Vitality I",
         description: "+1 Max HP",
         cost: 30,
+        currency: Currency::Gold,
+        requires: None,
         max_level: 1,
         current_level_fn: |u| u.vitality_level.min(1),
+        apply_fn: |u| u.vitality_level += 1,
     },
     UpgradeDef {
         name: "Vitality II",
         description: "+1 Max HP",
         cost: 60,
+        currency: Currency::Gold,
+        requires: Some("Vitality I"),
         max_level: 1,
         current_level_fn: |u| u.vitality_level.saturating_sub(1).min(1),
+        apply_fn: |u| u.vitality_level += 1,
     },
     UpgradeDef {
         name: "Vitality III",
         description: "+1 Max HP",
         cost: 120,
+        currency: Currency::Gold,
+        requires: Some("Vitality II"),
         max_level: 1,
         current_level_fn: |u| u.vitality_level.saturating_sub(2).min(1),
+        apply_fn: |u| u.vitality_level += 1,
     },
     UpgradeDef {
         name: "Strength I",
         description: "+1 Damage",
         cost: 50,
+        currency: Currency::Gold,
+        requires: Some("Vitality I"),
         max_level: 1,
         current_level_fn: |u| u.strength_level.min(1),
+        apply_fn: |u| u.strength_level += 1,
     },
     UpgradeDef {
         name: "Strength II",
         description: "+1 Damage",
         cost: 100,
+        currency: Currency::Gold,
+        requires: Some("Strength I"),
         max_level: 1,
         current_level_fn: |u| u.strength_level.saturating_sub(1).min(1),
+        apply_fn: |u| u.strength_level += 1,
     },
     UpgradeDef {
         name: "Twin Dash",
         description: "2 dash charges",
         cost: 80,
+        currency: Currency::Gold,
+        requires: Some("Boon Reroll"),
         max_level: 1,
         current_level_fn: |u| u32::from(u.twin_dash),
+        apply_fn: |u| u.twin_dash = true,
     },
     UpgradeDef {
         name: "Boon Reroll",
         description: "+1 reroll per run",
         cost: 40,
+        currency: Currency::Gold,
+        requires: None,
         max_level: 1,
         current_level_fn: |u| u.boon_reroll_level.min(1),
+        apply_fn: |u| u.boon_reroll_level += 1,
     },
     UpgradeDef {
         name: "Boon Reroll+",
         description: "+1 reroll per run",
         cost: 80,
+        currency: Currency::Gold,
+        requires: Some("Boon Reroll"),
         max_level: 1,
         current_level_fn: |u| u.boon_reroll_level.saturating_sub(1).min(1),
+        apply_fn: |u| u.boon_reroll_level += 1,
+    },
+    UpgradeDef {
+        name: "Vitality IV",
+        description: "+1 Max HP",
+        cost: 3,
+        currency: Currency::Souls,
+        requires: Some("Vitality III"),
+        max_level: 1,
+        current_level_fn: |u| u.vitality_level.saturating_sub(3).min(1),
+        apply_fn: |u| u.vitality_level += 1,
+    },
+    UpgradeDef {
+        name: "Boon Reroll++",
+        description: "+1 reroll per run",
+        cost: 2,
+        currency: Currency::Souls,
+        requires: Some("Boon Reroll+"),
+        max_level: 1,
+        current_level_fn: |u| u.boon_reroll_level.saturating_sub(2).min(1),
+        apply_fn: |u| u.boon_reroll_level += 1,
     },
 ];
 
+/// The upgrade table in play: `UPGRADES` as patched by `defs/upgrades.json`.
+pub fn upgrades() -> &'static [UpgradeDef] {
+    crate::defs::upgrades().unwrap_or(UPGRADES)
+}
+
+/// Upgrades in shop order, each followed by the ones that require it, as
+/// (index into `defs`, depth in the tree).
+pub fn upgrade_tree(defs: &[UpgradeDef]) -> Vec<(usize, usize)> {
+    fn branch(
+        defs: &[UpgradeDef],
+        parent: Option<&str>,
+        depth: usize,
+        out: &mut Vec<(usize, usize)>,
+    ) {
+        for (i, def) in defs.iter().enumerate() {
+            if def.requires == parent {
+                out.push((i, depth));
+                branch(defs, Some(def.name), depth + 1, out);
+            }
+        }
+    }
+    let mut out = Vec::new();
+    branch(defs, None, 0, &mut out);
+    out
+}
+
 fn save_path() -> PathBuf {
+    data_path("save.json")
+}
+
+/// Path of a file inside the `~/.cryptfall` data directory.
+pub fn data_path(file: &str) -> PathBuf {
     let mut path = dirs_fallback();
     path.push(".cryptfall");
-    path.push("save.json");
+    path.push(file);
     path
 }
 
@@ -119,13 +258,23 @@ fn dirs_fallback() -> PathBuf {
 impl SaveData {
     pub fn load() -> Self {
         let path = save_path();
-        match std::fs::read_to_string(&path) {
+        let mut data: Self = match std::fs::read_to_string(&path) {
             Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
                 eprintln!("Warning: corrupted save file, using defaults: {e}");
                 Self::default()
             }),
             Err(_) => Self::default(),
-        }
+        };
+        data.backfill_achievements();
+        data
+    }
+
+    /// Carry lifetime totals into achievement progress, for saves written
+    /// before achievements were tracked.
+    fn backfill_achievements(&mut self) {
+        let wins = self.total_runs.saturating_sub(self.total_deaths);
+        self.achievements
+            .backfill(self.total_kills, self.best_floor, wins);
     }
 
     pub fn save(&self) {
@@ -148,8 +297,123 @@ impl SaveData {
         }
     }
 
-    pub fn can_afford(&self, cost: u32) -> bool {
-        self.total_gold >= cost
+    /// Fold a finished run into the personal bests. Returns the ones it
+    /// beat; a best with nothing to compare against is set quietly.
+    pub fn record_bests(&mut self, run: &RunState, victory: bool) -> Vec<Record> {
+        let mut beaten = Vec::new();
+        if raise_best(&mut self.best_floor, run.floor_reached) {
+            beaten.push(Record::Floor);
+        }
+        if raise_best(&mut self.bests.kills, run.kills) {
+            beaten.push(Record::Kills);
+        }
+        if raise_best(&mut self.bests.damage_dealt, run.damage_dealt) {
+            beaten.push(Record::DamageDealt);
+        }
+        let secs = run.elapsed_secs as u32;
+        let fastest = self.bests.fastest_win_secs;
+        if victory && fastest.is_none_or(|best| secs < best) {
+            if fastest.is_some() {
+                beaten.push(Record::FastestWin);
+            }
+            self.bests.fastest_win_secs = Some(secs);
+        }
+        beaten
+    }
+
+    /// Every permanent upgrade is owned; pacts unlock from here.
+    pub fn upgrades_maxed(&self) -> bool {
+        upgrades()
+            .iter()
+            .all(|u| (u.current_level_fn)(&self.upgrades) >= u.max_level)
+    }
+
+    /// Best heat a run with this weapon has been won at (0 if none).
+    pub fn heat_record(&self, weapon: WeaponId) -> u32 {
+        self.heat_records.get(&weapon).copied().unwrap_or(0)
+    }
+
+    /// Record a won run's heat. Returns true if it beat the weapon's record.
+    pub fn record_heat(&mut self, weapon: WeaponId, heat: u32) -> bool {
+        if heat <= self.heat_record(weapon) {
+            return false;
+        }
+        self.heat_records.insert(weapon, heat);
+        true
+    }
+
+    /// Every level of the named upgrade is owned.
+    pub fn owns_upgrade(&self, name: &str) -> bool {
+        upgrades()
+            .iter()
+            .find(|u| u.name == name)
+            .is_some_and(|u| (u.current_level_fn)(&self.upgrades) >= u.max_level)
+    }
+
+    /// The upgrade's prerequisite is owned, or it has none.
+    pub fn upgrade_available(&self, upgrade: &UpgradeDef) -> bool {
+        upgrade.requires.is_none_or(|name| self.owns_upgrade(name))
+    }
+
+    /// Buy the next level of an upgrade. Returns false (and spends nothing)
+    /// if it's maxed, still locked or too expensive.
+    pub fn buy_upgrade(&mut self, upgrade: &UpgradeDef) -> bool {
+        let level = (upgrade.current_level_fn)(&self.upgrades);
+        if level >= upgrade.max_level
+            || !self.upgrade_available(upgrade)
+            || !self.can_afford(upgrade.currency, upgrade.cost)
+        {
+            return false;
+        }
+        match upgrade.currency {
+            Currency::Gold => {
+                self.spend_gold(upgrade.cost);
+            }
+            Currency::Souls => self.souls -= upgrade.cost,
+        }
+        (upgrade.apply_fn)(&mut self.upgrades);
+        true
+    }
+
+    pub fn weapon_unlocked(&self, weapon: WeaponId) -> bool {
+        weapon == STARTER_WEAPON || self.unlocked_weapons.contains(&weapon)
+    }
+
+    /// Weapons still waiting on their milestone.
+    pub fn locked_weapons(&self) -> Vec<WeaponId> {
+        WEAPON_UNLOCKS
+            .iter()
+            .map(|u| u.weapon)
+            .filter(|&w| !self.weapon_unlocked(w))
+            .collect()
+    }
+
+    /// Record the floor a run reached with a weapon. Returns the weapons
+    /// whose milestones this met for the first time. A weapon not yet
+    /// unlocked (a daily run can hand one out) counts for nothing.
+    pub fn record_floor(&mut self, weapon: WeaponId, floor: u32) -> Vec<WeaponId> {
+        if !self.weapon_unlocked(weapon) {
+            return Vec::new();
+        }
+        let best = self.weapon_floors.entry(weapon).or_insert(0);
+        *best = (*best).max(floor);
+        let mut unlocked = Vec::new();
+        for unlock in WEAPON_UNLOCKS {
+            if unlock.with == weapon
+                && floor >= unlock.floor
+                && self.unlocked_weapons.insert(unlock.weapon)
+            {
+                unlocked.push(unlock.weapon);
+            }
+        }
+        unlocked
+    }
+
+    pub fn can_afford(&self, currency: Currency, cost: u32) -> bool {
+        match currency {
+            Currency::Gold => self.total_gold >= cost,
+            Currency::Souls => self.souls >= cost,
+        }
     }
 
     pub fn spend_gold(&mut self, cost: u32) -> bool {
@@ -186,6 +450,16 @@ mod tests {
                 twin_dash: true,
                 boon_reroll_level: 1,
             },
+            heat_records: BTreeMap::from([(WeaponId::Spear, 4)]),
+            souls: 6,
+            weapon_floors: BTreeMap::from([(WeaponId::Sword, 3)]),
+            unlocked_weapons: BTreeSet::from([WeaponId::Spear]),
+            achievements: Achievements::default(),
+            bests: PersonalBests {
+                kills: 80,
+                damage_dealt: 600,
+                fastest_win_secs: Some(900),
+            },
         };
 
         // Write directly to temp path
@@ -205,6 +479,12 @@ mod tests {
         assert_eq!(loaded.upgrades.strength_level, 1);
         assert!(loaded.upgrades.twin_dash);
         assert_eq!(loaded.upgrades.boon_reroll_level, 1);
+        assert_eq!(loaded.heat_record(WeaponId::Spear), 4);
+        assert_eq!(loaded.souls, 6);
+        assert_eq!(loaded.weapon_floors[&WeaponId::Sword], 3);
+        assert!(loaded.weapon_unlocked(WeaponId::Spear));
+        assert!(!loaded.weapon_unlocked(WeaponId::Daggers));
+        assert_eq!(loaded.bests.fastest_win_secs, Some(900));
 
         let _ = fs::remove_dir_all(&dir);
     }
@@ -243,15 +523,121 @@ mod tests {
         assert_eq!(data.upgrades.boon_reroll_level, 0);
     }
 
+    #[test]
+    fn test_saves_without_heat_records_still_load() {
+        let old = r#"{"total_gold":7,"total_runs":1,"best_floor":2,"total_kills":9,
+            "total_deaths":1,"upgrades":{"vitality_level":0,"strength_level":0,
+            "twin_dash":false,"boon_reroll_level":0}}"#;
+        let loaded: SaveData = serde_json::from_str(old).unwrap();
+        assert_eq!(loaded.total_gold, 7);
+        assert!(loaded.heat_records.is_empty());
+        assert_eq!(loaded.souls, 0);
+        // Weapons were never locked before, so old saves keep them all
+        assert!(loaded.locked_weapons().is_empty());
+    }
+
+    #[test]
+    fn test_old_saves_backfill_achievements() {
+        let old = r#"{"total_gold":0,"total_runs":3,"best_floor":5,"total_kills":40,
+            "total_deaths":2,"upgrades":{"vitality_level":0,"strength_level":0,
+            "twin_dash":false,"boon_reroll_level":0}}"#;
+        let mut loaded: SaveData = serde_json::from_str(old).unwrap();
+        loaded.backfill_achievements();
+        // Kills, floor and the one win all carry over
+        assert_eq!(loaded.achievements.unlocked_count(), 3);
+    }
+
+    #[test]
+    fn test_record_bests_flags_only_beaten_bests() {
+        let mut data = SaveData::default();
+        let mut run = RunState::new();
+        run.floor_reached = 2;
+        run.kills = 30;
+        run.damage_dealt = 90;
+        run.elapsed_secs = 700.0;
+        // The first run only sets the bests
+        assert!(data.record_bests(&run, true).is_empty());
+        assert_eq!(data.best_floor, 2);
+        assert_eq!(data.bests.fastest_win_secs, Some(700));
+
+        run.floor_reached = 3;
+        run.kills = 10;
+        run.elapsed_secs = 650.0;
+        assert_eq!(data.record_bests(&run, false), [Record::Floor]);
+        assert_eq!(data.bests.fastest_win_secs, Some(700)); // a loss isn't a win
+        assert_eq!(data.record_bests(&run, true), [Record::FastestWin]);
+        assert_eq!(data.bests.kills, 30);
+    }
+
+    #[test]
+    fn test_new_saves_start_with_the_starter_weapon() {
+        let data = SaveData::default();
+        assert!(data.weapon_unlocked(STARTER_WEAPON));
+        assert_eq!(data.locked_weapons().len(), WEAPON_UNLOCKS.len());
+    }
+
+    #[test]
+    fn test_record_floor_unlocks_on_milestones() {
+        let mut data = SaveData::default();
+        assert!(data.record_floor(WeaponId::Sword, 2).is_empty());
+        assert!(data.record_floor(WeaponId::Staff, 5).is_empty()); // nothing needs it
+        assert_eq!(data.record_floor(WeaponId::Sword, 3), [WeaponId::Spear]);
+        assert!(data.record_floor(WeaponId::Sword, 4).is_empty()); // only once
+        assert_eq!(data.weapon_floors[&WeaponId::Sword], 4);
+        data.record_floor(WeaponId::Sword, 1);
+        assert_eq!(data.weapon_floors[&WeaponId::Sword], 4);
+        assert!(data.weapon_unlocked(WeaponId::Spear));
+    }
+
+    #[test]
+    fn test_record_floor_ignores_locked_weapons() {
+        // A daily run can hand out the Bow before it's unlocked
+        let mut data = SaveData::default();
+        assert!(data.record_floor(WeaponId::Bow, 6).is_empty());
+        assert!(!data.weapon_unlocked(WeaponId::Staff));
+        assert!(!data.weapon_floors.contains_key(&WeaponId::Bow));
+
+        data.unlocked_weapons.insert(WeaponId::Bow);
+        assert_eq!(data.record_floor(WeaponId::Bow, 6), [WeaponId::Staff]);
+    }
+
+    #[test]
+    fn test_record_heat_keeps_the_best() {
+        let mut data = SaveData::default();
+        assert!(!data.record_heat(WeaponId::Bow, 0));
+        assert!(data.record_heat(WeaponId::Bow, 3));
+        assert!(!data.record_heat(WeaponId::Bow, 2));
+        assert_eq!(data.heat_record(WeaponId::Bow), 3);
+        assert_eq!(data.heat_record(WeaponId::Sword), 0);
+    }
+
+    #[test]
+    fn test_upgrades_maxed() {
+        let mut data = SaveData::default();
+        assert!(!data.upgrades_maxed());
+        data.upgrades = PermanentUpgrades {
+            vitality_level: 3,
+            strength_level: 2,
+            twin_dash: true,
+            boon_reroll_level: 2,
+        };
+        // The soul upgrades are still missing
+        assert!(!data.upgrades_maxed());
+        data.upgrades.vitality_level = 4;
+        data.upgrades.boon_reroll_level = 3;
+        assert!(data.upgrades_maxed());
+    }
+
     #[test]
     fn test_can_afford() {
         let data = SaveData {
             total_gold: 50,
             ..Default::default()
         };
-        assert!(data.can_afford(50));
-        assert!(data.can_afford(30));
-        assert!(!data.can_afford(51));
+        assert!(data.can_afford(Currency::Gold, 50));
+        assert!(data.can_afford(Currency::Gold, 30));
+        assert!(!data.can_afford(Currency::Gold, 51));
+        assert!(!data.can_afford(Currency::Souls, 1));
     }
 
     #[test]
@@ -290,6 +676,52 @@ mod tests {
         // Boon Reroll: 40, 80
         assert_eq!(UPGRADES[6].cost, 40);
         assert_eq!(UPGRADES[7].cost, 80);
+        // Soul upgrades: 3, 2
+        assert_eq!(UPGRADES[8].cost, 3);
+        assert_eq!(UPGRADES[9].cost, 2);
+        assert_eq!(UPGRADES[8].currency, Currency::Souls);
+    }
+
+    #[test]
+    fn test_upgrade_tree_reaches_every_upgrade_once() {
+        let tree = upgrade_tree(UPGRADES);
+        let mut seen: Vec<usize> = tree.iter().map(|&(i, _)| i).collect();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..UPGRADES.len()).collect::<Vec<_>>());
+        // Children follow their parent one level deeper
+        for (pos, &(i, depth)) in tree.iter().enumerate() {
+            if let Some(parent) = UPGRADES[i].requires {
+                let (_, parent_depth) = tree[..pos]
+                    .iter()
+                    .rev()
+                    .find(|&&(j, _)| UPGRADES[j].name == parent)
+                    .expect("parent listed first");
+                assert_eq!(depth, parent_depth + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_buy_upgrade_needs_prerequisite_and_currency() {
+        let mut data = SaveData {
+            total_gold: 1000,
+            ..Default::default()
+        };
+        let vit = |n: usize| &UPGRADES[n];
+        assert!(!data.buy_upgrade(vit(1))); // needs Vitality I
+        assert!(data.buy_upgrade(vit(0)));
+        assert!(!data.buy_upgrade(vit(0))); // already owned
+        assert!(data.buy_upgrade(vit(1)));
+        assert!(data.buy_upgrade(vit(2)));
+        assert_eq!(data.total_gold, 1000 - 30 - 60 - 120);
+        assert_eq!(data.upgrades.vitality_level, 3);
+
+        // Vitality IV costs souls, not gold
+        assert!(!data.buy_upgrade(vit(8)));
+        data.souls = 5;
+        assert!(data.buy_upgrade(vit(8)));
+        assert_eq!(data.souls, 2);
+        assert_eq!(data.upgrades.vitality_level, 4);
     }
 
     #[test]
@@ -300,10 +732,10 @@ mod tests {
         }
 
         let full = PermanentUpgrades {
-            vitality_level: 3,
+            vitality_level: 4,
             strength_level: 2,
             twin_dash: true,
-            boon_reroll_level: 2,
+            boon_reroll_level: 3,
         };
         // All individual upgrades should report level 1 (maxed)
         for upgrade in UPGRADES {
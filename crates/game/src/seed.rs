@@ -0,0 +1,70 @@
+//! Run seeds as text. Seeds are written in base 36 (0-9 then A-Z) so any
+//! 64-bit seed fits in a few characters and what the game shows can be typed
+//! back in.
+
+/// Longest seed text: u64::MAX is 13 base-36 digits.
+pub const MAX_SEED_LEN: usize = 13;
+
+const RADIX: u32 = 36;
+
+/// Seed for a run nobody asked to reproduce, taken from the clock.
+pub fn clock_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(42)
+}
+
+/// Seed as the game shows it, e.g. "RW3K1Z".
+pub fn format_seed(seed: u64) -> String {
+    let mut digits = Vec::new();
+    let mut rest = seed;
+    loop {
+        let digit = (rest % RADIX as u64) as u32;
+        digits.push(char::from_digit(digit, RADIX).unwrap().to_ascii_uppercase());
+        rest /= RADIX as u64;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Read a typed seed. Case doesn't matter; None if it's empty, has a
+/// character outside 0-9/A-Z, or doesn't fit in 64 bits.
+pub fn parse_seed(text: &str) -> Option<u64> {
+    if text.is_empty() {
+        return None;
+    }
+    text.chars().try_fold(0u64, |acc, c| {
+        let digit = c.to_digit(RADIX)?;
+        acc.checked_mul(RADIX as u64)?.checked_add(digit as u64)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_roundtrip_through_text() {
+        for seed in [0, 1, 35, 36, 1_739_912_345, u64::MAX] {
+            assert_eq!(parse_seed(&format_seed(seed)), Some(seed));
+        }
+        assert_eq!(format_seed(35), "Z");
+        assert_eq!(format_seed(36), "10");
+        assert_eq!(format_seed(u64::MAX).len(), MAX_SEED_LEN);
+    }
+
+    #[test]
+    fn parse_ignores_case() {
+        assert_eq!(parse_seed("cryptfall"), parse_seed("CRYPTFALL"));
+    }
+
+    #[test]
+    fn parse_rejects_bad_text() {
+        assert_eq!(parse_seed(""), None);
+        assert_eq!(parse_seed("AB-C"), None);
+        assert_eq!(parse_seed("ZZZZZZZZZZZZZZ"), None);
+    }
+}
@@ -0,0 +1,338 @@
+//! Merchant shops: seeded stock, floor-scaled prices, and purchases with run gold.
+
+use engine::sprite::SpriteData;
+use engine::tilemap::TILE_SIZE;
+use engine::{Color, FrameBuffer};
+
+use crate::boon_select::boon_icon;
+use crate::boons::selection::select_boon_options;
+use crate::boons::{boon_def, boon_defs, BoonId, Rarity};
+use crate::dungeon::room_template::RoomTemplate;
+use crate::run_state::RunState;
+use crate::sprites::font::{render_text, text_width};
+use crate::sprites::{merchant, pickups};
+
+/// HP restored by a shop heal.
+pub const SHOP_HEAL_AMOUNT: i32 = 2;
+
+const HEAL_PRICE: u32 = 15;
+const REROLL_PRICE: u32 = 10;
+const COMMON_BOON_PRICE: u32 = 25;
+const RARE_BOON_PRICE: u32 = 40;
+const LEGENDARY_BOON_PRICE: u32 = 60;
+
+/// Price increase per floor beyond the first, in percent of the base price.
+const FLOOR_PRICE_SCALE: u32 = 25;
+
+/// Max distance between player center and pedestal center to buy.
+const BUY_RADIUS: f32 = 8.0;
+const DENY_FLASH_DURATION: f32 = 0.4;
+const BOB_SPEED: f32 = 3.0;
+const BOB_AMPLITUDE: f32 = 1.0;
+
+const PEDESTAL_COLOR: Color = [70, 60, 50];
+const PRICE_COLOR: Color = [255, 200, 50];
+const PRICE_TOO_HIGH_COLOR: Color = [140, 70, 60];
+const DENY_COLOR: Color = [255, 60, 60];
+
+/// What a shop pedestal sells.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ShopItemKind {
+    Heal,
+    Boon(BoonId),
+    /// One extra reroll on the next boon selection screens.
+    Reroll,
+}
+
+impl ShopItemKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShopItemKind::Heal => "HEAL 2 HP",
+            ShopItemKind::Boon(id) => boon_def(*id).name,
+            ShopItemKind::Reroll => "BOON REROLL",
+        }
+    }
+
+    fn icon(&self) -> &'static SpriteData {
+        match self {
+            ShopItemKind::Heal => &pickups::PICKUP_HEART_BIG,
+            ShopItemKind::Boon(id) => boon_icon(*id),
+            ShopItemKind::Reroll => &pickups::PICKUP_REROLL,
+        }
+    }
+}
+
+/// Price of an item on the given floor (1-based).
+pub fn item_price(kind: ShopItemKind, floor: u32) -> u32 {
+    let base = match kind {
+        ShopItemKind::Heal => HEAL_PRICE,
+        ShopItemKind::Reroll => REROLL_PRICE,
+        ShopItemKind::Boon(id) => match boon_def(id).rarity {
+            Rarity::Common => COMMON_BOON_PRICE,
+            Rarity::Rare => RARE_BOON_PRICE,
+            Rarity::Legendary => LEGENDARY_BOON_PRICE,
+        },
+    };
+    base * (100 + FLOOR_PRICE_SCALE * floor.saturating_sub(1)) / 100
+}
+
+/// An item on display. Position is the pedestal center in world pixels.
+pub struct ShopItem {
+    pub kind: ShopItemKind,
+    pub price: u32,
+    pub x: f32,
+    pub y: f32,
+    pub sold: bool,
+}
+
+/// Stock and merchant for one shop room.
+pub struct Shop {
+    pub room_index: usize,
+    pub items: Vec<ShopItem>,
+    /// Merchant position (top-left of sprite) in world pixels.
+    merchant_pos: Option<(f32, f32)>,
+    timer: f32,
+    /// Item that was just refused, and how long to flash its price.
+    denied: Option<(usize, f32)>,
+}
+
+impl Shop {
+    /// Stock a shop room. Items go on the template's spawn points; the same
+    /// seed always produces the same stock in the same order. Rerolls are
+    /// left out when a pact forbids them.
+    pub fn new(
+        room_index: usize,
+        template: &RoomTemplate,
+        floor: u32,
+        seed: u64,
+        active_boons: &[BoonId],
+        lucky: bool,
+        sells_rerolls: bool,
+    ) -> Self {
+        let ts = TILE_SIZE as f32;
+
+        let mut kinds = vec![ShopItemKind::Heal];
+        if sells_rerolls {
+            kinds.push(ShopItemKind::Reroll);
+        }
+        if let Some(boon) = select_boon_options(boon_defs(), active_boons, lucky, seed).first() {
+            kinds.push(ShopItemKind::Boon(boon.id));
+        }
+
+        // Seeded shuffle so pedestal order varies between shops
+        let mut rng = seed;
+        for i in (1..kinds.len()).rev() {
+            rng = rng.wrapping_mul(1103515245).wrapping_add(12345);
+            let j = ((rng >> 16) as usize) % (i + 1);
+            kinds.swap(i, j);
+        }
+
+        let items = template
+            .spawn_points
+            .iter()
+            .zip(kinds)
+            .map(|(sp, kind)| ShopItem {
+                kind,
+                price: item_price(kind, floor),
+                x: sp.x as f32 * ts + ts / 2.0,
+                y: sp.y as f32 * ts + ts / 2.0,
+                sold: false,
+            })
+            .collect();
+
+        let merchant_pos = template.merchant.map(|(mx, my)| {
+            let frame = merchant::MERCHANT_IDLE_ANIM.frames[0];
+            (
+                mx as f32 * ts + (ts - frame.width as f32) / 2.0,
+                (my + 1) as f32 * ts - frame.height as f32,
+            )
+        });
+
+        Self {
+            room_index,
+            items,
+            merchant_pos,
+            timer: 0.0,
+            denied: None,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.timer += dt;
+        if let Some((_, ref mut t)) = self.denied {
+            *t -= dt;
+            if *t <= 0.0 {
+                self.denied = None;
+            }
+        }
+    }
+
+    /// Index of the unsold item the player (center position) is standing at.
+    pub fn item_near(&self, px: f32, py: f32) -> Option<usize> {
+        self.items.iter().position(|item| {
+            let dx = item.x - px;
+            let dy = item.y - py;
+            !item.sold && dx * dx + dy * dy < BUY_RADIUS * BUY_RADIUS
+        })
+    }
+
+    /// Buy item `index` with run gold. Returns what was bought, or None
+    /// (flashing the price) if it's sold out or unaffordable.
+    pub fn try_buy(&mut self, index: usize, run: &mut RunState) -> Option<ShopItemKind> {
+        let item = self.items.get_mut(index)?;
+        if item.sold || !run.spend_gold(item.price) {
+            self.deny(index);
+            return None;
+        }
+        item.sold = true;
+        Some(item.kind)
+    }
+
+    /// Flash an item's price to show the purchase was refused.
+    pub fn deny(&mut self, index: usize) {
+        self.denied = Some((index, DENY_FLASH_DURATION));
+    }
+
+    /// Draw the merchant, pedestals, wares and price tags.
+    pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32, gold: u32) {
+        if let Some((mx, my)) = self.merchant_pos {
+            let anim = &merchant::MERCHANT_IDLE_ANIM;
+            let frame = (self.timer as f64 / anim.frame_duration) as usize % anim.frames.len();
+            fb.blit_sprite(anim.frames[frame], mx as i32 - cam_x, my as i32 - cam_y);
+        }
+
+        let bob = ((self.timer * BOB_SPEED).sin() * BOB_AMPLITUDE) as i32;
+        for (i, item) in self.items.iter().enumerate() {
+            let cx = item.x as i32 - cam_x;
+            let cy = item.y as i32 - cam_y;
+
+            // Stone pedestal
+            for y in (cy + 3)..(cy + 5) {
+                for x in (cx - 3)..(cx + 4) {
+                    fb.set_pixel_safe(x, y, PEDESTAL_COLOR);
+                }
+            }
+
+            if item.sold {
+                continue;
+            }
+
+            let icon = item.kind.icon();
+            let ix = cx - icon.width as i32 / 2;
+            let iy = cy + 2 - icon.height as i32 + bob;
+            fb.blit_sprite(icon, ix, iy);
+
+            let price = format!("{}G", item.price);
+            let color = match self.denied {
+                Some((idx, _)) if idx == i => DENY_COLOR,
+                _ if gold < item.price => PRICE_TOO_HIGH_COLOR,
+                _ => PRICE_COLOR,
+            };
+            render_text(fb, &price, cx - text_width(&price) / 2, cy + 6, color);
+        }
+    }
+
+    /// Draw the name of the item at `index` and a buy hint along the bottom edge.
+    pub fn render_prompt(&self, fb: &mut FrameBuffer, index: usize) {
+        let Some(item) = self.items.get(index) else {
+            return;
+        };
+        let fw = fb.width() as i32;
+        let fh = fb.height() as i32;
+
+        for y in (fh - 14)..fh {
+            for x in 0..fw {
+                fb.set_pixel_safe(x, y, [0, 0, 0]);
+            }
+        }
+
+        let label = item.kind.label();
+        let lx = (fw - text_width(label)) / 2;
+        render_text(fb, label, lx, fh - 13, [255, 255, 255]);
+
+        let hint = format!("ATTACK - BUY {}G", item.price);
+        let hx = (fw - text_width(&hint)) / 2;
+        render_text(fb, &hint, hx, fh - 6, [150, 150, 150]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::templates;
+
+    fn test_shop(seed: u64) -> Shop {
+        Shop::new(0, &templates::shop(), 1, seed, &[], false, true)
+    }
+
+    #[test]
+    fn test_prices_scale_by_floor() {
+        assert_eq!(item_price(ShopItemKind::Heal, 1), HEAL_PRICE);
+        assert_eq!(item_price(ShopItemKind::Heal, 5), HEAL_PRICE * 2);
+        assert!(item_price(ShopItemKind::Reroll, 3) > item_price(ShopItemKind::Reroll, 2));
+    }
+
+    #[test]
+    fn test_boon_price_follows_rarity() {
+        let common = item_price(ShopItemKind::Boon(BoonId::SharpenedBlade), 1);
+        let rare = item_price(ShopItemKind::Boon(BoonId::BerserkersRage), 1);
+        assert!(rare > common);
+    }
+
+    #[test]
+    fn test_stock_has_heal_reroll_and_boon() {
+        let shop = test_shop(12345);
+        assert_eq!(shop.items.len(), 3);
+        let kinds: Vec<_> = shop.items.iter().map(|i| i.kind).collect();
+        assert!(kinds.contains(&ShopItemKind::Heal));
+        assert!(kinds.contains(&ShopItemKind::Reroll));
+        assert!(kinds.iter().any(|k| matches!(k, ShopItemKind::Boon(_))));
+    }
+
+    #[test]
+    fn test_stock_can_leave_out_rerolls() {
+        let shop = Shop::new(0, &templates::shop(), 1, 12345, &[], false, false);
+        assert_eq!(shop.items.len(), 2);
+        assert!(shop.items.iter().all(|i| i.kind != ShopItemKind::Reroll));
+    }
+
+    #[test]
+    fn test_stock_is_deterministic_per_seed() {
+        let a: Vec<_> = test_shop(777).items.iter().map(|i| i.kind).collect();
+        let b: Vec<_> = test_shop(777).items.iter().map(|i| i.kind).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_try_buy_spends_gold_once() {
+        let mut shop = test_shop(42);
+        let mut run = RunState::new();
+        run.record_kill(100);
+        let price = shop.items[0].price;
+
+        assert_eq!(shop.try_buy(0, &mut run), Some(shop.items[0].kind));
+        assert!(shop.items[0].sold);
+        assert_eq!(run.gold(), 100 - price);
+
+        // Sold out
+        assert_eq!(shop.try_buy(0, &mut run), None);
+        assert_eq!(run.gold(), 100 - price);
+    }
+
+    #[test]
+    fn test_try_buy_refuses_without_gold() {
+        let mut shop = test_shop(42);
+        let mut run = RunState::new();
+        assert_eq!(shop.try_buy(1, &mut run), None);
+        assert!(!shop.items[1].sold);
+    }
+
+    #[test]
+    fn test_item_near_skips_sold_items() {
+        let mut shop = test_shop(42);
+        let (x, y) = (shop.items[2].x, shop.items[2].y);
+        assert_eq!(shop.item_near(x + 2.0, y), Some(2));
+        shop.items[2].sold = true;
+        assert_eq!(shop.item_near(x + 2.0, y), None);
+    }
+}
@@ -0,0 +1,169 @@
+#![allow(dead_code)]
+
+use engine::animation::AnimationData;
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const B: Option<Color> = Some([220, 210, 190]); // bone white
+const D: Option<Color> = Some([160, 150, 130]); // bone shadow
+const E: Option<Color> = Some([100, 90, 80]); // dark bone
+const R: Option<Color> = Some([200, 40, 40]); // red eyes
+const H: Option<Color> = Some([60, 90, 50]); // hood green
+const W: Option<Color> = Some([140, 90, 50]); // bow wood
+const S: Option<Color> = Some([200, 200, 180]); // bowstring
+const A: Option<Color> = Some([180, 180, 190]); // arrow shaft
+const K: Option<Color> = Some([255, 200, 50]); // telegraph glow
+
+// =============================================================================
+// IDLE — 2 frames, 0.5s/frame, looping. Bow held at the side.
+// 10x14, same frame as the skeleton.
+// =============================================================================
+
+#[rustfmt::skip]
+static ARCHER_IDLE_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, R, B, B, R, H, N, N,
+    N, N, N, B, D, D, B, N, W, N,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, D, B, D, B, B, B, S, W,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, N, B, B, B, B, N, S, W,
+    N, N, N, D, B, B, D, N, S, W,
+    N, N, N, N, D, D, N, N, W, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, E, N, E, N, N, N,
+    N, N, N, E, E, N, E, E, N, N,
+]);
+
+#[rustfmt::skip]
+static ARCHER_IDLE_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, R, B, B, R, H, N, N,
+    N, N, N, B, D, D, B, N, W, N,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, D, B, D, B, B, B, S, W,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, N, D, B, B, D, N, S, W,
+    N, N, N, N, D, D, N, N, W, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, E, N, E, N, N, N,
+    N, N, N, E, E, N, E, E, N, N,
+]);
+
+pub static ARCHER_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&ARCHER_IDLE_0, &ARCHER_IDLE_1],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// WALK — 2 frames, 0.2s/frame, looping
+// =============================================================================
+
+#[rustfmt::skip]
+static ARCHER_WALK_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, R, B, B, R, H, N, N,
+    N, N, N, B, D, D, B, N, W, N,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, D, B, D, B, B, B, S, W,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, N, B, B, B, B, N, S, W,
+    N, N, N, D, B, B, D, N, S, W,
+    N, N, N, N, D, D, N, N, W, N,
+    N, N, N, D, N, N, N, D, N, N,
+    N, N, N, D, N, N, N, D, N, N,
+    N, N, E, N, N, N, N, E, N, N,
+    N, N, E, E, N, N, N, E, E, N,
+]);
+
+#[rustfmt::skip]
+static ARCHER_WALK_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, R, B, B, R, H, N, N,
+    N, N, N, B, D, D, B, N, W, N,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, D, B, D, B, B, B, S, W,
+    N, N, D, B, B, B, B, D, S, W,
+    N, N, N, B, B, B, B, N, S, W,
+    N, N, N, D, B, B, D, N, S, W,
+    N, N, N, N, D, D, N, N, W, N,
+    N, N, N, N, D, D, N, N, N, N,
+    N, N, N, N, D, D, N, N, N, N,
+    N, N, N, N, E, E, N, N, N, N,
+    N, N, N, E, E, N, N, N, N, N,
+]);
+
+pub static ARCHER_WALK_ANIM: AnimationData = AnimationData {
+    frames: &[&ARCHER_WALK_0, &ARCHER_WALK_1],
+    frame_duration: 0.2,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// DRAW — 2 frames, 0.15s/frame, looping while aiming. Eyes and arrowhead glow.
+// =============================================================================
+
+#[rustfmt::skip]
+static ARCHER_DRAW_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, R, B, B, R, H, N, N,
+    N, N, N, B, D, D, B, N, N, W,
+    N, N, D, B, B, B, B, D, N, W,
+    N, N, D, B, D, B, B, S, N, W,
+    N, N, D, B, B, B, S, A, A, K,
+    N, N, N, B, B, B, B, S, N, W,
+    N, N, N, D, B, B, D, N, N, W,
+    N, N, N, N, D, D, N, N, N, W,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, E, N, E, N, N, N,
+    N, N, N, E, E, N, E, E, N, N,
+]);
+
+#[rustfmt::skip]
+static ARCHER_DRAW_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, K, B, B, K, H, N, N,
+    N, N, N, B, D, D, B, N, N, W,
+    N, N, D, B, B, B, B, D, N, W,
+    N, N, D, B, D, B, B, S, N, W,
+    N, N, D, B, B, B, S, A, A, K,
+    N, N, N, B, B, B, B, S, N, W,
+    N, N, N, D, B, B, D, N, N, W,
+    N, N, N, N, D, D, N, N, N, W,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, D, N, D, N, N, N,
+    N, N, N, N, E, N, E, N, N, N,
+    N, N, N, E, E, N, E, E, N, N,
+]);
+
+pub static ARCHER_DRAW_ANIM: AnimationData = AnimationData {
+    frames: &[&ARCHER_DRAW_0, &ARCHER_DRAW_1],
+    frame_duration: 0.15,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// STAGGER — 1 frame, one-shot. Death reuses the skeleton's collapse.
+// =============================================================================
+
+pub static ARCHER_STAGGER_ANIM: AnimationData = AnimationData {
+    frames: &[&ARCHER_IDLE_1],
+    frame_duration: 0.3,
+    looping: false,
+    events: &[],
+};
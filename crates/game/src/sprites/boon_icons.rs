@@ -154,6 +154,61 @@ pub static ICON_FURY: SpriteData = SpriteData::new(8, 8, &[
     FR, FR, FR, FR, FR, FR,  N,  N,
 ]);
 
+// Searing Strikes colors
+const HB: Option<Color> = Some([190, 190, 200]);  // steel blade
+const HO: Option<Color> = Some([255, 130, 30]);   // flame
+const HY: Option<Color> = Some([255, 220, 80]);   // flame core
+const HD: Option<Color> = Some([120, 30, 30]);    // dark handle
+
+/// Sword wrapped in flame — orange. 8x8.
+#[rustfmt::skip]
+pub static ICON_SEARING_STRIKES: SpriteData = SpriteData::new(8, 8, &[
+    N,  N,  N,  N, HO, HY, HO,  N,
+    N,  N,  N, HO, HY, HB, HO,  N,
+    N,  N, HO, HY, HB, HO,  N,  N,
+    N, HO, HY, HB, HO,  N,  N,  N,
+    N, HO, HB, HO,  N,  N,  N,  N,
+    N, HD, HO,  N,  N,  N,  N,  N,
+    HD, HD, HD,  N,  N,  N,  N,  N,
+    N, HD,  N,  N,  N,  N,  N,  N,
+]);
+
+// Venom Blade colors
+const OB: Option<Color> = Some([170, 190, 170]);  // greenish steel
+const OG: Option<Color> = Some([90, 220, 60]);    // venom
+const OD: Option<Color> = Some([30, 100, 30]);    // dark handle
+
+/// Dagger dripping venom — green. 8x8.
+#[rustfmt::skip]
+pub static ICON_VENOM_BLADE: SpriteData = SpriteData::new(8, 8, &[
+    N,  N,  N,  N,  N,  N, OB,  N,
+    N,  N,  N,  N,  N, OB, OG,  N,
+    N,  N,  N,  N, OB, OG,  N,  N,
+    N,  N,  N, OB, OG,  N,  N,  N,
+    N, OD,  N, OG,  N,  N,  N,  N,
+    N,  N, OD,  N,  N,  N, OG,  N,
+    N, OD,  N, OD,  N,  N,  N,  N,
+    OD,  N,  N,  N,  N, OG,  N,  N,
+]);
+
+// Frostbite colors
+const ZW: Option<Color> = Some([230, 245, 255]);  // white ice
+const ZB: Option<Color> = Some([120, 180, 255]);  // frost blue
+const ZD: Option<Color> = Some([60, 100, 200]);   // deep blue
+
+/// Snowflake — icy blue. 8x8.
+#[rustfmt::skip]
+pub static ICON_FROSTBITE: SpriteData = SpriteData::new(8, 8, &[
+    N, ZD,  N, ZB,  N, ZD,  N,  N,
+    N,  N, ZB, ZW, ZB,  N,  N,  N,
+    ZD, ZB,  N, ZW,  N, ZB, ZD,  N,
+    ZB, ZW, ZW, ZW, ZW, ZW, ZB,  N,
+    ZD, ZB,  N, ZW,  N, ZB, ZD,  N,
+    N,  N, ZB, ZW, ZB,  N,  N,  N,
+    N, ZD,  N, ZB,  N, ZD,  N,  N,
+    N,  N,  N,  N,  N,  N,  N,  N,
+]);
+
 // ============================================================================
 // DEFENSE BOONS (blue tones)
 // ============================================================================
@@ -418,3 +473,58 @@ pub static ICON_DEATHS_BARGAIN: SpriteData = SpriteData::new(8, 8, &[
     N,  N, DK, DD, DD, DK,  N,  N,
     N,  N,  N, DK, DK,  N,  N,  N,
 ]);
+
+// ============================================================================
+// DUO BOONS (teal accents, combining both halves' motifs)
+// ============================================================================
+
+// Storm Edge colors
+const EB: Option<Color> = Some([190, 200, 220]);  // steel blade
+const EY: Option<Color> = Some([255, 240, 90]);   // lightning
+const EW: Option<Color> = Some([255, 255, 255]);  // crit glint
+const ED: Option<Color> = Some([60, 150, 160]);   // teal handle
+
+/// Blade struck by lightning — steel/yellow. 8x8.
+#[rustfmt::skip]
+pub static ICON_STORM_EDGE: SpriteData = SpriteData::new(8, 8, &[
+    N,  N,  N,  N, EY,  N, EW,  N,
+    N,  N,  N, EY,  N, EB, EY,  N,
+    N,  N,  N, EY, EB, EY,  N,  N,
+    N,  N,  N, EB, EY, EY,  N,  N,
+    N,  N, EB, EB,  N, EY,  N,  N,
+    N, ED, EB,  N,  N, EY,  N,  N,
+    ED, ED, ED,  N,  N,  N,  N,  N,
+    N, ED,  N,  N,  N,  N,  N,  N,
+]);
+
+// Phase Strike colors
+const JG: Option<Color> = Some([120, 240, 220]);  // phantom teal
+const JD: Option<Color> = Some([50, 120, 130]);   // faded trail
+const JW: Option<Color> = Some([230, 255, 250]);  // impact flash
+
+/// Ghostly dash arrow hitting a star — teal. 8x8.
+#[rustfmt::skip]
+pub static ICON_PHASE_STRIKE: SpriteData = SpriteData::new(8, 8, &[
+    N,  N,  N,  N,  N,  N, JW,  N,
+    N,  N,  N,  N, JG, JW, JW, JW,
+    JD,  N, JD, JG,  N,  N, JW,  N,
+    JD, JD, JG, JG, JG, JG,  N,  N,
+    JD,  N, JD, JG,  N,  N,  N,  N,
+    N,  N,  N,  N, JG,  N,  N,  N,
+    N,  N,  N,  N,  N,  N,  N,  N,
+    N,  N,  N,  N,  N,  N,  N,  N,
+]);
+
+// Synergy badge colors
+const XT: Option<Color> = Some([90, 230, 210]);   // teal ring
+const XW: Option<Color> = Some([220, 255, 250]);  // link highlight
+
+/// Two linked rings marking a duo boon card. 5x5.
+#[rustfmt::skip]
+pub static SYNERGY_BADGE: SpriteData = SpriteData::new(5, 5, &[
+    XT, XT,  N,  N,  N,
+    XT,  N, XW, XT,  N,
+    N, XW, XW, XW,  N,
+    N, XT, XW,  N, XT,
+    N,  N,  N, XT, XT,
+]);
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use engine::animation::AnimationData;
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
 use engine::color::Color;
 use engine::sprite::SpriteData;
 
@@ -200,41 +200,81 @@ pub static BONE_KING_ROAR: SpriteData = SpriteData::new(20, 24, &[
 ]);
 
 // =============================================================================
-// ANIMATION DEFINITIONS — single-frame anims for each boss pose
+// ANIMATION DEFINITIONS — one pose per anim; attack anims carry the
+// hitbox and impact frame events
 // =============================================================================
 
 pub static BONE_KING_IDLE_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_IDLE],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
 
-pub static BONE_KING_SLAM_ANIM: AnimationData = AnimationData {
+/// Raised-arms telegraph before the slam. No hitbox.
+pub static BONE_KING_SLAM_WINDUP_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_SLAM],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
 
-pub static BONE_KING_SWEEP_ANIM: AnimationData = AnimationData {
+/// Slam impact + recovery. Frame 0 lasts SLAM_ACTIVE (0.15s).
+pub static BONE_KING_SLAM_ANIM: AnimationData = AnimationData {
+    frames: &[&BONE_KING_SLAM, &BONE_KING_SLAM],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(0, AnimEvent::SpawnParticle("slam_impact")),
+        FrameEvent::new(1, AnimEvent::HitboxOff),
+    ],
+};
+
+pub static BONE_KING_SWEEP_WINDUP_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_SWEEP],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
+};
+
+/// Sweep swing + recovery. Frame 0 lasts SWEEP_ACTIVE (0.2s).
+pub static BONE_KING_SWEEP_ANIM: AnimationData = AnimationData {
+    frames: &[&BONE_KING_SWEEP, &BONE_KING_SWEEP],
+    frame_duration: 0.2,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(0, AnimEvent::PlaySound("sweep")),
+        FrameEvent::new(1, AnimEvent::HitboxOff),
+    ],
+};
+
+pub static BONE_KING_CHARGE_WINDUP_ANIM: AnimationData = AnimationData {
+    frames: &[&BONE_KING_CHARGE],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
 };
 
+/// Body hitbox stays live for the whole charge; the stun anim closes it.
 pub static BONE_KING_CHARGE_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_CHARGE],
     frame_duration: 0.5,
     looping: true,
+    events: &[FrameEvent::new(0, AnimEvent::HitboxOn)],
 };
 
 pub static BONE_KING_STUNNED_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_STUNNED],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
 
 pub static BONE_KING_ROAR_ANIM: AnimationData = AnimationData {
     frames: &[&BONE_KING_ROAR],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
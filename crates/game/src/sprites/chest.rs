@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const B: Option<Color> = Some([120, 70, 30]); // wood
+const D: Option<Color> = Some([80, 45, 20]); // dark wood
+const G: Option<Color> = Some([255, 200, 50]); // gold bands
+const K: Option<Color> = Some([200, 150, 30]); // dark gold / lock
+const I: Option<Color> = Some([20, 15, 10]); // empty interior
+const L: Option<Color> = Some([255, 240, 150]); // light spill
+
+// =============================================================================
+// CLOSED — single frame. 10x8 sprite
+// =============================================================================
+
+#[rustfmt::skip]
+static CHEST_CLOSED: SpriteData = SpriteData::new(10, 8, &[
+    N, N, N, N, N, N, N, N, N, N,
+    N, D, D, D, D, D, D, D, D, N,
+    D, B, G, B, B, B, B, G, B, D,
+    D, G, G, G, K, K, G, G, G, D,
+    D, B, G, B, K, K, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    N, D, D, D, D, D, D, D, D, N,
+]);
+
+pub static CHEST_CLOSED_ANIM: AnimationData = AnimationData {
+    frames: &[&CHEST_CLOSED],
+    frame_duration: 1.0,
+    looping: false,
+    events: &[],
+};
+
+// =============================================================================
+// OPEN — 3 frames, 0.12s/frame, non-looping. Lid rattles, lifts, flips back.
+// Loot bursts out on the last frame.
+// =============================================================================
+
+#[rustfmt::skip]
+static CHEST_OPEN_0: SpriteData = SpriteData::new(10, 8, &[
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, D, D, D, D, D, D, D, D,
+    N, D, B, G, B, B, B, B, G, D,
+    D, G, G, G, K, K, G, G, G, D,
+    D, B, G, B, K, K, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    N, D, D, D, D, D, D, D, D, N,
+]);
+
+#[rustfmt::skip]
+static CHEST_OPEN_1: SpriteData = SpriteData::new(10, 8, &[
+    N, D, D, D, D, D, D, D, D, N,
+    D, B, G, B, B, B, B, G, B, D,
+    N, D, D, D, D, D, D, D, D, N,
+    D, L, L, L, L, L, L, L, L, D,
+    D, B, G, B, K, K, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    N, D, D, D, D, D, D, D, D, N,
+]);
+
+#[rustfmt::skip]
+static CHEST_OPEN_2: SpriteData = SpriteData::new(10, 8, &[
+    N, D, D, D, D, D, D, D, D, N,
+    D, B, G, B, B, B, B, G, B, D,
+    D, G, G, G, G, G, G, G, G, D,
+    D, I, I, I, I, I, I, I, I, D,
+    D, B, G, B, K, K, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    D, B, G, B, B, B, B, G, B, D,
+    N, D, D, D, D, D, D, D, D, N,
+]);
+
+pub static CHEST_OPEN_ANIM: AnimationData = AnimationData {
+    frames: &[&CHEST_OPEN_0, &CHEST_OPEN_1, &CHEST_OPEN_2],
+    frame_duration: 0.12,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("chest_creak")),
+        FrameEvent::new(2, AnimEvent::SpawnParticle("chest_loot")),
+    ],
+};
@@ -16,6 +16,17 @@ pub static PROJECTILE_ORB: SpriteData = SpriteData::new(3, 3, &[
     N, B, N,
 ]);
 
+// --- Slash orb: 3x3, player-fired projectile (Projectile Slash boon) ---
+const W: Option<Color> = Some([255, 255, 220]); // white-hot center
+const Y: Option<Color> = Some([255, 200, 60]); // gold edge
+
+#[rustfmt::skip]
+pub static SLASH_ORB: SpriteData = SpriteData::new(3, 3, &[
+    N, Y, N,
+    Y, W, Y,
+    N, Y, N,
+]);
+
 // --- Heart sprites for HUD: 5x5 ---
 const R: Option<Color> = Some([220, 30, 30]); // red heart
 const D: Option<Color> = Some([160, 20, 20]); // dark red
@@ -56,3 +67,96 @@ pub static SPAWN_WARNING: SpriteData = SpriteData::new(7, 7, &[
     N, O, I, I, I, O, N,
     N, N, O, O, O, N, N,
 ]);
+
+// --- Status effect HUD icons: 5x5, one per kind ---
+const BO: Option<Color> = Some([255, 130, 40]); // burn orange
+const BY: Option<Color> = Some([255, 220, 90]); // burn core
+const PG: Option<Color> = Some([110, 220, 80]); // poison green
+const PD: Option<Color> = Some([40, 120, 40]); // poison dark
+const SB: Option<Color> = Some([130, 180, 255]); // slow blue
+const FW: Option<Color> = Some([220, 245, 255]); // frost white
+const FB: Option<Color> = Some([100, 160, 230]); // frost blue
+const SY: Option<Color> = Some([255, 240, 120]); // stun yellow
+const WP: Option<Color> = Some([190, 120, 230]); // weakness purple
+
+/// Flame.
+#[rustfmt::skip]
+pub static STATUS_BURN: SpriteData = SpriteData::new(5, 5, &[
+    N, N, BO, N, N,
+    N, BO, BO, N, N,
+    N, BO, BY, BO, N,
+    BO, BY, BY, BO, N,
+    N, BO, BO, BO, N,
+]);
+
+/// Venom drop.
+#[rustfmt::skip]
+pub static STATUS_POISON: SpriteData = SpriteData::new(5, 5, &[
+    N, N, PG, N, N,
+    N, PG, PG, PG, N,
+    PG, PG, PG, PD, PG,
+    PG, PG, PD, PD, PG,
+    N, PG, PG, PG, N,
+]);
+
+/// Downward arrow.
+#[rustfmt::skip]
+pub static STATUS_SLOW: SpriteData = SpriteData::new(5, 5, &[
+    N, N, SB, N, N,
+    N, N, SB, N, N,
+    SB, N, SB, N, SB,
+    N, SB, SB, SB, N,
+    N, N, SB, N, N,
+]);
+
+/// Ice crystal.
+#[rustfmt::skip]
+pub static STATUS_FREEZE: SpriteData = SpriteData::new(5, 5, &[
+    FB, N, FW, N, FB,
+    N, FB, FW, FB, N,
+    FW, FW, FW, FW, FW,
+    N, FB, FW, FB, N,
+    FB, N, FW, N, FB,
+]);
+
+/// Dizzy stars.
+#[rustfmt::skip]
+pub static STATUS_STUN: SpriteData = SpriteData::new(5, 5, &[
+    N, SY, N, N, N,
+    SY, SY, SY, N, N,
+    N, SY, N, N, SY,
+    N, N, N, SY, SY,
+    N, N, N, N, SY,
+]);
+
+/// Broken blade.
+#[rustfmt::skip]
+pub static STATUS_WEAKNESS: SpriteData = SpriteData::new(5, 5, &[
+    N, N, N, N, WP,
+    N, N, N, WP, N,
+    N, N, N, N, N,
+    N, WP, N, N, N,
+    WP, N, N, N, N,
+]);
+
+// --- Player ranged shots: 3x3 ---
+const AT: Option<Color> = Some([230, 230, 240]); // arrowhead steel
+const AS: Option<Color> = Some([150, 100, 50]); // arrow shaft
+const SP: Option<Color> = Some([180, 120, 255]); // spell violet
+const SG: Option<Color> = Some([240, 220, 255]); // spell glow
+
+/// Bow arrow in flight.
+#[rustfmt::skip]
+pub static ARROW_HEAD: SpriteData = SpriteData::new(3, 3, &[
+    N, AT, N,
+    AT, AS, AT,
+    N, AT, N,
+]);
+
+/// Staff spell bolt.
+#[rustfmt::skip]
+pub static SPELL_BOLT: SpriteData = SpriteData::new(3, 3, &[
+    N, SP, N,
+    SP, SG, SP,
+    N, SP, N,
+]);
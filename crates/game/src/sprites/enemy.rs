@@ -48,6 +48,40 @@ pub static ENEMY_IDLE_ANIM: AnimationData = AnimationData {
     frames: &[&ENEMY_IDLE_0, &ENEMY_IDLE_1],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// STRETCH — 1 frame
+// Mid-hop: tall and narrow. Squash reuses the wide idle frame.
+// =============================================================================
+
+#[rustfmt::skip]
+static ENEMY_STRETCH_0: SpriteData = SpriteData::new(10, 10, &[
+    N, N, N, N, L, L, N, N, N, N,
+    N, N, N, L, G, G, L, N, N, N,
+    N, N, N, G, G, G, G, N, N, N,
+    N, N, N, R, G, G, R, N, N, N,
+    N, N, N, G, G, G, G, N, N, N,
+    N, N, N, G, G, G, G, N, N, N,
+    N, N, N, D, G, G, D, N, N, N,
+    N, N, N, N, D, D, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+]);
+
+pub static ENEMY_SQUASH_ANIM: AnimationData = AnimationData {
+    frames: &[&ENEMY_IDLE_1],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static ENEMY_STRETCH_ANIM: AnimationData = AnimationData {
+    frames: &[&ENEMY_STRETCH_0],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -120,4 +154,5 @@ pub static ENEMY_DEATH_ANIM: AnimationData = AnimationData {
     ],
     frame_duration: 0.15,
     looping: false,
+    events: &[],
 };
@@ -53,6 +53,7 @@ pub static GHOST_IDLE_ANIM: AnimationData = AnimationData {
     frames: &[&GHOST_IDLE_0, &GHOST_IDLE_1],
     frame_duration: 0.6,
     looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -95,6 +96,7 @@ pub static GHOST_AIM_ANIM: AnimationData = AnimationData {
     frames: &[&GHOST_AIM_0, &GHOST_AIM_1],
     frame_duration: 0.3,
     looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -105,6 +107,7 @@ pub static GHOST_STAGGER_ANIM: AnimationData = AnimationData {
     frames: &[&GHOST_IDLE_1],
     frame_duration: 0.3,
     looping: false,
+    events: &[],
 };
 
 // =============================================================================
@@ -147,4 +150,5 @@ pub static GHOST_DEATH_ANIM: AnimationData = AnimationData {
     frames: &[&GHOST_DEATH_0, &GHOST_DEATH_1],
     frame_duration: 0.2,
     looping: false,
+    events: &[],
 };
@@ -0,0 +1,152 @@
+#![allow(dead_code)]
+
+use engine::animation::AnimationData;
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const B: Option<Color> = Some([220, 215, 200]); // skull bone
+const D: Option<Color> = Some([150, 140, 125]); // bone shadow
+const P: Option<Color> = Some([90, 40, 130]); // violet robe
+const Q: Option<Color> = Some([55, 22, 85]); // robe shadow
+const T: Option<Color> = Some([70, 35, 100]); // tattered hem
+const C: Option<Color> = Some([80, 255, 255]); // soul fire
+const O: Option<Color> = Some([40, 160, 180]); // staff orb
+const S: Option<Color> = Some([120, 90, 60]); // staff wood
+const W: Option<Color> = Some([220, 255, 255]); // flare
+
+// The Lich: 16x20, floats on a tattered hem with a soul-fire staff.
+
+// =============================================================================
+// POSES
+// =============================================================================
+
+#[rustfmt::skip]
+static LICH_IDLE_0: SpriteData = SpriteData::new(16, 20, &[
+    N, N, N, N, N, N, Q, Q, Q, Q, N, N, N, N, N, N,
+    N, N, N, N, N, Q, P, P, P, P, Q, N, N, O, O, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, O, C, C, O,
+    N, N, N, N, Q, P, B, B, B, B, P, Q, O, C, C, O,
+    N, N, N, N, Q, B, C, B, B, C, B, Q, N, O, O, N,
+    N, N, N, N, Q, B, B, B, B, B, B, Q, N, N, S, N,
+    N, N, N, N, Q, P, B, D, D, B, P, Q, N, N, S, N,
+    N, N, N, Q, P, P, B, B, B, B, P, P, Q, N, S, N,
+    N, N, Q, P, P, P, P, P, P, P, P, P, P, Q, S, N,
+    N, Q, P, P, Q, P, P, P, P, P, P, Q, P, P, B, N,
+    N, Q, P, Q, N, P, P, P, P, P, P, N, Q, P, S, N,
+    N, B, Q, N, N, P, P, P, P, P, P, N, N, Q, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, Q, Q, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, Q, P, P, Q, P, Q, N, N, S, N,
+    N, N, N, N, N, Q, T, P, P, T, Q, N, N, N, S, N,
+    N, N, N, N, N, T, N, T, T, N, T, N, N, N, S, N,
+    N, N, N, N, N, N, T, N, N, T, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static LICH_IDLE_1: SpriteData = SpriteData::new(16, 20, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, Q, Q, Q, Q, N, N, N, N, N, N,
+    N, N, N, N, N, Q, P, P, P, P, Q, N, N, O, O, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, O, C, C, O,
+    N, N, N, N, Q, P, B, B, B, B, P, Q, O, C, C, O,
+    N, N, N, N, Q, B, C, B, B, C, B, Q, N, O, O, N,
+    N, N, N, N, Q, B, B, B, B, B, B, Q, N, N, S, N,
+    N, N, N, N, Q, P, B, D, D, B, P, Q, N, N, S, N,
+    N, N, N, Q, P, P, B, B, B, B, P, P, Q, N, S, N,
+    N, N, Q, P, P, P, P, P, P, P, P, P, P, Q, S, N,
+    N, Q, P, P, Q, P, P, P, P, P, P, Q, P, P, B, N,
+    N, Q, P, Q, N, P, P, P, P, P, P, N, Q, P, S, N,
+    N, B, Q, N, N, P, P, P, P, P, P, N, N, Q, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, Q, Q, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, Q, P, P, Q, P, Q, N, N, S, N,
+    N, N, N, N, N, Q, T, P, P, T, Q, N, N, N, S, N,
+    N, N, N, N, N, T, N, T, T, N, T, N, N, N, S, N,
+    N, N, N, N, N, N, T, N, N, T, N, N, N, N, N, N,
+]);
+
+/// Hands on the orb, soul fire flaring while a pattern charges and fires.
+#[rustfmt::skip]
+static LICH_CAST: SpriteData = SpriteData::new(16, 20, &[
+    N, N, N, N, N, N, Q, Q, Q, Q, N, N, N, C, N, N,
+    N, N, N, N, N, Q, P, P, P, P, Q, N, C, W, W, C,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, W, C, C, W,
+    N, N, N, N, Q, P, B, B, B, B, P, Q, W, C, C, W,
+    N, N, N, N, Q, B, W, B, B, W, B, Q, C, W, W, C,
+    N, N, N, N, Q, B, B, B, B, B, B, Q, B, S, C, N,
+    N, N, N, N, Q, P, B, D, D, B, P, Q, P, S, N, N,
+    N, N, N, Q, P, P, B, B, B, B, P, P, P, S, N, N,
+    N, N, Q, P, P, P, P, P, P, P, P, P, Q, S, N, N,
+    N, Q, P, P, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    B, Q, P, Q, N, P, P, P, P, P, P, N, N, N, S, N,
+    C, N, Q, N, N, P, P, P, P, P, P, N, N, N, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, P, P, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, P, Q, Q, P, P, Q, N, N, S, N,
+    N, N, N, N, Q, P, Q, P, P, Q, P, Q, N, N, S, N,
+    N, N, N, N, N, Q, T, P, P, T, Q, N, N, N, S, N,
+    N, N, N, N, N, T, N, T, T, N, T, N, N, N, S, N,
+    N, N, N, N, N, N, T, N, N, T, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+/// Robes flared and head thrown back: phase change and death.
+#[rustfmt::skip]
+static LICH_ROAR: SpriteData = SpriteData::new(16, 20, &[
+    N, N, N, Q, N, N, Q, Q, Q, Q, N, N, Q, N, N, N,
+    N, N, N, N, Q, Q, P, P, P, P, Q, Q, N, O, O, N,
+    N, N, N, Q, Q, P, P, P, P, P, P, Q, Q, C, C, O,
+    N, N, N, N, Q, P, B, B, B, B, P, Q, O, C, C, O,
+    N, N, N, N, Q, B, W, B, B, W, B, Q, N, O, O, N,
+    N, N, N, N, Q, B, B, D, D, B, B, Q, N, N, S, N,
+    N, N, N, N, Q, P, B, D, D, B, P, Q, N, N, S, N,
+    N, N, Q, Q, P, P, B, B, B, B, P, P, Q, Q, S, N,
+    N, Q, P, P, P, P, P, P, P, P, P, P, P, P, Q, S,
+    Q, P, P, Q, P, P, P, P, P, P, P, P, Q, P, P, B,
+    Q, P, Q, N, N, P, P, P, P, P, P, N, N, Q, P, S,
+    B, Q, N, N, N, P, P, P, P, P, P, N, N, N, Q, S,
+    N, N, N, Q, Q, P, P, P, P, P, P, Q, Q, N, S, N,
+    N, N, N, Q, P, P, P, P, P, P, P, P, Q, N, S, N,
+    N, N, N, Q, P, P, P, Q, Q, P, P, P, Q, N, S, N,
+    N, N, N, Q, P, P, Q, P, P, Q, P, P, Q, N, S, N,
+    N, N, N, N, Q, Q, T, P, P, T, Q, Q, N, N, S, N,
+    N, N, N, N, T, N, T, N, T, T, N, T, N, N, S, N,
+    N, N, N, N, N, T, N, N, T, N, N, T, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+// =============================================================================
+// ANIMATIONS
+// =============================================================================
+
+pub static LICH_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&LICH_IDLE_0, &LICH_IDLE_1],
+    frame_duration: 0.4,
+    looping: true,
+    events: &[],
+};
+
+pub static LICH_CAST_WINDUP_ANIM: AnimationData = AnimationData {
+    frames: &[&LICH_CAST, &LICH_IDLE_0],
+    frame_duration: 0.1,
+    looping: true,
+    events: &[],
+};
+
+pub static LICH_CAST_ANIM: AnimationData = AnimationData {
+    frames: &[&LICH_CAST],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static LICH_ROAR_ANIM: AnimationData = AnimationData {
+    frames: &[&LICH_ROAR],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
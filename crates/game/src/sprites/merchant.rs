@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use engine::animation::AnimationData;
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const H: Option<Color> = Some([90, 60, 40]); // hood brown
+const K: Option<Color> = Some([60, 40, 25]); // dark hood shadow
+const F: Option<Color> = Some([230, 190, 150]); // face
+const E: Option<Color> = Some([40, 30, 20]); // eyes
+const B: Option<Color> = Some([200, 200, 210]); // beard
+const R: Option<Color> = Some([130, 40, 50]); // robe red
+const D: Option<Color> = Some([90, 25, 35]); // dark robe
+const G: Option<Color> = Some([255, 200, 50]); // gold trim / coin
+const Y: Option<Color> = Some([255, 240, 150]); // coin glint
+
+// =============================================================================
+// IDLE — 2 frames, 0.5s/frame, looping. Merchant flips a coin.
+// 10x12 sprite, stands behind the shop counter
+// =============================================================================
+
+#[rustfmt::skip]
+static MERCHANT_IDLE_0: SpriteData = SpriteData::new(10, 12, &[
+    N, N, N, K, H, H, K, N, N, N,
+    N, N, K, H, H, H, H, K, N, N,
+    N, N, H, F, F, F, F, H, N, N,
+    N, N, H, E, F, F, E, H, N, N,
+    N, N, K, B, F, F, B, K, N, N,
+    N, N, N, B, B, B, B, N, N, N,
+    N, N, R, R, B, B, R, R, N, N,
+    N, R, R, G, R, R, G, R, R, N,
+    N, F, R, R, R, R, R, R, F, G,
+    N, N, D, R, R, R, R, D, N, N,
+    N, N, D, R, G, G, R, D, N, N,
+    N, N, D, D, D, D, D, D, N, N,
+]);
+
+#[rustfmt::skip]
+static MERCHANT_IDLE_1: SpriteData = SpriteData::new(10, 12, &[
+    N, N, N, K, H, H, K, N, N, N,
+    N, N, K, H, H, H, H, K, N, N,
+    N, N, H, F, F, F, F, H, N, N,
+    N, N, H, E, F, F, E, H, N, Y,
+    N, N, K, B, F, F, B, K, N, N,
+    N, N, N, B, B, B, B, N, N, N,
+    N, N, R, R, B, B, R, R, N, N,
+    N, R, R, G, R, R, G, R, F, N,
+    N, F, R, R, R, R, R, R, R, N,
+    N, N, D, R, R, R, R, D, N, N,
+    N, N, D, R, G, G, R, D, N, N,
+    N, N, D, D, D, D, D, D, N, N,
+]);
+
+pub static MERCHANT_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&MERCHANT_IDLE_0, &MERCHANT_IDLE_1],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
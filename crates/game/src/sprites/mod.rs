@@ -1,16 +1,29 @@
+mod archer;
 pub mod boon_icons;
 pub mod boss;
+pub mod chest;
 pub mod doors;
 pub mod effects;
 mod enemy;
 pub mod font;
 mod ghost;
+pub mod lich;
+pub mod merchant;
+mod necromancer;
 pub mod pickups;
 mod player;
+mod shield_bearer;
 mod skeleton;
+mod slime;
+pub mod slime_mother;
 pub mod title;
+pub mod traps;
 
+pub use archer::*;
 pub use enemy::*;
 pub use ghost::*;
+pub use necromancer::*;
 pub use player::*;
+pub use shield_bearer::*;
 pub use skeleton::*;
+pub use slime::*;
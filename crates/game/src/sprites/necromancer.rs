@@ -0,0 +1,215 @@
+#![allow(dead_code)]
+
+use engine::animation::AnimationData;
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const P: Option<Color> = Some([70, 40, 90]); // robe
+const Q: Option<Color> = Some([100, 60, 130]); // robe highlight
+const E: Option<Color> = Some([35, 20, 45]); // robe hem
+const K: Option<Color> = Some([220, 210, 190]); // skull
+const W: Option<Color> = Some([110, 80, 50]); // staff
+const G: Option<Color> = Some([80, 255, 120]); // soul fire
+const F: Option<Color> = Some([40, 140, 70]); // dim soul fire
+const L: Option<Color> = Some([200, 255, 210]); // soul fire flare
+
+// =============================================================================
+// IDLE — 2 frames, 0.4s/frame, looping. Staff flame flickers.
+// 10x14, hooded skull in a robe.
+// =============================================================================
+
+#[rustfmt::skip]
+static NECRO_IDLE_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, N, N, F,
+    N, N, P, P, P, P, P, P, N, G,
+    N, N, P, K, K, K, K, P, N, W,
+    N, N, P, G, K, K, G, P, N, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, K, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, P, P, P, P, P, P, P, P, W,
+    N, E, E, E, E, E, E, E, E, W,
+]);
+
+#[rustfmt::skip]
+static NECRO_IDLE_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, N, N, G,
+    N, N, P, P, P, P, P, P, N, F,
+    N, N, P, K, K, K, K, P, N, W,
+    N, N, P, G, K, K, G, P, N, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, K, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, P, P, P, P, P, P, P, P, W,
+    N, E, E, E, E, E, E, E, E, W,
+]);
+
+pub static NECRO_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&NECRO_IDLE_0, &NECRO_IDLE_1],
+    frame_duration: 0.4,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// WALK — 2 frames, 0.25s/frame, looping. Hem sways.
+// =============================================================================
+
+#[rustfmt::skip]
+static NECRO_WALK_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, N, N, F,
+    N, N, P, P, P, P, P, P, N, G,
+    N, N, P, K, K, K, K, P, N, W,
+    N, N, P, G, K, K, G, P, N, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, K, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    P, P, P, P, P, P, P, P, N, W,
+    E, E, E, E, E, E, E, N, N, W,
+]);
+
+#[rustfmt::skip]
+static NECRO_WALK_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, N, N, F,
+    N, N, P, P, P, P, P, P, N, G,
+    N, N, P, K, K, K, K, P, N, W,
+    N, N, P, G, K, K, G, P, N, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, K, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, N, P, P, P, P, P, P, P, W,
+    N, N, E, E, E, E, E, E, E, W,
+]);
+
+pub static NECRO_WALK_ANIM: AnimationData = AnimationData {
+    frames: &[&NECRO_WALK_0, &NECRO_WALK_1],
+    frame_duration: 0.25,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// CAST — 2 frames, 0.12s/frame, looping while raising. Staff held high, flame flares.
+// =============================================================================
+
+#[rustfmt::skip]
+static NECRO_CAST_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, G, G, G,
+    N, N, P, P, P, P, P, P, G, G,
+    N, N, P, K, K, K, K, P, K, W,
+    N, N, P, G, K, K, G, P, K, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, P, P, P, P, P, P, P, P, W,
+    N, E, E, E, E, E, E, E, E, W,
+]);
+
+#[rustfmt::skip]
+static NECRO_CAST_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, P, P, P, P, L, L, L,
+    N, N, P, P, P, P, P, P, L, L,
+    N, N, P, K, K, K, K, P, K, W,
+    N, N, P, L, K, K, L, P, K, W,
+    N, N, N, K, E, E, K, N, N, W,
+    N, N, Q, P, P, P, P, Q, N, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, P, N, W,
+    N, Q, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, N, P, P, Q, P, P, P, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, P, P, P, P, P, P, P, P, W,
+    N, E, E, E, E, E, E, E, E, W,
+]);
+
+pub static NECRO_CAST_ANIM: AnimationData = AnimationData {
+    frames: &[&NECRO_CAST_0, &NECRO_CAST_1],
+    frame_duration: 0.12,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// STAGGER — 1 frame, one-shot
+// =============================================================================
+
+pub static NECRO_STAGGER_ANIM: AnimationData = AnimationData {
+    frames: &[&NECRO_IDLE_1],
+    frame_duration: 0.3,
+    looping: false,
+    events: &[],
+};
+
+// =============================================================================
+// DEATH — 2 frames, 0.2s/frame, one-shot. Robe crumples around the staff.
+// =============================================================================
+
+#[rustfmt::skip]
+static NECRO_DEATH_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, P, P, P, P, N, N, N,
+    N, N, P, K, K, K, K, P, N, N,
+    N, N, P, G, K, K, G, P, N, N,
+    N, N, Q, P, P, P, P, Q, N, N,
+    N, Q, P, P, Q, P, P, P, W, N,
+    N, Q, P, P, Q, P, P, Q, W, N,
+    N, N, P, P, Q, P, P, Q, N, W,
+    N, P, P, P, Q, P, P, P, P, W,
+    N, P, P, P, P, P, P, P, P, N,
+    N, E, E, E, E, E, E, E, E, N,
+    N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static NECRO_DEATH_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N,
+    N, N, N, K, K, N, N, N, N, N,
+    N, N, P, P, Q, P, P, N, W, N,
+    N, P, P, P, P, P, P, P, W, N,
+    N, E, E, E, E, E, E, E, N, W,
+]);
+
+pub static NECRO_DEATH_ANIM: AnimationData = AnimationData {
+    frames: &[&NECRO_DEATH_0, &NECRO_DEATH_1],
+    frame_duration: 0.2,
+    looping: false,
+    events: &[],
+};
@@ -47,3 +47,34 @@ pub static PICKUP_COIN: SpriteData = SpriteData::new(5, 5, &[
     K, G, G, G, K,
     N, K, G, K, N,
 ]);
+
+// Die colors
+const I: Option<Color> = Some([230, 230, 240]); // ivory face
+const S: Option<Color> = Some([150, 150, 170]); // shaded edge
+const P: Option<Color> = Some([40, 40, 60]); // pips
+
+/// Reroll token sold by the merchant. Six-sided die, 5x5.
+#[rustfmt::skip]
+pub static PICKUP_REROLL: SpriteData = SpriteData::new(5, 5, &[
+    I, I, I, I, S,
+    I, P, I, I, S,
+    I, I, P, I, S,
+    I, I, I, P, S,
+    S, S, S, S, S,
+]);
+
+// Soul colors
+const V: Option<Color> = Some([170, 110, 255]); // violet
+const U: Option<Color> = Some([110, 60, 200]); // deep violet
+const E: Option<Color> = Some([235, 215, 255]); // pale core
+
+/// Boss soul, the rare currency. Wisp flame, 5x6.
+#[rustfmt::skip]
+pub static PICKUP_SOUL: SpriteData = SpriteData::new(5, 6, &[
+    N, N, V, N, N,
+    N, V, V, N, N,
+    N, V, E, V, N,
+    V, E, E, V, U,
+    U, V, E, V, U,
+    N, U, V, U, N,
+]);
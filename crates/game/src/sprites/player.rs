@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use engine::animation::AnimationData;
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
 use engine::color::Color;
 use engine::sprite::SpriteData;
 
@@ -59,6 +59,7 @@ pub static IDLE_ANIM: AnimationData = AnimationData {
     frames: &[&IDLE_0, &IDLE_1],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -128,6 +129,10 @@ pub static WALK_ANIM: AnimationData = AnimationData {
     frames: &[&WALK_0, &WALK_1, &WALK_2, &WALK_1],
     frame_duration: 0.12,
     looping: true,
+    events: &[
+        FrameEvent::new(0, AnimEvent::Footstep),
+        FrameEvent::new(2, AnimEvent::Footstep),
+    ],
 };
 
 // =============================================================================
@@ -177,11 +182,16 @@ pub static DASH_ANIM: AnimationData = AnimationData {
     frames: &[&DASH_0, &DASH_1],
     frame_duration: 0.07,
     looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::SpawnParticle("dust_puff")),
+        FrameEvent::new(0, AnimEvent::PlaySound("dash")),
+    ],
 };
 
 // =============================================================================
 // ATTACK — 4 frames, 0.06s/frame, one-shot
-// Wind-up → Swing mid → Follow-through (HITBOX) → Recovery
+// Wind-up → Swing mid → Follow-through → Recovery
+// Each weapon shares the frames but opens its hitbox on its own frame.
 // =============================================================================
 
 /// Attack frame 0: wind-up, weapon pulled up/back.
@@ -260,10 +270,259 @@ static ATTACK_3: SpriteData = SpriteData::new(10, 14, &[
     N, N, N, B, B, N, B, B, N, N,
 ]);
 
-pub static ATTACK_ANIM: AnimationData = AnimationData {
-    frames: &[&ATTACK_0, &ATTACK_1, &ATTACK_2, &ATTACK_3],
+const ATTACK_FRAMES: &[&SpriteData] = &[&ATTACK_0, &ATTACK_1, &ATTACK_2, &ATTACK_3];
+
+/// Sword: hitbox live from follow-through to the end.
+pub static SWORD_ATTACK_ANIM: AnimationData = AnimationData {
+    frames: ATTACK_FRAMES,
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("swing")),
+        FrameEvent::new(2, AnimEvent::HitboxOn),
+    ],
+};
+
+/// Spear: same window as the sword; the reach comes from the hitbox shape.
+pub static SPEAR_ATTACK_ANIM: AnimationData = AnimationData {
+    frames: ATTACK_FRAMES,
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("thrust")),
+        FrameEvent::new(2, AnimEvent::HitboxOn),
+    ],
+};
+
+/// Daggers: early, short window for rapid strikes.
+pub static DAGGERS_ATTACK_ANIM: AnimationData = AnimationData {
+    frames: ATTACK_FRAMES,
     frame_duration: 0.06,
     looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("stab")),
+        FrameEvent::new(1, AnimEvent::HitboxOn),
+        FrameEvent::new(3, AnimEvent::HitboxOff),
+    ],
+};
+
+/// Sword combo second hit: the blade swings back the way it came.
+pub static SWORD_BACKSWING_ANIM: AnimationData = AnimationData {
+    frames: &[&ATTACK_2, &ATTACK_1, &ATTACK_0, &ATTACK_3],
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("swing")),
+        FrameEvent::new(1, AnimEvent::HitboxOn),
+        FrameEvent::new(3, AnimEvent::HitboxOff),
+    ],
+};
+
+/// Sword combo finisher: a slower, wider-wound full swing.
+pub static SWORD_FINISHER_ANIM: AnimationData = AnimationData {
+    frames: &[&ATTACK_0, &ATTACK_0, &ATTACK_1, &ATTACK_2, &ATTACK_3],
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("swing_heavy")),
+        FrameEvent::new(3, AnimEvent::HitboxOn),
+    ],
+};
+
+/// Spear combo finisher: a held-back lunge.
+pub static SPEAR_LUNGE_ANIM: AnimationData = AnimationData {
+    frames: &[
+        &ATTACK_0, &ATTACK_0, &ATTACK_1, &ATTACK_2, &ATTACK_2, &ATTACK_3,
+    ],
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("thrust")),
+        FrameEvent::new(3, AnimEvent::HitboxOn),
+    ],
+};
+
+/// Daggers combo finisher: a quick stab with a longer window.
+pub static DAGGERS_FLURRY_ANIM: AnimationData = AnimationData {
+    frames: ATTACK_FRAMES,
+    frame_duration: 0.07,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("stab")),
+        FrameEvent::new(1, AnimEvent::HitboxOn),
+    ],
+};
+
+/// Melee heavy attack, released after holding Attack: long wind-up, wide swing.
+pub static HEAVY_ATTACK_ANIM: AnimationData = AnimationData {
+    frames: &[&ATTACK_0, &ATTACK_1, &ATTACK_2, &ATTACK_2, &ATTACK_3],
+    frame_duration: 0.08,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::PlaySound("swing_heavy")),
+        FrameEvent::new(2, AnimEvent::HitboxOn),
+        FrameEvent::new(4, AnimEvent::HitboxOff),
+    ],
+};
+
+// =============================================================================
+// BOW — draw pose held while charging, then a 3-frame release
+// Arrow leaves the string on the release frame (HitboxOn).
+// =============================================================================
+
+const O: Option<Color> = Some([150, 100, 50]); // bow wood
+const T: Option<Color> = Some([220, 220, 200]); // bowstring
+
+/// Bow frame 0: arrow nocked, string barely pulled.
+#[rustfmt::skip]
+static BOW_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, S, S, S, S, H, N, N,
+    N, N, N, S, S, S, S, N, O, N,
+    N, N, A, A, A, A, A, A, T, O,
+    N, N, D, A, L, A, A, D, T, O,
+    N, N, D, A, A, A, A, S, X, O,
+    N, N, N, A, A, A, A, N, T, O,
+    N, N, N, D, A, A, D, N, O, N,
+    N, N, N, N, A, A, N, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Bow frame 1: full draw, string pulled back to the chest.
+#[rustfmt::skip]
+static BOW_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, S, S, S, S, H, N, N,
+    N, N, N, S, S, S, S, N, O, N,
+    N, N, A, A, A, A, A, T, N, O,
+    N, N, D, A, L, A, T, D, N, O,
+    N, N, D, A, A, S, W, W, X, O,
+    N, N, N, A, A, A, T, N, N, O,
+    N, N, N, D, A, A, D, T, O, N,
+    N, N, N, N, A, A, N, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Bow frame 2: loosed, string snapped straight and the hand open.
+#[rustfmt::skip]
+static BOW_2: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, S, S, S, S, H, N, N,
+    N, N, N, S, S, S, S, N, O, N,
+    N, N, A, A, A, A, A, A, T, O,
+    N, N, D, A, L, A, A, D, T, O,
+    N, N, D, A, A, A, S, D, T, O,
+    N, N, N, A, A, A, A, N, T, O,
+    N, N, N, D, A, A, D, N, O, N,
+    N, N, N, N, A, A, N, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Bow draw: nock, then hold the full draw until the shot is loosed.
+pub static BOW_DRAW_ANIM: AnimationData = AnimationData {
+    frames: &[&BOW_0, &BOW_1],
+    frame_duration: 0.4,
+    looping: false,
+    events: &[FrameEvent::new(0, AnimEvent::PlaySound("bow_draw"))],
+};
+
+/// Bow release: the arrow flies on the loosed frame.
+pub static BOW_RELEASE_ANIM: AnimationData = AnimationData {
+    frames: &[&BOW_1, &BOW_2, &BOW_2],
+    frame_duration: 0.06,
+    looping: false,
+    events: &[
+        FrameEvent::new(1, AnimEvent::PlaySound("bow_release")),
+        FrameEvent::new(1, AnimEvent::HitboxOn),
+    ],
+};
+
+// =============================================================================
+// STAFF — 3 frames, 0.07s/frame, one-shot
+// Raise → Thrust (bolts leave, HitboxOn) → Recovery
+// =============================================================================
+
+const P: Option<Color> = Some([180, 120, 255]); // staff orb
+const G: Option<Color> = Some([240, 220, 255]); // orb glow
+
+/// Staff frame 0: staff raised overhead, orb gathering light.
+#[rustfmt::skip]
+static STAFF_0: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, P, N,
+    N, N, H, H, H, H, H, H, B, N,
+    N, N, H, S, S, S, S, H, B, N,
+    N, N, N, S, S, S, S, N, B, N,
+    N, N, A, A, A, A, A, A, S, N,
+    N, N, D, A, L, A, A, D, B, N,
+    N, N, D, A, A, A, A, D, B, N,
+    N, N, N, A, A, A, A, N, B, N,
+    N, N, N, D, A, A, D, N, B, N,
+    N, N, N, N, A, A, N, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Staff frame 1: thrust forward, orb flaring as the bolts leave.
+#[rustfmt::skip]
+static STAFF_1: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, N, N,
+    N, N, H, S, S, S, S, H, N, N,
+    N, N, N, S, S, S, S, N, N, G,
+    N, N, A, A, A, A, A, A, G, P,
+    N, N, D, A, L, A, A, S, B, G,
+    N, N, D, A, A, A, B, D, N, N,
+    N, N, N, A, A, B, A, N, N, N,
+    N, N, N, D, B, A, D, N, N, N,
+    N, N, N, N, A, A, N, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Staff frame 2: recovery, staff back upright.
+#[rustfmt::skip]
+static STAFF_2: SpriteData = SpriteData::new(10, 14, &[
+    N, N, N, H, H, H, H, N, N, N,
+    N, N, H, H, H, H, H, H, P, N,
+    N, N, H, S, S, S, S, H, B, N,
+    N, N, N, S, S, S, S, N, B, N,
+    N, N, A, A, A, A, A, A, B, N,
+    N, N, D, A, L, A, A, D, S, N,
+    N, N, D, A, A, A, A, D, B, N,
+    N, N, N, A, A, A, A, N, B, N,
+    N, N, N, D, A, A, D, N, B, N,
+    N, N, N, N, A, A, N, N, B, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, A, N, A, N, N, N,
+    N, N, N, N, B, N, B, N, N, N,
+    N, N, N, B, B, N, B, B, N, N,
+]);
+
+/// Staff cast: the burst leaves on the thrust frame.
+pub static STAFF_CAST_ANIM: AnimationData = AnimationData {
+    frames: &[&STAFF_0, &STAFF_1, &STAFF_2],
+    frame_duration: 0.07,
+    looping: false,
+    events: &[
+        FrameEvent::new(1, AnimEvent::PlaySound("cast")),
+        FrameEvent::new(1, AnimEvent::HitboxOn),
+    ],
 };
 
 // =============================================================================
@@ -313,6 +572,7 @@ pub static HIT_ANIM: AnimationData = AnimationData {
     frames: &[&HIT_0, &HIT_1],
     frame_duration: 0.1,
     looping: false,
+    events: &[],
 };
 
 // =============================================================================
@@ -400,4 +660,5 @@ pub static DEATH_ANIM: AnimationData = AnimationData {
     frames: &[&DEATH_0, &DEATH_1, &DEATH_2, &DEATH_3],
     frame_duration: 0.15,
     looping: false,
+    events: &[],
 };
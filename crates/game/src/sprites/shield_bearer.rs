@@ -0,0 +1,266 @@
+#![allow(dead_code)]
+
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const B: Option<Color> = Some([220, 210, 190]); // bone white
+const D: Option<Color> = Some([160, 150, 130]); // bone shadow
+const E: Option<Color> = Some([100, 90, 80]); // dark bone
+const R: Option<Color> = Some([200, 40, 40]); // red eyes
+const S: Option<Color> = Some([130, 130, 145]); // shield steel
+const H: Option<Color> = Some([90, 90, 105]); // shield shadow
+const Y: Option<Color> = Some([200, 160, 60]); // brass rim
+const K: Option<Color> = Some([255, 200, 50]); // telegraph glow
+const X: Option<Color> = Some([255, 255, 230]); // bash streak
+
+// =============================================================================
+// IDLE — 2 frames, 0.5s/frame, looping. Tower shield held in front.
+// 12x14: skeleton body plus a 4-wide shield on the facing side.
+// =============================================================================
+
+#[rustfmt::skip]
+static SHIELD_IDLE_0: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, Y, Y, Y, Y,
+    N, N, N, B, D, D, B, N, Y, S, S, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, H, S, Y,
+    N, N, N, B, B, B, B, N, Y, S, H, Y,
+    N, N, N, D, B, B, D, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, Y, Y, Y,
+    N, N, N, N, E, N, E, N, N, N, N, N,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SHIELD_IDLE_1: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, Y, Y, Y, Y,
+    N, N, N, B, D, D, B, N, Y, S, S, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, H, S, Y,
+    N, N, N, D, B, B, D, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, H, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, E, N, E, N, Y, Y, Y, Y,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+pub static SHIELD_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_IDLE_0, &SHIELD_IDLE_1],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// WALK — 2 frames, 0.25s/frame, looping. Heavy, shield bobs.
+// =============================================================================
+
+#[rustfmt::skip]
+static SHIELD_WALK_0: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, Y, Y, Y, Y,
+    N, N, N, B, D, D, B, N, Y, S, S, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, H, S, Y,
+    N, N, N, B, B, B, B, N, Y, S, H, Y,
+    N, N, N, D, B, B, D, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, S, Y,
+    N, N, N, D, N, N, N, D, Y, S, S, Y,
+    N, N, N, D, N, N, N, D, Y, Y, Y, Y,
+    N, N, E, N, N, N, N, E, N, N, N, N,
+    N, N, E, E, N, N, N, E, E, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SHIELD_WALK_1: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, N, N, N, N,
+    N, N, N, B, D, D, B, N, Y, Y, Y, Y,
+    N, N, D, B, B, B, B, D, Y, S, S, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, N, B, B, B, B, N, Y, H, S, Y,
+    N, N, N, D, B, B, D, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, S, Y,
+    N, N, N, N, D, D, N, N, Y, S, S, Y,
+    N, N, N, N, E, E, N, N, Y, Y, Y, Y,
+    N, N, N, E, E, N, N, N, N, N, N, N,
+]);
+
+pub static SHIELD_WALK_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_WALK_0, &SHIELD_WALK_1],
+    frame_duration: 0.25,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// WINDUP — 2 frames, 0.25s/frame, one-shot. Shield drawn back and raised.
+// =============================================================================
+
+#[rustfmt::skip]
+static SHIELD_WINDUP_0: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, Y, Y, Y, Y, N,
+    N, N, B, K, B, B, K, Y, S, S, Y, N,
+    N, N, N, B, D, D, B, Y, S, H, Y, N,
+    N, N, D, B, B, B, B, Y, S, H, Y, N,
+    N, N, D, B, D, B, B, Y, H, S, Y, N,
+    N, N, D, B, B, B, B, Y, S, H, Y, N,
+    N, N, N, B, B, B, B, Y, S, H, Y, N,
+    N, N, N, D, B, B, D, Y, S, S, Y, N,
+    N, N, N, N, D, D, N, Y, S, S, Y, N,
+    N, N, N, N, D, N, D, Y, Y, Y, Y, N,
+    N, N, N, N, D, N, D, N, N, N, N, N,
+    N, N, N, N, E, N, E, N, N, N, N, N,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SHIELD_WINDUP_1: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, Y, Y, Y, Y, N,
+    N, N, B, B, B, B, B, Y, S, S, Y, N,
+    N, N, B, K, B, B, K, Y, S, H, Y, N,
+    N, N, N, B, D, D, B, Y, S, H, Y, N,
+    N, N, D, B, B, B, B, Y, H, S, Y, N,
+    N, N, D, B, D, B, B, Y, S, H, Y, N,
+    N, N, D, B, B, B, B, Y, S, H, Y, N,
+    N, N, N, B, B, B, B, Y, S, S, Y, N,
+    N, N, N, D, B, B, D, Y, S, S, Y, N,
+    N, N, N, N, D, D, N, Y, Y, Y, Y, N,
+    N, N, N, N, D, N, D, N, N, N, N, N,
+    N, N, N, N, D, N, D, N, N, N, N, N,
+    N, N, N, N, E, N, E, N, N, N, N, N,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+pub static SHIELD_WINDUP_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_WINDUP_0, &SHIELD_WINDUP_1],
+    frame_duration: 0.25,
+    looping: false,
+    events: &[],
+};
+
+// =============================================================================
+// BASH — 2 frames, 0.075s/frame, one-shot. Body lunges behind the shield.
+// =============================================================================
+
+#[rustfmt::skip]
+static SHIELD_BASH_0: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, N, B, B, B, B, N, N, N, X,
+    N, N, N, B, B, B, B, B, B, N, N, X,
+    N, N, N, B, R, B, B, R, Y, Y, Y, X,
+    N, N, N, N, B, D, D, B, Y, S, S, Y,
+    N, N, N, D, B, B, B, B, Y, S, H, Y,
+    N, N, N, D, B, D, B, B, Y, S, H, Y,
+    N, N, N, D, B, B, B, B, Y, H, S, Y,
+    N, N, N, N, B, B, B, B, Y, S, H, Y,
+    N, N, N, N, D, B, B, D, Y, S, H, Y,
+    N, N, N, N, N, D, D, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, Y, Y, Y,
+    N, N, N, N, E, N, E, N, N, N, N, N,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SHIELD_BASH_1: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, Y, Y, Y, Y,
+    N, N, N, B, D, D, B, N, Y, S, S, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, H, S, Y,
+    N, N, N, B, B, B, B, N, Y, S, H, Y,
+    N, N, N, D, B, B, D, N, Y, S, H, Y,
+    N, N, N, N, D, D, N, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, D, N, D, N, Y, Y, Y, Y,
+    N, N, N, N, E, N, E, N, N, N, N, N,
+    N, N, N, E, E, N, E, E, N, N, N, N,
+]);
+
+pub static SHIELD_BASH_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_BASH_0, &SHIELD_BASH_1],
+    frame_duration: 0.075,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(0, AnimEvent::PlaySound("shield_bash")),
+    ],
+};
+
+// =============================================================================
+// STAGGER — 1 frame, one-shot
+// =============================================================================
+
+pub static SHIELD_STAGGER_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_IDLE_1],
+    frame_duration: 0.3,
+    looping: false,
+    events: &[],
+};
+
+// =============================================================================
+// DEATH — 2 frames, 0.15s/frame, one-shot. Bones scatter, shield falls flat.
+// =============================================================================
+
+#[rustfmt::skip]
+static SHIELD_DEATH_0: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, B, B, B, B, N, N, N, N, N,
+    N, N, B, B, B, B, B, B, N, N, N, N,
+    N, N, B, R, B, B, R, B, Y, Y, Y, Y,
+    N, N, N, B, D, D, B, N, Y, S, S, Y,
+    N, N, D, B, B, B, B, D, Y, S, H, Y,
+    N, N, D, B, D, B, B, B, Y, S, H, Y,
+    N, N, D, B, B, B, B, D, Y, H, S, Y,
+    N, N, N, B, B, B, B, N, Y, S, H, Y,
+    N, N, N, N, D, N, D, N, Y, S, H, Y,
+    N, N, N, N, D, N, D, N, Y, S, S, Y,
+    N, N, N, N, E, N, E, N, Y, S, S, Y,
+    N, N, N, E, E, N, E, E, Y, Y, Y, Y,
+]);
+
+#[rustfmt::skip]
+static SHIELD_DEATH_1: SpriteData = SpriteData::new(12, 14, &[
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, B, N, B, N, N, N, N, N, N, N,
+    N, N, N, D, N, N, Y, Y, Y, Y, Y, Y,
+    N, N, B, N, E, N, Y, S, H, S, S, Y,
+    N, N, N, E, N, N, Y, Y, Y, Y, Y, Y,
+    N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+pub static SHIELD_DEATH_ANIM: AnimationData = AnimationData {
+    frames: &[&SHIELD_DEATH_0, &SHIELD_DEATH_1],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[],
+};
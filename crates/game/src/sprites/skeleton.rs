@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use engine::animation::AnimationData;
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
 use engine::color::Color;
 use engine::sprite::SpriteData;
 
@@ -58,6 +58,7 @@ pub static SKEL_IDLE_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_IDLE_0, &SKEL_IDLE_1],
     frame_duration: 0.5,
     looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -104,6 +105,7 @@ pub static SKEL_WALK_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_WALK_0, &SKEL_WALK_1, &SKEL_WALK_0, &SKEL_WALK_1],
     frame_duration: 0.12,
     looping: true,
+    events: &[],
 };
 
 // =============================================================================
@@ -150,6 +152,7 @@ pub static SKEL_WINDUP_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_WINDUP_0, &SKEL_WINDUP_1],
     frame_duration: 0.2,
     looping: false,
+    events: &[],
 };
 
 // =============================================================================
@@ -196,6 +199,10 @@ pub static SKEL_ATTACK_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_ATTACK_0, &SKEL_ATTACK_1],
     frame_duration: 0.075,
     looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(0, AnimEvent::PlaySound("bone_swing")),
+    ],
 };
 
 // =============================================================================
@@ -206,6 +213,7 @@ pub static SKEL_STAGGER_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_IDLE_1],
     frame_duration: 0.3,
     looping: false,
+    events: &[],
 };
 
 // =============================================================================
@@ -270,4 +278,5 @@ pub static SKEL_DEATH_ANIM: AnimationData = AnimationData {
     frames: &[&SKEL_DEATH_0, &SKEL_DEATH_1, &SKEL_DEATH_2],
     frame_duration: 0.15,
     looping: false,
+    events: &[],
 };
@@ -0,0 +1,252 @@
+#![allow(dead_code)]
+
+use engine::animation::AnimationData;
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+// Large and small slimes. The medium slime is the original `ENEMY_*` set.
+
+const N: Option<Color> = None; // transparent
+const G: Option<Color> = Some([60, 160, 60]); // green body
+const D: Option<Color> = Some([30, 100, 30]); // dark shadow
+const L: Option<Color> = Some([120, 210, 120]); // light highlight
+const R: Option<Color> = Some([200, 40, 40]); // red eyes
+const W: Option<Color> = Some([255, 255, 255]); // white (death flash)
+
+// =============================================================================
+// LARGE (14x12) — rest, squash and stretch poses
+// =============================================================================
+
+#[rustfmt::skip]
+static SLIME_BIG_REST: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, L, L, L, L, L, L, N, N, N, N,
+    N, N, N, L, G, G, G, G, G, G, L, N, N, N,
+    N, N, L, G, G, G, G, G, G, G, G, L, N, N,
+    N, N, G, G, R, G, G, G, G, R, G, G, N, N,
+    N, N, G, G, G, G, G, G, G, G, G, G, N, N,
+    N, N, G, G, G, G, G, G, G, G, G, G, N, N,
+    N, N, G, G, G, G, G, G, G, G, G, G, N, N,
+    N, N, D, G, G, G, G, G, G, G, G, D, N, N,
+    N, N, N, D, G, G, G, G, G, G, D, N, N, N,
+    N, N, N, N, D, D, D, D, D, D, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_BIG_SQUASH: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, L, L, L, L, L, L, N, N, N, N,
+    N, N, L, L, G, G, G, G, G, G, L, L, N, N,
+    N, L, G, G, R, G, G, G, G, R, G, G, L, N,
+    N, G, G, G, G, G, G, G, G, G, G, G, G, N,
+    G, G, G, G, G, G, G, G, G, G, G, G, G, G,
+    D, G, G, G, G, G, G, G, G, G, G, G, G, D,
+    N, D, D, G, G, G, G, G, G, G, G, D, D, N,
+    N, N, D, D, D, D, D, D, D, D, D, D, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_BIG_STRETCH: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, L, L, L, L, N, N, N, N, N,
+    N, N, N, N, L, G, G, G, G, L, N, N, N, N,
+    N, N, N, N, G, R, G, G, R, G, N, N, N, N,
+    N, N, N, N, G, G, G, G, G, G, N, N, N, N,
+    N, N, N, N, G, G, G, G, G, G, N, N, N, N,
+    N, N, N, N, G, G, G, G, G, G, N, N, N, N,
+    N, N, N, N, G, G, G, G, G, G, N, N, N, N,
+    N, N, N, N, G, G, G, G, G, G, N, N, N, N,
+    N, N, N, N, D, G, G, G, G, D, N, N, N, N,
+    N, N, N, N, N, D, G, G, D, N, N, N, N, N,
+    N, N, N, N, N, D, D, D, D, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+pub static SLIME_BIG_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_BIG_REST, &SLIME_BIG_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static SLIME_BIG_SQUASH_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_BIG_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static SLIME_BIG_STRETCH_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_BIG_STRETCH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// LARGE (14x12) DEATH — 4 frames, 0.15s/frame, one-shot
+// =============================================================================
+
+#[rustfmt::skip]
+static SLIME_BIG_DEATH_1: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, L, L, L, L, L, L, L, L, L, L, N, N,
+    N, G, G, G, G, G, G, G, G, G, G, G, G, N,
+    N, D, D, D, D, D, D, D, D, D, D, D, D, N,
+    N, N, D, D, D, D, D, D, D, D, D, D, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_BIG_DEATH_2: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, W, W, W, W, W, W, W, W, W, W, W, W, N,
+    N, W, W, W, W, W, W, W, W, W, W, W, W, N,
+    N, N, W, W, W, W, W, W, W, W, W, W, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_BIG_DEATH_3: SpriteData = SpriteData::new(14, 12, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, W, N, W, W, N, N, W, W, N, W, N, N,
+    N, N, N, W, N, N, W, W, N, N, W, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+pub static SLIME_BIG_DEATH_ANIM: AnimationData = AnimationData {
+    frames: &[
+        &SLIME_BIG_SQUASH,
+        &SLIME_BIG_DEATH_1,
+        &SLIME_BIG_DEATH_2,
+        &SLIME_BIG_DEATH_3,
+    ],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[],
+};
+
+// =============================================================================
+// SMALL (6x6) — rest, squash and stretch poses
+// =============================================================================
+
+#[rustfmt::skip]
+static SLIME_SMALL_REST: SpriteData = SpriteData::new(6, 6, &[
+    N, N, N, N, N, N,
+    N, N, L, L, N, N,
+    N, L, G, G, L, N,
+    N, R, G, G, R, N,
+    N, D, G, G, D, N,
+    N, N, D, D, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_SMALL_SQUASH: SpriteData = SpriteData::new(6, 6, &[
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, L, L, L, L, N,
+    L, R, G, G, R, L,
+    D, G, G, G, G, D,
+    N, D, D, D, D, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_SMALL_STRETCH: SpriteData = SpriteData::new(6, 6, &[
+    N, N, L, L, N, N,
+    N, L, G, G, L, N,
+    N, R, G, G, R, N,
+    N, N, G, G, N, N,
+    N, N, D, D, N, N,
+    N, N, N, N, N, N,
+]);
+
+pub static SLIME_SMALL_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_SMALL_REST, &SLIME_SMALL_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static SLIME_SMALL_SQUASH_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_SMALL_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static SLIME_SMALL_STRETCH_ANIM: AnimationData = AnimationData {
+    frames: &[&SLIME_SMALL_STRETCH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+// =============================================================================
+// SMALL (6x6) DEATH — 4 frames, 0.15s/frame, one-shot
+// =============================================================================
+
+#[rustfmt::skip]
+static SLIME_SMALL_DEATH_1: SpriteData = SpriteData::new(6, 6, &[
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, L, L, L, L, N,
+    N, D, D, D, D, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_SMALL_DEATH_2: SpriteData = SpriteData::new(6, 6, &[
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, W, W, W, W, N,
+    N, N, W, W, N, N,
+]);
+
+#[rustfmt::skip]
+static SLIME_SMALL_DEATH_3: SpriteData = SpriteData::new(6, 6, &[
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    N, N, N, N, N, N,
+    W, N, W, W, N, W,
+    N, W, N, N, W, N,
+]);
+
+pub static SLIME_SMALL_DEATH_ANIM: AnimationData = AnimationData {
+    frames: &[
+        &SLIME_SMALL_SQUASH,
+        &SLIME_SMALL_DEATH_1,
+        &SLIME_SMALL_DEATH_2,
+        &SLIME_SMALL_DEATH_3,
+    ],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[],
+};
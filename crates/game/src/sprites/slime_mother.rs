@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+
+use engine::animation::{AnimEvent, AnimationData, FrameEvent};
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const G: Option<Color> = Some([60, 160, 60]); // green body
+const D: Option<Color> = Some([30, 100, 30]); // dark shadow
+const L: Option<Color> = Some([120, 210, 120]); // light highlight
+const I: Option<Color> = Some([40, 120, 40]); // brood inside her
+const R: Option<Color> = Some([200, 40, 40]); // red eyes
+const M: Option<Color> = Some([20, 60, 20]); // mouth
+const Y: Option<Color> = Some([255, 210, 60]); // crown
+const W: Option<Color> = Some([255, 255, 255]); // roaring eyes
+
+// The Slime Mother: 24x18, a crowned slime with her brood visible inside.
+
+// =============================================================================
+// POSES
+// =============================================================================
+
+#[rustfmt::skip]
+static MOTHER_REST: SpriteData = SpriteData::new(24, 18, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, N, Y, N, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, L, L, L, L, L, L, L, L, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, L, L, G, G, G, G, G, G, G, G, L, L, N, N, N, N, N, N,
+    N, N, N, N, N, L, G, G, G, G, G, G, G, G, G, G, G, G, L, N, N, N, N, N,
+    N, N, N, N, L, G, G, G, R, R, G, G, G, G, R, R, G, G, G, L, N, N, N, N,
+    N, N, N, L, G, G, G, G, R, R, G, G, G, G, R, R, G, G, G, G, L, N, N, N,
+    N, N, N, G, G, G, G, G, G, G, G, G, G, G, G, G, G, G, G, G, G, N, N, N,
+    N, N, G, G, G, I, I, G, G, G, G, M, M, M, M, G, G, G, G, G, G, G, N, N,
+    N, N, G, G, I, I, I, I, G, G, G, G, M, M, G, G, G, G, I, I, G, G, N, N,
+    N, N, G, G, G, I, I, G, G, G, G, G, G, G, G, G, G, G, I, I, I, G, N, N,
+    N, N, D, G, G, G, G, G, G, G, G, I, I, G, G, G, G, G, G, I, G, D, N, N,
+    N, N, D, G, G, G, G, G, G, G, I, I, I, I, G, G, G, G, G, G, G, D, N, N,
+    N, N, N, D, G, G, G, G, G, G, G, I, I, G, G, G, G, G, G, G, D, N, N, N,
+    N, N, N, N, D, D, G, G, G, G, G, G, G, G, G, G, G, G, D, D, N, N, N, N,
+    N, N, N, N, N, D, D, D, D, D, D, D, D, D, D, D, D, D, D, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static MOTHER_SQUASH: SpriteData = SpriteData::new(24, 18, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, N, Y, N, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, L, L, L, L, L, L, L, L, L, L, L, L, N, N, N, N, N, N,
+    N, N, N, L, L, L, G, G, G, G, G, G, G, G, G, G, G, G, L, L, L, N, N, N,
+    N, L, L, G, G, G, G, R, R, G, G, G, G, G, R, R, G, G, G, G, G, L, L, N,
+    L, G, G, G, G, G, G, R, R, G, G, G, G, G, R, R, G, G, G, G, G, G, G, L,
+    G, G, G, I, I, G, G, G, G, G, G, M, M, M, M, G, G, G, G, G, I, I, G, G,
+    G, G, I, I, I, I, G, G, G, G, G, G, M, M, G, G, G, G, G, I, I, I, I, G,
+    D, G, G, I, I, G, G, G, G, G, G, G, G, G, G, G, G, G, G, G, I, I, G, D,
+    D, G, G, G, G, G, G, G, G, G, I, I, I, I, G, G, G, G, G, G, G, G, G, D,
+    N, D, D, G, G, G, G, G, G, G, G, I, I, G, G, G, G, G, G, G, G, D, D, N,
+    N, N, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, D, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static MOTHER_STRETCH: SpriteData = SpriteData::new(24, 18, &[
+    N, N, N, N, N, N, N, N, N, Y, N, Y, N, Y, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, L, L, L, L, L, L, L, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, L, G, G, G, G, G, G, G, L, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, L, G, G, G, G, G, G, G, G, G, L, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, G, R, R, G, G, G, R, R, G, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, G, R, R, G, G, G, R, R, G, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, G, G, G, G, M, M, G, G, G, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, I, I, G, G, M, M, G, G, I, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, I, I, I, G, G, G, G, I, I, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, G, I, G, G, G, G, G, G, I, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, G, G, G, G, G, I, I, G, G, G, G, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, D, G, G, G, I, I, I, I, G, G, D, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, D, G, G, G, I, I, G, G, D, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, D, G, G, G, G, G, D, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, D, D, D, D, D, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+#[rustfmt::skip]
+static MOTHER_ROAR: SpriteData = SpriteData::new(24, 18, &[
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, N, Y, N, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, L, L, L, L, L, L, L, L, N, N, N, N, N, N, N, N,
+    N, N, N, N, N, N, L, L, G, G, G, G, G, G, G, G, L, L, N, N, N, N, N, N,
+    N, N, N, N, N, L, G, G, G, G, G, G, G, G, G, G, G, G, L, N, N, N, N, N,
+    N, N, N, N, L, G, G, G, R, R, G, G, G, G, R, R, G, G, G, L, N, N, N, N,
+    N, N, N, L, G, G, G, G, W, W, G, G, G, G, W, W, G, G, G, G, L, N, N, N,
+    N, N, N, G, G, G, G, G, G, G, M, M, M, M, G, G, G, G, G, G, G, N, N, N,
+    N, N, G, G, G, I, I, G, G, G, M, M, M, M, M, M, G, G, G, G, G, G, N, N,
+    N, N, G, G, I, I, I, I, G, G, M, M, M, M, M, M, G, G, G, I, I, G, G, N,
+    N, N, G, G, G, I, I, G, G, G, G, M, M, M, M, G, G, G, G, I, I, I, G, N,
+    N, N, D, G, G, G, G, G, G, G, G, I, I, G, G, G, G, G, G, I, G, D, N, N,
+    N, N, D, G, G, G, G, G, G, G, I, I, I, I, G, G, G, G, G, G, G, D, N, N,
+    N, N, N, D, G, G, G, G, G, G, G, I, I, G, G, G, G, G, G, G, D, N, N, N,
+    N, N, N, N, D, D, G, G, G, G, G, G, G, G, G, G, G, G, D, D, N, N, N, N,
+    N, N, N, N, N, D, D, D, D, D, D, D, D, D, D, D, D, D, D, N, N, N, N, N,
+    N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+]);
+
+// =============================================================================
+// ANIMATIONS — landing anims carry the hitbox and impact frame events
+// =============================================================================
+
+pub static MOTHER_IDLE_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_REST, &MOTHER_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static MOTHER_SQUASH_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_SQUASH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+pub static MOTHER_STRETCH_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_STRETCH],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
+
+/// Touchdown from a hop: a short body-splash hitbox.
+pub static MOTHER_LAND_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_SQUASH, &MOTHER_REST],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(1, AnimEvent::HitboxOff),
+    ],
+};
+
+/// Touchdown from a leap: same splash plus the ground-shaking impact.
+pub static MOTHER_SLAM_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_SQUASH, &MOTHER_REST],
+    frame_duration: 0.15,
+    looping: false,
+    events: &[
+        FrameEvent::new(0, AnimEvent::HitboxOn),
+        FrameEvent::new(0, AnimEvent::SpawnParticle("slam_impact")),
+        FrameEvent::new(1, AnimEvent::HitboxOff),
+    ],
+};
+
+pub static MOTHER_ROAR_ANIM: AnimationData = AnimationData {
+    frames: &[&MOTHER_ROAR],
+    frame_duration: 0.5,
+    looping: true,
+    events: &[],
+};
@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+
+use engine::color::Color;
+use engine::sprite::SpriteData;
+
+const N: Option<Color> = None;
+const P: Option<Color> = Some([70, 70, 78]); // plate
+const E: Option<Color> = Some([50, 50, 56]); // plate edge
+const H: Option<Color> = Some([20, 20, 24]); // hole
+const S: Option<Color> = Some([200, 200, 210]); // spike steel
+const T: Option<Color> = Some([255, 255, 255]); // spike tip
+const G: Option<Color> = Some([60, 50, 45]); // vent grate
+const K: Option<Color> = Some([25, 18, 15]); // vent slot
+const O: Option<Color> = Some([255, 120, 30]); // ember glow
+const R: Option<Color> = Some([200, 60, 20]); // dim ember
+const W: Option<Color> = Some([30, 25, 22]); // arrow slot
+const A: Option<Color> = Some([150, 140, 120]); // arrowhead
+
+// =============================================================================
+// SPIKE PLATE — 8x8. Retracted, tips showing (warning), fully extended.
+// =============================================================================
+
+#[rustfmt::skip]
+pub static SPIKES_DOWN: SpriteData = SpriteData::new(8, 8, &[
+    E, E, E, E, E, E, E, E,
+    E, P, P, P, P, P, P, E,
+    E, P, H, P, P, H, P, E,
+    E, P, P, P, P, P, P, E,
+    E, P, P, P, P, P, P, E,
+    E, P, H, P, P, H, P, E,
+    E, P, P, P, P, P, P, E,
+    E, E, E, E, E, E, E, E,
+]);
+
+#[rustfmt::skip]
+pub static SPIKES_WARN: SpriteData = SpriteData::new(8, 8, &[
+    E, E, E, E, E, E, E, E,
+    E, P, P, P, P, P, P, E,
+    E, P, T, P, P, T, P, E,
+    E, P, P, P, P, P, P, E,
+    E, P, P, P, P, P, P, E,
+    E, P, T, P, P, T, P, E,
+    E, P, P, P, P, P, P, E,
+    E, E, E, E, E, E, E, E,
+]);
+
+#[rustfmt::skip]
+pub static SPIKES_UP: SpriteData = SpriteData::new(8, 8, &[
+    E, E, T, E, E, T, E, E,
+    E, P, S, P, P, S, P, E,
+    E, P, S, P, P, S, P, E,
+    E, T, P, P, T, P, T, E,
+    E, S, P, P, S, P, S, E,
+    E, P, S, P, P, S, P, E,
+    E, P, S, P, P, S, P, E,
+    E, E, E, E, E, E, E, E,
+]);
+
+// =============================================================================
+// FIRE VENT — 8x8 grate. Glows before it erupts; flames are particles.
+// =============================================================================
+
+#[rustfmt::skip]
+pub static VENT_IDLE: SpriteData = SpriteData::new(8, 8, &[
+    N, G, G, G, G, G, G, N,
+    G, K, G, K, G, K, G, G,
+    G, K, G, K, G, K, G, G,
+    G, K, G, K, G, K, G, G,
+    G, K, G, K, G, K, G, G,
+    G, K, G, K, G, K, G, G,
+    G, K, G, K, G, K, G, G,
+    N, G, G, G, G, G, G, N,
+]);
+
+#[rustfmt::skip]
+pub static VENT_GLOW: SpriteData = SpriteData::new(8, 8, &[
+    N, G, G, G, G, G, G, N,
+    G, R, G, O, G, R, G, G,
+    G, O, G, R, G, O, G, G,
+    G, R, G, O, G, R, G, G,
+    G, O, G, R, G, O, G, G,
+    G, R, G, O, G, R, G, G,
+    G, O, G, R, G, O, G, G,
+    N, G, G, G, G, G, G, N,
+]);
+
+// =============================================================================
+// ARROW SHOOTER — 4x4 slot drawn over the wall face, arrowhead when loaded.
+// =============================================================================
+
+#[rustfmt::skip]
+pub static ARROW_SLOT: SpriteData = SpriteData::new(4, 4, &[
+    N, W, W, N,
+    W, W, W, W,
+    W, W, W, W,
+    N, W, W, N,
+]);
+
+#[rustfmt::skip]
+pub static ARROW_SLOT_LOADED: SpriteData = SpriteData::new(4, 4, &[
+    N, W, W, N,
+    W, A, A, W,
+    W, A, A, W,
+    N, W, W, N,
+]);
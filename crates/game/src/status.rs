@@ -0,0 +1,260 @@
+//! Status effects: timed conditions on the player or an enemy. Each kind
+//! stacks up to a cap, and may deal damage on a timer or change how the
+//! bearer moves, acts and hits.
+
+use engine::Color;
+
+/// Speed lost per stack of Slow.
+const SLOW_PER_STACK: f32 = 0.2;
+/// Outgoing damage lost per stack of Weakness.
+const WEAKNESS_PER_STACK: f32 = 0.25;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StatusKind {
+    /// Quick, heavy ticks of damage; reapplying only refreshes it
+    Burn,
+    /// Slow ticks of damage that build up with each application
+    Poison,
+    /// Moves slower per stack; slowed enemies attack slower too
+    Slow,
+    /// Frozen solid: can't move or act
+    Freeze,
+    /// Dazed: can't move or act, briefly
+    Stun,
+    /// Deals less damage per stack
+    Weakness,
+}
+
+impl StatusKind {
+    pub fn max_stacks(self) -> u32 {
+        match self {
+            StatusKind::Poison | StatusKind::Slow => 3,
+            StatusKind::Weakness => 2,
+            StatusKind::Burn | StatusKind::Freeze | StatusKind::Stun => 1,
+        }
+    }
+
+    /// Seconds an application lasts. Reapplying refreshes it.
+    pub fn duration(self) -> f32 {
+        match self {
+            StatusKind::Burn => 1.5,
+            StatusKind::Poison => 4.0,
+            StatusKind::Slow => 2.5,
+            StatusKind::Freeze => 1.5,
+            StatusKind::Stun => 0.5,
+            StatusKind::Weakness => 4.0,
+        }
+    }
+
+    /// Damage per stack per tick and seconds between ticks, for the kinds that hurt.
+    fn tick(self) -> Option<(i32, f32)> {
+        match self {
+            StatusKind::Burn => Some((1, 0.5)),
+            StatusKind::Poison => Some((1, 1.5)),
+            _ => None,
+        }
+    }
+
+    /// Stops the bearer from moving or acting at all.
+    pub fn disables(self) -> bool {
+        matches!(self, StatusKind::Freeze | StatusKind::Stun)
+    }
+
+    /// Sprite tint while the effect is on.
+    pub fn tint(self) -> Color {
+        match self {
+            StatusKind::Burn => [255, 140, 60],
+            StatusKind::Poison => [130, 230, 100],
+            StatusKind::Slow => [130, 180, 255],
+            StatusKind::Freeze => [190, 235, 255],
+            StatusKind::Stun => [255, 240, 140],
+            StatusKind::Weakness => [190, 120, 230],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    /// Seconds left before it wears off
+    pub remaining: f32,
+    tick_timer: f32,
+}
+
+/// Every effect currently on one bearer, at most one entry per kind.
+#[derive(Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+    /// Shrugs off Freeze and Stun (bosses)
+    unstoppable: bool,
+}
+
+impl StatusEffects {
+    /// Effects for a bearer that can't be frozen or stunned.
+    pub fn unstoppable() -> Self {
+        Self {
+            effects: Vec::new(),
+            unstoppable: true,
+        }
+    }
+
+    /// Add a stack of `kind` and refresh its duration. The effect moves to
+    /// the end of the list, so it's the most recent. Returns false if the
+    /// bearer is immune.
+    pub fn apply(&mut self, kind: StatusKind) -> bool {
+        if self.unstoppable && kind.disables() {
+            return false;
+        }
+        let effect = match self.effects.iter().position(|e| e.kind == kind) {
+            Some(i) => {
+                let mut effect = self.effects.remove(i);
+                effect.stacks = (effect.stacks + 1).min(kind.max_stacks());
+                effect.remaining = kind.duration();
+                effect
+            }
+            None => StatusEffect {
+                kind,
+                stacks: 1,
+                remaining: kind.duration(),
+                tick_timer: 0.0,
+            },
+        };
+        self.effects.push(effect);
+        true
+    }
+
+    /// Remove every stack of `kind`.
+    pub fn cure(&mut self, kind: StatusKind) {
+        self.effects.retain(|e| e.kind != kind);
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    /// Count down durations. Returns the damage the effects dealt this tick.
+    pub fn update(&mut self, dt: f32) -> i32 {
+        let mut damage = 0;
+        for effect in &mut self.effects {
+            effect.remaining -= dt;
+            if let Some((per_stack, interval)) = effect.kind.tick() {
+                effect.tick_timer += dt;
+                if effect.tick_timer >= interval {
+                    effect.tick_timer -= interval;
+                    damage += per_stack * effect.stacks as i32;
+                }
+            }
+        }
+        self.effects.retain(|e| e.remaining > 0.0);
+        damage
+    }
+
+    pub fn stacks(&self, kind: StatusKind) -> u32 {
+        self.effects
+            .iter()
+            .find(|e| e.kind == kind)
+            .map_or(0, |e| e.stacks)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    /// False while frozen or stunned.
+    pub fn can_act(&self) -> bool {
+        !self.effects.iter().any(|e| e.kind.disables())
+    }
+
+    /// Movement and action speed factor.
+    pub fn speed_mult(&self) -> f32 {
+        if !self.can_act() {
+            return 0.0;
+        }
+        1.0 - SLOW_PER_STACK * self.stacks(StatusKind::Slow) as f32
+    }
+
+    /// Scale outgoing damage down for Weakness, rounding down so a single
+    /// stack still bites on small hits. A weakened hit deals at least 1.
+    pub fn weaken(&self, damage: i32) -> i32 {
+        let stacks = self.stacks(StatusKind::Weakness);
+        if stacks == 0 || damage <= 0 {
+            return damage;
+        }
+        let mult = 1.0 - WEAKNESS_PER_STACK * stacks as f32;
+        ((damage as f32 * mult).floor() as i32).max(1)
+    }
+
+    /// Tint for the most recently applied effect, if any.
+    pub fn tint(&self) -> Option<Color> {
+        self.effects.last().map(|e| e.kind.tint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stacks_cap_and_refresh() {
+        let mut status = StatusEffects::default();
+        for _ in 0..5 {
+            status.apply(StatusKind::Poison);
+        }
+        assert_eq!(status.stacks(StatusKind::Poison), 3);
+        status.update(3.0);
+        status.apply(StatusKind::Poison);
+        status.update(3.0);
+        assert_eq!(status.stacks(StatusKind::Poison), 3);
+    }
+
+    #[test]
+    fn test_ticks_scale_with_stacks_then_expire() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Poison);
+        status.apply(StatusKind::Poison);
+        assert_eq!(status.update(1.5), 2);
+        assert_eq!(status.update(1.0), 0);
+        status.update(2.0);
+        assert_eq!(status.stacks(StatusKind::Poison), 0);
+    }
+
+    #[test]
+    fn test_modifiers() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Slow);
+        status.apply(StatusKind::Weakness);
+        assert!((status.speed_mult() - 0.8).abs() < 1e-6);
+        assert_eq!(status.weaken(4), 3);
+        status.apply(StatusKind::Stun);
+        assert!(!status.can_act());
+        assert_eq!(status.speed_mult(), 0.0);
+    }
+
+    #[test]
+    fn test_one_weakness_stack_cuts_small_hits() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Weakness);
+        assert_eq!(status.weaken(2), 1);
+        assert_eq!(status.weaken(1), 1);
+        assert_eq!(status.weaken(3), 2);
+    }
+
+    #[test]
+    fn test_tint_follows_the_latest_application() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Burn);
+        status.apply(StatusKind::Slow);
+        assert_eq!(status.tint(), Some(StatusKind::Slow.tint()));
+        status.apply(StatusKind::Burn);
+        assert_eq!(status.tint(), Some(StatusKind::Burn.tint()));
+    }
+
+    #[test]
+    fn test_unstoppable_ignores_disables() {
+        let mut status = StatusEffects::unstoppable();
+        assert!(!status.apply(StatusKind::Freeze));
+        assert!(status.apply(StatusKind::Burn));
+        assert!(status.can_act());
+    }
+}
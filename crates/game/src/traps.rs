@@ -0,0 +1,334 @@
+//! Room traps: spike plates, fire vents and arrow shooters on fixed activation cycles.
+
+use engine::collision::AABB;
+use engine::tilemap::TILE_SIZE;
+use engine::{BurstConfig, Color, FrameBuffer, ParticleSystem};
+
+use crate::dungeon::room_template::{Direction, RoomTemplate, TrapKind};
+use crate::projectile::ProjectileSystem;
+use crate::sprites::traps as sprites;
+
+/// Damage dealt by spikes, flames and pits.
+pub const TRAP_DAMAGE: i32 = 1;
+
+/// Where a trap is in its activation cycle.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrapPhase {
+    Idle,
+    /// Telegraph before the trap goes off.
+    Warning,
+    Active,
+}
+
+/// Seconds spent idle, warning and active per cycle.
+struct Cycle {
+    idle: f32,
+    warning: f32,
+    active: f32,
+}
+
+impl Cycle {
+    fn period(&self) -> f32 {
+        self.idle + self.warning + self.active
+    }
+}
+
+const SPIKE_CYCLE: Cycle = Cycle {
+    idle: 1.6,
+    warning: 0.4,
+    active: 0.4,
+};
+const FIRE_CYCLE: Cycle = Cycle {
+    idle: 2.0,
+    warning: 0.5,
+    active: 0.7,
+};
+const ARROW_CYCLE: Cycle = Cycle {
+    idle: 2.0,
+    warning: 0.5,
+    active: 0.1,
+};
+
+const FLAME_COLORS: &[Color] = &[[255, 240, 150], [255, 180, 50], [255, 100, 20]];
+
+const FLAME_GRADIENT: &[Color] = &[[255, 240, 150], [255, 140, 30], [180, 40, 10], [60, 20, 10]];
+
+const FLAME_CONFIG: BurstConfig = BurstConfig {
+    count_min: 2,
+    count_max: 3,
+    speed_min: 15.0,
+    speed_max: 35.0,
+    lifetime_min: 0.2,
+    lifetime_max: 0.4,
+    colors: FLAME_COLORS,
+    gravity: -60.0,
+    friction: 0.9,
+    angle_spread: 0.8,
+    base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: FLAME_GRADIENT,
+    size_start: 2.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+const SMOKE_COLORS: &[Color] = &[[90, 80, 75], [70, 62, 58]];
+
+const SMOKE_CONFIG: BurstConfig = BurstConfig {
+    count_min: 0,
+    count_max: 1,
+    speed_min: 5.0,
+    speed_max: 12.0,
+    lifetime_min: 0.3,
+    lifetime_max: 0.5,
+    colors: SMOKE_COLORS,
+    gravity: -20.0,
+    friction: 0.9,
+    angle_spread: 1.0,
+    base_angle: -std::f32::consts::FRAC_PI_2,
+    gradient: &[],
+    size_start: 1.0,
+    size_end: 1.0,
+    bounce: None,
+};
+
+pub struct Trap {
+    pub kind: TrapKind,
+    /// Tile position.
+    pub tx: usize,
+    pub ty: usize,
+    timer: f32,
+    /// Arrow shooter fired on the last update.
+    fired: bool,
+}
+
+impl Trap {
+    /// `phase_offset` is seconds into the cycle, so neighbouring traps can
+    /// be staggered.
+    pub fn new(kind: TrapKind, tx: usize, ty: usize, phase_offset: f32) -> Self {
+        let mut trap = Self {
+            kind,
+            tx,
+            ty,
+            timer: 0.0,
+            fired: false,
+        };
+        trap.timer = phase_offset % trap.cycle().period();
+        trap
+    }
+
+    fn cycle(&self) -> &'static Cycle {
+        match self.kind {
+            TrapKind::Spikes => &SPIKE_CYCLE,
+            TrapKind::FireVent => &FIRE_CYCLE,
+            TrapKind::ArrowShooter { .. } => &ARROW_CYCLE,
+        }
+    }
+
+    pub fn phase(&self) -> TrapPhase {
+        let cycle = self.cycle();
+        if self.timer < cycle.idle {
+            TrapPhase::Idle
+        } else if self.timer < cycle.idle + cycle.warning {
+            TrapPhase::Warning
+        } else {
+            TrapPhase::Active
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        let before = self.phase();
+        self.timer = (self.timer + dt) % self.cycle().period();
+        self.fired = matches!(self.kind, TrapKind::ArrowShooter { .. })
+            && before != TrapPhase::Active
+            && self.phase() == TrapPhase::Active;
+    }
+
+    /// Tile center in world pixels.
+    pub fn center(&self) -> (f32, f32) {
+        let ts = TILE_SIZE as f32;
+        ((self.tx as f32 + 0.5) * ts, (self.ty as f32 + 0.5) * ts)
+    }
+
+    /// Damaging area while spikes are up or the vent is burning.
+    /// Arrow shooters hurt through their projectiles instead.
+    pub fn hazard_box(&self) -> Option<AABB> {
+        if self.phase() != TrapPhase::Active {
+            return None;
+        }
+        let ts = TILE_SIZE as f32;
+        let (x, y) = (self.tx as f32 * ts, self.ty as f32 * ts);
+        match self.kind {
+            TrapKind::Spikes => Some(AABB::new(1.0, 1.0, ts - 2.0, ts - 2.0).at(x, y)),
+            // Flames lick a little above the grate
+            TrapKind::FireVent => Some(AABB::new(1.0, -3.0, ts - 2.0, ts + 1.0).at(x, y)),
+            TrapKind::ArrowShooter { .. } => None,
+        }
+    }
+
+    /// Spawn position and direction of an arrow fired on the last update.
+    pub fn arrow_fired(&self) -> Option<(f32, f32, f32, f32)> {
+        let TrapKind::ArrowShooter { facing } = self.kind else {
+            return None;
+        };
+        if !self.fired {
+            return None;
+        }
+        // Arrows start just outside the wall tile so they don't hit it
+        let ts = TILE_SIZE as f32;
+        let (x, y) = (self.tx as f32 * ts, self.ty as f32 * ts);
+        Some(match facing {
+            Direction::North => (x + 2.5, y - 3.5, 0.0, -1.0),
+            Direction::South => (x + 2.5, y + ts + 0.5, 0.0, 1.0),
+            Direction::West => (x - 3.5, y + 2.5, -1.0, 0.0),
+            Direction::East => (x + ts + 0.5, y + 2.5, 1.0, 0.0),
+        })
+    }
+
+    pub fn render(&self, fb: &mut FrameBuffer, cam_x: i32, cam_y: i32) {
+        let ts = TILE_SIZE as i32;
+        let px = self.tx as i32 * ts - cam_x;
+        let py = self.ty as i32 * ts - cam_y;
+        let phase = self.phase();
+        match self.kind {
+            TrapKind::Spikes => {
+                let sprite = match phase {
+                    TrapPhase::Idle => &sprites::SPIKES_DOWN,
+                    TrapPhase::Warning => &sprites::SPIKES_WARN,
+                    TrapPhase::Active => &sprites::SPIKES_UP,
+                };
+                fb.blit_sprite(sprite, px, py);
+            }
+            TrapKind::FireVent => {
+                let sprite = match phase {
+                    TrapPhase::Idle => &sprites::VENT_IDLE,
+                    _ => &sprites::VENT_GLOW,
+                };
+                fb.blit_sprite(sprite, px, py);
+            }
+            TrapKind::ArrowShooter { .. } => {
+                let sprite = match phase {
+                    TrapPhase::Warning => &sprites::ARROW_SLOT_LOADED,
+                    _ => &sprites::ARROW_SLOT,
+                };
+                fb.blit_sprite(sprite, px + 2, py + 2);
+            }
+        }
+    }
+}
+
+/// Build the traps placed in a room template, staggering their cycles by position.
+pub fn spawn_traps(template: &RoomTemplate) -> Vec<Trap> {
+    template
+        .traps
+        .iter()
+        .map(|t| {
+            let offset = ((t.x * 7 + t.y * 13) % 10) as f32 * 0.17;
+            Trap::new(t.kind, t.x, t.y, offset)
+        })
+        .collect()
+}
+
+/// Advance trap cycles: fire arrows and spawn flame and smoke particles.
+pub fn update_traps(
+    traps: &mut [Trap],
+    dt: f32,
+    projectiles: &mut ProjectileSystem,
+    particles: &mut ParticleSystem,
+) {
+    for trap in traps.iter_mut() {
+        trap.update(dt);
+        let (cx, cy) = trap.center();
+        if let Some((x, y, dx, dy)) = trap.arrow_fired() {
+            projectiles.spawn(x, y, dx, dy);
+        }
+        if trap.kind == TrapKind::FireVent {
+            match trap.phase() {
+                TrapPhase::Active => particles.burst(cx, cy, &FLAME_CONFIG),
+                TrapPhase::Warning => particles.burst(cx, cy, &SMOKE_CONFIG),
+                TrapPhase::Idle => {}
+            }
+        }
+    }
+}
+
+/// Center of the first active trap overlapping `hurtbox`.
+pub fn hazard_at(traps: &[Trap], hurtbox: &AABB) -> Option<(f32, f32)> {
+    traps
+        .iter()
+        .find(|t| t.hazard_box().is_some_and(|hb| hb.overlaps(hurtbox)))
+        .map(|t| t.center())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::templates;
+
+    const DT: f32 = 1.0 / 30.0;
+
+    #[test]
+    fn test_spikes_cycle_through_phases() {
+        let mut trap = Trap::new(TrapKind::Spikes, 1, 1, 0.0);
+        assert_eq!(trap.phase(), TrapPhase::Idle);
+        assert!(trap.hazard_box().is_none());
+
+        let mut seen = vec![trap.phase()];
+        for _ in 0..90 {
+            trap.update(DT);
+            if seen.last() != Some(&trap.phase()) {
+                seen.push(trap.phase());
+            }
+        }
+        assert_eq!(
+            &seen[..4],
+            &[
+                TrapPhase::Idle,
+                TrapPhase::Warning,
+                TrapPhase::Active,
+                TrapPhase::Idle
+            ]
+        );
+    }
+
+    #[test]
+    fn test_active_spikes_hurt_only_on_their_tile() {
+        let trap = Trap::new(
+            TrapKind::Spikes,
+            2,
+            2,
+            SPIKE_CYCLE.idle + SPIKE_CYCLE.warning,
+        );
+        let traps = [trap];
+        let on_tile = AABB::new(0.0, 0.0, 4.0, 4.0).at(18.0, 18.0);
+        let off_tile = AABB::new(0.0, 0.0, 4.0, 4.0).at(40.0, 18.0);
+        assert_eq!(hazard_at(&traps, &on_tile), Some((20.0, 20.0)));
+        assert_eq!(hazard_at(&traps, &off_tile), None);
+    }
+
+    #[test]
+    fn test_arrow_shooter_fires_once_per_cycle() {
+        let facing = Direction::South;
+        let mut trap = Trap::new(TrapKind::ArrowShooter { facing }, 3, 0, 0.0);
+        let mut shots = Vec::new();
+        // Two full cycles
+        for _ in 0..((ARROW_CYCLE.period() * 2.0 / DT) as usize) {
+            trap.update(DT);
+            shots.extend(trap.arrow_fired());
+        }
+        assert_eq!(shots.len(), 2);
+        let (_, y, dx, dy) = shots[0];
+        assert_eq!((dx, dy), (0.0, 1.0));
+        assert!(
+            y >= TILE_SIZE as f32,
+            "arrow must start below the wall tile"
+        );
+    }
+
+    #[test]
+    fn test_spawn_traps_from_template() {
+        let traps = spawn_traps(&templates::corridor_h());
+        assert!(traps
+            .iter()
+            .any(|t| matches!(t.kind, TrapKind::ArrowShooter { .. })));
+    }
+}
@@ -1,7 +1,8 @@
 use engine::{Color, FrameBuffer, GameKey, InputState};
 
+use crate::seed::{format_seed, parse_seed, MAX_SEED_LEN};
 use crate::sprites::font::{render_text, text_width};
-use crate::weapons::{all_weapons, WeaponId};
+use crate::weapons::{all_weapons, get_weapon, weapon_unlock, WeaponId};
 
 /// Border color per weapon type.
 fn weapon_color(id: WeaponId) -> Color {
@@ -9,6 +10,8 @@ fn weapon_color(id: WeaponId) -> Color {
         WeaponId::Sword => [180, 180, 200],
         WeaponId::Spear => [160, 120, 80],
         WeaponId::Daggers => [80, 180, 80],
+        WeaponId::Bow => [200, 160, 90],
+        WeaponId::Staff => [170, 110, 240],
     }
 }
 
@@ -24,18 +27,38 @@ pub struct WeaponSelectScreen {
     pub active: bool,
     confirmed: Option<usize>,
     flash_timer: f32,
+    /// Seed typed for the run; empty for a fresh one.
+    seed_text: String,
+    /// Keys go to the seed field instead of the weapon cards.
+    editing_seed: bool,
+    /// Weapons whose unlock milestone hasn't been met yet.
+    locked: Vec<WeaponId>,
 }
 
 impl WeaponSelectScreen {
-    pub fn new() -> Self {
+    /// `seed` pre-fills the seed field (from `--seed`).
+    pub fn new(seed: Option<u64>, locked: Vec<WeaponId>) -> Self {
         Self {
             selected: 0,
             active: true,
             confirmed: None,
             flash_timer: 0.0,
+            seed_text: seed.map(format_seed).unwrap_or_default(),
+            editing_seed: false,
+            locked,
         }
     }
 
+    /// The seed field is taking keys, so Esc shouldn't leave the screen.
+    pub fn is_editing_seed(&self) -> bool {
+        self.editing_seed
+    }
+
+    /// Seed the player asked for, if they typed a valid one.
+    pub fn seed(&self) -> Option<u64> {
+        parse_seed(&self.seed_text)
+    }
+
     /// Handle input. Returns Some(WeaponId) when selection animation completes.
     pub fn update(&mut self, input: &InputState, dt: f32) -> Option<WeaponId> {
         if !self.active {
@@ -53,6 +76,26 @@ impl WeaponSelectScreen {
             return None;
         }
 
+        // Tab opens the seed field; Tab or Esc closes it
+        if self.editing_seed {
+            for &c in input.typed() {
+                if c.is_ascii_alphanumeric() && self.seed_text.len() < MAX_SEED_LEN {
+                    self.seed_text.push(c.to_ascii_uppercase());
+                }
+            }
+            if input.is_pressed(GameKey::Erase) {
+                self.seed_text.pop();
+            }
+            if input.is_pressed(GameKey::Map) || input.is_pressed(GameKey::Pause) {
+                self.editing_seed = false;
+            }
+            return None;
+        }
+        if input.is_pressed(GameKey::Map) {
+            self.editing_seed = true;
+            return None;
+        }
+
         let weapons = all_weapons();
         let count = weapons.len();
 
@@ -63,7 +106,7 @@ impl WeaponSelectScreen {
             self.selected += 1;
         }
 
-        if input.is_pressed(GameKey::Attack) {
+        if input.is_pressed(GameKey::Attack) && !self.locked.contains(&weapons[self.selected].id) {
             self.confirmed = Some(self.selected);
             self.flash_timer = FLASH_DURATION;
         }
@@ -117,11 +160,33 @@ impl WeaponSelectScreen {
 
         // Navigation hint
         if self.confirmed.is_none() {
-            let hint = "LEFT/RIGHT - ATTACK SELECT";
-            let hw = text_width(hint);
+            // A locked card names its milestone instead
+            let selected = weapons[self.selected].id;
+            let (hint, hint_color): (String, Color) = match weapon_unlock(selected) {
+                Some(unlock) if self.locked.contains(&selected) => {
+                    let (floor, with) = (unlock.floor, get_weapon(unlock.with).name);
+                    let needs = format!("REACH FLOOR {floor} WITH {}", with.to_uppercase());
+                    (needs, [180, 80, 80])
+                }
+                _ => ("LEFT/RIGHT - ATTACK SELECT".to_string(), [120, 120, 120]),
+            };
+            let hw = text_width(&hint);
             let hx = (fw - hw) / 2;
             let hy = start_y + CARD_H + 4;
-            render_text(fb, hint, hx, hy, [120, 120, 120]);
+            render_text(fb, &hint, hx, hy, hint_color);
+
+            let (seed_line, seed_color): (String, Color) = if self.editing_seed {
+                (format!("SEED {}_", self.seed_text), [255, 255, 255])
+            } else if self.seed_text.is_empty() {
+                ("TAB - SEED".to_string(), [120, 120, 120])
+            } else if self.seed().is_none() {
+                // Too big for 64 bits: the run falls back to a fresh seed
+                (format!("SEED {}", self.seed_text), [180, 80, 80])
+            } else {
+                (format!("SEED {}", self.seed_text), [255, 200, 50])
+            };
+            let sw = text_width(&seed_line);
+            render_text(fb, &seed_line, (fw - sw) / 2, hy + 7, seed_color);
         }
     }
 
@@ -134,7 +199,12 @@ impl WeaponSelectScreen {
         selected: bool,
         confirmed: bool,
     ) {
-        let border_color = weapon_color(weapon.id);
+        let locked = self.locked.contains(&weapon.id);
+        let border_color = if locked {
+            [70, 70, 80]
+        } else {
+            weapon_color(weapon.id)
+        };
 
         let bg: Color = if confirmed {
             [50, 50, 80]
@@ -194,6 +264,12 @@ impl WeaponSelectScreen {
             [120, 120, 130]
         };
 
+        if locked {
+            let lw = text_width("LOCKED");
+            render_text(fb, "LOCKED", x + (CARD_W - lw) / 2, y + 15, [110, 70, 70]);
+            return;
+        }
+
         let dmg_str = format!("DMG {}", weapon.base_damage);
         render_text(fb, &dmg_str, x + 2, y + 9, stat_color);
 
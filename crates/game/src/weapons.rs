@@ -1,21 +1,89 @@
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+use engine::animation::AnimationData;
+use serde::{Deserialize, Serialize};
+
+use crate::sprites;
+
+/// Seconds of drawing for a bow shot at full power.
+pub const BOW_FULL_DRAW: f32 = 0.8;
+/// Extra arrow damage at full draw, scaled down for shorter draws.
+pub const BOW_DRAW_DAMAGE: f32 = 3.0;
+/// Enemies a fully drawn arrow passes through before it stops.
+pub const BOW_MAX_PIERCE: f32 = 3.0;
+/// Walking speed factor while holding a draw.
+pub const BOW_DRAW_MOVE_MULT: f32 = 0.5;
+/// Casts a full staff holds.
+pub const STAFF_CHARGES: u32 = 4;
+/// Seconds for the staff to regain one charge.
+pub const STAFF_RECHARGE: f32 = 1.0;
+/// Bolts in each staff burst, fanned out by `STAFF_SPREAD` radians apiece.
+pub const STAFF_BOLTS: u32 = 3;
+pub const STAFF_SPREAD: f32 = 0.35;
+/// Seconds after a swing ends in which the next attack continues the combo.
+pub const COMBO_WINDOW: f32 = 0.4;
+/// Seconds Attack must be held for a heavy attack.
+pub const HEAVY_CHARGE_TIME: f32 = 0.6;
+pub const HEAVY_DAMAGE_MULT: i32 = 2;
+pub const HEAVY_KNOCKBACK_MULT: f32 = 2.0;
+/// Heavy attacks reach this much further than the weapon's normal swing.
+pub const HEAVY_REACH_MULT: f32 = 1.5;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
 pub enum WeaponId {
     Sword,
     Spear,
     Daggers,
+    Bow,
+    Staff,
+}
+
+/// How a weapon delivers its damage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WeaponClass {
+    /// Swings a hitbox in front of the player.
+    Melee,
+    /// Press to start drawing, press again to loose a piercing arrow. A
+    /// longer draw hits harder and pierces more enemies.
+    Bow,
+    /// Each cast spends a charge on a short-range fan of bolts; charges
+    /// refill over time.
+    Staff,
+}
+
+/// One swing in a weapon's combo string.
+pub struct ComboStep {
+    /// Its HitboxOn/HitboxOff events set the active window. Ranged weapons
+    /// fire on HitboxOn and have no hitbox of their own.
+    pub anim: &'static AnimationData,
+    /// Added to the weapon's base damage
+    pub bonus_damage: i32,
+    /// Scales the weapon's knockback
+    pub knockback_mult: f32,
+}
+
+impl ComboStep {
+    const fn new(anim: &'static AnimationData, bonus_damage: i32, knockback_mult: f32) -> Self {
+        Self {
+            anim,
+            bonus_damage,
+            knockback_mult,
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct WeaponDef {
     pub id: WeaponId,
     pub name: &'static str,
+    pub class: WeaponClass,
     pub base_damage: i32,
     pub attack_cooldown: f32,
     pub hitbox_w: f32,
     pub hitbox_h: f32,
     pub hitbox_offset_x: f32,
     pub hitbox_offset_y: f32,
-    pub active_frame_start: usize,
-    pub active_frame_end: usize,
+    /// Swings in order; attacking again within `COMBO_WINDOW` of one ending
+    /// moves on to the next, wrapping back to the first.
+    pub combo: &'static [ComboStep],
     pub knockback_force: f32,
     #[allow(dead_code)] // Will be rendered in weapon select UI
     pub description: &'static str,
@@ -26,14 +94,18 @@ pub struct WeaponDef {
 static SWORD: WeaponDef = WeaponDef {
     id: WeaponId::Sword,
     name: "Sword",
+    class: WeaponClass::Melee,
     base_damage: 2,
     attack_cooldown: 0.35,
     hitbox_w: 12.0,
     hitbox_h: 8.0,
     hitbox_offset_x: 8.0,
     hitbox_offset_y: 3.0,
-    active_frame_start: 2,
-    active_frame_end: 3,
+    combo: &[
+        ComboStep::new(&sprites::SWORD_ATTACK_ANIM, 0, 1.0),
+        ComboStep::new(&sprites::SWORD_BACKSWING_ANIM, 0, 1.0),
+        ComboStep::new(&sprites::SWORD_FINISHER_ANIM, 1, 2.0),
+    ],
     knockback_force: 60.0,
     description: "Reliable blade",
     speed_label: "Normal",
@@ -43,14 +115,17 @@ static SWORD: WeaponDef = WeaponDef {
 static SPEAR: WeaponDef = WeaponDef {
     id: WeaponId::Spear,
     name: "Spear",
+    class: WeaponClass::Melee,
     base_damage: 3,
     attack_cooldown: 0.5,
     hitbox_w: 18.0,
     hitbox_h: 4.0,
     hitbox_offset_x: 10.0,
     hitbox_offset_y: 5.0,
-    active_frame_start: 2,
-    active_frame_end: 3,
+    combo: &[
+        ComboStep::new(&sprites::SPEAR_ATTACK_ANIM, 0, 1.0),
+        ComboStep::new(&sprites::SPEAR_LUNGE_ANIM, 1, 1.5),
+    ],
     knockback_force: 80.0,
     description: "Precise thrust",
     speed_label: "Slow",
@@ -60,36 +135,136 @@ static SPEAR: WeaponDef = WeaponDef {
 static DAGGERS: WeaponDef = WeaponDef {
     id: WeaponId::Daggers,
     name: "Daggers",
+    class: WeaponClass::Melee,
     base_damage: 1,
     attack_cooldown: 0.15,
     hitbox_w: 6.0,
     hitbox_h: 10.0,
     hitbox_offset_x: 5.0,
     hitbox_offset_y: 2.0,
-    active_frame_start: 1,
-    active_frame_end: 2,
+    combo: &[
+        ComboStep::new(&sprites::DAGGERS_ATTACK_ANIM, 0, 1.0),
+        ComboStep::new(&sprites::DAGGERS_ATTACK_ANIM, 0, 1.0),
+        ComboStep::new(&sprites::DAGGERS_FLURRY_ANIM, 1, 2.0),
+    ],
     knockback_force: 30.0,
     description: "Rapid strikes",
     speed_label: "Fast",
     range_label: "Short",
 };
 
+static BOW: WeaponDef = WeaponDef {
+    id: WeaponId::Bow,
+    name: "Bow",
+    class: WeaponClass::Bow,
+    base_damage: 1,
+    attack_cooldown: 0.3,
+    hitbox_w: 0.0,
+    hitbox_h: 0.0,
+    hitbox_offset_x: 0.0,
+    hitbox_offset_y: 0.0,
+    combo: &[ComboStep::new(&sprites::BOW_RELEASE_ANIM, 0, 1.0)],
+    knockback_force: 50.0,
+    description: "Charged piercing shots",
+    speed_label: "Slow",
+    range_label: "Far",
+};
+
+static STAFF: WeaponDef = WeaponDef {
+    id: WeaponId::Staff,
+    name: "Staff",
+    class: WeaponClass::Staff,
+    base_damage: 1,
+    attack_cooldown: 0.25,
+    hitbox_w: 0.0,
+    hitbox_h: 0.0,
+    hitbox_offset_x: 0.0,
+    hitbox_offset_y: 0.0,
+    combo: &[ComboStep::new(&sprites::STAFF_CAST_ANIM, 0, 1.0)],
+    knockback_force: 50.0,
+    description: "Spell bursts from charges",
+    speed_label: "Normal",
+    range_label: "Short",
+};
+
+/// A weapon as patched by `defs/weapons.json`.
 pub fn get_weapon(id: WeaponId) -> &'static WeaponDef {
-    match id {
-        WeaponId::Sword => &SWORD,
-        WeaponId::Spear => &SPEAR,
-        WeaponId::Daggers => &DAGGERS,
+    all_weapons()
+        .into_iter()
+        .find(|w| w.id == id)
+        .expect("missing weapon definition")
+}
+
+/// Every weapon as patched by `defs/weapons.json`, in select-screen order.
+pub fn all_weapons() -> [&'static WeaponDef; 5] {
+    match crate::defs::weapons() {
+        Some(loaded) => std::array::from_fn(|i| &loaded[i]),
+        None => default_weapons(),
     }
 }
 
-pub fn all_weapons() -> [&'static WeaponDef; 3] {
-    [&SWORD, &SPEAR, &DAGGERS]
+/// The compiled-in weapons, before any overrides.
+pub fn default_weapons() -> [&'static WeaponDef; 5] {
+    [&SWORD, &SPEAR, &DAGGERS, &BOW, &STAFF]
+}
+
+/// The weapon every new save starts with.
+pub const STARTER_WEAPON: WeaponId = WeaponId::Sword;
+
+/// Milestone that unlocks a weapon: reach `floor` in a run with `with`.
+pub struct WeaponUnlock {
+    pub weapon: WeaponId,
+    pub with: WeaponId,
+    pub floor: u32,
+}
+
+/// Each weapon past the starter unlocks from a run with the one before it.
+pub const WEAPON_UNLOCKS: &[WeaponUnlock] = &[
+    WeaponUnlock {
+        weapon: WeaponId::Spear,
+        with: WeaponId::Sword,
+        floor: 3,
+    },
+    WeaponUnlock {
+        weapon: WeaponId::Daggers,
+        with: WeaponId::Spear,
+        floor: 3,
+    },
+    WeaponUnlock {
+        weapon: WeaponId::Bow,
+        with: WeaponId::Daggers,
+        floor: 4,
+    },
+    WeaponUnlock {
+        weapon: WeaponId::Staff,
+        with: WeaponId::Bow,
+        floor: 5,
+    },
+];
+
+/// The milestone that unlocks a weapon; None for the starter.
+pub fn weapon_unlock(id: WeaponId) -> Option<&'static WeaponUnlock> {
+    WEAPON_UNLOCKS.iter().find(|u| u.weapon == id)
+}
+
+/// Damage and enemies pierced by an arrow drawn for `draw` seconds.
+pub fn arrow_power(base_damage: i32, draw: f32) -> (i32, u32) {
+    let t = (draw / BOW_FULL_DRAW).clamp(0.0, 1.0);
+    let damage = base_damage + (t * BOW_DRAW_DAMAGE).round() as i32;
+    (damage, (t * BOW_MAX_PIERCE).floor() as u32)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The weapons that swing a hitbox.
+    fn melee_weapons() -> impl Iterator<Item = &'static WeaponDef> {
+        all_weapons()
+            .into_iter()
+            .filter(|w| w.class == WeaponClass::Melee)
+    }
+
     #[test]
     fn test_get_weapon_returns_correct_sword() {
         let w = get_weapon(WeaponId::Sword);
@@ -121,12 +296,14 @@ mod tests {
     }
 
     #[test]
-    fn test_all_weapons_returns_three_distinct() {
+    fn test_all_weapons_returns_five_distinct() {
         let weapons = all_weapons();
-        assert_eq!(weapons.len(), 3);
+        assert_eq!(weapons.len(), 5);
         assert_eq!(weapons[0].id, WeaponId::Sword);
         assert_eq!(weapons[1].id, WeaponId::Spear);
         assert_eq!(weapons[2].id, WeaponId::Daggers);
+        assert_eq!(weapons[3].id, WeaponId::Bow);
+        assert_eq!(weapons[4].id, WeaponId::Staff);
     }
 
     #[test]
@@ -146,9 +323,8 @@ mod tests {
 
     #[test]
     fn test_spear_has_longest_range() {
-        let weapons = all_weapons();
         let spear = get_weapon(WeaponId::Spear);
-        for w in &weapons {
+        for w in melee_weapons() {
             assert!(spear.hitbox_w >= w.hitbox_w);
             assert!(spear.hitbox_offset_x >= w.hitbox_offset_x);
         }
@@ -174,19 +350,65 @@ mod tests {
     }
 
     #[test]
-    fn test_active_frames_valid() {
+    fn test_attack_anim_opens_hitbox_after_windup() {
+        use engine::animation::AnimEvent;
         for w in &all_weapons() {
-            assert!(w.active_frame_start <= w.active_frame_end);
-            assert!(w.active_frame_start > 0, "active frames should not start at frame 0 (wind-up needed)");
+            for step in w.combo {
+                let on = step
+                    .anim
+                    .events
+                    .iter()
+                    .find(|e| e.event == AnimEvent::HitboxOn)
+                    .expect("attack animation must open a hitbox");
+                assert!(
+                    on.frame > 0,
+                    "hitbox should not open on frame 0 (wind-up needed)"
+                );
+                assert!(on.frame < step.anim.frames.len());
+            }
         }
     }
 
+    #[test]
+    fn test_melee_combos_end_on_a_finisher() {
+        for w in melee_weapons() {
+            assert!(w.combo.len() >= 2);
+            let last = w.combo.last().unwrap();
+            assert!(w.combo[..w.combo.len() - 1]
+                .iter()
+                .all(|s| s.knockback_mult < last.knockback_mult));
+        }
+    }
+
+    #[test]
+    fn test_arrow_power_grows_with_draw() {
+        assert_eq!(arrow_power(1, 0.0), (1, 0));
+        assert_eq!(arrow_power(1, BOW_FULL_DRAW * 0.5), (3, 1));
+        assert_eq!(arrow_power(1, BOW_FULL_DRAW * 2.0), (4, 3));
+    }
+
     #[test]
     fn test_hitbox_dimensions_positive() {
-        for w in &all_weapons() {
+        for w in melee_weapons() {
             assert!(w.hitbox_w > 0.0);
             assert!(w.hitbox_h > 0.0);
             assert!(w.hitbox_offset_x > 0.0);
         }
     }
+
+    #[test]
+    fn test_every_weapon_is_reachable() {
+        let mut unlocked = vec![STARTER_WEAPON];
+        for unlock in WEAPON_UNLOCKS {
+            assert!(
+                unlocked.contains(&unlock.with),
+                "{:?} unlocks too early",
+                unlock.weapon
+            );
+            assert!(!unlocked.contains(&unlock.weapon));
+            unlocked.push(unlock.weapon);
+        }
+        assert_eq!(unlocked.len(), all_weapons().len());
+        assert!(weapon_unlock(STARTER_WEAPON).is_none());
+    }
 }